@@ -0,0 +1,68 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use spawn_wasm_erc721::ERC721;
+
+const SIZES: [u64; 3] = [1_000, 100_000, 1_000_000];
+const HOLDERS: u64 = 100;
+
+/// `size` tokenlık koleksiyon: tamamı toplu mint'lenir, her onuncu token farklı sahiplere dağıtılır
+fn collection(size: u64) -> ERC721 {
+    let mut erc721 = ERC721::new("owner".to_string());
+    erc721.mint_batch("owner".to_string(), 1, size, String::new()).unwrap();
+    for token_id in (1..=size).step_by(10) {
+        let holder = format!("holder{}", (token_id / 10) % HOLDERS);
+        erc721.transfer("owner".to_string(), holder, token_id).unwrap();
+    }
+    erc721
+}
+
+fn bench_mint(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mint");
+    for &size in &SIZES {
+        let mut erc721 = collection(size);
+        let mut next_id = size + 1;
+        group.bench_function(BenchmarkId::from_parameter(size), |b| {
+            b.iter(|| {
+                erc721.mint("owner".to_string(), next_id).unwrap();
+                next_id += 1;
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_transfer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transfer");
+    for &size in &SIZES {
+        let mut erc721 = collection(size);
+        let token_id = 2; // Toplu mint aralığından, henüz açık kaydı olmayan token
+        let mut owners = ["owner".to_string(), "alice".to_string()];
+        group.bench_function(BenchmarkId::from_parameter(size), |b| {
+            b.iter(|| {
+                erc721.transfer(owners[0].clone(), owners[1].clone(), token_id).unwrap();
+                owners.swap(0, 1);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_enumeration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("enumeration");
+    group.sample_size(10);
+    for &size in &SIZES {
+        let erc721 = collection(size);
+        group.bench_with_input(BenchmarkId::new("tokens_of_owner", size), &erc721, |b, erc721| {
+            b.iter(|| erc721.tokens_of_owner("holder7".to_string()))
+        });
+        group.bench_with_input(BenchmarkId::new("all_tokens_page", size), &erc721, |b, erc721| {
+            b.iter(|| erc721.all_tokens_page((size / 2) as u32, 100))
+        });
+        group.bench_with_input(BenchmarkId::new("cursor_chunk", size), &erc721, |b, erc721| {
+            b.iter(|| erc721.token_cursor().next_chunk(erc721, 100))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_mint, bench_transfer, bench_enumeration);
+criterion_main!(benches);
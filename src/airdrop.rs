@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::hash::keccak256;
+use crate::merkle::{self, MerkleTree};
+use crate::bytes::{decode_hex, to_hex};
+
+/// Talep edilen sıraları bit başına bir kayıtla tutan bit haritası
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct ClaimBitmap {
+    words: Vec<u64>, // Her kelime 64 sırayı kapsar
+}
+
+impl ClaimBitmap {
+    pub(crate) fn with_capacity(bits: u64) -> ClaimBitmap {
+        ClaimBitmap {
+            words: vec![0; bits.div_ceil(64) as usize],
+        }
+    }
+
+    pub(crate) fn get(&self, index: u64) -> bool {
+        self.words
+            .get((index / 64) as usize)
+            .map(|word| word & (1 << (index % 64)) != 0)
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn set(&mut self, index: u64) {
+        self.words[(index / 64) as usize] |= 1 << (index % 64);
+    }
+}
+
+/// Merkle köküyle tanımlanan ve `start_id + index` tokenlarını dağıtan airdrop
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Airdrop {
+    root: [u8; 32],
+    start_id: u64,
+    end_id: u64, // Dahil
+    claimed: ClaimBitmap,
+}
+
+impl Airdrop {
+    pub(crate) fn new(root: [u8; 32], start_id: u64, end_id: u64) -> Result<Airdrop, String> {
+        if end_id < start_id {
+            return Err("Token range end must not be before its start".to_string());
+        }
+        Ok(Airdrop {
+            root,
+            start_id,
+            end_id,
+            claimed: ClaimBitmap::with_capacity(end_id - start_id + 1),
+        })
+    }
+
+    pub(crate) fn is_claimed(&self, index: u64) -> bool {
+        self.claimed.get(index)
+    }
+
+    /// Kanıtı doğrular ve sıraya düşen Token ID'yi döner; talep henüz işaretlenmez
+    pub(crate) fn verify_claim(&self, recipient: &str, index: u64, proof: &[[u8; 32]]) -> Result<u64, String> {
+        if index > self.end_id - self.start_id {
+            return Err("Index is outside the airdrop".to_string());
+        }
+        if self.is_claimed(index) {
+            return Err("Airdrop already claimed".to_string());
+        }
+        if !merkle::verify(&self.root, airdrop_leaf(index, recipient), proof) {
+            return Err("Invalid airdrop proof".to_string());
+        }
+        Ok(self.start_id + index)
+    }
+
+    pub(crate) fn mark_claimed(&mut self, index: u64) {
+        self.claimed.set(index);
+    }
+}
+
+/// Airdrop yaprağı: keccak256(abi.encodePacked(uint256 index, address account));
+/// hex olmayan adresler utf8 baytlarıyla eklenir
+pub(crate) fn airdrop_leaf(index: u64, account: &str) -> [u8; 32] {
+    let mut data = vec![0u8; 24];
+    data.extend_from_slice(&index.to_be_bytes());
+    match decode_hex(account) {
+        Ok(bytes) if account.starts_with("0x") && bytes.len() == 20 => data.extend_from_slice(&bytes),
+        _ => data.extend_from_slice(account.as_bytes()),
+    }
+    keccak256(&data)
+}
+
+/// Alıcı listesinden airdrop Merkle ağacı ve kanıtları üretir; listedeki sıra talep sırasıdır
+#[wasm_bindgen]
+pub struct AirdropList {
+    tree: MerkleTree,
+}
+
+#[wasm_bindgen]
+impl AirdropList {
+    #[wasm_bindgen(constructor)]
+    pub fn new(recipients: Vec<String>) -> AirdropList {
+        let leaves = recipients
+            .iter()
+            .enumerate()
+            .map(|(index, recipient)| airdrop_leaf(index as u64, recipient))
+            .collect();
+        AirdropList {
+            tree: MerkleTree::new(leaves),
+        }
+    }
+
+    /// `create_airdrop` için kullanılacak kökü döner
+    pub fn root(&self) -> String {
+        to_hex(&self.tree.root())
+    }
+
+    /// Sıradaki alıcının kanıtını döner
+    pub fn proof(&self, index: u64) -> Vec<String> {
+        self.tree.proof(index as usize).iter().map(|hash| to_hex(hash)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_bitmap() {
+        let mut bitmap = ClaimBitmap::with_capacity(50_000);
+        assert_eq!(bitmap.words.len(), 782);
+        bitmap.set(0);
+        bitmap.set(49_999);
+        assert!(bitmap.get(0) && bitmap.get(49_999));
+        assert!(!bitmap.get(1) && !bitmap.get(50_000));
+    }
+
+    #[test]
+    fn test_verify_claim() {
+        let recipients: Vec<String> = (0..5).map(|i| format!("user{}", i)).collect();
+        let tree = MerkleTree::new(recipients.iter().enumerate().map(|(i, r)| airdrop_leaf(i as u64, r)).collect());
+        let mut airdrop = Airdrop::new(tree.root(), 100, 104).unwrap();
+
+        assert_eq!(airdrop.verify_claim("user3", 3, &tree.proof(3)), Ok(103));
+        assert!(airdrop.verify_claim("user2", 3, &tree.proof(3)).is_err());
+        assert!(airdrop.verify_claim("user3", 5, &tree.proof(3)).is_err());
+        airdrop.mark_claimed(3);
+        assert!(airdrop.verify_claim("user3", 3, &tree.proof(3)).is_err());
+        assert!(Airdrop::new(tree.root(), 5, 4).is_err());
+    }
+}
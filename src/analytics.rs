@@ -0,0 +1,116 @@
+use std::collections::{BTreeMap, HashMap};
+use wasm_bindgen::prelude::*;
+
+use crate::events::LogEntry;
+
+/// Bir adresin sahip olduğu token sayısı
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HolderBalance {
+    pub address: String,
+    pub balance: u64,
+}
+
+/// Sahiplik dağılımı histogramının bir sütunu: `tokens` adet token'a sahip cüzdan sayısı
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DistributionBucket {
+    pub tokens: u64,
+    pub wallets: u64,
+}
+
+/// Belirli bir andaki tekil sahip sayısı
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HolderCountPoint {
+    pub timestamp: u64, // Unix zamanı (ms), denetim günlüğüyle aynı
+    pub holders: u64,
+}
+
+/// Bakiyeleri azalan sırada döner; eşit bakiyeler adrese göre sıralanır
+pub(crate) fn top_holders<'a>(balances: impl Iterator<Item = (&'a String, u64)>, n: usize) -> Vec<HolderBalance> {
+    let mut holders: Vec<HolderBalance> = balances
+        .map(|(address, balance)| HolderBalance {
+            address: address.clone(),
+            balance,
+        })
+        .collect();
+    holders.sort_by(|a, b| b.balance.cmp(&a.balance).then_with(|| a.address.cmp(&b.address)));
+    holders.truncate(n);
+    holders
+}
+
+/// Cüzdan başına token sayısı histogramı; sütunlar token sayısına göre artan sıradadır
+pub(crate) fn distribution(balances: impl Iterator<Item = u64>) -> Vec<DistributionBucket> {
+    let mut buckets = BTreeMap::new();
+    for balance in balances {
+        *buckets.entry(balance).or_insert(0) += 1;
+    }
+    buckets
+        .into_iter()
+        .map(|(tokens, wallets)| DistributionBucket { tokens, wallets })
+        .collect()
+}
+
+/// Denetim günlüğündeki mint, transfer ve burn kayıtlarını yeniden oynatarak sahip sayısının seyrini çıkarır
+///
+/// Aynı zaman damgalı kayıtlar tek noktada birleşir.
+pub(crate) fn holders_over_time<'a>(entries: impl Iterator<Item = &'a LogEntry>) -> Vec<HolderCountPoint> {
+    let mut balances: HashMap<&str, u64> = HashMap::new();
+    let mut points: Vec<HolderCountPoint> = Vec::new();
+    for entry in entries {
+        let quantity = match (entry.kind.as_str(), entry.token_id, entry.range_end) {
+            ("BatchMint", Some(start), Some(end)) => end - start + 1,
+            ("Mint", _, _) | ("Transfer", _, _) | ("Burn", _, _) => 1,
+            _ => continue,
+        };
+        if entry.from == entry.to {
+            continue;
+        }
+        if let Some(from) = entry.from.as_deref() {
+            let balance = balances.entry(from).or_insert(0);
+            *balance = balance.saturating_sub(quantity);
+            if *balance == 0 {
+                balances.remove(from);
+            }
+        }
+        if let Some(to) = entry.to.as_deref() {
+            *balances.entry(to).or_insert(0) += quantity;
+        }
+
+        let point = HolderCountPoint {
+            timestamp: entry.timestamp,
+            holders: balances.len() as u64,
+        };
+        match points.last_mut() {
+            Some(last) if last.timestamp == point.timestamp => *last = point,
+            _ => points.push(point),
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holders_over_time() {
+        let mut entries = [
+            LogEntry::new("BatchMint").token_range(1, 3).to("owner"),
+            LogEntry::new("Transfer").token(1).from("owner").to("alice"),
+            LogEntry::new("Approval").token(2).from("owner").to("bob"),
+            LogEntry::new("Transfer").token(2).from("owner").to("bob"),
+            LogEntry::new("Burn").token(1).from("alice"),
+        ];
+        for (entry, timestamp) in entries.iter_mut().zip([100, 200, 300, 300, 400]) {
+            entry.timestamp = timestamp;
+        }
+
+        let points: Vec<(u64, u64)> = holders_over_time(entries.iter())
+            .into_iter()
+            .map(|point| (point.timestamp, point.holders))
+            .collect();
+        assert_eq!(points, vec![(100, 1), (200, 2), (300, 3), (400, 2)]);
+    }
+}
@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
+
+
+/// Zincir üstünde saklanan tipli özellik değeri
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AttributeValue {
+    String(String),
+    Number(f64),
+    Boost(f64),
+    Date(u64), // Unix zamanı (saniye)
+}
+
+impl AttributeValue {
+    /// JS'ten gelen değeri `display_type` bilgisine göre tipli değere çevirir
+    ///
+    /// `display_type` boşsa metin `String`, sayı `Number` olur; "number", "boost_number"
+    /// (veya "boost") ve "date" sayısal değer bekler.
+    pub(crate) fn parse(
+        display_type: Option<&str>,
+        text: Option<String>,
+        number: Option<f64>,
+    ) -> Result<AttributeValue, String> {
+        match (display_type, text, number) {
+            (None, Some(text), _) | (Some("string"), Some(text), _) => Ok(AttributeValue::String(text)),
+            (None, None, Some(number)) | (Some("number"), _, Some(number)) => Ok(AttributeValue::Number(number)),
+            (Some("boost_number"), _, Some(number)) | (Some("boost"), _, Some(number)) => {
+                Ok(AttributeValue::Boost(number))
+            }
+            (Some("date"), _, Some(number)) if number >= 0.0 => Ok(AttributeValue::Date(number as u64)),
+            (Some(display_type), _, _) => Err(format!("Invalid value for display type: {}", display_type)),
+            (None, None, None) => Err("Attribute value must be a string or a number".to_string()),
+        }
+    }
+
+    /// OpenSea `display_type` karşılığı
+    fn display_type(&self) -> Option<&'static str> {
+        match self {
+            AttributeValue::String(_) => None,
+            AttributeValue::Number(_) => Some("number"),
+            AttributeValue::Boost(_) => Some("boost_number"),
+            AttributeValue::Date(_) => Some("date"),
+        }
+    }
+
+    /// Özellik indeksinde kullanılan anahtar (sayılar için `5`, `2.5` gibi)
+    pub(crate) fn index_key(&self) -> String {
+        match self {
+            AttributeValue::String(text) => text.clone(),
+            AttributeValue::Number(number) | AttributeValue::Boost(number) => number.to_string(),
+            AttributeValue::Date(timestamp) => timestamp.to_string(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            AttributeValue::String(text) => json!(text),
+            AttributeValue::Number(number) | AttributeValue::Boost(number) => json!(number),
+            AttributeValue::Date(timestamp) => json!(timestamp),
+        }
+    }
+}
+
+/// Token'a ait tek bir özellik (trait)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Attribute {
+    pub trait_type: String,
+    pub value: AttributeValue,
+}
+
+impl Attribute {
+    /// OpenSea metadata standardındaki `{display_type?, trait_type, value}` nesnesi
+    pub(crate) fn to_json(&self) -> Value {
+        let mut object = json!({ "trait_type": self.trait_type, "value": self.value.to_json() });
+        if let Some(display_type) = self.value.display_type() {
+            object["display_type"] = json!(display_type);
+        }
+        object
+    }
+
+    /// `to_json` biçimindeki nesneyi tipli özelliğe çevirir
+    pub(crate) fn from_json(value: &Value) -> Result<Attribute, String> {
+        let trait_type = value["trait_type"].as_str().ok_or("Attribute trait_type must be a string")?;
+        let value = AttributeValue::parse(
+            value["display_type"].as_str(),
+            value["value"].as_str().map(str::to_string),
+            value["value"].as_f64(),
+        )?;
+        Ok(Attribute {
+            trait_type: trait_type.to_string(),
+            value,
+        })
+    }
+}
+
+/// Tokenların zincir üstü özelliklerini eklenme sırasıyla saklar
+///
+/// Özellik indeksi her değişiklikte güncellenir; sorgular tüm tokenları taramaz.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct AttributeStore {
+    attributes: BTreeMap<u64, Vec<Attribute>>, // Token ID -> Özellikler
+    index: BTreeMap<String, BTreeMap<String, BTreeSet<u64>>>, // Özellik -> Değer -> Tokenlar
+}
+
+impl AttributeStore {
+    /// Özelliği ekler; aynı `trait_type` varsa değerini günceller
+    pub(crate) fn set(&mut self, token_id: u64, trait_type: String, value: AttributeValue) {
+        let key = value.index_key();
+        let attributes = self.attributes.entry(token_id).or_default();
+        let previous = match attributes.iter_mut().find(|attribute| attribute.trait_type == trait_type) {
+            Some(attribute) => Some(std::mem::replace(&mut attribute.value, value)),
+            None => {
+                attributes.push(Attribute { trait_type: trait_type.clone(), value });
+                None
+            }
+        };
+        if let Some(previous) = previous {
+            self.index_remove(&trait_type, &previous.index_key(), token_id);
+        }
+        self.index_insert(&trait_type, key, token_id);
+    }
+
+    /// Özelliği kaldırır; özellik yoksa `false` döner
+    pub(crate) fn remove(&mut self, token_id: u64, trait_type: &str) -> bool {
+        let attributes = match self.attributes.get_mut(&token_id) {
+            Some(attributes) => attributes,
+            None => return false,
+        };
+        let position = match attributes.iter().position(|attribute| attribute.trait_type == trait_type) {
+            Some(position) => position,
+            None => return false,
+        };
+        let removed = attributes.remove(position);
+        if attributes.is_empty() {
+            self.attributes.remove(&token_id);
+        }
+        self.index_remove(trait_type, &removed.value.index_key(), token_id);
+        true
+    }
+
+    pub(crate) fn get(&self, token_id: u64) -> Vec<Attribute> {
+        self.attributes.get(&token_id).cloned().unwrap_or_default()
+    }
+
+    /// Token'ın tüm özelliklerini siler (burn sırasında kullanılır)
+    pub(crate) fn clear_token(&mut self, token_id: u64) {
+        for attribute in self.attributes.remove(&token_id).unwrap_or_default() {
+            self.index_remove(&attribute.trait_type, &attribute.value.index_key(), token_id);
+        }
+    }
+
+    /// Özelliği verilen değerde olan tokenları `start_after`'dan sonra, en fazla `limit` adet döner
+    pub(crate) fn tokens_with(
+        &self,
+        trait_type: &str,
+        value: &str,
+        start_after: Option<u64>,
+        limit: usize,
+    ) -> Vec<u64> {
+        let lower = match start_after {
+            Some(token_id) => Bound::Excluded(token_id),
+            None => Bound::Unbounded,
+        };
+        self.index
+            .get(trait_type)
+            .and_then(|values| values.get(value))
+            .map(|tokens| tokens.range((lower, Bound::Unbounded)).take(limit).copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Özelliği olan tokenları ve özelliklerini döner
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (u64, &[Attribute])> {
+        self.attributes.iter().map(|(&token_id, attributes)| (token_id, attributes.as_slice()))
+    }
+
+    /// En az bir özelliği olan token sayısını döner
+    pub(crate) fn token_count(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Özelliği verilen değerde olan token sayısını döner
+    pub(crate) fn count_with(&self, trait_type: &str, value: &str) -> usize {
+        self.index
+            .get(trait_type)
+            .and_then(|values| values.get(value))
+            .map(BTreeSet::len)
+            .unwrap_or(0)
+    }
+
+    fn index_insert(&mut self, trait_type: &str, key: String, token_id: u64) {
+        self.index
+            .entry(trait_type.to_string())
+            .or_default()
+            .entry(key)
+            .or_default()
+            .insert(token_id);
+    }
+
+    fn index_remove(&mut self, trait_type: &str, key: &str, token_id: u64) {
+        if let Some(values) = self.index.get_mut(trait_type) {
+            if let Some(tokens) = values.get_mut(key) {
+                tokens.remove(&token_id);
+                if tokens.is_empty() {
+                    values.remove(key);
+                }
+            }
+            if values.is_empty() {
+                self.index.remove(trait_type);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_store() {
+        let mut store = AttributeStore::default();
+        store.set(1, "Base".to_string(), AttributeValue::String("Starfish".to_string()));
+        store.set(1, "Speed".to_string(), AttributeValue::Boost(10.0));
+        store.set(1, "Base".to_string(), AttributeValue::String("Whale".to_string()));
+
+        let attributes = store.get(1);
+        assert_eq!(attributes.len(), 2);
+        assert_eq!(attributes[0].value, AttributeValue::String("Whale".to_string()));
+        assert_eq!(attributes[1].to_json()["display_type"], "boost_number");
+        assert_eq!(Attribute::from_json(&attributes[1].to_json()).as_ref(), Ok(&attributes[1]));
+
+        assert!(store.remove(1, "Speed"));
+        assert!(!store.remove(1, "Speed"));
+        assert!(!store.remove(2, "Base"));
+    }
+
+    #[test]
+    fn test_trait_index() {
+        let mut store = AttributeStore::default();
+        for token_id in 1..=5 {
+            store.set(token_id, "Level".to_string(), AttributeValue::Number(1.0));
+        }
+        store.set(3, "Level".to_string(), AttributeValue::Number(2.0));
+        store.set(3, "Level".to_string(), AttributeValue::Number(2.0));
+
+        assert_eq!(store.tokens_with("Level", "1", None, 10), vec![1, 2, 4, 5]);
+        assert_eq!(store.tokens_with("Level", "1", Some(2), 1), vec![4]);
+        assert_eq!(store.tokens_with("Level", "2", None, 10), vec![3]);
+
+        store.remove(4, "Level");
+        store.clear_token(5);
+        assert_eq!(store.count_with("Level", "1"), 2);
+        assert!(store.tokens_with("Color", "Red", None, 10).is_empty());
+    }
+
+    #[test]
+    fn test_parse_attribute_value() {
+        assert_eq!(
+            AttributeValue::parse(None, Some("Gold".to_string()), None),
+            Ok(AttributeValue::String("Gold".to_string()))
+        );
+        assert_eq!(AttributeValue::parse(None, None, Some(3.0)), Ok(AttributeValue::Number(3.0)));
+        assert_eq!(
+            AttributeValue::parse(Some("date"), None, Some(1_546_360_800.0)),
+            Ok(AttributeValue::Date(1_546_360_800))
+        );
+        assert!(AttributeValue::parse(Some("boost_number"), Some("fast".to_string()), None).is_err());
+        assert!(AttributeValue::parse(None, None, None).is_err());
+    }
+}
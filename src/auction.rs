@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+/// Artan fiyatlı (İngiliz) açık artırma
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Auction {
+    pub seller: String,
+    pub reserve: u64, // İlk teklifin ulaşması gereken en düşük tutar
+    pub end_time: u64, // Son teklif anı (Unix saniye); son dakika teklifleri uzatır
+    pub highest_bidder: Option<String>,
+    pub highest_bid: u64,
+}
+
+/// Açık artırmalar ve teklif kuralları
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct AuctionHouse {
+    auctions: BTreeMap<u64, Auction>, // Token ID -> Açık Artırma
+    min_increment_bps: u32, // Yeni teklifin öncekini geçmesi gereken oran (baz puan)
+    extension_window: u64, // Bitişe bu kadar kala gelen teklif süreyi bu kadar uzatır (saniye)
+}
+
+impl Default for AuctionHouse {
+    fn default() -> AuctionHouse {
+        AuctionHouse {
+            auctions: BTreeMap::new(),
+            min_increment_bps: 500,
+            extension_window: 10 * 60,
+        }
+    }
+}
+
+impl AuctionHouse {
+    /// Ayarları aynı, açık artırma kaydı olmayan kopya (`clone_with` için)
+    pub(crate) fn settings_only(&self) -> AuctionHouse {
+        AuctionHouse {
+            auctions: BTreeMap::new(),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn configure(&mut self, min_increment_bps: u32, extension_window: u64) -> Result<(), String> {
+        if min_increment_bps > 10_000 {
+            return Err("Minimum increment cannot exceed 10000 basis points".to_string());
+        }
+        self.min_increment_bps = min_increment_bps;
+        self.extension_window = extension_window;
+        Ok(())
+    }
+
+    pub(crate) fn create(&mut self, token_id: u64, seller: &str, reserve: u64, end_time: u64) -> Result<(), String> {
+        if self.auctions.contains_key(&token_id) {
+            return Err("Token is already being auctioned".to_string());
+        }
+        self.auctions.insert(
+            token_id,
+            Auction {
+                seller: seller.to_string(),
+                reserve,
+                end_time,
+                highest_bidder: None,
+                highest_bid: 0,
+            },
+        );
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, token_id: u64) -> Option<&Auction> {
+        self.auctions.get(&token_id)
+    }
+
+    /// Kabul edilecek en düşük teklif
+    pub(crate) fn minimum_bid(&self, token_id: u64) -> Option<u64> {
+        let auction = self.auctions.get(&token_id)?;
+        Some(match auction.highest_bidder {
+            None => auction.reserve.max(1),
+            Some(_) => {
+                let increment = (auction.highest_bid as u128 * self.min_increment_bps as u128 / 10_000) as u64;
+                auction.highest_bid.saturating_add(increment.max(1))
+            }
+        })
+    }
+
+    /// Teklifi kaydeder ve geçilen önceki teklifi (iade için) döner
+    pub(crate) fn bid(
+        &mut self,
+        token_id: u64,
+        bidder: &str,
+        amount: u64,
+        now: u64,
+    ) -> Result<Option<(String, u64)>, String> {
+        let minimum = self.minimum_bid(token_id).ok_or("Auction does not exist")?;
+        let window = self.extension_window;
+        let auction = self.auctions.get_mut(&token_id).expect("auction exists");
+        if now >= auction.end_time {
+            return Err("Auction has ended".to_string());
+        }
+        if amount < minimum {
+            return Err(format!("Bid must be at least {}", minimum));
+        }
+
+        // Son dakika teklifi (anti-sniping): diğer katılımcılara yanıt süresi tanınır
+        if auction.end_time - now < window {
+            auction.end_time = now + window;
+        }
+        let previous = auction.highest_bidder.replace(bidder.to_string());
+        let previous_bid = std::mem::replace(&mut auction.highest_bid, amount);
+        Ok(previous.map(|bidder| (bidder, previous_bid)))
+    }
+
+    /// Süresi dolmuş açık artırmayı kapatıp döner
+    pub(crate) fn close(&mut self, token_id: u64, now: u64) -> Result<Auction, String> {
+        let auction = self.auctions.get(&token_id).ok_or("Auction does not exist")?;
+        if now < auction.end_time {
+            return Err(format!("Auction ends at {}", auction.end_time));
+        }
+        Ok(self.auctions.remove(&token_id).expect("auction exists"))
+    }
+
+    /// Teklif almamış açık artırmayı iptal edip döner
+    pub(crate) fn cancel(&mut self, token_id: u64) -> Result<Auction, String> {
+        let auction = self.auctions.get(&token_id).ok_or("Auction does not exist")?;
+        if auction.highest_bidder.is_some() {
+            return Err("Auction already has bids".to_string());
+        }
+        Ok(self.auctions.remove(&token_id).expect("auction exists"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bidding_rules() {
+        let mut house = AuctionHouse::default();
+        house.configure(1_000, 100).unwrap();
+        house.create(1, "seller", 50, 1_000).unwrap();
+        assert!(house.create(1, "seller", 50, 1_000).is_err());
+
+        assert!(house.bid(1, "alice", 49, 10).is_err());
+        assert_eq!(house.bid(1, "alice", 50, 10), Ok(None));
+        assert_eq!(house.minimum_bid(1), Some(55));
+        assert!(house.bid(1, "bob", 54, 20).is_err());
+        assert_eq!(house.bid(1, "bob", 55, 20), Ok(Some(("alice".to_string(), 50))));
+        assert!(house.cancel(1).is_err());
+
+        // A bid in the last 100 seconds pushes the end back
+        house.bid(1, "alice", 70, 950).unwrap();
+        assert_eq!(house.get(1).unwrap().end_time, 1_050);
+        assert!(house.close(1, 1_049).is_err());
+        assert!(house.bid(1, "bob", 100, 1_050).is_err());
+        assert_eq!(house.close(1, 1_050).unwrap().highest_bid, 70);
+    }
+}
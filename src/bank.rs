@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+
+/// Adres başına simüle edilmiş ETH bakiyeleri ve kontratın kendi bakiyesi (wei benzeri birim)
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Bank {
+    balances: BTreeMap<String, u64>, // Adres -> Bakiye
+    contract_balance: u64, // Ödemelerle kontratta biriken, henüz çekilmemiş gelir
+    escrowed: u64, // Teklif vb. için emanette tutulan toplam miktar
+}
+
+impl Bank {
+    /// Adrese simülasyon için bakiye ekler
+    pub(crate) fn deposit(&mut self, address: &str, amount: u64) -> Result<(), String> {
+        let balance = self.balances.entry(address.to_string()).or_default();
+        *balance = balance.checked_add(amount).ok_or("Balance overflow")?;
+        Ok(())
+    }
+
+    pub(crate) fn balance_of(&self, address: &str) -> u64 {
+        self.balances.get(address).copied().unwrap_or(0)
+    }
+
+    /// Bakiyenin yeterli olup olmadığını kontrol eder
+    pub(crate) fn ensure_funds(&self, address: &str, amount: u64) -> Result<(), String> {
+        if self.balance_of(address) < amount {
+            return Err(format!("Insufficient balance: {}", self.balance_of(address)));
+        }
+        Ok(())
+    }
+
+    /// İki adres arasında ödeme yapar
+    pub(crate) fn transfer(&mut self, from: &str, to: &str, amount: u64) -> Result<(), String> {
+        self.debit(from, amount)?;
+        self.deposit(to, amount)
+    }
+
+    /// `from` adresinden sırayla yapılacak ödemelerin hepsinin yapılabileceğini durumu değiştirmeden doğrular
+    pub(crate) fn ensure_payouts(&self, from: &str, payouts: &[(String, u64)]) -> Result<(), String> {
+        let mut balances: BTreeMap<&str, u64> = BTreeMap::new();
+        for (to, amount) in payouts {
+            let available = *balances.entry(from).or_insert_with(|| self.balance_of(from));
+            if available < *amount {
+                return Err(format!("Insufficient balance: {}", available));
+            }
+            balances.insert(from, available - amount);
+            let balance = balances.entry(to).or_insert_with(|| self.balance_of(to));
+            *balance = balance.checked_add(*amount).ok_or("Balance overflow")?;
+        }
+        Ok(())
+    }
+
+    /// Ödemeleri `from` adresinden yapar; herhangi biri yapılamayacaksa hiçbiri yapılmaz
+    pub(crate) fn pay_out(&mut self, from: &str, payouts: &[(String, u64)]) -> Result<(), String> {
+        self.ensure_payouts(from, payouts)?;
+        for (to, amount) in payouts {
+            self.transfer(from, to, *amount)?;
+        }
+        Ok(())
+    }
+
+    /// Adresten kontrata ödeme yapar
+    pub(crate) fn pay_contract(&mut self, from: &str, amount: u64) -> Result<(), String> {
+        self.debit(from, amount)?;
+        self.contract_balance += amount;
+        Ok(())
+    }
+
+    /// Kontratın tüm bakiyesini adrese aktarır ve aktarılan miktarı döner
+    pub(crate) fn withdraw_contract(&mut self, to: &str) -> Result<u64, String> {
+        let amount = std::mem::take(&mut self.contract_balance);
+        self.deposit(to, amount)?;
+        Ok(amount)
+    }
+
+    pub(crate) fn contract_balance(&self) -> u64 {
+        self.contract_balance
+    }
+
+    /// Adresin bakiyesinden emanete para ayırır
+    pub(crate) fn escrow(&mut self, from: &str, amount: u64) -> Result<(), String> {
+        self.debit(from, amount)?;
+        self.escrowed += amount;
+        Ok(())
+    }
+
+    /// Emanetteki paranın adrese bırakılabileceğini durumu değiştirmeden doğrular
+    pub(crate) fn ensure_release(&self, to: &str, amount: u64) -> Result<(), String> {
+        if amount > self.escrowed {
+            return Err("Escrow balance is too low".to_string());
+        }
+        self.balance_of(to).checked_add(amount).ok_or("Balance overflow")?;
+        Ok(())
+    }
+
+    /// Emanetteki parayı adrese bırakır
+    pub(crate) fn release_escrow(&mut self, to: &str, amount: u64) -> Result<(), String> {
+        self.ensure_release(to, amount)?;
+        self.escrowed -= amount;
+        self.deposit(to, amount)
+    }
+
+    pub(crate) fn escrowed(&self) -> u64 {
+        self.escrowed
+    }
+
+    /// Adresin bakiyesinden düşer; sıfır tutar hiç bakiyesi olmayan adres için de geçerlidir
+    pub(crate) fn debit(&mut self, from: &str, amount: u64) -> Result<(), String> {
+        self.ensure_funds(from, amount)?;
+        if let Some(balance) = self.balances.get_mut(from) {
+            *balance -= amount;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_payments() {
+        let mut bank = Bank::default();
+        bank.deposit("alice", 100).unwrap();
+        assert!(bank.pay_contract("alice", 101).is_err());
+        bank.pay_contract("alice", 60).unwrap();
+        assert_eq!(bank.balance_of("alice"), 40);
+        assert_eq!(bank.contract_balance(), 60);
+
+        assert_eq!(bank.withdraw_contract("treasury"), Ok(60));
+        assert_eq!(bank.balance_of("treasury"), 60);
+        assert_eq!(bank.withdraw_contract("treasury"), Ok(0));
+    }
+
+    #[test]
+    fn test_payouts_are_all_or_nothing() {
+        let mut bank = Bank::default();
+        bank.deposit("alice", 100).unwrap();
+        bank.deposit("carol", u64::MAX - 10).unwrap();
+        let payouts = vec![("bob".to_string(), 60), ("carol".to_string(), 20)];
+        assert_eq!(bank.pay_out("alice", &payouts), Err("Balance overflow".to_string()));
+        assert_eq!((bank.balance_of("alice"), bank.balance_of("bob")), (100, 0));
+
+        // Payments back to the payer are credited before the next one is debited
+        let payouts = vec![("alice".to_string(), 100), ("bob".to_string(), 100), ("bob".to_string(), 1)];
+        assert!(bank.ensure_payouts("alice", &payouts).is_err());
+        bank.pay_out("alice", &payouts[..2]).unwrap();
+        assert_eq!((bank.balance_of("alice"), bank.balance_of("bob")), (0, 100));
+    }
+
+    #[test]
+    fn test_escrow() {
+        let mut bank = Bank::default();
+        bank.deposit("alice", 100).unwrap();
+        bank.escrow("alice", 70).unwrap();
+        assert!(bank.escrow("alice", 31).is_err());
+        assert_eq!(bank.escrowed(), 70);
+
+        bank.release_escrow("bob", 50).unwrap();
+        assert!(bank.release_escrow("alice", 21).is_err());
+        assert_eq!(bank.balance_of("bob"), 50);
+        assert_eq!(bank.escrowed(), 20);
+    }
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryInto;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Hex metin ve bayt dönüşümlerinin hataları
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BytesError {
+    /// Hex basamak sayısı tek
+    OddLength { digits: usize },
+    /// Hex olmayan karakter (konum `0x` öneki sayılmadan, karakter cinsinden)
+    InvalidDigit { position: usize, character: char },
+    /// Sabit uzunluklu değerin bayt sayısı beklenenden farklı
+    InvalidLength { expected: usize, actual: usize },
+}
+
+impl fmt::Display for BytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BytesError::OddLength { digits } => write!(f, "Invalid hex: odd number of digits ({})", digits),
+            BytesError::InvalidDigit { position, character } => {
+                write!(f, "Invalid hex: unexpected character {:?} at position {}", character, position)
+            }
+            BytesError::InvalidLength { expected, actual } => {
+                write!(f, "Invalid length: expected {} bytes, got {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BytesError {}
+
+impl From<BytesError> for String {
+    fn from(err: BytesError) -> String {
+        err.to_string()
+    }
+}
+
+/// Baytları `0x` önekli küçük harf hex metne çevirir
+pub fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = String::with_capacity(2 + bytes.len() * 2);
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push(DIGITS[usize::from(byte >> 4)] as char);
+        hex.push(DIGITS[usize::from(byte & 0x0f)] as char);
+    }
+    hex
+}
+
+/// `0x` önekli (veya öneksiz) hex metni baytlara çevirir; büyük ve küçük harf kabul edilir
+pub fn decode_hex(hex: &str) -> Result<Vec<u8>, BytesError> {
+    let digits = hex.strip_prefix("0x").unwrap_or(hex);
+    let nibbles = digits
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            character
+                .to_digit(16)
+                .map(|nibble| nibble as u8)
+                .ok_or(BytesError::InvalidDigit { position, character })
+        })
+        .collect::<Result<Vec<u8>, BytesError>>()?;
+    if !nibbles.len().is_multiple_of(2) {
+        return Err(BytesError::OddLength { digits: nibbles.len() });
+    }
+    Ok(nibbles.chunks(2).map(|pair| pair[0] << 4 | pair[1]).collect())
+}
+
+/// Hex metni tam olarak `N` baytlık diziye çevirir
+pub fn decode_fixed<const N: usize>(hex: &str) -> Result<[u8; N], BytesError> {
+    let bytes = decode_hex(hex)?;
+    bytes.as_slice().try_into().map_err(|_| BytesError::InvalidLength {
+        expected: N,
+        actual: bytes.len(),
+    })
+}
+
+/// `0x` önekli (veya öneksiz) hex metni 32 baytlık özete çevirir
+pub fn parse_hash(hex: &str) -> Result<[u8; 32], BytesError> {
+    decode_fixed(hex)
+}
+
+/// 32 baytlık değer (özet, EIP-712 kelimesi vb.); JSON'da `0x` önekli hex olarak yazılır
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes32(pub [u8; 32]);
+
+impl Bytes32 {
+    pub fn from_hex(hex: &str) -> Result<Bytes32, BytesError> {
+        decode_fixed(hex).map(Bytes32)
+    }
+
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.0)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for Bytes32 {
+    fn from(bytes: [u8; 32]) -> Bytes32 {
+        Bytes32(bytes)
+    }
+}
+
+impl From<Bytes32> for [u8; 32] {
+    fn from(value: Bytes32) -> [u8; 32] {
+        value.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes32 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Bytes32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl std::str::FromStr for Bytes32 {
+    type Err = BytesError;
+
+    fn from_str(hex: &str) -> Result<Bytes32, BytesError> {
+        Bytes32::from_hex(hex)
+    }
+}
+
+impl Serialize for Bytes32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes32 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Bytes32, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Bytes32::from_hex(&hex).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Baytları `0x` önekli hex metne çevirir
+#[wasm_bindgen]
+pub fn bytes_to_hex(bytes: &[u8]) -> String {
+    to_hex(bytes)
+}
+
+/// Hex metni baytlara çevirir; `length` verilirse bayt sayısı ona eşit olmalıdır
+#[wasm_bindgen]
+pub fn hex_to_bytes(hex: String, length: Option<usize>) -> Result<Vec<u8>, String> {
+    let bytes = decode_hex(&hex)?;
+    match length {
+        Some(expected) if expected != bytes.len() => Err(BytesError::InvalidLength {
+            expected,
+            actual: bytes.len(),
+        }
+        .into()),
+        _ => Ok(bytes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_roundtrip_and_errors() {
+        assert_eq!(to_hex(&[0x00, 0xab, 0xff]), "0x00abff");
+        assert_eq!(decode_hex("0x00ABff").unwrap(), vec![0x00, 0xab, 0xff]);
+        assert_eq!(decode_hex("").unwrap(), Vec::<u8>::new());
+
+        assert_eq!(decode_hex("0xabc"), Err(BytesError::OddLength { digits: 3 }));
+        assert_eq!(
+            decode_hex("0x+1"),
+            Err(BytesError::InvalidDigit {
+                position: 0,
+                character: '+'
+            })
+        );
+        assert!(decode_hex("0xé1").is_err());
+        assert_eq!(
+            hex_to_bytes("0x1234".to_string(), Some(3)),
+            Err("Invalid length: expected 3 bytes, got 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bytes32() {
+        let hex = format!("0x{}", "ab".repeat(32));
+        let value: Bytes32 = hex.parse().unwrap();
+        assert_eq!(value, Bytes32([0xab; 32]));
+        assert_eq!(value.to_string(), hex);
+        assert_eq!(serde_json::to_string(&value).unwrap(), format!("\"{}\"", hex));
+        assert_eq!(serde_json::from_str::<Bytes32>(&format!("\"{}\"", hex)).unwrap(), value);
+
+        assert_eq!(
+            Bytes32::from_hex("0x1234"),
+            Err(BytesError::InvalidLength {
+                expected: 32,
+                actual: 2
+            })
+        );
+        assert!(serde_json::from_str::<Bytes32>("\"0x12\"").is_err());
+    }
+}
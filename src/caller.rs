@@ -0,0 +1,143 @@
+use std::cell::Cell;
+use wasm_bindgen::prelude::*;
+
+use crate::eip712::recover_address;
+use crate::hash::personal_message_hash;
+
+thread_local! {
+    static TEST_MODE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Test modunu açar veya kapatır; açıkken `Caller::impersonate` imzasız çağıran oluşturabilir
+#[wasm_bindgen]
+pub fn set_test_mode(enabled: bool) {
+    TEST_MODE.with(|mode| mode.set(enabled));
+}
+
+/// Test modunun açık olup olmadığını döner
+#[wasm_bindgen]
+pub fn test_mode() -> bool {
+    TEST_MODE.with(Cell::get)
+}
+
+/// İşlemi gönderen doğrulanmış adres (`msg.sender`)
+///
+/// Yalnızca imzalı bir oturum mesajından veya test modunda `impersonate` ile oluşturulabilir.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Caller {
+    address: String,
+    impersonated: bool,
+}
+
+#[wasm_bindgen]
+impl Caller {
+    /// `personal_sign` ile imzalanmış mesajdan çağıranı oluşturur; imza `address`'e ait olmalıdır
+    pub fn from_signature(address: String, message: String, signature: String) -> Result<Caller, String> {
+        let recovered = recover_address(&personal_message_hash(message.as_bytes()), &signature)?;
+        if !recovered.eq_ignore_ascii_case(&address) {
+            return Err("Signature does not match caller address".to_string());
+        }
+        Ok(Caller {
+            address: recovered,
+            impersonated: false,
+        })
+    }
+
+    /// İmza olmadan herhangi bir adres adına çağıran oluşturur (yalnızca test modunda)
+    pub fn impersonate(address: String) -> Result<Caller, String> {
+        if !test_mode() {
+            return Err("Impersonation is only allowed in test mode".to_string());
+        }
+        if address.is_empty() {
+            return Err("Caller address cannot be empty".to_string());
+        }
+        Ok(Caller {
+            address,
+            impersonated: true,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// Çağıran imza yerine `impersonate` ile mi oluşturuldu
+    #[wasm_bindgen(getter)]
+    pub fn impersonated(&self) -> bool {
+        self.impersonated
+    }
+}
+
+/// Kontrata bağlanmış çağıran; bağlıyken işlemlerde bildirilen her çağıran adresi onunla eşleşmelidir
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Session(Option<Caller>);
+
+impl Session {
+    pub(crate) fn connect(&mut self, caller: &Caller) {
+        self.0 = Some(caller.clone());
+    }
+
+    pub(crate) fn disconnect(&mut self) {
+        self.0 = None;
+    }
+
+    pub(crate) fn sender(&self) -> Option<String> {
+        self.0.as_ref().map(Caller::address)
+    }
+
+    /// Bağlı çağıran varsa ve `claimed` ondan farklıysa hata döner
+    pub(crate) fn ensure(&self, claimed: &str) -> Result<(), String> {
+        match &self.0 {
+            Some(caller) if !caller.address.eq_ignore_ascii_case(claimed) => Err(format!(
+                "Caller {} does not match the connected sender {}",
+                claimed, caller.address
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Kontratın kendi adına yaptığı iç çağrılar için denetimi askıya alır; `resume` ile geri konur
+    pub(crate) fn suspend(&mut self) -> Session {
+        Session(self.0.take())
+    }
+
+    pub(crate) fn resume(&mut self, session: Session) {
+        *self = session;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip712::tests::sign;
+    use k256::ecdsa::SigningKey;
+
+    #[test]
+    fn test_caller_creation() {
+        let key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let (signature, address) = sign(&key, &personal_message_hash(b"login"));
+
+        let checksummed = address.to_uppercase().replace("0X", "0x");
+        let caller = Caller::from_signature(checksummed, "login".to_string(), signature.clone()).unwrap();
+        assert_eq!((caller.address(), caller.impersonated()), (address.clone(), false));
+        assert!(Caller::from_signature(address.clone(), "logout".to_string(), signature).is_err());
+
+        set_test_mode(false);
+        assert!(Caller::impersonate("user1".to_string()).is_err());
+        set_test_mode(true);
+        assert!(Caller::impersonate("user1".to_string()).unwrap().impersonated());
+
+        let mut session = Session::default();
+        assert!(session.ensure("anyone").is_ok());
+        session.connect(&caller);
+        assert!(session.ensure(&address).is_ok());
+        assert!(session.ensure("user1").is_err());
+
+        let suspended = session.suspend();
+        assert!(session.ensure("user1").is_ok());
+        session.resume(suspended);
+        assert_eq!(session.sender(), Some(address));
+    }
+}
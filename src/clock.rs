@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::now_ms;
+
+/// Zaman tabanlı kurallar için saat: ayarlanmadıysa sistem saatini, ayarlandıysa simüle zamanı kullanır
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Clock {
+    simulated: Option<u64>, // Simüle edilen Unix zamanı (saniye)
+}
+
+impl Clock {
+    /// Şu anki Unix zamanını saniye cinsinden döner
+    pub(crate) fn now(&self) -> u64 {
+        self.simulated.unwrap_or_else(|| (now_ms() / 1_000.0) as u64)
+    }
+
+    /// Şu anki Unix zamanını milisaniye cinsinden döner (denetim günlüğü zaman damgaları için)
+    pub(crate) fn now_ms(&self) -> u64 {
+        match self.simulated {
+            Some(timestamp) => timestamp.saturating_mul(1_000),
+            None => now_ms() as u64,
+        }
+    }
+
+    /// Simüle zamanı ayarlar
+    pub(crate) fn set(&mut self, timestamp: u64) {
+        self.simulated = Some(timestamp);
+    }
+
+    /// Simüle zamanı ileri sarar (ayarlı değilse sistem saatinden başlar)
+    pub(crate) fn advance(&mut self, seconds: u64) {
+        self.simulated = Some(self.now().saturating_add(seconds));
+    }
+
+    /// Sistem saatine geri döner
+    pub(crate) fn use_system_time(&mut self) {
+        self.simulated = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulated_clock() {
+        let mut clock = Clock::default();
+        assert!(clock.now() > 1_600_000_000);
+
+        clock.set(1_000);
+        clock.advance(500);
+        assert_eq!(clock.now(), 1_500);
+
+        clock.use_system_time();
+        assert!(clock.simulated.is_none());
+    }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+/// Bir token'a bağlanmış (ERC-998) alt token
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChildToken {
+    pub contract: String, // Alt token'ın koleksiyonunun adresi (EIP-712 `verifying_contract`)
+    pub token_id: u64,
+}
+
+/// Alt tokenların tutulduğu, üst token'a ait adres
+///
+/// Alt tokenların sahibi bu adres olduğu için üst token transfer edildiğinde alt tokenlar da onunla gider.
+pub(crate) fn holder_address(contract: &str, parent_id: u64) -> String {
+    format!("{}{}", holder_prefix(contract), parent_id)
+}
+
+/// Adres bir üst token'ın alt token adresiyse üst token'ın ID'sini döner
+pub(crate) fn parent_of_holder(contract: &str, address: &str) -> Option<u64> {
+    address.strip_prefix(&holder_prefix(contract))?.parse().ok()
+}
+
+fn holder_prefix(contract: &str) -> String {
+    format!("erc998:{}:", contract.to_lowercase())
+}
+
+/// Üst tokenlar ve bağlı alt tokenları
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Composables {
+    children: BTreeMap<u64, Vec<ChildToken>>, // Üst Token ID -> Alt Tokenlar (bağlanma sırasıyla)
+}
+
+impl Composables {
+    pub(crate) fn attach(&mut self, parent_id: u64, child: ChildToken) {
+        self.children.entry(parent_id).or_default().push(child);
+    }
+
+    /// Alt token'ı üst token'dan ayırır; bağlı değilse `false` döner
+    pub(crate) fn detach(&mut self, parent_id: u64, contract: &str, token_id: u64) -> bool {
+        let children = match self.children.get_mut(&parent_id) {
+            Some(children) => children,
+            None => return false,
+        };
+        let position = children
+            .iter()
+            .position(|child| child.contract.eq_ignore_ascii_case(contract) && child.token_id == token_id);
+        let position = match position {
+            Some(position) => position,
+            None => return false,
+        };
+        children.remove(position);
+        if children.is_empty() {
+            self.children.remove(&parent_id);
+        }
+        true
+    }
+
+    pub(crate) fn children_of(&self, parent_id: u64) -> &[ChildToken] {
+        self.children.get(&parent_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attach_and_detach() {
+        let mut composables = Composables::default();
+        let child = |contract: &str, token_id| ChildToken {
+            contract: contract.to_string(),
+            token_id,
+        };
+        composables.attach(1, child("0xAA", 5));
+        composables.attach(1, child("0xbb", 5));
+        assert_eq!(composables.children_of(1).len(), 2);
+
+        assert!(composables.detach(1, "0xaa", 5));
+        assert!(!composables.detach(1, "0xaa", 5));
+        assert!(composables.detach(1, "0xbb", 5));
+        assert!(composables.children_of(1).is_empty());
+        assert_eq!(holder_address("0xAB", 1), "erc998:0xab:1");
+        assert_eq!(parent_of_holder("0xab", "erc998:0xab:10"), Some(10));
+        assert_eq!(parent_of_holder("0xab", "erc998:0xabc:10"), None);
+    }
+}
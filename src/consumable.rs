@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Uses {
+    remaining: u32,
+    burn_when_depleted: bool, // Son kullanımda token otomatik yakılır
+}
+
+/// Sınırlı sayıda kullanılabilen tokenlar (oyun eşyaları, biletler)
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Consumables {
+    uses: BTreeMap<u64, Uses>, // Token ID -> Kalan Kullanım
+}
+
+impl Consumables {
+    pub(crate) fn set(&mut self, token_id: u64, remaining: u32, burn_when_depleted: bool) {
+        self.uses.insert(
+            token_id,
+            Uses {
+                remaining,
+                burn_when_depleted,
+            },
+        );
+    }
+
+    /// Token sınırlı kullanımlı değilse `None` döner
+    pub(crate) fn remaining(&self, token_id: u64) -> Option<u32> {
+        self.uses.get(&token_id).map(|uses| uses.remaining)
+    }
+
+    /// Bir kullanım düşer; kalan kullanım ve token'ın yakılması gerekip gerekmediğini döner
+    pub(crate) fn consume(&mut self, token_id: u64) -> Result<(u32, bool), String> {
+        let uses = self.uses.get_mut(&token_id).ok_or("Token is not consumable")?;
+        if uses.remaining == 0 {
+            return Err("Token has no uses left".to_string());
+        }
+        uses.remaining -= 1;
+        Ok((uses.remaining, uses.remaining == 0 && uses.burn_when_depleted))
+    }
+
+    pub(crate) fn clear_token(&mut self, token_id: u64) {
+        self.uses.remove(&token_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consume_uses() {
+        let mut consumables = Consumables::default();
+        assert!(consumables.consume(1).is_err());
+        consumables.set(1, 2, false);
+        consumables.set(2, 1, true);
+
+        assert_eq!(consumables.consume(1), Ok((1, false)));
+        assert_eq!(consumables.consume(1), Ok((0, false)));
+        assert!(consumables.consume(1).is_err());
+        assert_eq!(consumables.consume(2), Ok((0, true)));
+        assert_eq!(consumables.remaining(3), None);
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+use crate::royalty::RoyaltyInfo;
+
+/// Pazaryerlerinin `contractURI()` üzerinden okuduğu koleksiyon düzeyi bilgiler
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContractMetadata {
+    pub name: Option<String>,
+    pub symbol: Option<String>, // Koleksiyonun kısa sembolü (ERC-721 `symbol()`)
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub external_link: Option<String>,
+    pub fee_recipient: Option<String>, // Boşsa varsayılan royalty alıcısı kullanılır
+}
+
+#[wasm_bindgen]
+impl ContractMetadata {
+    /// Boş bir koleksiyon bilgisi oluşturur
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ContractMetadata {
+        ContractMetadata::default()
+    }
+}
+
+impl ContractMetadata {
+    /// OpenSea uyumlu koleksiyon JSON'unu üretir; ücret oranı varsayılan royalty'den gelir
+    pub(crate) fn to_storefront_json(&self, royalty: Option<&RoyaltyInfo>) -> String {
+        let fee_recipient = self
+            .fee_recipient
+            .clone()
+            .or_else(|| royalty.map(|royalty| royalty.receiver.clone()));
+        json!({
+            "name": self.name,
+            "description": self.description,
+            "image": self.image,
+            "external_link": self.external_link,
+            "seller_fee_basis_points": royalty.map(|royalty| royalty.bps).unwrap_or(0),
+            "fee_recipient": fee_recipient,
+        })
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storefront_json() {
+        let mut metadata = ContractMetadata::new();
+        metadata.name = Some("Spawn".to_string());
+        let royalty = RoyaltyInfo::new("artist".to_string(), 500).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&metadata.to_storefront_json(Some(&royalty))).unwrap();
+        assert_eq!(json["name"], "Spawn");
+        assert_eq!(json["seller_fee_basis_points"], 500);
+        assert_eq!(json["fee_recipient"], "artist");
+        assert!(json["image"].is_null());
+    }
+}
@@ -0,0 +1,54 @@
+use wasm_bindgen::prelude::*;
+
+use crate::erc721::ERC721;
+
+/// Koleksiyonun tokenları üzerinde Token ID sırasıyla ilerleyen imleç
+///
+/// İmleç yalnızca son dönen Token ID'yi tutar; parçalar arasında koleksiyon değişse de
+/// kaldığı yerden devam eder ve aynı token'ı iki kez döndürmez.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenCursor {
+    owner: Option<String>, // Boşsa tüm tokenlar
+    start_after: Option<u64>,
+    done: bool,
+}
+
+#[wasm_bindgen]
+impl TokenCursor {
+    /// Sonraki en fazla `n` Token ID'yi döner; `n`'den az dönmesi imlecin bittiğini gösterir
+    pub fn next_chunk(&mut self, collection: &ERC721, n: u32) -> Vec<u64> {
+        if self.done || n == 0 {
+            return Vec::new();
+        }
+        let chunk = collection.tokens_after(self.owner.as_deref(), self.start_after, n as usize);
+        if chunk.len() < n as usize {
+            self.done = true;
+        }
+        if let Some(&last) = chunk.last() {
+            self.start_after = Some(last);
+        }
+        chunk
+    }
+
+    /// Tüm tokenların dönülüp dönülmediğini belirtir
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// İmleci başa sarar
+    pub fn reset(&mut self) {
+        self.start_after = None;
+        self.done = false;
+    }
+}
+
+impl TokenCursor {
+    pub(crate) fn new(owner: Option<String>) -> TokenCursor {
+        TokenCursor {
+            owner,
+            start_after: None,
+            done: false,
+        }
+    }
+}
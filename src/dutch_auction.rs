@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// Fiyatı zamanla düşen (Hollanda usulü) birincil mint ayarları
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DutchAuction {
+    pub start_price: u64,
+    pub end_price: u64, // Süre dolduktan sonra sabit kalan taban fiyat
+    pub start_time: u64, // Unix saniye
+    pub duration: u64, // Fiyatın taban fiyata inme süresi (saniye)
+    pub step: u64, // Fiyatın kaç saniyede bir düştüğü
+}
+
+impl DutchAuction {
+    pub(crate) fn new(
+        start_price: u64,
+        end_price: u64,
+        start_time: u64,
+        duration: u64,
+        step: u64,
+    ) -> Result<DutchAuction, String> {
+        if start_price < end_price {
+            return Err("Start price must not be below the end price".to_string());
+        }
+        if duration == 0 || step == 0 {
+            return Err("Duration and step must be greater than zero".to_string());
+        }
+        Ok(DutchAuction {
+            start_price,
+            end_price,
+            start_time,
+            duration,
+            step,
+        })
+    }
+
+    /// Verilen andaki fiyat; tamamlanan her adımda doğrusal olarak düşer
+    pub(crate) fn price_at(&self, now: u64) -> u64 {
+        let elapsed = now.saturating_sub(self.start_time).min(self.duration);
+        let stepped = elapsed - elapsed % self.step;
+        let drop = (self.start_price - self.end_price) as u128 * stepped as u128 / self.duration as u128;
+        self.start_price - drop as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stepped_price_decay() {
+        let auction = DutchAuction::new(1_000, 100, 500, 900, 300).unwrap();
+        assert_eq!(auction.price_at(0), 1_000);
+        assert_eq!(auction.price_at(799), 1_000);
+        assert_eq!(auction.price_at(800), 700);
+        assert_eq!(auction.price_at(1_399), 400);
+        assert_eq!(auction.price_at(1_400), 100);
+        assert_eq!(auction.price_at(10_000), 100);
+
+        assert!(DutchAuction::new(100, 1_000, 0, 900, 300).is_err());
+        assert!(DutchAuction::new(1_000, 100, 0, 900, 0).is_err());
+    }
+}
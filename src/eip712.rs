@@ -0,0 +1,261 @@
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::hash::keccak256;
+use crate::bytes::{decode_hex, parse_hash, to_hex};
+
+/// EIP-712 alan (domain) bilgileri
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub verifying_contract: String, // 0x önekli 20 baytlık adres
+}
+
+impl Default for Eip712Domain {
+    fn default() -> Eip712Domain {
+        Eip712Domain {
+            name: "SpawnWasmERC721".to_string(),
+            version: "1".to_string(),
+            chain_id: 1,
+            verifying_contract: format!("0x{}", "0".repeat(40)),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Eip712Domain {
+    /// Yeni bir alan tanımı oluşturur
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: String, version: String, chain_id: u64, verifying_contract: String) -> Eip712Domain {
+        Eip712Domain {
+            name,
+            version,
+            chain_id,
+            verifying_contract,
+        }
+    }
+
+    /// Alan ayırıcısını (domain separator) hex olarak döner
+    pub fn separator(&self) -> Result<String, String> {
+        self.separator_hash().map(|hash| to_hex(&hash))
+    }
+
+    /// Yapı özetinden imzalanacak EIP-712 özetini hex olarak döner
+    pub fn hash_typed_data(&self, struct_hash: String) -> Result<String, String> {
+        self.digest(&parse_hash(&struct_hash)?).map(|hash| to_hex(&hash))
+    }
+}
+
+impl Eip712Domain {
+    /// keccak256(abi.encode(EIP712Domain typehash, name, version, chainId, verifyingContract))
+    pub(crate) fn separator_hash(&self) -> Result<[u8; 32], String> {
+        let mut data =
+            type_hash("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)").to_vec();
+        data.extend_from_slice(&encode_string(&self.name));
+        data.extend_from_slice(&encode_string(&self.version));
+        data.extend_from_slice(&encode_u64(self.chain_id));
+        data.extend_from_slice(&encode_address(&self.verifying_contract)?);
+        Ok(keccak256(&data))
+    }
+
+    /// keccak256(0x19 0x01 ‖ domainSeparator ‖ structHash)
+    pub(crate) fn digest(&self, struct_hash: &[u8; 32]) -> Result<[u8; 32], String> {
+        let mut message = vec![0x19, 0x01];
+        message.extend_from_slice(&self.separator_hash()?);
+        message.extend_from_slice(struct_hash);
+        Ok(keccak256(&message))
+    }
+}
+
+/// Tip tanımının özetini döner, ör. `Mail(address from,string contents)`
+pub(crate) fn type_hash(signature: &str) -> [u8; 32] {
+    keccak256(signature.as_bytes())
+}
+
+/// Alanları kodlanmış bir yapının özetini döner: keccak256(typeHash ‖ alanlar)
+pub(crate) fn hash_struct(type_signature: &str, fields: &[[u8; 32]]) -> [u8; 32] {
+    let mut data = type_hash(type_signature).to_vec();
+    for field in fields {
+        data.extend_from_slice(field);
+    }
+    keccak256(&data)
+}
+
+/// uint256 ABI kodlaması (32 bayt, büyük uçlu)
+pub(crate) fn encode_u64(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// string ABI kodlaması (içeriğin keccak256 özeti)
+pub(crate) fn encode_string(value: &str) -> [u8; 32] {
+    keccak256(value.as_bytes())
+}
+
+/// address ABI kodlaması (sola sıfır doldurulmuş 32 bayt)
+pub(crate) fn encode_address(address: &str) -> Result<[u8; 32], String> {
+    let bytes = decode_hex(address)?;
+    if bytes.len() != 20 {
+        return Err(format!("Invalid address: {}", address));
+    }
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(&bytes);
+    Ok(word)
+}
+
+/// 65 baytlık (r, s, v) veya 64 baytlık EIP-2098 kısa (r, yParity|s) imzadan imzacının adresini çıkarır
+///
+/// `v` 0/1, 27/28 veya EIP-155 biçiminde (`chain_id * 2 + 35/36`) olabilir; yüksek `s` değerli
+/// (değiştirilebilir) imzalar reddedilir.
+pub(crate) fn recover_address(digest: &[u8; 32], signature: &str) -> Result<String, String> {
+    let mut bytes = decode_hex(signature)?;
+    let parity = match bytes.len() {
+        65 => match bytes[64] {
+            v @ 0..=1 => v,
+            v @ 27..=28 => v - 27,
+            v if v >= 35 => (v - 35) % 2,
+            _ => return Err("Invalid recovery id".to_string()),
+        },
+        64 => {
+            let parity = bytes[32] >> 7;
+            bytes[32] &= 0x7f;
+            parity
+        }
+        _ => return Err("Signature must be 65 or 64 bytes".to_string()),
+    };
+
+    let signature = Signature::from_slice(&bytes[..64]).map_err(|_| "Invalid signature".to_string())?;
+    let recovery_id = RecoveryId::from_byte(parity).ok_or("Invalid recovery id")?;
+    let key = VerifyingKey::recover_from_prehash(digest, &signature, recovery_id)
+        .map_err(|_| "Signature recovery failed".to_string())?;
+    Ok(address_of(&key))
+}
+
+/// Açık anahtarın Ethereum adresi: sıkıştırılmamış (x, y) koordinatlarının keccak256 özetinin son 20 baytı
+pub(crate) fn address_of(key: &VerifyingKey) -> String {
+    let point = key.to_encoded_point(false);
+    to_hex(&keccak256(&point.as_bytes()[1..])[12..])
+}
+
+/// İmzalanan özet ve imzadan imzacının adresini döner (ethers `recoverAddress` karşılığı)
+#[wasm_bindgen]
+pub fn recover_signer(message_hash: String, signature: String) -> Result<String, String> {
+    recover_address(&parse_hash(&message_hash)?, &signature)
+}
+
+/// Solidity `ecrecover` karşılığı: özet ve imzadan imzacının adresini döner
+#[wasm_bindgen]
+pub fn ecrecover(digest: String, signature: String) -> Result<String, String> {
+    recover_signer(digest, signature)
+}
+
+/// Hex açık anahtarın Ethereum adresini döner
+///
+/// Sıkıştırılmış (33 bayt), sıkıştırılmamış (`0x04` önekli 65 bayt) veya öneksiz (64 bayt) anahtar kabul edilir.
+#[wasm_bindgen]
+pub fn address_from_pubkey(pubkey: String) -> Result<String, String> {
+    let mut bytes = decode_hex(&pubkey)?;
+    if bytes.len() == 64 {
+        bytes.insert(0, 0x04);
+    }
+    let key = VerifyingKey::from_sec1_bytes(&bytes).map_err(|_| format!("Invalid public key: {}", pubkey))?;
+    Ok(address_of(&key))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use k256::ecdsa::SigningKey;
+
+    /// Testler için özeti imzalar ve imzacının adresini döner
+    pub(crate) fn sign(key: &SigningKey, digest: &[u8; 32]) -> (String, String) {
+        let (signature, recovery_id) = key.sign_prehash_recoverable(digest).unwrap();
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(27 + recovery_id.to_byte());
+        (to_hex(&bytes), address_of(key.verifying_key()))
+    }
+
+    #[test]
+    fn test_eip712_mail_example() {
+        // Reference vector from the EIP-712 specification
+        let domain = Eip712Domain::new(
+            "Ether Mail".to_string(),
+            "1".to_string(),
+            1,
+            "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_string(),
+        );
+        assert_eq!(
+            domain.separator().unwrap(),
+            "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+
+        let person = "Person(string name,address wallet)";
+        let from = hash_struct(
+            person,
+            &[encode_string("Cow"), encode_address("0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826").unwrap()],
+        );
+        let to = hash_struct(
+            person,
+            &[encode_string("Bob"), encode_address("0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB").unwrap()],
+        );
+        let mail = hash_struct(
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)",
+            &[from, to, encode_string("Hello, Bob!")],
+        );
+        let digest = domain.hash_typed_data(to_hex(&mail)).unwrap();
+        assert_eq!(digest, "0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+
+        let signature = "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d\
+                         07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c";
+        assert_eq!(
+            ecrecover(digest, signature.to_string()).unwrap(),
+            "0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826"
+        );
+    }
+
+    #[test]
+    fn test_recover_signer_and_pubkey_address() {
+        // The public key of private key 1 is the generator point
+        let x = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let y = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        let expected = "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf";
+        assert_eq!(address_from_pubkey(format!("0x04{}{}", x, y)).unwrap(), expected);
+        assert_eq!(address_from_pubkey(format!("0x{}{}", x, y)).unwrap(), expected);
+        assert_eq!(address_from_pubkey(format!("0x02{}", x)).unwrap(), expected);
+        assert!(address_from_pubkey(format!("0x07{}", x)).is_err());
+
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        let key = SigningKey::from_slice(&secret).unwrap();
+        let digest = keccak256(b"message");
+        let (signature, address) = sign(&key, &digest);
+        assert_eq!(address, expected);
+        assert_eq!(recover_signer(to_hex(&digest), signature.clone()).unwrap(), expected);
+
+        // EIP-2098 compact form folds the y parity into the top bit of s
+        let mut compact = decode_hex(&signature).unwrap();
+        let parity = compact.pop().unwrap() - 27;
+        compact[32] |= parity << 7;
+        assert_eq!(recover_signer(to_hex(&digest), to_hex(&compact)).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_recover_rejects_malformed_input() {
+        let key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let digest = keccak256(b"message");
+        let (signature, address) = sign(&key, &digest);
+        assert_eq!(recover_address(&digest, &signature).unwrap(), address);
+
+        assert!(recover_address(&digest, "0x1234").is_err());
+        let mut bytes = decode_hex(&signature).unwrap();
+        bytes[64] = 29;
+        assert!(recover_address(&digest, &to_hex(&bytes)).is_err());
+        assert!(ecrecover("0x12".to_string(), signature).is_err());
+        assert!(encode_address("user1").is_err());
+    }
+}
@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use wasm_bindgen::prelude::*;
+
+use crate::events::{AuditLog, LogEntry};
+use crate::uri::validate_uri;
+use crate::utils::{log_event, now_ms};
+
+/// NFT'lerin yanında yarı takas edilebilir eşyalar için ERC-1155 çoklu token kontratı
+///
+/// Olaylar ve hatalar `ERC721` ile aynı denetim günlüğü ve `log_event` altyapısını kullanır.
+#[wasm_bindgen]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ERC1155 {
+    owner: String,
+    uri: String, // Tüm ID'ler için `{id}` yer tutuculu varsayılan URI
+    token_uris: BTreeMap<u64, String>, // Token ID -> Özel URI
+    balances: BTreeMap<u64, BTreeMap<String, u64>>, // Token ID -> Adres -> Bakiye
+    total_supply: BTreeMap<u64, u64>, // Token ID -> Toplam Arz
+    operator_approvals: BTreeSet<(String, String)>, // (Sahip, Operatör)
+    audit_log: AuditLog,
+}
+
+#[wasm_bindgen]
+impl ERC1155 {
+    /// Yeni bir çoklu token kontratı oluşturur
+    #[wasm_bindgen(constructor)]
+    pub fn new(owner: String, uri: String) -> ERC1155 {
+        ERC1155 {
+            owner,
+            uri,
+            token_uris: BTreeMap::new(),
+            balances: BTreeMap::new(),
+            total_supply: BTreeMap::new(),
+            operator_approvals: BTreeSet::new(),
+            audit_log: AuditLog::default(),
+        }
+    }
+
+    /// Adresin token ID'sindeki bakiyesini döner
+    pub fn balance_of(&self, owner: String, id: u64) -> u64 {
+        self.balance(&owner, id)
+    }
+
+    /// Adres ve ID listelerindeki her çift için bakiyeyi döner
+    pub fn balance_of_batch(&self, owners: Vec<String>, ids: Vec<u64>) -> Result<Vec<u64>, String> {
+        if owners.len() != ids.len() {
+            return Err("Owners and ids length mismatch".to_string());
+        }
+        Ok(owners.iter().zip(&ids).map(|(owner, &id)| self.balance(owner, id)).collect())
+    }
+
+    /// Token ID'sinin toplam arzını döner
+    pub fn total_supply(&self, id: u64) -> u64 {
+        self.total_supply.get(&id).copied().unwrap_or(0)
+    }
+
+    /// Operatöre sahibin tüm tokenları için yetki verir veya yetkiyi kaldırır
+    pub fn set_approval_for_all(&mut self, owner: String, operator: String, approved: bool) -> Result<(), String> {
+        if owner == operator {
+            return Err("Cannot approve yourself as operator".to_string());
+        }
+        if approved {
+            self.operator_approvals.insert((owner.clone(), operator.clone()));
+        } else {
+            self.operator_approvals.remove(&(owner.clone(), operator.clone()));
+        }
+
+        log_event(
+            "Approval For All",
+            &format!("Owner: {}, Operator: {}, Approved: {}", owner, operator, approved),
+        );
+        self.record(
+            LogEntry::new("ApprovalForAll")
+                .from(&owner)
+                .to(&operator)
+                .details(approved.to_string()),
+        );
+        Ok(())
+    }
+
+    /// Operatörün sahibin tüm tokenları için onaylı olup olmadığını döner
+    pub fn is_approved_for_all(&self, owner: String, operator: String) -> bool {
+        self.operator_approvals.contains(&(owner, operator))
+    }
+
+    /// Token aktarır (sahip veya onaylı operatör)
+    pub fn safe_transfer_from(
+        &mut self,
+        operator: String,
+        from: String,
+        to: String,
+        id: u64,
+        amount: u64,
+    ) -> Result<(), String> {
+        self.safe_batch_transfer_from(operator, from, to, vec![id], vec![amount])
+    }
+
+    /// Birden fazla ID'yi tek işlemde aktarır; bakiyelerden biri yetersizse hiçbiri aktarılmaz
+    pub fn safe_batch_transfer_from(
+        &mut self,
+        operator: String,
+        from: String,
+        to: String,
+        ids: Vec<u64>,
+        amounts: Vec<u64>,
+    ) -> Result<(), String> {
+        self.ensure_operator(&operator, &from, "Transfer Failed")?;
+        if to.is_empty() {
+            log_event("Transfer Failed", "Transfer to the zero address");
+            return Err("Cannot transfer to the zero address".to_string());
+        }
+        self.ensure_balances(&from, &ids, &amounts, "Transfer Failed")?;
+
+        for (&id, &amount) in ids.iter().zip(&amounts) {
+            self.debit(&from, id, amount);
+            *self.balances.entry(id).or_default().entry(to.clone()).or_default() += amount;
+        }
+        log_event(
+            "Token Transferred",
+            &format!("IDs: {:?}, Amounts: {:?}, From: {}, To: {}", ids, amounts, from, to),
+        );
+        self.record(transfer_entry(&ids, &amounts).from(&from).to(&to));
+        Ok(())
+    }
+
+    /// Token mint'ler (sadece kontrat sahibi)
+    pub fn mint(&mut self, caller: String, to: String, id: u64, amount: u64) -> Result<(), String> {
+        if caller != self.owner {
+            log_event("Minting Failed", "Unauthorized attempt");
+            return Err("Only the contract owner can mint new tokens".to_string());
+        }
+        if to.is_empty() {
+            return Err("Cannot mint to the zero address".to_string());
+        }
+        let supply = self.total_supply.entry(id).or_default();
+        *supply = supply.checked_add(amount).ok_or("Supply overflow")?;
+
+        *self.balances.entry(id).or_default().entry(to.clone()).or_default() += amount;
+        log_event("Token Minted", &format!("ID: {}, Amount: {}, To: {}", id, amount, to));
+        self.record(transfer_entry(&[id], &[amount]).to(&to));
+        Ok(())
+    }
+
+    /// Token yakar (sahip veya onaylı operatör)
+    pub fn burn(&mut self, operator: String, from: String, id: u64, amount: u64) -> Result<(), String> {
+        self.ensure_operator(&operator, &from, "Burn Failed")?;
+        self.ensure_balances(&from, &[id], &[amount], "Burn Failed")?;
+
+        self.debit(&from, id, amount);
+        *self.total_supply.entry(id).or_default() -= amount;
+        log_event("Token Burned", &format!("ID: {}, Amount: {}, From: {}", id, amount, from));
+        self.record(transfer_entry(&[id], &[amount]).from(&from));
+        Ok(())
+    }
+
+    /// Token ID'sinin metadata URI'sini döner
+    ///
+    /// Özel URI yoksa varsayılan URI'deki `{id}`, standarttaki gibi 64 haneli küçük harf hex ID ile değiştirilir.
+    pub fn uri(&self, id: u64) -> String {
+        match self.token_uris.get(&id) {
+            Some(uri) => uri.clone(),
+            None => self.uri.replace("{id}", &format!("{:064x}", id)),
+        }
+    }
+
+    /// Tüm ID'ler için varsayılan URI'yi ayarlar (sadece kontrat sahibi)
+    pub fn set_uri(&mut self, caller: String, uri: String) -> Result<(), String> {
+        self.ensure_owner(&caller, "URI Update Failed")?;
+        self.uri = uri.clone();
+        log_event("URI Updated", &format!("URI: {}", uri));
+        self.record(LogEntry::new("URI").from(&caller).details(uri));
+        Ok(())
+    }
+
+    /// Tek bir ID'nin URI'sini ayarlar (sadece kontrat sahibi)
+    pub fn set_token_uri(&mut self, caller: String, id: u64, uri: String) -> Result<(), String> {
+        self.ensure_owner(&caller, "URI Update Failed")?;
+        validate_uri(&uri, false).inspect_err(|err| log_event("URI Update Failed", err))?;
+
+        self.token_uris.insert(id, uri.clone());
+        log_event("URI Updated", &format!("ID: {}, URI: {}", id, uri));
+        self.record(LogEntry::new("URI").token(id).from(&caller).details(uri));
+        Ok(())
+    }
+
+    /// Token ID'sini etkileyen tüm işlemleri sırayla döner
+    pub fn history(&self, id: u64) -> Vec<LogEntry> {
+        self.audit_log.history(id, now_ms() as u64)
+    }
+
+    /// Adresin gönderen veya alıcı olduğu tüm işlemleri sırayla döner
+    pub fn history_by_address(&self, address: String) -> Vec<LogEntry> {
+        self.audit_log.history_by_address(&address, now_ms() as u64)
+    }
+}
+
+impl ERC1155 {
+    fn balance(&self, owner: &str, id: u64) -> u64 {
+        self.balances.get(&id).and_then(|holders| holders.get(owner)).copied().unwrap_or(0)
+    }
+
+    /// Bakiyeden düşer; yeterli bakiye önceden kontrol edilmiş olmalıdır
+    fn debit(&mut self, from: &str, id: u64, amount: u64) {
+        let holders = self.balances.get_mut(&id).expect("balance is checked");
+        let balance = holders.get_mut(from).expect("balance is checked");
+        *balance -= amount;
+        if *balance == 0 {
+            holders.remove(from);
+        }
+    }
+
+    fn ensure_owner(&self, caller: &str, event: &str) -> Result<(), String> {
+        if caller != self.owner {
+            log_event(event, "Unauthorized attempt");
+            return Err("Only the contract owner can perform this action".to_string());
+        }
+        Ok(())
+    }
+
+    fn ensure_operator(&self, operator: &str, from: &str, event: &str) -> Result<(), String> {
+        if operator != from && !self.operator_approvals.contains(&(from.to_string(), operator.to_string())) {
+            log_event(event, "Unauthorized attempt");
+            return Err("Caller is not the owner nor an approved operator".to_string());
+        }
+        Ok(())
+    }
+
+    /// Her ID için yeterli bakiye olduğunu kontrol eder (aynı ID birden fazla kez geçebilir)
+    fn ensure_balances(&self, from: &str, ids: &[u64], amounts: &[u64], event: &str) -> Result<(), String> {
+        if ids.len() != amounts.len() {
+            return Err("Ids and amounts length mismatch".to_string());
+        }
+        let mut required: BTreeMap<u64, u64> = BTreeMap::new();
+        for (&id, &amount) in ids.iter().zip(amounts) {
+            let total = required.entry(id).or_default();
+            *total = total.checked_add(amount).ok_or("Amount overflow")?;
+        }
+        for (id, amount) in required {
+            let balance = self.balance(from, id);
+            if balance < amount {
+                log_event(event, &format!("Insufficient balance for ID {}", id));
+                return Err(format!("Insufficient balance for ID {}: {}", id, balance));
+            }
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, entry: LogEntry) {
+        self.audit_log.record(entry, now_ms() as u64);
+    }
+}
+
+/// Tekli ve toplu transferler için `TransferSingle` / `TransferBatch` kaydı
+fn transfer_entry(ids: &[u64], amounts: &[u64]) -> LogEntry {
+    match (ids, amounts) {
+        ([id], [amount]) => LogEntry::new("TransferSingle").token(*id).details(format!("Amount: {}", amount)),
+        _ => LogEntry::new("TransferBatch").details(format!("IDs: {:?}, Amounts: {:?}", ids, amounts)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erc1155_transfers() {
+        let mut items = ERC1155::new("owner".to_string(), "https://items.example/{id}.json".to_string());
+        assert!(items.mint("user1".to_string(), "user1".to_string(), 1, 10).is_err());
+        items.mint("owner".to_string(), "user1".to_string(), 1, 10).unwrap();
+        items.mint("owner".to_string(), "user1".to_string(), 2, 5).unwrap();
+
+        assert!(items.safe_transfer_from("user2".to_string(), "user1".to_string(), "user2".to_string(), 1, 1).is_err());
+        items.set_approval_for_all("user1".to_string(), "user2".to_string(), true).unwrap();
+        items.safe_transfer_from("user2".to_string(), "user1".to_string(), "user2".to_string(), 1, 4).unwrap();
+
+        // A batch that overdraws any id is rejected as a whole
+        let from = "user1".to_string();
+        let to = "user3".to_string();
+        assert!(items.safe_batch_transfer_from(from.clone(), from.clone(), to, vec![1, 2, 2], vec![1, 3, 3]).is_err());
+        items.safe_batch_transfer_from(from.clone(), from, "user3".to_string(), vec![1, 2], vec![1, 5]).unwrap();
+
+        let owners = vec!["user1".to_string(), "user2".to_string(), "user3".to_string(), "user1".to_string()];
+        assert_eq!(items.balance_of_batch(owners, vec![1, 1, 1, 2]), Ok(vec![5, 4, 1, 0]));
+        assert_eq!(items.total_supply(2), 5);
+        assert_eq!(items.history(1).len(), 2);
+        assert_eq!(items.history_by_address("user3".to_string())[0].kind, "TransferBatch");
+    }
+
+    #[test]
+    fn test_erc1155_uris() {
+        let mut items = ERC1155::new("owner".to_string(), "https://items.example/{id}.json".to_string());
+        assert_eq!(items.uri(26), format!("https://items.example/{:0>64}.json", "1a"));
+
+        assert!(items.set_token_uri("owner".to_string(), 1, "ftp://item".to_string()).is_err());
+        let uri = "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string();
+        items.set_token_uri("owner".to_string(), 1, uri).unwrap();
+        assert!(items.uri(1).starts_with("ipfs://"));
+
+        items.mint("owner".to_string(), "user1".to_string(), 1, 3).unwrap();
+        assert!(items.burn("user2".to_string(), "user1".to_string(), 1, 1).is_err());
+        items.burn("user1".to_string(), "user1".to_string(), 1, 3).unwrap();
+        assert_eq!(items.total_supply(1), 0);
+    }
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+use crate::bank::Bank;
+use crate::events::{AuditLog, LogEntry};
+use crate::role_manager::{RoleManager, BURNER_ROLE, MINTER_ROLE};
+use crate::utils::{log_event, now_ms};
+
+/// Ödül, ödeme ve pay tokenları için ERC-20 kontratı
+///
+/// Bakiyeler simüle ETH ile aynı `Bank` defter yapısında tutulur; olaylar `ERC721` ile aynı denetim günlüğünü kullanır.
+#[wasm_bindgen]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ERC20 {
+    name: String,
+    symbol: String,
+    decimals: u8,
+    total_supply: u64,
+    ledger: Bank,
+    allowances: BTreeMap<String, BTreeMap<String, u64>>, // Sahip -> Harcayıcı -> İzin Verilen Miktar
+    roles: RoleManager,
+    audit_log: AuditLog,
+}
+
+#[wasm_bindgen]
+impl ERC20 {
+    /// Yeni bir ERC-20 kontratı oluşturur; sahip MINTER ve BURNER rollerini alır
+    #[wasm_bindgen(constructor)]
+    pub fn new(owner: String, name: String, symbol: String, decimals: u8) -> ERC20 {
+        log_event("ERC20 Created", &format!("Owner: {}, Symbol: {}", owner, symbol));
+        let mut roles = RoleManager::new(owner.clone());
+        for role in [MINTER_ROLE, BURNER_ROLE] {
+            roles
+                .assign_role(owner.clone(), role.to_string(), owner.clone())
+                .expect("owner can always assign roles");
+        }
+        ERC20 {
+            name,
+            symbol,
+            decimals,
+            total_supply: 0,
+            ledger: Bank::default(),
+            allowances: BTreeMap::new(),
+            roles,
+            audit_log: AuditLog::default(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn symbol(&self) -> String {
+        self.symbol.clone()
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    pub fn total_supply(&self) -> u64 {
+        self.total_supply
+    }
+
+    pub fn balance_of(&self, owner: String) -> u64 {
+        self.ledger.balance_of(&owner)
+    }
+
+    /// Harcayıcının sahip adına harcayabileceği kalan miktarı döner
+    pub fn allowance(&self, owner: String, spender: String) -> u64 {
+        self.allowances
+            .get(&owner)
+            .and_then(|spenders| spenders.get(&spender))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Çağıranın bakiyesinden token gönderir
+    pub fn transfer(&mut self, from: String, to: String, amount: u64) -> Result<(), String> {
+        self.move_tokens(&from, &to, amount)
+    }
+
+    /// Harcayıcıya sahip adına harcama izni verir (önceki izni değiştirir)
+    pub fn approve(&mut self, owner: String, spender: String, amount: u64) -> Result<(), String> {
+        if spender.is_empty() {
+            return Err("Cannot approve the zero address".to_string());
+        }
+        self.allowances.entry(owner.clone()).or_default().insert(spender.clone(), amount);
+        log_event("Approval", &format!("Owner: {}, Spender: {}, Amount: {}", owner, spender, amount));
+        self.record(
+            LogEntry::new("Approval")
+                .from(&owner)
+                .to(&spender)
+                .details(format!("Amount: {}", amount)),
+        );
+        Ok(())
+    }
+
+    /// Harcayıcının izni dahilinde sahibin bakiyesinden token gönderir
+    ///
+    /// `u64::MAX` izin sınırsız kabul edilir ve azaltılmaz.
+    pub fn transfer_from(&mut self, spender: String, from: String, to: String, amount: u64) -> Result<(), String> {
+        let allowance = self.allowance(from.clone(), spender.clone());
+        if allowance < amount {
+            log_event("Transfer Failed", &format!("Allowance {} is below {}", allowance, amount));
+            return Err(format!("Insufficient allowance: {}", allowance));
+        }
+
+        self.move_tokens(&from, &to, amount)?;
+        if allowance != u64::MAX {
+            self.allowances.entry(from).or_default().insert(spender, allowance - amount);
+        }
+        Ok(())
+    }
+
+    /// Yeni token basar (sadece MINTER rolü)
+    pub fn mint(&mut self, caller: String, to: String, amount: u64) -> Result<(), String> {
+        self.ensure_role(&caller, MINTER_ROLE, "Minting Failed")?;
+        if to.is_empty() {
+            return Err("Cannot mint to the zero address".to_string());
+        }
+        let total_supply = self.total_supply.checked_add(amount).ok_or("Supply overflow")?;
+
+        self.ledger.deposit(&to, amount)?;
+        self.total_supply = total_supply;
+        log_event("Tokens Minted", &format!("To: {}, Amount: {}", to, amount));
+        self.record(LogEntry::new("Transfer").to(&to).details(format!("Amount: {}", amount)));
+        Ok(())
+    }
+
+    /// Adresin bakiyesinden token yakar (sadece BURNER rolü)
+    pub fn burn(&mut self, caller: String, from: String, amount: u64) -> Result<(), String> {
+        self.ensure_role(&caller, BURNER_ROLE, "Burn Failed")?;
+
+        self.ledger.debit(&from, amount).inspect_err(|err| log_event("Burn Failed", err))?;
+        self.total_supply -= amount;
+        log_event("Tokens Burned", &format!("From: {}, Amount: {}", from, amount));
+        self.record(LogEntry::new("Transfer").from(&from).details(format!("Amount: {}", amount)));
+        Ok(())
+    }
+
+    /// Kullanıcıya rol atar (sadece sahip yapabilir)
+    pub fn assign_role(&mut self, caller: String, role: String, user: String) -> Result<(), String> {
+        self.roles.assign_role(caller.clone(), role.clone(), user.clone())?;
+        self.record(LogEntry::new("RoleGranted").from(&caller).to(&user).details(role));
+        Ok(())
+    }
+
+    /// Kullanıcıdan rolü geri alır (sadece sahip yapabilir)
+    pub fn remove_role(&mut self, caller: String, role: String, user: String) -> Result<(), String> {
+        self.roles.remove_role(caller.clone(), role.clone(), user.clone())?;
+        self.record(LogEntry::new("RoleRevoked").from(&caller).to(&user).details(role));
+        Ok(())
+    }
+
+    /// Kullanıcının kendi rolünden vazgeçmesi (çağıran `user`'ın kendisidir)
+    pub fn renounce_role(&mut self, user: String, role: String) -> Result<(), String> {
+        self.roles.renounce_role(user.clone(), role.clone())?;
+        self.record(LogEntry::new("RoleRevoked").from(&user).to(&user).details(role));
+        Ok(())
+    }
+
+    /// Adresin gönderen veya alıcı olduğu tüm işlemleri sırayla döner
+    pub fn history_by_address(&self, address: String) -> Vec<LogEntry> {
+        self.audit_log.history_by_address(&address, now_ms() as u64)
+    }
+}
+
+impl ERC20 {
+    fn move_tokens(&mut self, from: &str, to: &str, amount: u64) -> Result<(), String> {
+        if to.is_empty() {
+            return Err("Cannot transfer to the zero address".to_string());
+        }
+        self.ledger
+            .transfer(from, to, amount)
+            .inspect_err(|err| log_event("Transfer Failed", err))?;
+        log_event("Tokens Transferred", &format!("From: {}, To: {}, Amount: {}", from, to, amount));
+        self.record(LogEntry::new("Transfer").from(from).to(to).details(format!("Amount: {}", amount)));
+        Ok(())
+    }
+
+    fn ensure_role(&self, caller: &str, role: &str, event: &str) -> Result<(), String> {
+        if !self.roles.has_role(role, caller) {
+            log_event(event, "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", role));
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, entry: LogEntry) {
+        self.audit_log.record(entry, now_ms() as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erc20_transfers_and_allowances() {
+        let mut token = ERC20::new("owner".to_string(), "Reward".to_string(), "RWD".to_string(), 18);
+        assert!(token.mint("user1".to_string(), "user1".to_string(), 100).is_err());
+        token.mint("owner".to_string(), "user1".to_string(), 100).unwrap();
+
+        token.transfer("user1".to_string(), "user2".to_string(), 30).unwrap();
+        assert!(token.transfer("user2".to_string(), "user3".to_string(), 31).is_err());
+
+        token.approve("user1".to_string(), "market".to_string(), 50).unwrap();
+        let (market, user1, user3) = ("market".to_string(), "user1".to_string(), "user3".to_string());
+        assert!(token.transfer_from(market.clone(), user1.clone(), user3.clone(), 51).is_err());
+        token.transfer_from(market.clone(), user1.clone(), user3.clone(), 20).unwrap();
+        assert_eq!(token.allowance(user1.clone(), market.clone()), 30);
+
+        token.approve(user1.clone(), market.clone(), u64::MAX).unwrap();
+        token.transfer_from(market.clone(), user1.clone(), user3, 50).unwrap();
+        assert_eq!(token.allowance(user1, market), u64::MAX);
+        assert_eq!(token.balance_of("user3".to_string()), 70);
+    }
+
+    #[test]
+    fn test_erc20_burn_requires_role() {
+        let mut token = ERC20::new("owner".to_string(), "Reward".to_string(), "RWD".to_string(), 18);
+        token.mint("owner".to_string(), "user1".to_string(), 100).unwrap();
+
+        assert!(token.burn("user1".to_string(), "user1".to_string(), 10).is_err());
+        token.assign_role("owner".to_string(), BURNER_ROLE.to_string(), "user1".to_string()).unwrap();
+        assert!(token.burn("user1".to_string(), "user1".to_string(), 101).is_err());
+        token.burn("user1".to_string(), "user1".to_string(), 40).unwrap();
+        assert_eq!(token.total_supply(), 60);
+        assert_eq!(token.history_by_address("user1".to_string()).len(), 3);
+    }
+}
@@ -1,14 +1,300 @@
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use tsify::Tsify;
 use wasm_bindgen::prelude::*;
-use web_sys::console;
+
+use crate::airdrop::Airdrop;
+use crate::analytics::{self, DistributionBucket, HolderBalance, HolderCountPoint};
+use crate::attributes::{Attribute, AttributeStore, AttributeValue};
+use crate::auction::{Auction, AuctionHouse};
+use crate::bank::Bank;
+use crate::bytes::{parse_hash, to_hex};
+use crate::caller::{Caller, Session};
+use crate::clock::Clock;
+use crate::composable::{self, ChildToken, Composables};
+use crate::consumable::Consumables;
+use crate::contract_metadata::ContractMetadata;
+use crate::dutch_auction::DutchAuction;
+use crate::cursor::TokenCursor;
+use crate::eip712::{encode_address, encode_u64, hash_struct, recover_address, Eip712Domain};
+use crate::events::{AuditLog, EventFilter, LogEntry, Retention};
+use crate::governor::{Governor, GovernorSettings, ProposalState, VoteType};
+use crate::hash::{keccak256, KeccakWriter};
+use crate::holder_snapshot::{HolderSnapshots, SnapshotId};
+use crate::hooks::{HookContext, HookKind, Hooks, RustHook};
+use crate::interner::{AddressId, AddressInterner, MemoryStats};
+use crate::marketplace::{Bundle, Listing, Marketplace, Offer, MARKETPLACE_OPERATOR};
+use crate::merge::{MergeRule, Merging};
+use crate::merkle::{self, allowlist_leaf, ownership_leaf, MerkleTree};
+use crate::metadata;
+use crate::metrics::Metrics;
+use crate::migration::{self, Migrations};
+use crate::order::Order;
+use crate::owned_index::OwnedIndex;
+use crate::pagination::{AddressPage, TokenPage};
+use crate::perf::{OpTiming, PerfCounters};
+use crate::phases::{MintPhase, PhaseSchedule};
+use crate::progression::Progression;
+use crate::rand::SeededRng;
+use crate::rarity::{self, RarityMethod, RarityTable};
+use crate::role_manager::{
+    AccessConfig, RoleManager, ADMIN_ROLE, BURNER_ROLE, COMPLIANCE_ROLE, GAME_ROLE, GUARDIAN_ROLE, MINTER_ROLE,
+    PAUSER_ROLE, SIGNER_ROLE,
+};
+use crate::royalty::{RoyaltyInfo, RoyaltyPayment};
+use crate::staking::Staking;
+use crate::state_diff::{StateDiff, StateView};
+use crate::storage::{self, Persistence};
+use crate::svg::{self, SvgTemplate};
+use crate::swap::{Swap, SwapSide, Swaps};
+use crate::multisig::Multisig;
+use crate::timelock::{AdminAction, Timelock};
+use crate::uri::validate_uri;
+use crate::utils::{log_event, yield_now};
+use crate::votes::Votes;
+use crate::voucher::NFTVoucher;
+
+/// Promise dönen işlemlerde olay döngüsüne dönmeden önce işlenen token sayısı
+const ASYNC_CHUNK_SIZE: usize = 10_000;
+
+/// Toplu mint ile ayrılan ardışık token aralığı (ERC-721A tarzı)
+#[derive(Clone, Serialize, Deserialize)]
+struct MintBatch {
+    end: u64, // Aralığın son Token ID'si (dahil)
+    owner: String,
+    base_uri: String,
+}
+
+/// ERC-4907 kiralama kaydı: sahip token'ı tutarken kiracıya geçici kullanım hakkı verir
+#[derive(Clone, Serialize, Deserialize)]
+struct Rental {
+    user: String,
+    expires: u64, // Kullanım hakkının bittiği an (dahil, Unix saniye)
+}
+
+/// Doğrulanmış ve "before" kancalarından geçmiş, henüz uygulanmamış transfer
+///
+/// `check_transfer` üretir, `apply_transfer` başarısız olmadan uygular. Bileşik işlemler tüm
+/// adımlarını önce doğrulayıp ardından uygulayarak durumu kopyalamadan atomik kalır.
+pub(crate) struct PreparedTransfer {
+    owner: String,
+    to: String,
+    context: HookContext,
+}
+
+/// Doğrulanmış ve "before" kancalarından geçmiş, henüz uygulanmamış mint; `check_mint` üretir
+pub(crate) struct PreparedMint {
+    owner: String,
+    context: HookContext,
+}
+
+/// Doğrulanmış ve "before" kancalarından geçmiş, henüz uygulanmamış yakma; `check_burn` üretir
+pub(crate) struct PreparedBurn {
+    owner: String,
+    context: HookContext,
+}
+
+/// `state_hash` ile özetlenen kanonik durum: tokenlar, sahiplik, bakiyeler ve yapılandırma
+///
+/// Saatten türeyen kayıtlar (denetim günlükleri, rol atama zamanları, bekleme süreleri) ile saat,
+/// sayaçlar ve oturum gibi çalışma anı alanları dahil edilmez.
+#[derive(Serialize)]
+struct CanonicalState<'a> {
+    token_owner: BTreeMap<u64, &'a str>, // Token ID -> Sahip Adresi (kimlik yerine adres)
+    batches: &'a BTreeMap<u64, MintBatch>,
+    burned_batch_tokens: &'a BTreeSet<u64>,
+    approvals: &'a BTreeMap<u64, String>,
+    operator_approvals: &'a BTreeSet<(String, String)>,
+    locked_tokens: &'a BTreeSet<u64>,
+    total_minted: u64,
+    total_burned: u64,
+    token_uris: &'a BTreeMap<u64, String>,
+    base_uri: &'a Option<String>,
+    uri_suffix: &'a str,
+    frozen_uris: &'a BTreeSet<u64>,
+    metadata_frozen: bool,
+    provenance_hash: &'a Option<String>,
+    placeholder_uri: &'a Option<String>,
+    reveal_offset: Option<u64>,
+    attributes: &'a AttributeStore,
+    token_royalties: &'a BTreeMap<u64, RoyaltyInfo>,
+    default_royalty: &'a Option<RoyaltyInfo>,
+    bank: &'a Bank,
+    access: AccessConfig<'a>,
+    blocked_operators: &'a BTreeSet<String>,
+    denylist: &'a BTreeSet<String>,
+    decentralized_uris: bool,
+    contract_uri: &'a Option<String>,
+    contract_metadata: &'a ContractMetadata,
+    domain: &'a Eip712Domain,
+    max_supply: Option<u64>,
+    mint_price: u64,
+    mint_limit_per_wallet: Option<u64>,
+    treasury: &'a Option<String>,
+    allowlist_root: Option<[u8; 32]>,
+    transfer_cooldown: u64,
+    paused: bool,
+    soulbound: bool,
+}
+
+/// `checkpoint` ile alınan geri dönüş noktasının kimliği
+pub type CheckpointId = u64;
+
+/// Geri alma için saklanan kontrat durumu
+struct Checkpoint {
+    id: CheckpointId,
+    state: ERC721,
+}
+
+/// Geri dönüş noktası yığını; kopyalanan kontratlar noktaları devralmaz
+#[derive(Default)]
+struct Checkpoints(Vec<Checkpoint>);
+
+impl Clone for Checkpoints {
+    fn clone(&self) -> Checkpoints {
+        Checkpoints::default()
+    }
+}
+
+/// Toplu transferdeki tek bir transfer kalemi
+#[derive(Clone, Debug, Deserialize, Tsify)]
+pub struct BatchTransfer {
+    pub from: String,
+    pub to: String,
+    pub token_id: u64,
+}
+
+/// `execute_batch` ile atomik olarak uygulanan tek bir işlem
+#[derive(Clone, Debug, Serialize, Deserialize, Tsify)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Operation {
+    Mint { owner: String, token_id: u64 },
+    Transfer { from: String, to: String, token_id: u64 },
+    Burn { caller: String, token_id: u64 },
+    Approve { owner: String, approved: String, token_id: u64 },
+}
+
+impl Operation {
+    /// İşlemi gerçekleştiren adres
+    fn actor(&self) -> &str {
+        match self {
+            Operation::Mint { owner, .. } | Operation::Approve { owner, .. } => owner,
+            Operation::Transfer { from, .. } => from,
+            Operation::Burn { caller, .. } => caller,
+        }
+    }
+}
+
+/// Meta-işlemle imzacı adına çalıştırılan çağrı
+#[derive(Clone, Debug, Serialize, Deserialize, Tsify)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum MetaCall {
+    Transfer { to: String, token_id: u64 },
+    Approve { approved: String, token_id: u64 },
+    Burn { token_id: u64 },
+}
 
 /// ERC721 Token standardına uygun NFT yönetimi
 #[wasm_bindgen]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ERC721 {
-    owner: String,
-    token_owner: HashMap<u64, String>, // Token ID -> Sahip Adresi
-    owned_tokens: HashMap<String, Vec<u64>>, // Kullanıcı Adresi -> Sahip Olduğu Tokenlar
-    approvals: HashMap<u64, String>, // Token ID -> Onaylı Adres
+    #[serde(
+        rename = "owner",
+        default,
+        skip_serializing_if = "migration::removed_since::<9>",
+        serialize_with = "migration::serialize_until::<9, String, _>",
+        deserialize_with = "migration::deserialize_until::<9, String, _>"
+    )]
+    removed_owner: (), // Sürüm 9'da kaldırılan sahip alanının eski düzenlerdeki yeri; sahip `roles`'tadır
+    addresses: AddressInterner, // Token kayıtlarındaki sahip adreslerinin tekil kopyaları
+    token_owner: BTreeMap<u64, AddressId>, // Token ID -> Sahip Adresi Kimliği
+    owned_tokens: OwnedIndex, // Kullanıcı Adresi -> Sahip Olduğu Tokenlar (toplu mint aralıkları hariç)
+    approvals: BTreeMap<u64, String>, // Token ID -> Onaylı Adres
+    operator_approvals: BTreeSet<(String, String)>, // (Sahip, Tüm Tokenları İçin Onaylı Operatör)
+    blocked_operators: BTreeSet<String>, // Operatör filtresindeki (engellenmiş) adresler
+    batches: BTreeMap<u64, MintBatch>, // Başlangıç Token ID -> Toplu Mint Kaydı
+    burned_batch_tokens: BTreeSet<u64>, // Toplu mint aralığında yakılan tokenlar
+    token_uris: BTreeMap<u64, String>, // Token ID -> Token'a Özel URI
+    base_uri: Option<String>, // Token'a özel URI yoksa `base_uri + Token ID + uri_suffix`
+    uri_suffix: String,
+    frozen_uris: BTreeSet<u64>, // URI'si kalıcı olarak sabitlenmiş tokenlar
+    metadata_frozen: bool, // Tüm metadata kalıcı olarak sabitlendi mi
+    decentralized_uris: bool, // Açıksa yalnızca ipfs://, ar:// ve data: adreslerine izin verilir
+    provenance_hash: Option<String>, // Mint öncesi taahhüt edilen metadata sıralamasının özeti
+    placeholder_uri: Option<String>, // Reveal öncesi tüm tokenların gösterdiği URI
+    reveal_offset: Option<u64>, // Reveal sonrası Token ID -> Metadata İndeksi kaydırması
+    attributes: AttributeStore, // Zincir üstü token özellikleri
+    svg_template: SvgTemplate, // Özelliklerden görsel üreten katman şablonu
+    rentals: BTreeMap<u64, Rental>, // Token ID -> Kiracı Kaydı (ERC-4907)
+    staking: Staking,
+    votes: Votes, // Oy birimleri, delegeler ve oy gücü checkpoint'leri (ERC721Votes)
+    holder_snapshots: HolderSnapshots, // Sahip ve bakiyelerin snapshot anlarındaki halleri
+    governor: Governor, // Oy gücüyle karar veren teklif ve oylama modülü
+    timelock: Timelock, // Yetkili işlemler için gecikmeli uygulama sırası
+    #[serde(
+        serialize_with = "migration::serialize_since::<5, _, _>",
+        deserialize_with = "migration::deserialize_since::<5, _, _>"
+    )]
+    multisig: Multisig, // Yetkili işlemler için M-of-N imzacı onayı
+    vesting_unlocks: BTreeMap<u64, u64>, // Token ID -> Transfer Kilidinin Açıldığı An (Unix saniye)
+    transfer_cooldown: u64, // Art arda iki transfer arasında beklenmesi gereken süre (saniye)
+    last_transfers: BTreeMap<u64, u64>, // Token ID -> Son Transfer Anı (Unix saniye)
+    default_royalty: Option<RoyaltyInfo>,
+    token_royalties: BTreeMap<u64, RoyaltyInfo>, // Token ID -> Token'a Özel Royalty
+    contract_uri: Option<String>,
+    contract_metadata: ContractMetadata,
+    max_supply: Option<u64>,
+    mint_price: u64, // Aktif aşama yoksa `mint_paid` için token başına fiyat
+    treasury: Option<String>, // Mint gelirlerinin çekildiği adres (boşsa kontrat sahibi)
+    bank: Bank, // Simüle edilmiş ETH bakiyeleri
+    dutch_auction: Option<DutchAuction>, // Fiyatı zamanla düşen birincil mint
+    next_dutch_token_id: u64, // `mint_dutch` ile verilecek sıradaki Token ID
+    marketplace: Marketplace, // İkincil satış ilanları
+    auctions: AuctionHouse, // Artan fiyatlı açık artırmalar
+    swaps: Swaps, // Emanetli NFT takasları
+    composables: Composables, // ERC-998 üst/alt token ilişkileri
+    merging: Merging,
+    progression: Progression, // Token XP'leri ve seviye eğrisi
+    consumables: Consumables, // Sınırlı kullanımlı tokenlar
+    mint_limit_per_wallet: Option<u64>,
+    minted_per_wallet: BTreeMap<String, u64>, // Alıcı Adresi -> Mint Edilen Token Sayısı
+    total_minted: u64,
+    total_burned: u64,
+    allowlist_root: Option<[u8; 32]>,
+    airdrop: Option<Airdrop>, // Merkle kanıtıyla talep edilen token aralığı
+    domain: Eip712Domain, // İmzalı mesajlar için EIP-712 alanı
+    redeemed_vouchers: BTreeSet<String>, // Kullanılmış kupon özetleri
+    permit_nonces: BTreeMap<u64, u64>, // Token ID -> ERC-4494 Permit Nonce'u
+    meta_nonces: BTreeMap<String, u64>, // İmzacı Adresi (küçük harf) -> Meta-İşlem Nonce'u
+    order_nonces: BTreeMap<String, u64>, // Emri Veren Adres (küçük harf) -> Emir Sayacı
+    closed_orders: BTreeSet<String>, // Karşılanmış veya iptal edilmiş emir özetleri
+    phases: PhaseSchedule,
+    phase_minted: BTreeMap<String, BTreeMap<String, u64>>, // Aşama Adı -> Alıcı Adresi -> Mint Sayısı
+    clock: Clock,
+    roles: RoleManager,
+    paused: bool,
+    denylist: BTreeSet<String>, // Mint ve transfer yapamayan adresler
+    soulbound: bool, // Koleksiyon genelinde transfer kilidi (ERC-5192)
+    locked_tokens: BTreeSet<u64>, // Tek tek kilitlenmiş (soulbound) tokenlar
+    audit_log: AuditLog,
+    #[serde(skip)]
+    persistence: Option<Persistence>,
+    #[serde(skip)]
+    checkpoints: Checkpoints,
+    #[serde(skip)]
+    next_checkpoint_id: CheckpointId,
+    #[serde(skip)]
+    open_transaction: Option<CheckpointId>,
+    #[serde(skip)]
+    hooks: Hooks,
+    #[serde(skip)]
+    merge_rule: MergeRule,
+    #[serde(skip)]
+    perf: PerfCounters, // İşlem başına çağrı sayısı ve süreleri
+    #[serde(skip)]
+    session: Session, // `connect` ile bağlanan çağıran (`msg.sender`)
+    #[serde(skip)]
+    rarity: Option<RarityTable>, // Son `compute_rarity_scores` sonucu; özellikler değişince silinir
 }
 
 #[wasm_bindgen]
@@ -16,132 +302,6927 @@ impl ERC721 {
     /// Yeni bir ERC721 kontratı oluşturur
     #[wasm_bindgen(constructor)]
     pub fn new(owner: String) -> ERC721 {
-        Self::log_event("ERC721 Created", &format!("Owner: {}", owner));
+        let mut roles = RoleManager::new(owner.clone());
+        for role in [PAUSER_ROLE, ADMIN_ROLE, MINTER_ROLE, BURNER_ROLE] {
+            roles
+                .assign_role(owner.clone(), role.to_string(), owner.clone())
+                .expect("owner can always assign roles");
+        }
+        ERC721::with_access_control(roles)
+    }
+
+    /// Yetkileri hazır bir rol yöneticisinden alan kontrat oluşturur
+    ///
+    /// Kontrat sahibi rol yöneticisinin sahibidir; mint `MINTER_ROLE`, duraklatma `PAUSER_ROLE`,
+    /// başkasının token'ını yakma `BURNER_ROLE` ister. `new`'in aksine sahibe rol atanmaz.
+    pub fn with_access_control(mut roles: RoleManager) -> ERC721 {
+        let owner = roles.owner();
+        log_event("ERC721 Created", &format!("Owner: {}", owner));
+        let clock = Clock::default();
+        roles.sync_clock(&clock);
+        roles.disconnect();
         ERC721 {
-            owner,
-            token_owner: HashMap::new(),
-            owned_tokens: HashMap::new(),
-            approvals: HashMap::new(),
+            removed_owner: (),
+            addresses: AddressInterner::default(),
+            token_owner: BTreeMap::new(),
+            owned_tokens: OwnedIndex::default(),
+            approvals: BTreeMap::new(),
+            operator_approvals: BTreeSet::new(),
+            blocked_operators: BTreeSet::new(),
+            batches: BTreeMap::new(),
+            burned_batch_tokens: BTreeSet::new(),
+            token_uris: BTreeMap::new(),
+            base_uri: None,
+            uri_suffix: String::new(),
+            frozen_uris: BTreeSet::new(),
+            metadata_frozen: false,
+            decentralized_uris: false,
+            provenance_hash: None,
+            placeholder_uri: None,
+            reveal_offset: None,
+            attributes: AttributeStore::default(),
+            svg_template: SvgTemplate::default(),
+            rentals: BTreeMap::new(),
+            staking: Staking::default(),
+            votes: Votes::default(),
+            holder_snapshots: HolderSnapshots::default(),
+            governor: Governor::default(),
+            timelock: Timelock::default(),
+            multisig: Multisig::default(),
+            vesting_unlocks: BTreeMap::new(),
+            transfer_cooldown: 0,
+            last_transfers: BTreeMap::new(),
+            default_royalty: None,
+            token_royalties: BTreeMap::new(),
+            contract_uri: None,
+            contract_metadata: ContractMetadata::default(),
+            max_supply: None,
+            mint_price: 0,
+            treasury: None,
+            bank: Bank::default(),
+            dutch_auction: None,
+            next_dutch_token_id: 1,
+            marketplace: Marketplace::default(),
+            auctions: AuctionHouse::default(),
+            swaps: Swaps::default(),
+            composables: Composables::default(),
+            merging: Merging::default(),
+            progression: Progression::default(),
+            consumables: Consumables::default(),
+            mint_limit_per_wallet: None,
+            minted_per_wallet: BTreeMap::new(),
+            total_minted: 0,
+            total_burned: 0,
+            allowlist_root: None,
+            airdrop: None,
+            domain: Eip712Domain::default(),
+            redeemed_vouchers: BTreeSet::new(),
+            permit_nonces: BTreeMap::new(),
+            meta_nonces: BTreeMap::new(),
+            order_nonces: BTreeMap::new(),
+            closed_orders: BTreeSet::new(),
+            phases: PhaseSchedule::default(),
+            phase_minted: BTreeMap::new(),
+            clock,
+            roles,
+            paused: false,
+            denylist: BTreeSet::new(),
+            soulbound: false,
+            locked_tokens: BTreeSet::new(),
+            audit_log: AuditLog::default(),
+            persistence: None,
+            checkpoints: Checkpoints::default(),
+            next_checkpoint_id: 0,
+            open_transaction: None,
+            hooks: Hooks::default(),
+            merge_rule: MergeRule::default(),
+            perf: PerfCounters::default(),
+            session: Session::default(),
+            rarity: None,
+        }
+    }
+
+    /// Toplam mint edilebilecek token sayısı sınırlı bir kontrat oluşturur
+    pub fn with_max_supply(owner: String, max_supply: u64) -> ERC721 {
+        let mut erc721 = ERC721::new(owner);
+        erc721.max_supply = Some(max_supply);
+        erc721
+    }
+
+    /// Kontratın ayarlarını (roller, royalty, aşamalar, URI ve metadata ayarları, kurallar) yeni
+    /// ad ve sembolle kopyalar; tokenlar, bakiyeler, teklifler ve denetim günlüğü boş başlar
+    ///
+    /// EIP-712 alan adı da yeni ada çevrilir, böylece şablon için atılmış imzalar kopyada geçmez.
+    /// Donma, provenance, reveal ve airdrop kayıtları koleksiyona özgü olduğundan taşınmaz.
+    pub fn clone_with(&self, name: String, symbol: String) -> ERC721 {
+        let mut clone = ERC721::new(self.roles.owner());
+        clone.roles = self.roles.clone();
+        clone.blocked_operators = self.blocked_operators.clone();
+        clone.base_uri = self.base_uri.clone();
+        clone.uri_suffix = self.uri_suffix.clone();
+        clone.decentralized_uris = self.decentralized_uris;
+        clone.placeholder_uri = self.placeholder_uri.clone();
+        clone.svg_template = self.svg_template.clone();
+        clone.staking = self.staking.settings_only();
+        clone.governor = self.governor.settings_only();
+        clone.timelock = self.timelock.settings_only();
+        clone.multisig = self.multisig.settings_only();
+        clone.transfer_cooldown = self.transfer_cooldown;
+        clone.default_royalty = self.default_royalty.clone();
+        clone.contract_uri = self.contract_uri.clone();
+        clone.contract_metadata = ContractMetadata {
+            name: Some(name.clone()),
+            symbol: Some(symbol.clone()),
+            ..self.contract_metadata.clone()
+        };
+        clone.max_supply = self.max_supply;
+        clone.mint_price = self.mint_price;
+        clone.treasury = self.treasury.clone();
+        clone.dutch_auction = self.dutch_auction.clone();
+        clone.auctions = self.auctions.settings_only();
+        clone.merging = self.merging.settings_only();
+        clone.progression = self.progression.settings_only();
+        clone.mint_limit_per_wallet = self.mint_limit_per_wallet;
+        clone.allowlist_root = self.allowlist_root;
+        clone.domain = Eip712Domain {
+            name: name.clone(),
+            ..self.domain.clone()
+        };
+        clone.phases = self.phases.clone();
+        clone.clock = self.clock.clone();
+        clone.paused = self.paused;
+        clone.denylist = self.denylist.clone();
+        clone.soulbound = self.soulbound;
+        clone.hooks = self.hooks.clone();
+        clone.merge_rule = self.merge_rule.clone();
+        log_event("ERC721 Cloned", &format!("Name: {}, Symbol: {}", name, symbol));
+        clone
+    }
+
+    /// Koleksiyon adını döner (`set_contract_metadata` veya `clone_with` ile ayarlanır)
+    pub fn name(&self) -> Option<String> {
+        self.contract_metadata.name.clone()
+    }
+
+    /// Koleksiyon sembolünü döner
+    pub fn symbol(&self) -> Option<String> {
+        self.contract_metadata.symbol.clone()
+    }
+
+    /// localStorage'a otomatik kaydedilen bir kontrat oluşturur
+    ///
+    /// Anahtar altında kayıtlı durum varsa ondan yüklenir, yoksa `owner` ile yeni kontrat açılır.
+    /// Her başarılı değişiklikten sonra durum en fazla `debounce` aralığında bir yazılır;
+    /// bekleyen değişiklikler `flush_storage` ile hemen yazdırılabilir.
+    pub fn with_storage(owner: String, key: String) -> Result<ERC721, String> {
+        let mut erc721 = match storage::load(&key)? {
+            Some(json) => {
+                log_event("ERC721 Restored", &format!("Storage key: {}", key));
+                ERC721::from_json(json)?
+            }
+            None => ERC721::new(owner),
+        };
+        erc721.persistence = Some(Persistence::new(key));
+        erc721.persist(true);
+        Ok(erc721)
+    }
+
+    /// Otomatik kayıtlar arasındaki en kısa süreyi (ms) ayarlar
+    pub fn set_storage_debounce(&mut self, debounce_ms: f64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_storage_debounce");
+        let persistence = self.persistence.as_mut().ok_or("Storage is not configured")?;
+        persistence.debounce_ms = debounce_ms.max(0.0);
+        timer.finish(Ok(()))
+    }
+
+    /// Bekleyen değişiklikleri hemen localStorage'a yazar
+    pub fn flush_storage(&mut self) -> Result<(), String> {
+        let timer = self.perf.start_fallible("flush_storage");
+        let persistence = self.persistence.as_ref().ok_or("Storage is not configured")?;
+        if persistence.is_dirty() {
+            self.persist(true);
         }
+        timer.finish(Ok(()))
+    }
+
+    /// Kaydedilmemiş değişiklik olup olmadığını döner
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.persistence.as_ref().map(Persistence::is_dirty).unwrap_or(false)
     }
 
     /// Token'ın sahibini döner
     pub fn owner_of(&self, token_id: u64) -> Option<String> {
-        self.token_owner.get(&token_id).cloned()
+        self.owner_lookup(token_id).map(str::to_string)
     }
 
-    /// Bir token'ı mint'ler ve sahibine atar (sadece kontrat sahibi yapabilir)
-    pub fn mint(&mut self, owner: String, token_id: u64) -> Result<(), String> {
-        if owner != self.owner {
-            Self::log_event("Minting Failed", "Unauthorized attempt");
-            return Err("Only the contract owner can mint new tokens".to_string());
+    /// Token'ın URI'sini döner
+    ///
+    /// Öncelik sırası: token'a özel URI, toplu mint'in taban URI'si, koleksiyonun taban URI'si.
+    /// Taban URI'lerden üretilen adreslere metadata indeksi (reveal yoksa Token ID) ve son ek eklenir.
+    /// Yer tutucu URI ayarlıysa reveal'e kadar tüm tokenlar onu gösterir.
+    pub fn token_uri(&self, token_id: u64) -> Option<String> {
+        self.owner_lookup(token_id)?;
+        if let Some(uri) = self.token_uris.get(&token_id) {
+            return Some(uri.clone());
         }
+        if let (Some(placeholder), None) = (&self.placeholder_uri, self.reveal_offset) {
+            return Some(placeholder.clone());
+        }
+        let base = self
+            .batch_containing(token_id)
+            .map(|batch| batch.base_uri.as_str())
+            .filter(|base| !base.is_empty())
+            .or(self.base_uri.as_deref())?;
+        Some(format!("{}{}{}", base, self.metadata_index(token_id), self.uri_suffix))
+    }
 
-        if self.token_owner.contains_key(&token_id) {
-            Self::log_event("Minting Failed", &format!("Token ID {} already exists", token_id));
-            return Err("Token ID already exists".to_string());
+    /// Koleksiyonun taban URI'sini ayarlar (sadece ADMIN rolü)
+    pub fn set_base_uri(&mut self, caller: String, base_uri: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_base_uri");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Base URI Update Failed")?;
+        self.ensure_timelocked("Base URI Update Failed")?;
+        self.ensure_multisig("Base URI Update Failed")?;
+        self.ensure_metadata_mutable(None, "Base URI Update Failed")?;
+        self.ensure_valid_uri(&base_uri, "Base URI Update Failed")?;
+
+        self.base_uri = Some(base_uri.clone());
+        log_event("Base URI Updated", &format!("Base URI: {}", base_uri));
+        self.after_mutation(LogEntry::new("BaseURIUpdated").from(&caller).details(base_uri));
+        self.emit_batch_metadata_update(0, u64::MAX);
+        timer.finish(Ok(()))
+    }
+
+    /// Koleksiyonun taban URI'sini döner
+    pub fn base_uri(&self) -> Option<String> {
+        self.base_uri.clone()
+    }
+
+    /// Taban URI'den üretilen adreslerin son ekini ayarlar, ör. ".json" (sadece ADMIN rolü)
+    pub fn set_uri_suffix(&mut self, caller: String, suffix: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_uri_suffix");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "URI Suffix Update Failed")?;
+        self.ensure_metadata_mutable(None, "URI Suffix Update Failed")?;
+
+        self.uri_suffix = suffix.clone();
+        log_event("URI Suffix Updated", &format!("Suffix: {}", suffix));
+        self.after_mutation(LogEntry::new("URISuffixUpdated").from(&caller).details(suffix));
+        self.emit_batch_metadata_update(0, u64::MAX);
+        timer.finish(Ok(()))
+    }
+
+    /// Token'a özel URI atar; taban URI'den üretilen adresin yerine geçer (sadece ADMIN rolü)
+    pub fn set_token_uri(&mut self, caller: String, token_id: u64, uri: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_token_uri");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Token URI Update Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+        self.ensure_metadata_mutable(Some(token_id), "Token URI Update Failed")?;
+        self.ensure_valid_uri(&uri, "Token URI Update Failed")?;
+
+        self.token_uris.insert(token_id, uri.clone());
+        log_event("Token URI Updated", &format!("Token ID: {}, URI: {}", token_id, uri));
+        self.after_mutation(LogEntry::new("TokenURIUpdated").token(token_id).from(&caller).details(uri));
+        self.emit_metadata_update(token_id);
+        timer.finish(Ok(()))
+    }
+
+    /// Token'a tipli bir özellik atar (sadece ADMIN rolü)
+    ///
+    /// `display_type` boşsa metin değer `string`, sayı `number` olarak saklanır;
+    /// "number", "boost_number" ve "date" sayısal değer bekler.
+    pub fn set_attribute(
+        &mut self,
+        caller: String,
+        token_id: u64,
+        trait_type: String,
+        #[wasm_bindgen(unchecked_param_type = "string | number")] value: JsValue,
+        display_type: Option<String>,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_attribute");
+        self.ensure_sender(&caller)?;
+        let value = AttributeValue::parse(display_type.as_deref(), value.as_string(), value.as_f64())?;
+        timer.finish(self.set_attribute_value(caller, token_id, trait_type, value))
+    }
+
+    /// Token'dan bir özelliği kaldırır (sadece ADMIN rolü)
+    pub fn remove_attribute(&mut self, caller: String, token_id: u64, trait_type: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("remove_attribute");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Attribute Update Failed")?;
+        self.ensure_metadata_mutable(Some(token_id), "Attribute Update Failed")?;
+
+        if !self.attributes.remove(token_id, &trait_type) {
+            return Err("Attribute does not exist".to_string());
         }
+        self.rarity = None;
+        log_event("Attribute Removed", &format!("Token ID: {}, Trait: {}", token_id, trait_type));
+        self.after_mutation(LogEntry::new("AttributeRemoved").token(token_id).from(&caller).details(trait_type));
+        self.emit_metadata_update(token_id);
+        timer.finish(Ok(()))
+    }
 
-        self.token_owner.insert(token_id, owner.clone());
-        self.owned_tokens.entry(owner.clone()).or_insert(Vec::new()).push(token_id);
-        Self::log_event("Token Minted", &format!("Token ID: {}, Owner: {}", token_id, owner));
-        Ok(())
+    /// Token'a XP ekler ve token'ın güncel seviyesini döner (sadece GAME rolü)
+    ///
+    /// Seviye değişirse ERC-4906 `MetadataUpdate` olayı yayınlanır.
+    pub fn add_xp(&mut self, caller: String, token_id: u64, amount: u64) -> Result<u32, String> {
+        let timer = self.perf.start_fallible("add_xp");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, GAME_ROLE, "XP Update Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+
+        let (before, after) = self.progression.add(token_id, amount);
+        log_event("XP Added", &format!("Token ID: {}, Amount: {}, Level: {}", token_id, amount, after));
+        self.after_mutation(
+            LogEntry::new("XpAdded")
+                .token(token_id)
+                .from(&caller)
+                .details(format!("Amount: {}, Level: {}", amount, after)),
+        );
+        if after != before {
+            self.emit_metadata_update(token_id);
+        }
+        timer.finish(Ok(after))
     }
 
-    /// Token'ı başka bir kullanıcıya transfer eder
-    pub fn transfer(&mut self, from: String, to: String, token_id: u64) -> Result<(), String> {
-        let owner = self.token_owner.get(&token_id).ok_or("Token does not exist")?;
+    /// Token'ın toplam XP'sini döner
+    pub fn xp_of(&self, token_id: u64) -> u64 {
+        self.progression.xp(token_id)
+    }
 
-        if owner != &from && !self.is_approved_or_owner(from.clone(), token_id) {
-            Self::log_event("Transfer Failed", "Unauthorized attempt");
-            return Err("Unauthorized transfer attempt".to_string());
+    /// Token'ın XP eğrisine göre seviyesini döner (1'den başlar)
+    pub fn level_of(&self, token_id: u64) -> u32 {
+        self.progression.level_for(self.progression.xp(token_id))
+    }
+
+    /// Seviye eşiklerini ayarlar: `thresholds[i]`, (i + 2). seviye için gereken toplam XP'dir (sadece ADMIN rolü)
+    pub fn set_xp_curve(&mut self, caller: String, thresholds: Vec<u64>) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_xp_curve");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "XP Curve Update Failed")?;
+
+        self.progression
+            .set_curve(thresholds)
+            .inspect_err(|err| log_event("XP Curve Update Failed", err))?;
+        let details = format!("Thresholds: {:?}", self.progression.curve());
+        log_event("XP Curve Set", &details);
+        self.after_mutation(LogEntry::new("XpCurveSet").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Güncel seviye eşiklerini döner
+    pub fn xp_curve(&self) -> Vec<u64> {
+        self.progression.curve().to_vec()
+    }
+
+    /// Token'ın kalan kullanım sayısını ayarlar (sadece GAME rolü)
+    ///
+    /// `burn_when_depleted` açıksa son kullanımda token otomatik yakılır.
+    pub fn set_uses(
+        &mut self,
+        caller: String,
+        token_id: u64,
+        uses: u32,
+        burn_when_depleted: bool,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_uses");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, GAME_ROLE, "Uses Update Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+
+        self.consumables.set(token_id, uses, burn_when_depleted);
+        let details = format!("Uses: {}, Burn When Depleted: {}", uses, burn_when_depleted);
+        log_event("Uses Set", &format!("Token ID: {}, {}", token_id, details));
+        self.after_mutation(LogEntry::new("UsesSet").token(token_id).from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın bir kullanımını harcar ve kalan kullanımı döner (sahip veya onaylı adres)
+    pub fn consume(&mut self, caller: String, token_id: u64) -> Result<u32, String> {
+        let timer = self.perf.start_fallible("consume");
+        self.ensure_sender(&caller)?;
+        self.ensure_not_paused("Consume Failed")?;
+        if !self.is_approved_or_owner(caller.clone(), token_id) {
+            log_event("Consume Failed", "Unauthorized attempt");
+            return Err("Only the owner can consume the token".to_string());
         }
 
-        self.remove_token_from_owner(from.clone(), token_id);
-        self.token_owner.insert(token_id, to.clone());
-        self.owned_tokens.entry(to.clone()).or_insert(Vec::new()).push(token_id);
-        Self::log_event("Token Transferred", &format!("Token ID: {}, From: {}, To: {}", token_id, from, to));
-        Ok(())
+        let (remaining, burn) = self
+            .consumables
+            .consume(token_id)
+            .inspect_err(|err| log_event("Consume Failed", err))?;
+        if burn {
+            if let Err(err) = self.burn(caller.clone(), token_id) {
+                // Yakılamayan token'ın son kullanımı geri verilir
+                self.consumables.set(token_id, 1, true);
+                return Err(err);
+            }
+        }
+        log_event("Token Consumed", &format!("Token ID: {}, Remaining: {}", token_id, remaining));
+        self.after_mutation(
+            LogEntry::new("Consumed")
+                .token(token_id)
+                .from(&caller)
+                .details(format!("Remaining: {}", remaining)),
+        );
+        timer.finish(Ok(remaining))
     }
 
-    /// Token'ı başka bir kullanıcıya transfer edebilmesi için onay verir
-    pub fn approve(&mut self, owner: String, approved: String, token_id: u64) -> Result<(), String> {
-        let token_owner = self.token_owner.get(&token_id).ok_or("Token does not exist")?;
+    /// Token'ın kalan kullanım sayısını döner; sınırlı kullanımlı değilse boş döner
+    pub fn remaining_uses(&self, token_id: u64) -> Option<u32> {
+        self.consumables.remaining(token_id)
+    }
 
-        if token_owner != &owner {
-            Self::log_event("Approval Failed", "Unauthorized attempt");
-            return Err("Only the owner can approve".to_string());
+    /// Token'ın özelliklerini OpenSea biçiminde `{display_type?, trait_type, value}` dizisi olarak döner
+    #[wasm_bindgen(unchecked_return_type = "TokenAttribute[]")]
+    pub fn get_attributes(&self, token_id: u64) -> JsValue {
+        use serde::Serialize as _;
+        self.attributes_value(token_id)
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .unwrap_or(JsValue::NULL)
+    }
+
+    /// Özelliği verilen değerde olan tokenları artan sırada sayfalı döner
+    ///
+    /// Sonraki sayfa için son dönen Token ID `start_after` olarak verilir. Sayısal değerler
+    /// `"5"`, `"2.5"` gibi metin olarak aranır.
+    pub fn tokens_with_trait(
+        &self,
+        trait_type: String,
+        value: String,
+        start_after: Option<u64>,
+        limit: u32,
+    ) -> Vec<u64> {
+        self.attributes.tokens_with(&trait_type, &value, start_after, limit as usize)
+    }
+
+    /// Özelliği verilen değerde olan token sayısını döner
+    pub fn count_with_trait(&self, trait_type: String, value: String) -> u32 {
+        self.attributes.count_with(&trait_type, &value) as u32
+    }
+
+    /// Özellikleri olan tüm tokenların nadirlik puanlarını hesaplar ve puanlanan token sayısını döner
+    ///
+    /// Sonuçlar özellikler değişene kadar `rarity_rank` ve `top_n_by_rarity` ile sorgulanabilir.
+    pub fn compute_rarity_scores(&mut self, method: RarityMethod) -> u32 {
+        let _timer = self.perf.start("compute_rarity_scores");
+        let table = RarityTable::compute(&self.attributes, method);
+        let count = table.len() as u32;
+        self.rarity = Some(table);
+        log_event("Rarity Computed", &format!("Method: {:?}, Tokens: {}", method, count));
+        count
+    }
+
+    /// `compute_rarity_scores`'un her `ASYNC_CHUNK_SIZE` tokenda olay döngüsüne dönen Promise sürümü
+    ///
+    /// Hesaplama sürerken kontrat ödünç alınmış durumdadır; araya giren çağrılar hata fırlatır.
+    pub async fn compute_rarity_scores_async(&mut self, method: RarityMethod) -> u32 {
+        let mut scores = HashMap::with_capacity(self.attributes.token_count());
+        for (index, (token_id, attributes)) in self.attributes.iter().enumerate() {
+            if index > 0 && index % ASYNC_CHUNK_SIZE == 0 {
+                yield_now().await;
+            }
+            scores.insert(token_id, rarity::score(&self.attributes, attributes, method));
         }
+        let table = RarityTable::from_scores(scores);
+        let count = table.len() as u32;
+        self.rarity = Some(table);
+        log_event("Rarity Computed", &format!("Method: {:?}, Tokens: {}", method, count));
+        count
+    }
 
-        self.approvals.insert(token_id, approved.clone());
-        Self::log_event("Approval Granted", &format!("Token ID: {}, Approved for: {}", token_id, approved));
-        Ok(())
+    /// Token'ın son hesaplanan nadirlik puanını döner
+    pub fn rarity_score(&self, token_id: u64) -> Option<f64> {
+        self.rarity.as_ref()?.score(token_id)
     }
 
-    /// Bir token'ın kime onaylı olduğunu döner
-    pub fn get_approved(&self, token_id: u64) -> Option<String> {
-        self.approvals.get(&token_id).cloned()
+    /// Token'ın nadirlik sırasını döner (1 en nadir); puanlar hesaplanmadıysa boş
+    pub fn rarity_rank(&self, token_id: u64) -> Option<u32> {
+        self.rarity.as_ref()?.rank(token_id)
     }
 
-    /// Token sahibinin onaylayıp onaylamadığını kontrol eder
-    pub fn is_approved_or_owner(&self, user: String, token_id: u64) -> bool {
-        let owner = self.token_owner.get(&token_id);
-        let approved = self.approvals.get(&token_id);
+    /// En nadir `n` token'ı döner
+    pub fn top_n_by_rarity(&self, n: u32) -> Vec<u64> {
+        self.rarity.as_ref().map(|table| table.top(n as usize)).unwrap_or_default()
+    }
+
+    /// Üretilen SVG görsellerinin boyutunu ayarlar (sadece ADMIN rolü)
+    pub fn set_svg_canvas(&mut self, caller: String, width: u32, height: u32) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_svg_canvas");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "SVG Update Failed")?;
+        self.ensure_metadata_mutable(None, "SVG Update Failed")?;
 
-        owner.map(|o| o == &user).unwrap_or(false) || approved.map(|a| a == &user).unwrap_or(false)
+        self.svg_template.set_canvas(width, height)?;
+        log_event("SVG Canvas Updated", &format!("Size: {}x{}", width, height));
+        self.after_mutation(LogEntry::new("SVGCanvasUpdated").from(&caller).details(format!("{}x{}", width, height)));
+        self.emit_batch_metadata_update(0, u64::MAX);
+        timer.finish(Ok(()))
     }
 
-    /// Kullanıcıya ait olan tüm token'ları listeler
-    pub fn tokens_of_owner(&self, owner: String) -> Vec<u64> {
-        self.owned_tokens.get(&owner).cloned().unwrap_or_else(Vec::new)
+    /// Özellik değeri için SVG katman parçası tanımlar (sadece ADMIN rolü)
+    ///
+    /// Değer `"*"` ise parça, ayrıca tanımlanmamış tüm değerler için kullanılır. Parçada
+    /// `{value}` ve `{token_id}` yer tutucuları desteklenir. Katmanlar ilk tanımlanma sırasıyla çizilir.
+    pub fn set_svg_layer(
+        &mut self,
+        caller: String,
+        trait_type: String,
+        value: String,
+        fragment: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_svg_layer");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "SVG Update Failed")?;
+        self.ensure_metadata_mutable(None, "SVG Update Failed")?;
+
+        let details = format!("{}: {}", trait_type, value);
+        self.svg_template.set_fragment(trait_type, value, fragment);
+        log_event("SVG Layer Updated", &details);
+        self.after_mutation(LogEntry::new("SVGLayerUpdated").from(&caller).details(details));
+        self.emit_batch_metadata_update(0, u64::MAX);
+        timer.finish(Ok(()))
     }
 
-    /// Olayları tarayıcı konsoluna loglar
-    fn log_event(event: &str, details: &str) {
-        console::log_2(&event.into(), &details.into());
+    /// Token'ın görselini özelliklerinden oluşturup `data:image/svg+xml;base64,...` adresi olarak döner
+    pub fn render_token(&self, token_id: u64) -> Result<String, String> {
+        self.render_token_svg(token_id).map(|svg| svg::to_data_uri(&svg))
     }
 
-    /// Token sahibinden token'ı kaldırır (Transfer sırasında kullanılır)
-    fn remove_token_from_owner(&mut self, owner: String, token_id: u64) {
-        if let Some(tokens) = self.owned_tokens.get_mut(&owner) {
-            tokens.retain(|&id| id != token_id);
+    /// Token'ın görselini ham SVG belgesi olarak döner
+    pub fn render_token_svg(&self, token_id: u64) -> Result<String, String> {
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+        if self.svg_template.layer_count() == 0 {
+            return Err("No SVG layers configured".to_string());
         }
+        Ok(self.svg_template.render(token_id, &self.attributes.get(token_id)))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Token'ın OpenSea uyumlu metadata JSON'unu zincir üstü alanlardan oluşturur
+    ///
+    /// Ad koleksiyon adı ve Token ID'den, açıklama koleksiyon bilgisinden gelir. SVG katmanları
+    /// tanımlıysa görsel token'dan üretilir, değilse koleksiyon görseli kullanılır.
+    pub fn build_metadata(&self, token_id: u64) -> Result<String, String> {
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
 
-    #[test]
-    fn test_mint_and_transfer() {
-        let mut erc721 = ERC721::new("owner".to_string());
+        let name = match &self.contract_metadata.name {
+            Some(collection) => format!("{} #{}", collection, token_id),
+            None => format!("#{}", token_id),
+        };
+        let image = if self.svg_template.layer_count() > 0 {
+            Some(self.render_token(token_id)?)
+        } else {
+            self.contract_metadata.image.clone()
+        };
+        let attributes = self.attributes.get(token_id).iter().map(Attribute::to_json).collect();
+        Ok(metadata::build(name, self.contract_metadata.description.clone(), image, attributes))
+    }
 
-        // Mint token
-        assert!(erc721.mint("owner".to_string(), 1).is_ok());
-        assert_eq!(erc721.owner_of(1).unwrap(), "owner".to_string());
+    /// Token'ın özelliklerini OpenSea biçiminde JSON metni olarak döner
+    pub fn attributes_json(&self, token_id: u64) -> String {
+        self.attributes_value(token_id).to_string()
+    }
 
-        // Transfer token
-        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 1).is_ok());
-        assert_eq!(erc721.owner_of(1).unwrap(), "user1".to_string());
+    /// Token'ın güncel URI'sini kalıcı olarak sabitler; geri alınamaz (sadece ADMIN rolü)
+    pub fn freeze_token_uri(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("freeze_token_uri");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Freeze Failed")?;
+        self.ensure_metadata_mutable(Some(token_id), "Freeze Failed")?;
+        let uri = self.token_uri(token_id).ok_or("Token has no URI to freeze")?;
 
-        // Unauthorized transfer should fail
-        assert!(erc721.transfer("owner".to_string(), "user2".to_string(), 1).is_err());
+        // Taban URI sonradan değişse bile token'ın adresi aynı kalır
+        self.token_uris.insert(token_id, uri.clone());
+        self.frozen_uris.insert(token_id);
+        log_event("PermanentURI", &format!("Token ID: {}, URI: {}", token_id, uri));
+        self.after_mutation(LogEntry::new("PermanentURI").token(token_id).from(&caller).details(uri));
+        timer.finish(Ok(()))
     }
 
-    #[test]
-    fn test_approval_and_transfer() {
-        let mut erc721 = ERC721::new("owner".to_string());
+    /// Tüm token URI'lerini ve taban URI'yi kalıcı olarak sabitler; geri alınamaz (sadece ADMIN rolü)
+    pub fn freeze_all_metadata(&mut self, caller: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("freeze_all_metadata");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Freeze Failed")?;
+        self.ensure_metadata_mutable(None, "Freeze Failed")?;
 
-        // Mint token
-        erc721.mint("owner".to_string(), 1).unwrap();
+        self.metadata_frozen = true;
+        log_event("Metadata Frozen", &format!("Caller: {}", caller));
+        self.after_mutation(LogEntry::new("MetadataFrozen").from(&caller));
+        timer.finish(Ok(()))
+    }
 
-        // Approve transfer
-        assert!(erc721.approve("owner".to_string(), "user1".to_string(), 1).is_ok());
-        assert_eq!(erc721.get_approved(1).unwrap(), "user1".to_string());
+    /// Token'ın URI'sinin kalıcı olarak sabitlenip sabitlenmediğini döner
+    pub fn uri_frozen(&self, token_id: u64) -> bool {
+        self.metadata_frozen || self.frozen_uris.contains(&token_id)
+    }
 
-        // Approved user can transfer
-        assert!(erc721.transfer("user1".to_string(), "user2".to_string(), 1).is_ok());
-        assert_eq!(erc721.owner_of(1).unwrap(), "user2".to_string());
+    /// Tüm metadata'nın sabitlenip sabitlenmediğini döner
+    pub fn metadata_frozen(&self) -> bool {
+        self.metadata_frozen
+    }
+
+    /// Açıkken yeni URI'ler için HTTP adresleri reddedilir (sadece ADMIN rolü)
+    pub fn set_decentralized_uris(&mut self, caller: String, strict: bool) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_decentralized_uris");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "URI Policy Update Failed")?;
+
+        self.decentralized_uris = strict;
+        log_event("URI Policy Updated", &format!("Decentralized only: {}", strict));
+        self.after_mutation(LogEntry::new("URIPolicyUpdated").from(&caller).details(strict.to_string()));
+        timer.finish(Ok(()))
+    }
+
+    /// Koleksiyonun yalnızca merkeziyetsiz URI kabul edip etmediğini döner
+    pub fn decentralized_uris(&self) -> bool {
+        self.decentralized_uris
+    }
+
+    /// Metadata sıralamasının özetini taahhüt eder; ilk mint'ten sonra değiştirilemez (sadece ADMIN rolü)
+    pub fn set_provenance_hash(&mut self, caller: String, hash: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_provenance_hash");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Provenance Update Failed")?;
+        if self.total_minted > 0 {
+            log_event("Provenance Update Failed", "Minting has already started");
+            return Err("Provenance hash must be set before minting".to_string());
+        }
+
+        let hash = to_hex(&parse_hash(&hash)?);
+        self.provenance_hash = Some(hash.clone());
+        log_event("Provenance Hash Set", &format!("Hash: {}", hash));
+        self.after_mutation(LogEntry::new("ProvenanceHashSet").from(&caller).details(hash));
+        timer.finish(Ok(()))
+    }
+
+    /// Taahhüt edilen provenance özetini döner
+    pub fn provenance_hash(&self) -> Option<String> {
+        self.provenance_hash.clone()
+    }
+
+    /// Reveal öncesi tüm tokenların göstereceği URI'yi ayarlar (sadece ADMIN rolü)
+    pub fn set_placeholder_uri(&mut self, caller: String, uri: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_placeholder_uri");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Placeholder URI Update Failed")?;
+        self.ensure_metadata_mutable(None, "Placeholder URI Update Failed")?;
+        self.ensure_valid_uri(&uri, "Placeholder URI Update Failed")?;
+        if self.reveal_offset.is_some() {
+            return Err("Collection is already revealed".to_string());
+        }
+
+        self.placeholder_uri = Some(uri.clone());
+        log_event("Placeholder URI Updated", &format!("URI: {}", uri));
+        self.after_mutation(LogEntry::new("PlaceholderURIUpdated").from(&caller).details(uri));
+        self.emit_batch_metadata_update(0, u64::MAX);
+        timer.finish(Ok(()))
+    }
+
+    /// Reveal öncesi yer tutucu URI'yi döner
+    pub fn placeholder_uri(&self) -> Option<String> {
+        self.placeholder_uri.clone()
+    }
+
+    /// Koleksiyonu açığa çıkarır; geri alınamaz (sadece ADMIN rolü)
+    ///
+    /// Token'ın metadata indeksi `(token_id + offset) % max_supply` olur. Ofset mint sonrası
+    /// belirlendiği için taahhüt edilen sıralama, hangi token'a hangi metadata'nın düşeceğini
+    /// önceden göstermez.
+    pub fn reveal(&mut self, caller: String, offset: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("reveal");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Reveal Failed")?;
+        self.ensure_metadata_mutable(None, "Reveal Failed")?;
+        if self.reveal_offset.is_some() {
+            log_event("Reveal Failed", "Already revealed");
+            return Err("Collection is already revealed".to_string());
+        }
+        let max_supply = self.max_supply.ok_or("Max supply must be set before reveal")?;
+        if max_supply == 0 {
+            return Err("Max supply must be greater than zero".to_string());
+        }
+
+        let offset = offset % max_supply;
+        self.reveal_offset = Some(offset);
+        log_event("Revealed", &format!("Offset: {}", offset));
+        self.after_mutation(LogEntry::new("Revealed").from(&caller).details(format!("Offset: {}", offset)));
+        self.emit_batch_metadata_update(0, u64::MAX);
+        timer.finish(Ok(()))
+    }
+
+    /// Reveal ofsetini tohumdan (veya simüle VRF çıktısından) türetip koleksiyonu açığa çıkarır (sadece ADMIN rolü)
+    pub fn reveal_with_seed(&mut self, caller: String, seed: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("reveal_with_seed");
+        self.ensure_sender(&caller)?;
+        let max_supply = self.max_supply.ok_or("Max supply must be set before reveal")?;
+        let offset = SeededRng::new(seed).next_below(max_supply)?;
+        timer.finish(self.reveal(caller, offset))
+    }
+
+    /// Koleksiyonun açığa çıkarılıp çıkarılmadığını döner
+    pub fn revealed(&self) -> bool {
+        self.reveal_offset.is_some()
+    }
+
+    /// Token'ın taban URI'ye eklenen metadata indeksini döner (reveal öncesi Token ID'nin kendisi)
+    pub fn metadata_index(&self, token_id: u64) -> u64 {
+        match (self.reveal_offset, self.max_supply) {
+            (Some(offset), Some(max_supply)) => ((token_id as u128 + offset as u128) % max_supply as u128) as u64,
+            _ => token_id,
+        }
+    }
+
+    /// Bir token'ı mint'ler ve çağırana atar (sadece MINTER rolü)
+    pub fn mint(&mut self, owner: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("mint");
+        self.ensure_sender(&owner)?;
+        self.ensure_not_paused("Minting Failed")?;
+        self.ensure_role(&owner, MINTER_ROLE, "Minting Failed")?;
+
+        timer.finish(self.mint_to(&owner.clone(), owner, token_id))
+    }
+
+    /// Ücretli mint: alıcı `value_sent` kadar simüle ETH öder; miktar güncel fiyattan az olamaz
+    ///
+    /// Ödemenin tamamı kontrat bakiyesine geçer ve `withdraw` ile hazineye aktarılır.
+    pub fn mint_paid(&mut self, recipient: String, token_id: u64, value_sent: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("mint_paid");
+        self.ensure_sender(&recipient)?;
+        self.ensure_not_paused("Minting Failed")?;
+
+        let price = self.mint_price();
+        if value_sent < price {
+            log_event("Minting Failed", &format!("Sent {} but the price is {}", value_sent, price));
+            return Err(format!("Insufficient payment: price is {}", price));
+        }
+        self.bank.ensure_funds(&recipient, value_sent)?;
+
+        self.mint_to(&recipient.clone(), recipient.clone(), token_id)?;
+        self.bank.pay_contract(&recipient, value_sent)?;
+        log_event("Payment Received", &format!("From: {}, Amount: {}", recipient, value_sent));
+        self.after_mutation(
+            LogEntry::new("PaymentReceived")
+                .token(token_id)
+                .from(&recipient)
+                .details(format!("Amount: {}", value_sent)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Hollanda usulü mint'i başlatır; fiyat `duration` boyunca her `step` saniyede bir düşer (sadece ADMIN rolü)
+    pub fn configure_dutch_auction(
+        &mut self,
+        caller: String,
+        start_price: u64,
+        end_price: u64,
+        duration: u64,
+        step: u64,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("configure_dutch_auction");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Dutch Auction Setup Failed")?;
+
+        let auction = DutchAuction::new(start_price, end_price, self.clock.now(), duration, step)?;
+        let details = format!(
+            "Start price: {}, End price: {}, Duration: {}, Step: {}",
+            start_price, end_price, duration, step
+        );
+        self.dutch_auction = Some(auction);
+        log_event("Dutch Auction Configured", &details);
+        self.after_mutation(LogEntry::new("DutchAuctionConfigured").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Hollanda usulü mint'in verilen andaki fiyatını döner
+    pub fn current_price(&self, now: u64) -> Option<u64> {
+        self.dutch_auction.as_ref().map(|auction| auction.price_at(now))
+    }
+
+    /// Hollanda usulü mint ayarlarını döner
+    pub fn dutch_auction(&self) -> Option<DutchAuction> {
+        self.dutch_auction.clone()
+    }
+
+    /// Sıradaki Token ID'yi güncel Hollanda fiyatından mint'ler ve Token ID'yi döner
+    ///
+    /// Gönderilen tutar fiyattan az olamaz; fazlası alıcının bakiyesine iade edilir.
+    pub fn mint_dutch(&mut self, recipient: String, value_sent: u64) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("mint_dutch");
+        self.ensure_sender(&recipient)?;
+        self.ensure_not_paused("Minting Failed")?;
+
+        let price = self.current_price(self.clock.now()).ok_or("Dutch auction is not configured")?;
+        if value_sent < price {
+            log_event("Minting Failed", &format!("Sent {} but the price is {}", value_sent, price));
+            return Err(format!("Insufficient payment: price is {}", price));
+        }
+        self.bank.ensure_funds(&recipient, value_sent)?;
+
+        let mut token_id = self.next_dutch_token_id;
+        while self.owner_lookup(token_id).is_some() || self.batch_containing(token_id).is_some() {
+            token_id = token_id.checked_add(1).ok_or("Token ID overflow")?;
+        }
+        self.mint_to(&recipient.clone(), recipient.clone(), token_id)?;
+        self.next_dutch_token_id = token_id.saturating_add(1);
+        // Yalnızca fiyat tahsil edilir; fazlası gönderene geri döner
+        self.bank.pay_contract(&recipient, price)?;
+        log_event(
+            "Payment Received",
+            &format!("From: {}, Amount: {}, Refund: {}", recipient, price, value_sent - price),
+        );
+        self.after_mutation(
+            LogEntry::new("PaymentReceived")
+                .token(token_id)
+                .from(&recipient)
+                .details(format!("Amount: {}, Refund: {}", price, value_sent - price)),
+        );
+        timer.finish(Ok(token_id))
+    }
+
+    /// Aktif aşamanın fiyatını, aşama yoksa varsayılan mint fiyatını döner
+    pub fn mint_price(&self) -> u64 {
+        self.phases
+            .active(self.clock.now())
+            .map(|phase| phase.price)
+            .unwrap_or(self.mint_price)
+    }
+
+    /// Aşama dışındaki ücretli mint'lerin token başına fiyatını ayarlar (sadece ADMIN rolü)
+    pub fn set_mint_price(&mut self, caller: String, price: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_mint_price");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Mint Price Update Failed")?;
+
+        self.mint_price = price;
+        log_event("Mint Price Updated", &format!("Price: {}", price));
+        self.after_mutation(LogEntry::new("MintPriceUpdated").from(&caller).details(format!("Price: {}", price)));
+        timer.finish(Ok(()))
+    }
+
+    /// Mint gelirlerinin aktarılacağı hazine adresini ayarlar (sadece ADMIN rolü)
+    pub fn set_treasury(&mut self, caller: String, treasury: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_treasury");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Treasury Update Failed")?;
+
+        self.treasury = Some(treasury.clone());
+        log_event("Treasury Updated", &format!("Treasury: {}", treasury));
+        self.after_mutation(LogEntry::new("TreasuryUpdated").from(&caller).to(&treasury));
+        timer.finish(Ok(()))
+    }
+
+    /// Hazine adresini döner (ayarlanmadıysa kontrat sahibi)
+    pub fn treasury(&self) -> String {
+        self.treasury.clone().unwrap_or_else(|| self.roles.owner())
+    }
+
+    /// Kontrat bakiyesini hazineye aktarır ve aktarılan miktarı döner (sadece ADMIN rolü veya hazine)
+    pub fn withdraw(&mut self, caller: String) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("withdraw");
+        self.ensure_sender(&caller)?;
+        let treasury = self.treasury();
+        if caller != treasury {
+            self.ensure_role(&caller, ADMIN_ROLE, "Withdraw Failed")?;
+        }
+
+        let amount = self.bank.withdraw_contract(&treasury)?;
+        log_event("Withdrawn", &format!("To: {}, Amount: {}", treasury, amount));
+        self.after_mutation(
+            LogEntry::new("Withdrawn")
+                .from(&caller)
+                .to(&treasury)
+                .details(format!("Amount: {}", amount)),
+        );
+        timer.finish(Ok(amount))
+    }
+
+    /// Adrese simülasyon için ETH ekler
+    pub fn deposit(&mut self, address: String, amount: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("deposit");
+        self.ensure_sender(&address)?;
+        self.bank.deposit(&address, amount)?;
+        self.after_mutation(LogEntry::new("Deposit").to(&address).details(format!("Amount: {}", amount)));
+        timer.finish(Ok(()))
+    }
+
+    /// Adresin simüle ETH bakiyesini döner
+    pub fn eth_balance(&self, address: String) -> u64 {
+        self.bank.balance_of(&address)
+    }
+
+    /// Kontratta biriken, henüz çekilmemiş bakiyeyi döner
+    pub fn contract_balance(&self) -> u64 {
+        self.bank.contract_balance()
+    }
+
+    /// Token'ı sabit fiyatla satışa çıkarır; satıcı pazaryeri operatörüne onay vermiş olmalıdır
+    pub fn list(&mut self, seller: String, token_id: u64, price: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("list");
+        self.ensure_sender(&seller)?;
+        self.ensure_sale_allowed(&seller, token_id, "Listing Failed")?;
+
+        self.marketplace.list(token_id, &seller, price)?;
+        log_event("Token Listed", &format!("Token ID: {}, Seller: {}, Price: {}", token_id, seller, price));
+        self.after_mutation(
+            LogEntry::new("Listed")
+                .token(token_id)
+                .from(&seller)
+                .details(format!("Price: {}", price)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// İlanı kaldırır (sadece ilanı veren veya token'ın güncel sahibi)
+    pub fn cancel_listing(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("cancel_listing");
+        self.ensure_sender(&caller)?;
+        let listing = self.marketplace.listing(token_id).ok_or("Token is not listed")?;
+        if listing.seller != caller && self.owner_lookup(token_id) != Some(&caller) {
+            log_event("Listing Cancel Failed", "Unauthorized attempt");
+            return Err("Only the seller can cancel the listing".to_string());
+        }
+
+        self.marketplace.remove(token_id);
+        log_event("Listing Cancelled", &format!("Token ID: {}", token_id));
+        self.after_mutation(LogEntry::new("ListingCancelled").token(token_id).from(&caller));
+        timer.finish(Ok(()))
+    }
+
+    /// İlanlı token'ı satın alır: token alıcıya geçer, ödemeden ERC-2981 royalty'si düşülüp kalanı satıcıya aktarılır
+    pub fn buy(&mut self, buyer: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("buy");
+        self.ensure_sender(&buyer)?;
+        let listing = self.marketplace.listing(token_id).cloned().ok_or("Token is not listed")?;
+        if self.owner_lookup(token_id) != Some(&listing.seller) {
+            return Err("Listing is no longer valid".to_string());
+        }
+
+        // Ödeme ve transfer token yer değiştirmeden önce doğrulanır; transfer ilanı da kaldırır
+        let payouts = self.sale_payouts(&listing.seller, token_id, listing.price);
+        self.bank
+            .ensure_payouts(&buyer, &payouts)
+            .inspect_err(|err| log_event("Purchase Failed", err))?;
+        let transfer = self.check_transfer(MARKETPLACE_OPERATOR, &buyer, token_id)?;
+        self.apply_transfer(transfer);
+        self.bank.pay_out(&buyer, &payouts)?;
+        log_event(
+            "Token Sold",
+            &format!("Token ID: {}, Seller: {}, Buyer: {}, Price: {}", token_id, listing.seller, buyer, listing.price),
+        );
+        self.after_mutation(
+            LogEntry::new("Sale")
+                .token(token_id)
+                .from(&listing.seller)
+                .to(&buyer)
+                .details(format!("Price: {}", listing.price)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Birden fazla token'ı tek fiyatla paket olarak satışa çıkarır ve paket ID'sini döner
+    pub fn list_bundle(&mut self, seller: String, token_ids: Vec<u64>, price: u64) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("list_bundle");
+        self.ensure_sender(&seller)?;
+        for &token_id in &token_ids {
+            self.ensure_sale_allowed(&seller, token_id, "Bundle Listing Failed")?;
+        }
+
+        let bundle = Bundle {
+            seller: seller.clone(),
+            token_ids,
+            price,
+        };
+        let bundle_id = self
+            .marketplace
+            .list_bundle(bundle)
+            .inspect_err(|err| log_event("Bundle Listing Failed", err))?;
+        log_event("Bundle Listed", &format!("Bundle ID: {}, Seller: {}, Price: {}", bundle_id, seller, price));
+        self.after_mutation(
+            LogEntry::new("BundleListed")
+                .from(&seller)
+                .details(format!("Bundle ID: {}, Price: {}", bundle_id, price)),
+        );
+        timer.finish(Ok(bundle_id))
+    }
+
+    /// Paket ilanını kaldırır (sadece ilanı veren)
+    pub fn cancel_bundle(&mut self, caller: String, bundle_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("cancel_bundle");
+        self.ensure_sender(&caller)?;
+        let bundle = self.marketplace.bundle(bundle_id).ok_or("Bundle is not listed")?;
+        if bundle.seller != caller {
+            log_event("Bundle Cancel Failed", "Unauthorized attempt");
+            return Err("Only the seller can cancel the bundle".to_string());
+        }
+
+        self.marketplace.remove_bundle(bundle_id);
+        log_event("Bundle Cancelled", &format!("Bundle ID: {}", bundle_id));
+        self.after_mutation(
+            LogEntry::new("BundleCancelled")
+                .from(&caller)
+                .details(format!("Bundle ID: {}", bundle_id)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Paketi atomik olarak satın alır: ya tüm tokenlar alıcıya geçer ya da hiçbiri
+    ///
+    /// Fiyat tokenlara eşit bölünür; her token'ın royalty'si kendi payı üzerinden hesaplanır.
+    pub fn buy_bundle(&mut self, buyer: String, bundle_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("buy_bundle");
+        self.ensure_sender(&buyer)?;
+        let bundle = self.marketplace.bundle(bundle_id).cloned().ok_or("Bundle is not listed")?;
+
+        // Tüm transferler ve ödemeler önce doğrulanır; ilk transfer paketi ilandan kaldırır
+        let mut transfers = Vec::with_capacity(bundle.token_ids.len());
+        let mut payouts = Vec::new();
+        for (token_id, share) in bundle.shares() {
+            if self.owner_lookup(token_id) != Some(&bundle.seller) {
+                log_event("Bundle Purchase Failed", &format!("Token ID: {} changed hands", token_id));
+                return Err("Bundle is no longer valid".to_string());
+            }
+            transfers.push(self.check_transfer(MARKETPLACE_OPERATOR, &buyer, token_id)?);
+            payouts.extend(self.sale_payouts(&bundle.seller, token_id, share));
+        }
+        self.bank
+            .ensure_payouts(&buyer, &payouts)
+            .inspect_err(|err| log_event("Bundle Purchase Failed", err))?;
+        for transfer in transfers {
+            self.apply_transfer(transfer);
+        }
+        self.bank.pay_out(&buyer, &payouts)?;
+        log_event(
+            "Bundle Sold",
+            &format!("Bundle ID: {}, Seller: {}, Buyer: {}, Price: {}", bundle_id, bundle.seller, buyer, bundle.price),
+        );
+        self.after_mutation(
+            LogEntry::new("BundleSale")
+                .from(&bundle.seller)
+                .to(&buyer)
+                .details(format!("Bundle ID: {}, Price: {}", bundle_id, bundle.price)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// İlanda olmayan token için teklif verir; tutar alıcının bakiyesinden emanete alınır
+    ///
+    /// Token'ın mevcut en yüksek teklifi geçilirse (veya süresi dolmuşsa) o teklif otomatik iade edilir.
+    pub fn make_offer(&mut self, buyer: String, token_id: u64, amount: u64, expiry: u64) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("make_offer");
+        self.ensure_sender(&buyer)?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+        self.bank.ensure_funds(&buyer, amount)?;
+
+        let offer = Offer {
+            buyer: buyer.clone(),
+            token_id,
+            amount,
+            expiry,
+        };
+        let (offer_id, replaced) = self
+            .marketplace
+            .add_offer(offer, self.clock.now())
+            .inspect_err(|err| log_event("Offer Failed", err))?;
+        self.bank.escrow(&buyer, amount)?;
+        if let Some(replaced) = replaced {
+            self.refund_offer(&replaced)?;
+        }
+        log_event(
+            "Offer Made",
+            &format!("Offer ID: {}, Token ID: {}, Buyer: {}, Amount: {}", offer_id, token_id, buyer, amount),
+        );
+        self.after_mutation(
+            LogEntry::new("OfferMade")
+                .token(token_id)
+                .from(&buyer)
+                .details(format!("Offer ID: {}, Amount: {}, Expiry: {}", offer_id, amount, expiry)),
+        );
+        timer.finish(Ok(offer_id))
+    }
+
+    /// Teklifi geri çeker ve emanetteki tutarı iade eder (sadece teklifi veren)
+    pub fn cancel_offer(&mut self, buyer: String, offer_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("cancel_offer");
+        self.ensure_sender(&buyer)?;
+        let offer = self.marketplace.offer(offer_id).ok_or("Offer does not exist")?;
+        if offer.buyer != buyer {
+            log_event("Offer Cancel Failed", "Unauthorized attempt");
+            return Err("Only the buyer can cancel the offer".to_string());
+        }
+
+        let offer = self.marketplace.remove_offer(offer_id).expect("offer exists");
+        self.refund_offer(&offer)?;
+        self.after_mutation(LogEntry::new("OfferCancelled").token(offer.token_id).from(&buyer));
+        timer.finish(Ok(()))
+    }
+
+    /// Teklifi kabul eder: token alıcıya geçer, emanetteki tutardan royalty düşülüp kalanı satıcıya ödenir
+    pub fn accept_offer(&mut self, seller: String, offer_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("accept_offer");
+        self.ensure_sender(&seller)?;
+        let offer = self.marketplace.offer(offer_id).cloned().ok_or("Offer does not exist")?;
+        if offer.expiry <= self.clock.now() {
+            return Err("Offer has expired".to_string());
+        }
+        self.ensure_sale_allowed(&seller, offer.token_id, "Offer Accept Failed")?;
+
+        self.operator_transfer(offer.buyer.clone(), offer.token_id)?;
+        self.marketplace.remove_offer(offer_id);
+        self.bank.release_escrow(&offer.buyer, offer.amount)?;
+        self.pay_sale(&offer.buyer, &seller, offer.token_id, offer.amount)?;
+        log_event(
+            "Offer Accepted",
+            &format!(
+                "Offer ID: {}, Token ID: {}, Seller: {}, Buyer: {}",
+                offer_id, offer.token_id, seller, offer.buyer
+            ),
+        );
+        self.after_mutation(
+            LogEntry::new("Sale")
+                .token(offer.token_id)
+                .from(&seller)
+                .to(&offer.buyer)
+                .details(format!("Offer ID: {}, Price: {}", offer_id, offer.amount)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Süresi dolmuş tüm teklifleri iade eder ve iade edilen teklif sayısını döner; herkes çağırabilir
+    pub fn refund_expired_offers(&mut self) -> Result<u32, String> {
+        let timer = self.perf.start_fallible("refund_expired_offers");
+        let expired = self.marketplace.remove_expired_offers(self.clock.now());
+        for offer in &expired {
+            self.refund_offer(offer)?;
+        }
+        if !expired.is_empty() {
+            self.after_mutation(LogEntry::new("OffersExpired").details(format!("Refunded: {}", expired.len())));
+        }
+        timer.finish(Ok(expired.len() as u32))
+    }
+
+    /// Teklifin bilgilerini döner
+    pub fn get_offer(&self, offer_id: u64) -> Option<Offer> {
+        self.marketplace.offer(offer_id).cloned()
+    }
+
+    /// Token'ın en yüksek teklifini döner
+    pub fn best_offer(&self, token_id: u64) -> Option<Offer> {
+        self.marketplace.best_offer(token_id).cloned()
+    }
+
+    /// Teklif vb. için emanette tutulan toplam tutarı döner
+    pub fn escrowed_funds(&self) -> u64 {
+        self.bank.escrowed()
+    }
+
+    /// Token'ı `duration` saniye sürecek açık artırmaya çıkarır; token sonuçlanana kadar pazaryerinde emanette tutulur
+    pub fn create_auction(&mut self, seller: String, token_id: u64, reserve: u64, duration: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("create_auction");
+        self.ensure_sender(&seller)?;
+        self.ensure_sale_allowed(&seller, token_id, "Auction Creation Failed")?;
+        if duration == 0 {
+            return Err("Auction duration must be greater than zero".to_string());
+        }
+
+        let end_time = self.clock.now().saturating_add(duration);
+        self.auctions.create(token_id, &seller, reserve, end_time)?;
+        if let Err(err) = self.operator_transfer(MARKETPLACE_OPERATOR.to_string(), token_id) {
+            self.auctions.cancel(token_id)?;
+            return Err(err);
+        }
+        log_event(
+            "Auction Created",
+            &format!("Token ID: {}, Seller: {}, Reserve: {}, Ends: {}", token_id, seller, reserve, end_time),
+        );
+        self.after_mutation(
+            LogEntry::new("AuctionCreated")
+                .token(token_id)
+                .from(&seller)
+                .details(format!("Reserve: {}, Ends: {}", reserve, end_time)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Açık artırmaya teklif verir; tutar emanete alınır, geçilen teklif iade edilir
+    ///
+    /// Bitişe uzatma penceresinden az süre kala gelen teklif, bitişi pencere kadar ileri alır.
+    pub fn bid(&mut self, bidder: String, token_id: u64, amount: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("bid");
+        self.ensure_sender(&bidder)?;
+        self.bank.ensure_funds(&bidder, amount)?;
+
+        let outbid = self
+            .auctions
+            .bid(token_id, &bidder, amount, self.clock.now())
+            .inspect_err(|err| log_event("Bid Failed", err))?;
+        self.bank.escrow(&bidder, amount)?;
+        if let Some((previous, refund)) = outbid {
+            self.bank.release_escrow(&previous, refund)?;
+        }
+        log_event("Bid Placed", &format!("Token ID: {}, Bidder: {}, Amount: {}", token_id, bidder, amount));
+        self.after_mutation(
+            LogEntry::new("Bid")
+                .token(token_id)
+                .from(&bidder)
+                .details(format!("Amount: {}", amount)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Süresi dolan açık artırmayı sonuçlandırır; herkes çağırabilir
+    ///
+    /// Teklif varsa token kazanana geçer ve bedel (royalty düşülerek) satıcıya ödenir; yoksa token satıcıya döner.
+    pub fn settle_auction(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("settle_auction");
+        self.ensure_sender(&caller)?;
+        let auction = self.auctions.get(token_id).cloned().ok_or("Auction does not exist")?;
+        if self.clock.now() < auction.end_time {
+            return Err(format!("Auction ends at {}", auction.end_time));
+        }
+
+        let winner = auction.highest_bidder.clone().unwrap_or_else(|| auction.seller.clone());
+        self.operator_transfer(winner.clone(), token_id)?;
+        self.auctions.close(token_id, self.clock.now())?;
+        if auction.highest_bidder.is_some() {
+            self.bank.release_escrow(&winner, auction.highest_bid)?;
+            self.pay_sale(&winner, &auction.seller, token_id, auction.highest_bid)?;
+        }
+        log_event("Auction Settled", &format!("Token ID: {}, Winner: {}", token_id, winner));
+        self.after_mutation(
+            LogEntry::new("AuctionSettled")
+                .token(token_id)
+                .from(&caller)
+                .to(&winner)
+                .details(format!("Price: {}", auction.highest_bid)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Teklif almamış açık artırmayı iptal edip token'ı satıcıya iade eder (sadece satıcı)
+    pub fn cancel_auction(&mut self, seller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("cancel_auction");
+        self.ensure_sender(&seller)?;
+        let auction = self.auctions.get(token_id).ok_or("Auction does not exist")?;
+        if auction.seller != seller {
+            log_event("Auction Cancel Failed", "Unauthorized attempt");
+            return Err("Only the seller can cancel the auction".to_string());
+        }
+
+        self.auctions.cancel(token_id)?;
+        self.operator_transfer(seller.clone(), token_id)?;
+        self.after_mutation(LogEntry::new("AuctionCancelled").token(token_id).from(&seller));
+        timer.finish(Ok(()))
+    }
+
+    /// Açık artırma kurallarını ayarlar (sadece ADMIN rolü)
+    pub fn set_auction_settings(
+        &mut self,
+        caller: String,
+        min_increment_bps: u32,
+        extension_window: u64,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_auction_settings");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Auction Settings Update Failed")?;
+
+        self.auctions.configure(min_increment_bps, extension_window)?;
+        let details = format!("Min increment: {} bps, Extension: {}s", min_increment_bps, extension_window);
+        log_event("Auction Settings Updated", &details);
+        self.after_mutation(LogEntry::new("AuctionSettingsUpdated").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın açık artırmasını döner
+    pub fn get_auction(&self, token_id: u64) -> Option<Auction> {
+        self.auctions.get(token_id).cloned()
+    }
+
+    /// Açık artırmada kabul edilecek en düşük teklifi döner
+    pub fn minimum_bid(&self, token_id: u64) -> Option<u64> {
+        self.auctions.minimum_bid(token_id)
+    }
+
+    /// Karşı tarafa takas teklif eder; teklif edilen tokenlar ve tutar hemen emanete alınır
+    ///
+    /// Tokenlar pazaryeri operatörüne devredilir, bu yüzden operatör onaylı olmalıdır.
+    pub fn create_swap(
+        &mut self,
+        maker: String,
+        taker: String,
+        offered: SwapSide,
+        requested: SwapSide,
+        expiry: u64,
+    ) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("create_swap");
+        self.ensure_sender(&maker)?;
+        if expiry <= self.clock.now() {
+            return Err("Swap expiry must be in the future".to_string());
+        }
+        let swap = Swap {
+            maker: maker.clone(),
+            taker: taker.clone(),
+            maker_side: offered.clone(),
+            taker_side: requested,
+            expiry,
+            taker_deposited: false,
+            maker_confirmed: false,
+            taker_confirmed: false,
+        };
+
+        let escrow = self.check_swap_escrow(&maker, &offered)?;
+        let swap_id = self.swaps.create(swap).inspect_err(|err| log_event("Swap Creation Failed", err))?;
+        self.apply_swap_escrow(&maker, &offered, escrow)?;
+        log_event("Swap Created", &format!("Swap ID: {}, Maker: {}, Taker: {}", swap_id, maker, taker));
+        self.after_mutation(
+            LogEntry::new("SwapCreated")
+                .from(&maker)
+                .to(&taker)
+                .details(format!("Swap ID: {}", swap_id)),
+        );
+        timer.finish(Ok(swap_id))
+    }
+
+    /// Karşı tarafın istenen tokenları ve tutarı emanete koymasını sağlar (sadece takasın karşı tarafı)
+    pub fn deposit_swap(&mut self, taker: String, swap_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("deposit_swap");
+        self.ensure_sender(&taker)?;
+        let swap = self.active_swap(swap_id)?;
+        if swap.taker != taker || swap.taker_deposited {
+            return Err("Only the taker can deposit, and only once".to_string());
+        }
+        let side = swap.taker_side.clone();
+
+        let escrow = self.check_swap_escrow(&taker, &side)?;
+        self.apply_swap_escrow(&taker, &side, escrow)?;
+        self.swaps.get_mut(swap_id).expect("swap exists").taker_deposited = true;
+        self.after_mutation(LogEntry::new("SwapDeposited").from(&taker).details(format!("Swap ID: {}", swap_id)));
+        timer.finish(Ok(()))
+    }
+
+    /// Tarafın takası onaylamasını sağlar
+    ///
+    /// İki taraf da onayladığında ve emanetler tamamsa takas atomik olarak gerçekleşir.
+    pub fn confirm_swap(&mut self, caller: String, swap_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("confirm_swap");
+        self.ensure_sender(&caller)?;
+        let swap = self.active_swap(swap_id)?;
+        if !swap.is_party(&caller) {
+            return Err("Only swap parties can confirm".to_string());
+        }
+
+        let swap = self.swaps.get_mut(swap_id).expect("swap exists");
+        if swap.maker == caller {
+            swap.maker_confirmed = true;
+        } else {
+            swap.taker_confirmed = true;
+        }
+        let swap = swap.clone();
+        self.after_mutation(LogEntry::new("SwapConfirmed").from(&caller).details(format!("Swap ID: {}", swap_id)));
+        if !swap.ready() {
+            return timer.finish(Ok(()));
+        }
+
+        let to_taker = self.check_swap_release(&swap.taker, &swap.maker_side)?;
+        let to_maker = self.check_swap_release(&swap.maker, &swap.taker_side)?;
+        self.apply_swap_release(&swap.taker, &swap.maker_side, to_taker)?;
+        self.apply_swap_release(&swap.maker, &swap.taker_side, to_maker)?;
+        self.swaps.remove(swap_id);
+        log_event("Swap Executed", &format!("Swap ID: {}", swap_id));
+        self.after_mutation(
+            LogEntry::new("SwapExecuted")
+                .from(&swap.maker)
+                .to(&swap.taker)
+                .details(format!("Swap ID: {}", swap_id)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Takası iptal edip emanetleri sahiplerine iade eder
+    ///
+    /// Taraflar her zaman, diğer adresler yalnızca süre dolduktan sonra iptal edebilir.
+    pub fn cancel_swap(&mut self, caller: String, swap_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("cancel_swap");
+        self.ensure_sender(&caller)?;
+        let swap = self.swaps.get(swap_id).cloned().ok_or("Swap does not exist")?;
+        if !swap.is_party(&caller) && self.clock.now() < swap.expiry {
+            log_event("Swap Cancel Failed", "Unauthorized attempt");
+            return Err("Only swap parties can cancel before expiry".to_string());
+        }
+
+        let to_maker = self.check_swap_release(&swap.maker, &swap.maker_side)?;
+        let to_taker = if swap.taker_deposited {
+            Some(self.check_swap_release(&swap.taker, &swap.taker_side)?)
+        } else {
+            None
+        };
+        self.apply_swap_release(&swap.maker, &swap.maker_side, to_maker)?;
+        if let Some(to_taker) = to_taker {
+            self.apply_swap_release(&swap.taker, &swap.taker_side, to_taker)?;
+        }
+        self.swaps.remove(swap_id);
+        log_event("Swap Cancelled", &format!("Swap ID: {}", swap_id));
+        self.after_mutation(LogEntry::new("SwapCancelled").from(&caller).details(format!("Swap ID: {}", swap_id)));
+        timer.finish(Ok(()))
+    }
+
+    /// Takasın bilgilerini döner
+    pub fn get_swap(&self, swap_id: u64) -> Option<Swap> {
+        self.swaps.get(swap_id).cloned()
+    }
+
+    /// Token'ın aktif ilanını döner
+    pub fn get_listing(&self, token_id: u64) -> Option<Listing> {
+        self.marketplace.listing(token_id).cloned()
+    }
+
+    /// Paket ilanının bilgilerini döner
+    pub fn get_bundle(&self, bundle_id: u64) -> Option<Bundle> {
+        self.marketplace.bundle(bundle_id).cloned()
+    }
+
+    /// İlanlı tüm Token ID'leri artan sırada döner
+    pub fn listed_tokens(&self) -> Vec<u64> {
+        self.marketplace.listed_tokens()
+    }
+
+    /// Satışları yürüten pazaryeri operatörünün adresini döner
+    pub fn marketplace_operator(&self) -> String {
+        MARKETPLACE_OPERATOR.to_string()
+    }
+
+    /// Belirtilen ana kadar transfer edilemeyen (vesting) bir token mint'ler (sadece MINTER rolü)
+    pub fn mint_vested(
+        &mut self,
+        caller: String,
+        recipient: String,
+        token_id: u64,
+        unlock_timestamp: u64,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("mint_vested");
+        self.ensure_sender(&caller)?;
+        self.ensure_not_paused("Minting Failed")?;
+        self.ensure_role(&caller, MINTER_ROLE, "Minting Failed")?;
+
+        self.mint_to(&caller, recipient, token_id)?;
+        self.vesting_unlocks.insert(token_id, unlock_timestamp);
+        log_event("Token Vesting", &format!("Token ID: {}, Unlocks At: {}", token_id, unlock_timestamp));
+        timer.finish(Ok(()))
+    }
+
+    /// Bir token'ın art arda iki transferi arasındaki bekleme süresini ayarlar (sadece ADMIN rolü)
+    pub fn set_transfer_cooldown(&mut self, caller: String, seconds: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_transfer_cooldown");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Cooldown Update Failed")?;
+
+        self.transfer_cooldown = seconds;
+        log_event("Transfer Cooldown Updated", &format!("Seconds: {}", seconds));
+        self.after_mutation(LogEntry::new("TransferCooldownUpdated").from(&caller).details(seconds.to_string()));
+        timer.finish(Ok(()))
+    }
+
+    /// Güncel transfer bekleme süresini döner
+    pub fn transfer_cooldown(&self) -> u64 {
+        self.transfer_cooldown
+    }
+
+    /// Token'ın yeniden transfer edilebileceği en erken anı döner (hiç transfer edilmediyse 0)
+    pub fn next_transferable_at(&self, token_id: u64) -> u64 {
+        self.last_transfers
+            .get(&token_id)
+            .map(|&at| at.saturating_add(self.transfer_cooldown))
+            .unwrap_or(0)
+    }
+
+    /// Token'ın vesting kilidinin açılacağı anı döner
+    pub fn vesting_unlock(&self, token_id: u64) -> Option<u64> {
+        self.vesting_unlocks.get(&token_id).copied()
+    }
+
+    /// `[start_id, end_id]` aralığındaki tokenları Merkle köküyle dağıtan airdrop'u başlatır (sadece ADMIN rolü)
+    ///
+    /// `index` sıradaki alıcı `start_id + index` token'ını talep eder; önceki airdrop'un yerini alır.
+    pub fn create_airdrop(
+        &mut self,
+        caller: String,
+        merkle_root: String,
+        start_id: u64,
+        end_id: u64,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("create_airdrop");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Airdrop Creation Failed")?;
+
+        let airdrop = Airdrop::new(parse_hash(&merkle_root)?, start_id, end_id)
+            .inspect_err(|err| log_event("Airdrop Creation Failed", err))?;
+        self.airdrop = Some(airdrop);
+        let details = format!("Root: {}, Token IDs: {}..={}", merkle_root, start_id, end_id);
+        log_event("Airdrop Created", &details);
+        let entry = LogEntry::new("AirdropCreated").token_range(start_id, end_id).from(&caller);
+        self.after_mutation(entry.details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Alıcının airdrop'taki token'ını Merkle kanıtıyla talep edip mint'ler ve Token ID'yi döner
+    pub fn claim(&mut self, recipient: String, index: u64, proof: Vec<String>) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("claim");
+        self.ensure_sender(&recipient)?;
+        self.ensure_not_paused("Airdrop Claim Failed")?;
+
+        let airdrop = self.airdrop.as_ref().ok_or("No airdrop is active")?;
+        let proof = proof.iter().map(|hash| parse_hash(hash)).collect::<Result<Vec<_>, _>>()?;
+        let token_id = airdrop
+            .verify_claim(&recipient, index, &proof)
+            .inspect_err(|err| log_event("Airdrop Claim Failed", err))?;
+
+        self.mint_to(&recipient.clone(), recipient, token_id)?;
+        self.airdrop.as_mut().expect("airdrop is active").mark_claimed(index);
+        timer.finish(Ok(token_id))
+    }
+
+    /// Sıradaki airdrop talebinin kullanılıp kullanılmadığını döner
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.airdrop.as_ref().map(|airdrop| airdrop.is_claimed(index)).unwrap_or(false)
+    }
+
+    /// Allowlist Merkle kökünü ayarlar; boş değer allowlist mint'i kapatır (sadece ADMIN rolü)
+    pub fn set_allowlist_root(&mut self, caller: String, root: Option<String>) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_allowlist_root");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Allowlist Update Failed")?;
+
+        self.allowlist_root = root.as_deref().map(parse_hash).transpose()?;
+        let details = format!("Root: {}", root.unwrap_or_default());
+        log_event("Allowlist Root Set", &details);
+        self.after_mutation(LogEntry::new("AllowlistRootSet").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// İmzalı mesajlarda kullanılan EIP-712 alan bilgilerini ayarlar (sadece ADMIN rolü)
+    pub fn set_eip712_domain(
+        &mut self,
+        caller: String,
+        name: String,
+        version: String,
+        chain_id: u64,
+        verifying_contract: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_eip712_domain");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Domain Update Failed")?;
+
+        let domain = Eip712Domain::new(name, version, chain_id, verifying_contract);
+        domain.separator_hash()?;
+        let details = format!("Name: {}, Chain ID: {}", domain.name, domain.chain_id);
+        self.domain = domain;
+        log_event("Domain Set", &details);
+        self.after_mutation(LogEntry::new("DomainSet").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Güncel EIP-712 alan bilgilerini döner
+    pub fn eip712_domain(&self) -> Eip712Domain {
+        self.domain.clone()
+    }
+
+    /// Kuponun imzalanması gereken EIP-712 özetini döner
+    pub fn voucher_digest(&self, voucher: &NFTVoucher) -> Result<String, String> {
+        voucher.digest(&self.domain).map(|digest| to_hex(&digest))
+    }
+
+    /// SIGNER rolündeki bir adresin imzaladığı kuponu doğrulayıp token'ı alıcıya mint'ler
+    pub fn redeem_voucher(&mut self, voucher: &NFTVoucher, signature: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("redeem_voucher");
+        self.ensure_not_paused("Voucher Redemption Failed")?;
+
+        self.ensure_valid_uri(&voucher.uri, "Voucher Redemption Failed")?;
+        let digest = voucher.digest(&self.domain)?;
+        let digest_hex = to_hex(&digest);
+        if self.redeemed_vouchers.contains(&digest_hex) {
+            log_event("Voucher Redemption Failed", "Voucher already redeemed");
+            return Err("Voucher already redeemed".to_string());
+        }
+
+        let signer = recover_address(&digest, &signature)?;
+        let authorized = self
+            .roles
+            .list_role_users(SIGNER_ROLE.to_string())
+            .iter()
+            .any(|user| user.eq_ignore_ascii_case(&signer));
+        if !authorized {
+            log_event("Voucher Redemption Failed", &format!("Unauthorized signer: {}", signer));
+            return Err("Voucher is not signed by an authorized signer".to_string());
+        }
+
+        self.bank
+            .ensure_funds(&voucher.recipient, voucher.price)
+            .inspect_err(|err| log_event("Voucher Redemption Failed", err))?;
+        let mint = self.check_mint(&signer, &voucher.recipient, voucher.token_id)?;
+
+        // URI ve kullanılmış kupon, mint'in kaydettiği duruma dahil olsun diye önceden yazılır
+        self.token_uris.insert(voucher.token_id, voucher.uri.clone());
+        self.redeemed_vouchers.insert(digest_hex);
+        self.apply_mint(mint);
+        self.bank.pay_contract(&voucher.recipient, voucher.price)?;
+        log_event(
+            "Voucher Redeemed",
+            &format!("Token ID: {}, Signer: {}, Price: {}", voucher.token_id, signer, voucher.price),
+        );
+        if voucher.price > 0 {
+            self.after_mutation(
+                LogEntry::new("PaymentReceived")
+                    .token(voucher.token_id)
+                    .from(&voucher.recipient)
+                    .details(format!("Amount: {}", voucher.price)),
+            );
+        }
+        timer.finish(Ok(()))
+    }
+
+    /// Satış emrinin imzalanması gereken EIP-712 özetini döner
+    pub fn order_hash(&self, order: &Order) -> Result<String, String> {
+        order.digest(&self.domain).map(|digest| to_hex(&digest))
+    }
+
+    /// Adresin emirlerinde kullanması gereken güncel sayacı döner
+    pub fn order_nonce(&self, offerer: String) -> u64 {
+        self.order_nonces.get(&offerer.to_lowercase()).copied().unwrap_or(0)
+    }
+
+    /// Emrin karşılanabilir olup olmadığını zincirdekiyle aynı kurallarla doğrular
+    ///
+    /// İmza, sayaç, süre, token sahipliği ve ödemelerin royalty'yi karşılaması kontrol edilir.
+    pub fn validate_order(&self, order: &Order, signature: String) -> Result<(), String> {
+        self.check_order(order, &signature).map(|_| ())
+    }
+
+    /// İmzalı emri karşılar: tüm ödemeler alıcıdan yapılır ve token alıcıya geçer (atomik)
+    pub fn fulfill_order(&mut self, fulfiller: String, order: &Order, signature: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("fulfill_order");
+        self.ensure_sender(&fulfiller)?;
+        self.ensure_not_paused("Order Fulfillment Failed")?;
+        let digest = self
+            .check_order(order, &signature)
+            .inspect_err(|err| log_event("Order Fulfillment Failed", err))?;
+        let total = order.total()?;
+
+        // Ödemeler ve transfer token yer değiştirmeden önce doğrulanır
+        let payouts: Vec<(String, u64)> =
+            order.consideration.iter().map(|item| (item.recipient.clone(), item.amount)).collect();
+        self.bank
+            .ensure_payouts(&fulfiller, &payouts)
+            .inspect_err(|err| log_event("Order Fulfillment Failed", err))?;
+        let transfer = self.check_transfer(MARKETPLACE_OPERATOR, &fulfiller, order.token_id)?;
+        self.apply_transfer(transfer);
+        self.bank.pay_out(&fulfiller, &payouts)?;
+        self.closed_orders.insert(digest.clone());
+        log_event(
+            "Order Fulfilled",
+            &format!("Order: {}, Token ID: {}, Buyer: {}, Total: {}", digest, order.token_id, fulfiller, total),
+        );
+        self.after_mutation(
+            LogEntry::new("OrderFulfilled")
+                .token(order.token_id)
+                .from(&order.offerer)
+                .to(&fulfiller)
+                .details(format!("Order: {}, Total: {}", digest, total)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Tek bir emri iptal eder (sadece emri veren)
+    pub fn cancel_order(&mut self, caller: String, order: &Order) -> Result<(), String> {
+        let timer = self.perf.start_fallible("cancel_order");
+        self.ensure_sender(&caller)?;
+        if !order.offerer.eq_ignore_ascii_case(&caller) {
+            log_event("Order Cancel Failed", "Unauthorized attempt");
+            return Err("Only the offerer can cancel the order".to_string());
+        }
+
+        let digest = self.order_hash(order)?;
+        self.closed_orders.insert(digest.clone());
+        log_event("Order Cancelled", &format!("Order: {}", digest));
+        self.after_mutation(
+            LogEntry::new("OrderCancelled")
+                .token(order.token_id)
+                .from(&caller)
+                .details(format!("Order: {}", digest)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Çağıranın sayacını artırarak imzaladığı tüm açık emirleri geçersiz kılar ve yeni sayacı döner
+    pub fn increment_order_nonce(&mut self, caller: String) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("increment_order_nonce");
+        self.ensure_sender(&caller)?;
+        let nonce = self.order_nonces.entry(caller.to_lowercase()).or_default();
+        *nonce += 1;
+        let nonce = *nonce;
+        log_event("Order Nonce Incremented", &format!("Offerer: {}, Nonce: {}", caller, nonce));
+        self.after_mutation(LogEntry::new("OrderNonceIncremented").from(&caller).details(format!("Nonce: {}", nonce)));
+        timer.finish(Ok(nonce))
+    }
+
+    /// Güncel allowlist Merkle kökünü döner
+    pub fn allowlist_root(&self) -> Option<String> {
+        self.allowlist_root.as_ref().map(|root| to_hex(root))
+    }
+
+    /// Alıcının allowlist'te olduğunu keccak256 Merkle kanıtıyla doğrulayıp token mint'ler
+    pub fn mint_allowlisted(&mut self, recipient: String, token_id: u64, proof: Vec<String>) -> Result<(), String> {
+        let timer = self.perf.start_fallible("mint_allowlisted");
+        self.ensure_sender(&recipient)?;
+        self.ensure_not_paused("Minting Failed")?;
+
+        let root = self.allowlist_root.ok_or("Allowlist is not configured")?;
+        let proof = proof.iter().map(|hash| parse_hash(hash)).collect::<Result<Vec<_>, _>>()?;
+        if !merkle::verify(&root, allowlist_leaf(&recipient), &proof) {
+            log_event("Minting Failed", &format!("Invalid allowlist proof for {}", recipient));
+            return Err("Invalid allowlist proof".to_string());
+        }
+
+        timer.finish(self.mint_to(&recipient.clone(), recipient, token_id))
+    }
+
+    /// Ardışık bir token aralığını tek çağrıda çağırana mint'ler (sadece MINTER rolü)
+    ///
+    /// Sahiplik token başına değil aralık başına tek kayıtla tutulur; aralıktan
+    /// transfer edilen tokenlar ayrıca kaydedilir.
+    pub fn mint_batch(&mut self, recipient: String, start_id: u64, quantity: u64, base_uri: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("mint_batch");
+        self.ensure_sender(&recipient)?;
+        self.ensure_not_paused("Batch Minting Failed")?;
+        self.ensure_role(&recipient, MINTER_ROLE, "Batch Minting Failed")?;
+
+        if quantity == 0 {
+            log_event("Batch Minting Failed", "Quantity must be greater than zero");
+            return Err("Quantity must be greater than zero".to_string());
+        }
+
+        let end = start_id
+            .checked_add(quantity - 1)
+            .ok_or("Token ID range overflows")
+            .inspect_err(|err| log_event("Batch Minting Failed", err))?;
+        if !base_uri.is_empty() {
+            self.ensure_valid_uri(&base_uri, "Batch Minting Failed")?;
+        }
+        self.ensure_not_denylisted(&[&recipient], "Batch Minting Failed")?;
+        self.ensure_supply(quantity, "Batch Minting Failed")?;
+        self.ensure_wallet_limit(&recipient, quantity, "Batch Minting Failed")?;
+        self.ensure_mint_phase(&recipient, quantity, "Batch Minting Failed")?;
+
+        let overlaps_batch = self
+            .batches
+            .range(..=end)
+            .next_back()
+            .map(|(_, batch)| batch.end >= start_id)
+            .unwrap_or(false);
+        if overlaps_batch || self.token_owner.range(start_id..=end).next().is_some() {
+            log_event("Batch Minting Failed", &format!("Token IDs {}..={} overlap existing tokens", start_id, end));
+            return Err("Token ID already exists".to_string());
+        }
+        let context = HookContext::new(HookKind::BeforeMint, start_id, &recipient)
+            .range_end(end)
+            .to(&recipient);
+        self.run_hooks(&context, "Batch Minting Failed")?;
+
+        self.holder_snapshots.record_batch(start_id);
+        self.snapshot_balance(&recipient);
+        self.record_mint(&recipient, quantity);
+        self.batches.insert(
+            start_id,
+            MintBatch {
+                end,
+                owner: recipient.clone(),
+                base_uri,
+            },
+        );
+        log_event(
+            "Batch Minted",
+            &format!("Token IDs: {}..={}, Owner: {}", start_id, end, recipient),
+        );
+        self.after_mutation(LogEntry::new("BatchMint").token_range(start_id, end).to(&recipient));
+        timer.finish(self.run_hooks(&context.with_kind(HookKind::AfterMint), "Batch Minting Failed"))
+    }
+
+    /// `mint_batch`'in aralığı `ASYNC_CHUNK_SIZE` tokenlık parçalara bölüp parçalar arasında
+    /// olay döngüsüne dönen Promise sürümü
+    ///
+    /// Her parça ayrı bir toplu mint kaydıdır (kancalar parça başına çalışır). Bir parça başarısız
+    /// olursa kontrat çağrı öncesine döner. Mint sürerken araya giren çağrılar hata fırlatır.
+    pub async fn mint_batch_async(
+        &mut self,
+        recipient: String,
+        start_id: u64,
+        quantity: u64,
+        base_uri: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("mint_batch_async");
+        let chunk = ASYNC_CHUNK_SIZE as u64;
+        if quantity <= chunk {
+            return timer.finish(self.mint_batch(recipient, start_id, quantity, base_uri));
+        }
+        let end = start_id
+            .checked_add(quantity - 1)
+            .ok_or("Token ID range overflows")
+            .inspect_err(|err| log_event("Batch Minting Failed", err))?;
+
+        let snapshot = self.state_snapshot();
+        let mut next = start_id;
+        loop {
+            let chunk_end = end.min(next + (chunk - 1));
+            if let Err(err) = self.mint_batch(recipient.clone(), next, chunk_end - next + 1, base_uri.clone()) {
+                self.restore_state(snapshot);
+                self.persist(true);
+                log_event("Batch Minting Reverted", &format!("Token IDs {}..={}: {}", next, chunk_end, err));
+                return Err(err);
+            }
+            if chunk_end == end {
+                return timer.finish(Ok(()));
+            }
+            next = chunk_end + 1;
+            yield_now().await;
+        }
+    }
+
+    /// Koleksiyonun adresini (EIP-712 `verifying_contract`) döner; alt token kayıtlarında kullanılır
+    pub fn contract_address(&self) -> String {
+        self.domain.verifying_contract.clone()
+    }
+
+    /// Üst token'ın alt tokenlarının sahibi olarak görünen adresi döner
+    pub fn child_holder(&self, parent_id: u64) -> String {
+        composable::holder_address(&self.domain.verifying_contract, parent_id)
+    }
+
+    /// Başka bir koleksiyondaki token'ı üst token'a bağlar (ERC-998)
+    ///
+    /// Alt token üst token'a ait adrese devredilir; üst token transfer edildiğinde onunla birlikte gider.
+    pub fn attach_child(
+        &mut self,
+        caller: String,
+        parent_id: u64,
+        child_contract: &mut ERC721,
+        child_id: u64,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("attach_child");
+        self.ensure_sender(&caller)?;
+        self.owner_lookup(parent_id).ok_or("Parent token does not exist")?;
+        let contract = child_contract.contract_address();
+        if contract.eq_ignore_ascii_case(&self.contract_address()) {
+            log_event("Attach Child Failed", "Child contract shares the parent contract address");
+            return Err("Use attach_own_child for tokens of this collection".to_string());
+        }
+
+        child_contract.transfer(caller.clone(), self.child_holder(parent_id), child_id)?;
+        self.record_child(caller, parent_id, contract, child_id);
+        timer.finish(Ok(()))
+    }
+
+    /// Aynı koleksiyondaki token'ı üst token'a bağlar
+    pub fn attach_own_child(&mut self, caller: String, parent_id: u64, child_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("attach_own_child");
+        self.ensure_sender(&caller)?;
+        self.owner_lookup(parent_id).ok_or("Parent token does not exist")?;
+        let mut ancestor = Some(parent_id);
+        while let Some(token_id) = ancestor {
+            if token_id == child_id {
+                log_event("Attach Child Failed", "Attachment would create a cycle");
+                return Err("A token cannot be attached to itself or its descendants".to_string());
+            }
+            ancestor = self.own_parent_of(token_id);
+        }
+
+        self.transfer(caller.clone(), self.child_holder(parent_id), child_id)?;
+        let contract = self.contract_address();
+        self.record_child(caller, parent_id, contract, child_id);
+        timer.finish(Ok(()))
+    }
+
+    /// Başka bir koleksiyondaki alt token'ı ayırıp `to` adresine gönderir (üst token'ın sahibi veya onaylı adres)
+    pub fn detach_child(
+        &mut self,
+        caller: String,
+        parent_id: u64,
+        child_contract: &mut ERC721,
+        child_id: u64,
+        to: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("detach_child");
+        self.ensure_sender(&caller)?;
+        let contract = child_contract.contract_address();
+        self.ensure_child_attached(&caller, parent_id, &contract, child_id)?;
+
+        child_contract.transfer(self.child_holder(parent_id), to.clone(), child_id)?;
+        self.remove_child(parent_id, &contract, child_id, &to);
+        timer.finish(Ok(()))
+    }
+
+    /// Aynı koleksiyondaki alt token'ı ayırıp `to` adresine gönderir
+    pub fn detach_own_child(
+        &mut self,
+        caller: String,
+        parent_id: u64,
+        child_id: u64,
+        to: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("detach_own_child");
+        self.ensure_sender(&caller)?;
+        let contract = self.contract_address();
+        self.ensure_child_attached(&caller, parent_id, &contract, child_id)?;
+
+        let holder = self.child_holder(parent_id);
+        self.as_contract(|erc721| erc721.transfer(holder, to.clone(), child_id))?;
+        self.remove_child(parent_id, &contract, child_id, &to);
+        timer.finish(Ok(()))
+    }
+
+    /// Üst token'a bağlı alt tokenları bağlanma sırasıyla döner
+    pub fn children_of(&self, parent_id: u64) -> Vec<ChildToken> {
+        self.composables.children_of(parent_id).to_vec()
+    }
+
+    /// Birleştirmeyle üretilen tokenların tekrar birleştirilebilmesi için beklenecek süreyi ayarlar (sadece ADMIN rolü)
+    pub fn set_merge_cooldown(&mut self, caller: String, seconds: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_merge_cooldown");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Merge Settings Failed")?;
+
+        self.merging.set_cooldown(seconds);
+        log_event("Merge Cooldown Set", &format!("Seconds: {}", seconds));
+        self.after_mutation(LogEntry::new("MergeCooldownSet").from(&caller).details(format!("Seconds: {}", seconds)));
+        timer.finish(Ok(()))
+    }
+
+    pub fn merge_cooldown(&self) -> u64 {
+        self.merging.cooldown()
+    }
+
+    /// Token'ın birleştirilebileceği ilk anı döner (bekleme yoksa 0)
+    pub fn merge_ready_at(&self, token_id: u64) -> u64 {
+        self.merging.ready_at(token_id)
+    }
+
+    /// Özellik kalıtım kuralını JS fonksiyonu olarak ayarlar (sadece ADMIN rolü)
+    ///
+    /// Fonksiyon iki girdinin OpenSea biçimindeki özellik dizilerini alır ve çıktının dizisini döner.
+    pub fn set_merge_rule(
+        &mut self,
+        caller: String,
+        #[wasm_bindgen(unchecked_param_type = "MergeRuleCallback")] callback: js_sys::Function,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_merge_rule");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Merge Settings Failed")?;
+        self.merge_rule.set_js(callback);
+        log_event("Merge Rule Set", &format!("Caller: {}", caller));
+        timer.finish(Ok(()))
+    }
+
+    /// Aynı sahibe ait iki token'ı yakıp özellikleri kalıtım kuralıyla türetilen yeni bir token mint'ler
+    ///
+    /// Yeni token girdilerin sahibine gider ve Token ID'si döner; işlem atomiktir.
+    pub fn merge(&mut self, caller: String, token_a: u64, token_b: u64) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("merge");
+        self.ensure_sender(&caller)?;
+        self.ensure_not_paused("Merge Failed")?;
+        if token_a == token_b {
+            return Err("Cannot merge a token with itself".to_string());
+        }
+        let owner = self.owner_lookup(token_a).map(str::to_string).ok_or("Token does not exist")?;
+        if self.owner_lookup(token_b) != Some(&owner) {
+            log_event("Merge Failed", "Tokens have different owners");
+            return Err("Tokens must have the same owner".to_string());
+        }
+        for token_id in [token_a, token_b] {
+            let ready_at = self.merging.ready_at(token_id);
+            if ready_at > self.clock.now() {
+                log_event("Merge Failed", &format!("Token ID: {} is in cooldown", token_id));
+                return Err(format!("Token {} is in merge cooldown until {}", token_id, ready_at));
+            }
+        }
+        let attributes = self
+            .merge_rule
+            .apply(&self.attributes.get(token_a), &self.attributes.get(token_b))
+            .inspect_err(|err| log_event("Merge Failed", err))?;
+
+        let mut token_id = self.merging.next_token_id();
+        while self.owner_lookup(token_id).is_some() || self.batch_containing(token_id).is_some() {
+            token_id = token_id.checked_add(1).ok_or("Token ID overflow")?;
+        }
+        let burns = [self.check_burn(&caller, token_a)?, self.check_burn(&caller, token_b)?];
+        let mint = self.check_mint(&caller, &owner, token_id)?;
+        for burn in burns {
+            self.apply_burn(burn);
+        }
+        self.apply_mint(mint);
+
+        for attribute in attributes {
+            self.attributes.set(token_id, attribute.trait_type, attribute.value);
+        }
+        self.rarity = None;
+        self.merging.record([token_a, token_b], token_id, self.clock.now());
+        log_event("Tokens Merged", &format!("Inputs: {}, {}, Output: {}", token_a, token_b, token_id));
+        self.after_mutation(
+            LogEntry::new("Merge")
+                .token(token_id)
+                .from(&caller)
+                .to(&owner)
+                .details(format!("Inputs: {}, {}", token_a, token_b)),
+        );
+        timer.finish(Ok(token_id))
+    }
+
+    /// Token'ı başka bir kullanıcıya transfer eder
+    pub fn transfer(&mut self, from: String, to: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("transfer");
+        self.ensure_sender(&from)?;
+        let transfer = self.check_transfer(&from, &to, token_id)?;
+        self.apply_transfer(transfer);
+        timer.finish(Ok(()))
+    }
+
+    /// `{from, to, token_id}` dizisindeki transferleri atomik olarak uygular
+    ///
+    /// Herhangi bir transfer başarısız olursa kontrat durumu çağrı öncesine döner.
+    pub fn transfer_batch(
+        &mut self,
+        caller: String,
+        #[wasm_bindgen(unchecked_param_type = "BatchTransfer[]")] transfers: JsValue,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("transfer_batch");
+        self.ensure_sender(&caller)?;
+        let transfers: Vec<BatchTransfer> = serde_wasm_bindgen::from_value(transfers)
+            .map_err(|e| format!("Invalid transfer list: {}", e))?;
+        timer.finish(self.apply_transfer_batch(caller, transfers))
+    }
+
+    /// `{op, ...}` dizisindeki işlemleri (mint, transfer, burn, approve) atomik olarak uygular
+    ///
+    /// Herhangi bir işlem başarısız olursa önceki adımlar otomatik olarak geri alınır.
+    pub fn execute_batch(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "Operation[]")] ops: JsValue,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("execute_batch");
+        let ops: Vec<Operation> =
+            serde_wasm_bindgen::from_value(ops).map_err(|e| format!("Invalid operation list: {}", e))?;
+        timer.finish(self.apply_operations(ops))
+    }
+
+    /// Aktarıcının (relayer) ilettiği imzalı `{op, ...}` çağrısını imzacı adına çalıştırır
+    ///
+    /// İmza, imzacının güncel nonce'u ile `MetaTransaction` yapısı üzerinden atılmış olmalıdır.
+    pub fn execute_meta_tx(
+        &mut self,
+        signer: String,
+        #[wasm_bindgen(unchecked_param_type = "MetaCall")] function_call: JsValue,
+        nonce: u64,
+        signature: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("execute_meta_tx");
+        let call: MetaCall =
+            serde_wasm_bindgen::from_value(function_call).map_err(|e| format!("Invalid meta-transaction call: {}", e))?;
+        timer.finish(self.apply_meta_tx(signer, call, nonce, signature))
+    }
+
+    /// İmzacının imzalaması gereken meta-işlem özetini döner
+    pub fn meta_tx_digest(
+        &self,
+        signer: String,
+        #[wasm_bindgen(unchecked_param_type = "MetaCall")] function_call: JsValue,
+        nonce: u64,
+    ) -> Result<String, String> {
+        let call: MetaCall =
+            serde_wasm_bindgen::from_value(function_call).map_err(|e| format!("Invalid meta-transaction call: {}", e))?;
+        self.meta_tx_hash(&signer, &call, nonce).map(|digest| to_hex(&digest))
+    }
+
+    /// İmzacının bir sonraki meta-işlemde kullanması gereken nonce'u döner
+    pub fn meta_nonce(&self, signer: String) -> u64 {
+        self.meta_nonces.get(&signer.to_lowercase()).copied().unwrap_or(0)
+    }
+
+    /// Çağıranı kontrata bağlar; bağlıyken işlemlerde bildirilen çağıran adresi onunla eşleşmelidir
+    ///
+    /// Pazaryeri aktarımları, onaylı yetkili işlemler ve meta-işlemler gibi kontratın kendi adına
+    /// yaptığı iç çağrılar bu denetimden muaftır.
+    pub fn connect(&mut self, caller: &Caller) {
+        self.session.connect(caller);
+        log_event("Caller Connected", &format!("Caller: {}", caller.address()));
+    }
+
+    /// Bağlı çağıranı kaldırır; çağıran adresleri yeniden denetimsiz kabul edilir
+    pub fn disconnect(&mut self) {
+        self.session.disconnect();
+        log_event("Caller Disconnected", "");
+    }
+
+    /// Bağlı çağıranın adresini döner
+    pub fn sender(&self) -> Option<String> {
+        self.session.sender()
+    }
+
+    /// Manuel işlem (transaction) başlatır; `commit` veya `revert` ile sonlandırılır
+    pub fn begin_tx(&mut self) -> Result<(), String> {
+        let timer = self.perf.start_fallible("begin_tx");
+        if self.open_transaction.is_some() {
+            return Err("A transaction is already open".to_string());
+        }
+        self.open_transaction = Some(self.checkpoint());
+        log_event("Transaction Started", "");
+        timer.finish(Ok(()))
+    }
+
+    /// Açık işlemdeki değişiklikleri kalıcı hale getirir
+    pub fn commit(&mut self) -> Result<(), String> {
+        let timer = self.perf.start_fallible("commit");
+        let id = self.open_transaction.take().ok_or("No open transaction")?;
+        self.checkpoints.0.retain(|checkpoint| checkpoint.id != id);
+        log_event("Transaction Committed", "");
+        timer.finish(Ok(()))
+    }
+
+    /// Açık işlemdeki tüm değişiklikleri geri alır
+    pub fn revert(&mut self) -> Result<(), String> {
+        let timer = self.perf.start_fallible("revert");
+        let id = self.open_transaction.take().ok_or("No open transaction")?;
+        self.rollback_to(id)?;
+        self.checkpoints.0.retain(|checkpoint| checkpoint.id != id);
+        log_event("Transaction Reverted", "");
+        timer.finish(Ok(()))
+    }
+
+    /// Açık bir işlem olup olmadığını döner
+    pub fn in_transaction(&self) -> bool {
+        self.open_transaction.is_some()
+    }
+
+    /// Token'ı yakar (sahip, onaylı adres veya BURNER rolü yapabilir)
+    pub fn burn(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("burn");
+        self.ensure_sender(&caller)?;
+        let burn = self.check_burn(&caller, token_id)?;
+        self.apply_burn(burn);
+        timer.finish(Ok(()))
+    }
+
+    /// Yakmayı durumu değiştirmeden doğrular ve "before" kancalarını çalıştırır
+    pub(crate) fn check_burn(&self, caller: &str, token_id: u64) -> Result<PreparedBurn, String> {
+        self.ensure_not_paused("Burn Failed")?;
+
+        let owner = self
+            .owner_lookup(token_id)
+            .map(str::to_string)
+            .ok_or("Token does not exist")
+            .inspect_err(|err| log_event("Burn Failed", err))?;
+
+        if !self.is_approved_or_owner(caller.to_string(), token_id) && !self.roles.has_role(BURNER_ROLE, caller) {
+            log_event("Burn Failed", "Unauthorized attempt");
+            return Err("Unauthorized burn attempt".to_string());
+        }
+        if self.staking.is_staked(token_id) {
+            log_event("Burn Failed", &format!("Token ID: {} is staked", token_id));
+            return Err("Token is staked".to_string());
+        }
+        if !self.composables.children_of(token_id).is_empty() {
+            log_event("Burn Failed", &format!("Token ID: {} has attached children", token_id));
+            return Err("Token has attached children".to_string());
+        }
+        let context = HookContext::new(HookKind::BeforeBurn, token_id, caller).from(&owner);
+        self.run_hooks(&context, "Burn Failed")?;
+        Ok(PreparedBurn { owner, context })
+    }
+
+    /// `check_burn` ile doğrulanmış yakmayı uygular; "after" kancaları işlemi iptal edemez
+    pub(crate) fn apply_burn(&mut self, burn: PreparedBurn) {
+        let PreparedBurn { owner, context } = burn;
+        let token_id = context.token_id;
+        self.snapshot_holders(token_id, Some(&owner), None);
+        self.remove_token_from_owner(owner.clone(), token_id);
+        self.token_owner.remove(&token_id);
+        if self.batch_containing(token_id).is_some() {
+            self.burned_batch_tokens.insert(token_id);
+        }
+        self.total_burned += 1;
+        self.votes.transfer_units(Some(&owner), None, 1, self.clock.now());
+        self.approvals.remove(&token_id);
+        self.locked_tokens.remove(&token_id);
+        self.rentals.remove(&token_id);
+        self.marketplace.remove(token_id);
+        self.vesting_unlocks.remove(&token_id);
+        self.last_transfers.remove(&token_id);
+        self.token_uris.remove(&token_id);
+        self.frozen_uris.remove(&token_id);
+        self.attributes.clear_token(token_id);
+        self.progression.clear_token(token_id);
+        self.consumables.clear_token(token_id);
+        self.rarity = None;
+        self.token_royalties.remove(&token_id);
+        log_event("Token Burned", &format!("Token ID: {}, Owner: {}", token_id, owner));
+        self.after_mutation(LogEntry::new("Burn").token(token_id).from(&owner));
+        self.hooks.run(&context.with_kind(HookKind::AfterBurn)).expect("after hooks cannot veto");
+    }
+
+    /// Token'ı başka bir kullanıcıya transfer edebilmesi için onay verir
+    pub fn approve(&mut self, owner: String, approved: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("approve");
+        self.ensure_sender(&owner)?;
+        let token_owner = self
+            .owner_lookup(token_id)
+            .ok_or("Token does not exist")
+            .inspect_err(|err| log_event("Approval Failed", err))?;
+
+        if token_owner != owner {
+            log_event("Approval Failed", "Unauthorized attempt");
+            return Err("Only the owner can approve".to_string());
+        }
+        self.ensure_operator_allowed(&approved, "Approval Failed")?;
+
+        self.approvals.insert(token_id, approved.clone());
+        log_event("Approval Granted", &format!("Token ID: {}, Approved for: {}", token_id, approved));
+        self.after_mutation(LogEntry::new("Approval").token(token_id).from(&owner).to(&approved));
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın kullanıcısını (kiracı) belirtilen ana kadar atar (ERC-4907; sahip veya onaylı adres)
+    pub fn set_user(&mut self, caller: String, token_id: u64, user: String, expires: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_user");
+        self.ensure_sender(&caller)?;
+        self.ensure_not_paused("Set User Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+
+        if !self.is_approved_or_owner(caller.clone(), token_id) {
+            log_event("Set User Failed", "Unauthorized attempt");
+            return Err("Only the owner or approved address can set the user".to_string());
+        }
+
+        self.rentals.insert(token_id, Rental { user: user.clone(), expires });
+        log_event("UpdateUser", &format!("Token ID: {}, User: {}, Expires: {}", token_id, user, expires));
+        self.after_mutation(
+            LogEntry::new("UpdateUser")
+                .token(token_id)
+                .from(&caller)
+                .to(&user)
+                .details(format!("Expires: {}", expires)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın güncel kullanıcısını döner (süresi dolduysa boş)
+    pub fn user_of(&self, token_id: u64) -> Option<String> {
+        self.rentals
+            .get(&token_id)
+            .filter(|rental| rental.expires >= self.clock.now())
+            .map(|rental| rental.user.clone())
+    }
+
+    /// Token kullanıcısının hak bitiş zamanını döner (kullanıcı yoksa 0)
+    pub fn user_expires(&self, token_id: u64) -> u64 {
+        self.rentals.get(&token_id).map(|rental| rental.expires).unwrap_or(0)
+    }
+
+    /// Token'ı stake eder; stake süresince transfer ve burn engellenir (sadece sahip)
+    pub fn stake(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("stake");
+        self.ensure_sender(&caller)?;
+        self.ensure_not_paused("Stake Failed")?;
+
+        let owner = self.owner_lookup(token_id).ok_or("Token does not exist")?;
+        if owner != caller {
+            log_event("Stake Failed", "Unauthorized attempt");
+            return Err("Only the owner can stake".to_string());
+        }
+
+        let now = self.clock.now();
+        self.staking.stake(token_id, &caller, now)?;
+        log_event("Staked", &format!("Token ID: {}, Owner: {}", token_id, caller));
+        self.after_mutation(LogEntry::new("Staked").token(token_id).from(&caller));
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın stake'ini sonlandırır (sadece stake eden)
+    pub fn unstake(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("unstake");
+        self.ensure_sender(&caller)?;
+        self.ensure_not_paused("Unstake Failed")?;
+
+        let position = self
+            .staking
+            .unstake(token_id, &caller, self.clock.now())
+            .inspect_err(|err| log_event("Unstake Failed", err))?;
+        let duration = self.clock.now().saturating_sub(position.staked_at);
+        log_event("Unstaked", &format!("Token ID: {}, Duration: {}", token_id, duration));
+        self.after_mutation(
+            LogEntry::new("Unstaked")
+                .token(token_id)
+                .to(&caller)
+                .details(format!("Duration: {}", duration)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Adresin stake ettiği tokenları listeler
+    pub fn staked_tokens(&self, owner: String) -> Vec<u64> {
+        self.staking.tokens_of(&owner)
+    }
+
+    /// Token'ın ne kadar süredir stake edildiğini saniye cinsinden döner
+    pub fn stake_duration(&self, token_id: u64) -> Option<u64> {
+        self.staking.duration(token_id, self.clock.now())
+    }
+
+    /// Stake edilen token başına saniyelik ödül oranını ayarlar (sadece ADMIN rolü)
+    pub fn set_reward_rate(&mut self, caller: String, per_second: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_reward_rate");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Reward Rate Update Failed")?;
+
+        let now = self.clock.now();
+        self.staking.set_reward_rate(per_second, now);
+        log_event("Reward Rate Updated", &format!("Per Second: {}", per_second));
+        self.after_mutation(LogEntry::new("RewardRateUpdated").from(&caller).details(per_second.to_string()));
+        timer.finish(Ok(()))
+    }
+
+    /// Güncel saniyelik ödül oranını döner
+    pub fn reward_rate(&self) -> u64 {
+        self.staking.reward_rate()
+    }
+
+    /// Adresin talep edebileceği stake ödülünü döner
+    pub fn pending_rewards(&self, owner: String) -> u64 {
+        self.staking.pending_rewards(&owner, self.clock.now())
+    }
+
+    /// Bekleyen stake ödülünü adresin ödül bakiyesine aktarır ve aktarılan miktarı döner
+    pub fn claim_rewards(&mut self, owner: String) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("claim_rewards");
+        self.ensure_sender(&owner)?;
+        self.ensure_not_paused("Claim Failed")?;
+
+        let now = self.clock.now();
+        let amount = self.staking.claim(&owner, now);
+        if amount == 0 {
+            log_event("Claim Failed", &format!("Owner: {}", owner));
+            return Err("No rewards to claim".to_string());
+        }
+
+        log_event("Rewards Claimed", &format!("Owner: {}, Amount: {}", owner, amount));
+        self.after_mutation(LogEntry::new("RewardsClaimed").to(&owner).details(format!("Amount: {}", amount)));
+        timer.finish(Ok(amount))
+    }
+
+    /// Adresin talep edilmiş ödül bakiyesini döner
+    pub fn reward_balance(&self, owner: String) -> u64 {
+        self.staking.balance_of(&owner)
+    }
+
+    /// Sahibin imzasıyla token onayı verir (ERC-4494)
+    ///
+    /// İmza, token'ın güncel nonce'u ile `Permit` yapısı üzerinden atılmış olmalıdır;
+    /// başarılı her permit ve transfer nonce'u bir artırır.
+    pub fn permit(&mut self, spender: String, token_id: u64, deadline: u64, signature: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("permit");
+        self.ensure_not_paused("Permit Failed")?;
+
+        let owner = self.owner_lookup(token_id).map(str::to_string).ok_or("Token does not exist")?;
+        if self.clock.now() > deadline {
+            log_event("Permit Failed", &format!("Token ID: {}, Deadline: {}", token_id, deadline));
+            return Err("Permit expired".to_string());
+        }
+
+        self.ensure_operator_allowed(&spender, "Permit Failed")?;
+        let digest = self.permit_hash(&spender, token_id, deadline)?;
+        let signer = recover_address(&digest, &signature)?;
+        if !signer.eq_ignore_ascii_case(&owner) {
+            log_event("Permit Failed", &format!("Unauthorized signer: {}", signer));
+            return Err("Permit is not signed by the token owner".to_string());
+        }
+
+        *self.permit_nonces.entry(token_id).or_default() += 1;
+        self.approvals.insert(token_id, spender.clone());
+        log_event("Approval Granted", &format!("Token ID: {}, Approved for: {}", token_id, spender));
+        self.after_mutation(
+            LogEntry::new("Approval")
+                .token(token_id)
+                .from(&owner)
+                .to(&spender)
+                .details("Permit".to_string()),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın güncel permit nonce'unu döner
+    pub fn nonces(&self, token_id: u64) -> u64 {
+        self.permit_nonces.get(&token_id).copied().unwrap_or(0)
+    }
+
+    /// Sahibin imzalaması gereken permit özetini döner
+    pub fn permit_digest(&self, spender: String, token_id: u64, deadline: u64) -> Result<String, String> {
+        self.permit_hash(&spender, token_id, deadline).map(|digest| to_hex(&digest))
+    }
+
+    /// Bir token'ın kime onaylı olduğunu döner
+    pub fn get_approved(&self, token_id: u64) -> Option<String> {
+        self.approvals.get(&token_id).cloned()
+    }
+
+    /// Token sahibinin onaylayıp onaylamadığını kontrol eder
+    pub fn is_approved_or_owner(&self, user: String, token_id: u64) -> bool {
+        let owner = self.owner_lookup(token_id);
+        let approved = self.approvals.get(&token_id);
+
+        owner.map(|o| o == user || self.is_operator(o, &user)).unwrap_or(false)
+            || approved.map(|a| a == &user).unwrap_or(false)
+    }
+
+    /// Operatöre sahibin tüm tokenları için onay verir veya onayı kaldırır
+    pub fn set_approval_for_all(&mut self, owner: String, operator: String, approved: bool) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_approval_for_all");
+        self.ensure_sender(&owner)?;
+        if owner == operator {
+            log_event("Approval Failed", "Cannot approve yourself as operator");
+            return Err("Cannot approve yourself as operator".to_string());
+        }
+        if approved {
+            self.ensure_operator_allowed(&operator, "Approval Failed")?;
+            self.operator_approvals.insert((owner.clone(), operator.clone()));
+        } else {
+            self.operator_approvals.remove(&(owner.clone(), operator.clone()));
+        }
+
+        log_event(
+            "Approval For All",
+            &format!("Owner: {}, Operator: {}, Approved: {}", owner, operator, approved),
+        );
+        self.after_mutation(
+            LogEntry::new("ApprovalForAll")
+                .from(&owner)
+                .to(&operator)
+                .details(approved.to_string()),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Operatörün sahibin tüm tokenları için onaylı olup olmadığını döner
+    pub fn is_approved_for_all(&self, owner: String, operator: String) -> bool {
+        self.is_operator(&owner, &operator)
+    }
+
+    /// Operatörü filtre listesine ekler; onay alamaz ve transfer başlatamaz (sadece ADMIN rolü)
+    pub fn block_operator(&mut self, caller: String, operator: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("block_operator");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Operator Filter Update Failed")?;
+
+        self.blocked_operators.insert(operator.clone());
+        log_event("Operator Blocked", &format!("Operator: {}", operator));
+        self.after_mutation(LogEntry::new("OperatorBlocked").from(&caller).to(&operator));
+        timer.finish(Ok(()))
+    }
+
+    /// Operatörü filtre listesinden çıkarır (sadece ADMIN rolü)
+    pub fn unblock_operator(&mut self, caller: String, operator: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("unblock_operator");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Operator Filter Update Failed")?;
+
+        self.blocked_operators.remove(&operator);
+        log_event("Operator Unblocked", &format!("Operator: {}", operator));
+        self.after_mutation(LogEntry::new("OperatorUnblocked").from(&caller).to(&operator));
+        timer.finish(Ok(()))
+    }
+
+    /// Operatörün filtre listesinde olup olmadığını döner
+    pub fn is_operator_blocked(&self, operator: String) -> bool {
+        self.blocked_operators.contains(&operator)
+    }
+
+    /// Mevcut tokenlar arasından tohuma göre tekrar etmeden en fazla `count` kazanan Token ID çeker
+    ///
+    /// Aynı tohum ve aynı sahiplik durumu her zaman aynı sonucu verir.
+    pub fn draw_raffle(&self, seed: String, count: u32) -> Vec<u64> {
+        let tokens = self.sorted_token_owners().into_iter().map(|(id, _)| id).collect();
+        SeededRng::new(seed).sample(tokens, count as usize)
+    }
+
+    /// Kullanıcıya ait olan tüm token'ları listeler
+    pub fn tokens_of_owner(&self, owner: String) -> Vec<u64> {
+        let _timer = self.perf.start("tokens_of_owner");
+        let mut tokens = Vec::with_capacity(self.addresses.get(&owner).map_or(0, |id| self.owned_tokens.len(id)));
+        tokens.extend(self.owned_token_ids(&owner));
+        tokens
+    }
+
+    /// `tokens_of_owner` sonucunu ara `Vec` oluşturmadan doğrudan `BigUint64Array`'e yazar
+    #[wasm_bindgen(unchecked_return_type = "BigUint64Array")]
+    pub fn tokens_of_owner_array(&self, owner: String) -> js_sys::BigUint64Array {
+        let array = js_sys::BigUint64Array::new_with_length(self.owned_token_ids(&owner).count() as u32);
+        for (index, token_id) in self.owned_token_ids(&owner).enumerate() {
+            array.set_index(index as u32, token_id);
+        }
+        array
+    }
+
+    /// Mevcut tüm tokenları `Map<bigint, string>` (Token ID -> sahip) olarak döner; sıra belirsizdir
+    #[wasm_bindgen(unchecked_return_type = "Map<bigint, string>")]
+    pub fn token_owners_map(&self) -> js_sys::Map {
+        let map = js_sys::Map::new();
+        for (token_id, owner) in self.token_owner_entries() {
+            map.set(&JsValue::from(token_id), &JsValue::from_str(owner));
+        }
+        map
+    }
+
+    /// Kullanıcının tokenlarını Token ID sırasına göre sayfalı döner
+    pub fn tokens_of_owner_page(&self, owner: String, offset: u32, limit: u32) -> TokenPage {
+        let mut tokens = self.tokens_of_owner(owner);
+        tokens.sort_unstable();
+        TokenPage::new(tokens.into_iter(), offset, limit)
+    }
+
+    /// Mevcut tüm tokenları Token ID sırasına göre sayfalı döner
+    pub fn all_tokens_page(&self, offset: u32, limit: u32) -> TokenPage {
+        let tokens = self.sorted_token_owners().into_iter().map(|(token_id, _)| token_id);
+        TokenPage::new(tokens, offset, limit)
+    }
+
+    /// Tüm tokenlar üzerinde parça parça ilerleyen bir imleç oluşturur
+    pub fn token_cursor(&self) -> TokenCursor {
+        TokenCursor::new(None)
+    }
+
+    /// Kullanıcının tokenları üzerinde parça parça ilerleyen bir imleç oluşturur
+    pub fn owner_token_cursor(&self, owner: String) -> TokenCursor {
+        TokenCursor::new(Some(owner))
+    }
+
+    /// Sahibin tüm tokenlarının oylarını delegeye devreder; kendi oyunu kullanmak için kendine devretmelidir
+    pub fn delegate(&mut self, owner: String, delegatee: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("delegate");
+        self.ensure_sender(&owner)?;
+        self.ensure_not_paused("Delegation Failed")?;
+
+        self.votes.delegate(&owner, &delegatee, self.clock.now());
+        log_event("Delegate Changed", &format!("Delegator: {}, Delegatee: {}", owner, delegatee));
+        self.after_mutation(LogEntry::new("DelegateChanged").from(&owner).to(&delegatee));
+        timer.finish(Ok(()))
+    }
+
+    /// Adresin oylarını devrettiği delegeyi döner
+    pub fn delegates(&self, account: String) -> Option<String> {
+        self.votes.delegates(&account).cloned()
+    }
+
+    /// Adresin güncel oy gücünü döner
+    pub fn get_votes(&self, account: String) -> u64 {
+        self.votes.votes(&account)
+    }
+
+    /// Adresin geçmişteki bir andaki (Unix saniye) oy gücünü döner; an geçmişte olmalıdır
+    pub fn get_past_votes(&self, account: String, timepoint: u64) -> Result<u64, String> {
+        self.ensure_past_timepoint(timepoint)?;
+        Ok(self.votes.past_votes(&account, timepoint))
+    }
+
+    /// Geçmişteki bir andaki toplam arzı (oy birimi) döner; an geçmişte olmalıdır
+    pub fn get_past_total_supply(&self, timepoint: u64) -> Result<u64, String> {
+        self.ensure_past_timepoint(timepoint)?;
+        Ok(self.votes.past_total_supply(timepoint))
+    }
+
+    /// Sahip ve bakiyelerin o anki halini saklayan bir snapshot alır (sadece ADMIN rolü)
+    ///
+    /// Snapshot almak sabit maliyetlidir; eski değerler yalnızca sonraki ilk değişiklikte kopyalanır.
+    pub fn take_holder_snapshot(&mut self, caller: String) -> Result<SnapshotId, String> {
+        let timer = self.perf.start_fallible("take_holder_snapshot");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Snapshot Failed")?;
+
+        let snapshot_id = self.holder_snapshots.take();
+        log_event("Snapshot Taken", &format!("Snapshot ID: {}", snapshot_id));
+        self.after_mutation(LogEntry::new("Snapshot").from(&caller).details(snapshot_id.to_string()));
+        timer.finish(Ok(snapshot_id))
+    }
+
+    /// En son alınan snapshot'ın kimliğini döner (hiç alınmadıysa 0)
+    pub fn current_snapshot_id(&self) -> SnapshotId {
+        self.holder_snapshots.current()
+    }
+
+    /// Token'ın snapshot anındaki sahibini döner
+    pub fn owner_of_at(&self, snapshot_id: SnapshotId, token_id: u64) -> Result<Option<String>, String> {
+        self.ensure_snapshot_exists(snapshot_id)?;
+        let batch_start = self
+            .batches
+            .range(..=token_id)
+            .next_back()
+            .filter(|(_, batch)| token_id <= batch.end)
+            .map(|(&start, _)| start);
+        Ok(self.holder_snapshots.owner_at(snapshot_id, token_id, self.owner_of(token_id), batch_start))
+    }
+
+    /// Adresin snapshot anındaki token sayısını döner
+    pub fn balance_of_at(&self, snapshot_id: SnapshotId, owner: String) -> Result<u64, String> {
+        self.ensure_snapshot_exists(snapshot_id)?;
+        Ok(self.holder_snapshots.balance_at(snapshot_id, &owner, self.votes.units(&owner)))
+    }
+
+    /// En az bir token'ı olan tekil adres sayısını döner
+    pub fn holder_count(&self) -> u64 {
+        self.votes.holders().count() as u64
+    }
+
+    /// En çok token'a sahip `n` adresi bakiyeleriyle döner
+    pub fn top_holders(&self, n: u32) -> Vec<HolderBalance> {
+        analytics::top_holders(self.votes.holders(), n as usize)
+    }
+
+    /// Cüzdan başına token sayısı histogramını döner
+    pub fn ownership_distribution(&self) -> Vec<DistributionBucket> {
+        analytics::distribution(self.votes.holders().map(|(_, balance)| balance))
+    }
+
+    /// Denetim günlüğünden tekil sahip sayısının zaman içindeki değişimini döner
+    pub fn unique_holders_over_time(&self) -> Vec<HolderCountPoint> {
+        analytics::holders_over_time(self.audit_log.iter())
+    }
+
+    /// Adresin oy gücü checkpoint sayısını döner
+    pub fn num_checkpoints(&self, account: String) -> u32 {
+        self.votes.checkpoint_count(&account) as u32
+    }
+
+    /// Yönetişim ayarlarını günceller (sadece ADMIN rolü)
+    ///
+    /// Yeter sayı, teklifin anlık görüntüsündeki toplam arzın baz puanı olarak verilir (400 = %4).
+    pub fn set_governor_settings(
+        &mut self,
+        caller: String,
+        voting_delay: u64,
+        voting_period: u64,
+        proposal_threshold: u64,
+        quorum_bps: u32,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_governor_settings");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Governor Update Failed")?;
+
+        let settings = GovernorSettings {
+            voting_delay,
+            voting_period,
+            proposal_threshold,
+            quorum_bps,
+        };
+        self.governor.configure(settings.clone())?;
+        log_event("Governor Settings Updated", &format!("{:?}", settings));
+        self.after_mutation(LogEntry::new("GovernorSettingsUpdated").from(&caller).details(format!("{:?}", settings)));
+        timer.finish(Ok(()))
+    }
+
+    /// `{op, ...}` işlem dizisini yönetişim teklifi olarak sunar ve teklif ID'sini döner
+    pub fn propose(
+        &mut self,
+        proposer: String,
+        description: String,
+        #[wasm_bindgen(unchecked_param_type = "Operation[]")] actions: JsValue,
+    ) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("propose");
+        self.ensure_sender(&proposer)?;
+        let actions: Vec<Operation> =
+            serde_wasm_bindgen::from_value(actions).map_err(|e| format!("Invalid action list: {}", e))?;
+        timer.finish(self.propose_actions(proposer, description, actions))
+    }
+
+    /// Teklife oy verir ve kullanılan oy gücünü (teklifin anlık görüntüsündeki) döner
+    pub fn cast_vote(&mut self, proposal_id: u64, voter: String, support: VoteType) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("cast_vote");
+        self.ensure_sender(&voter)?;
+        let weight = self
+            .governor
+            .cast_vote(proposal_id, &voter, support, &self.votes, self.clock.now())
+            .inspect_err(|err| log_event("Vote Failed", err))?;
+        log_event(
+            "Vote Cast",
+            &format!("Proposal: {}, Voter: {}, Support: {:?}, Weight: {}", proposal_id, voter, support, weight),
+        );
+        self.after_mutation(
+            LogEntry::new("VoteCast")
+                .from(&voter)
+                .details(format!("Proposal: {}, Support: {:?}, Weight: {}", proposal_id, support, weight)),
+        );
+        timer.finish(Ok(weight))
+    }
+
+    /// Başarılı teklifin işlemlerini atomik olarak uygular; herkes çağırabilir
+    pub fn execute_proposal(&mut self, caller: String, proposal_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("execute_proposal");
+        self.ensure_sender(&caller)?;
+        let actions = self
+            .governor
+            .executable_actions(proposal_id, &self.votes, self.clock.now())
+            .inspect_err(|err| log_event("Proposal Execution Failed", err))?;
+
+        // Teklif işlemleri kontrat sahibi adına uygulanır
+        self.as_contract(|erc721| erc721.apply_operations(actions))?;
+        self.governor.mark_executed(proposal_id);
+        log_event("Proposal Executed", &format!("Proposal: {}", proposal_id));
+        self.after_mutation(
+            LogEntry::new("ProposalExecuted")
+                .from(&caller)
+                .details(format!("Proposal: {}", proposal_id)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Teklifin güncel durumunu döner
+    pub fn proposal_state(&self, proposal_id: u64) -> Result<ProposalState, String> {
+        self.governor.state(proposal_id, &self.votes, self.clock.now())
+    }
+
+    /// Teklifin `[ret, kabul, çekimser]` oy toplamlarını döner
+    pub fn proposal_votes(&self, proposal_id: u64) -> Result<Vec<u64>, String> {
+        let votes = self.governor.proposal_votes(proposal_id).ok_or("Proposal does not exist")?;
+        Ok(votes.to_vec())
+    }
+
+    /// Teklifi verenin adresini döner
+    pub fn proposal_proposer(&self, proposal_id: u64) -> Option<String> {
+        self.governor.proposer(proposal_id).map(str::to_string)
+    }
+
+    /// Teklifin açıklamasını döner
+    pub fn proposal_description(&self, proposal_id: u64) -> Option<String> {
+        self.governor.description(proposal_id).map(str::to_string)
+    }
+
+    /// Oy gücünün ölçüldüğü anı döner; oylama bu andan sonra başlar
+    pub fn proposal_snapshot(&self, proposal_id: u64) -> Option<u64> {
+        self.governor.schedule(proposal_id).map(|(snapshot, _)| snapshot)
+    }
+
+    /// Oylamanın son anını döner
+    pub fn proposal_deadline(&self, proposal_id: u64) -> Option<u64> {
+        self.governor.schedule(proposal_id).map(|(_, deadline)| deadline)
+    }
+
+    /// Adresin teklife oy verip vermediğini döner
+    pub fn has_voted(&self, proposal_id: u64, account: String) -> bool {
+        self.governor.has_voted(proposal_id, &account)
+    }
+
+    /// Geçmişteki bir an için gereken yeter sayıyı döner
+    pub fn quorum(&self, timepoint: u64) -> Result<u64, String> {
+        self.ensure_past_timepoint(timepoint)?;
+        Ok(self.governor.quorum(&self.votes, timepoint))
+    }
+
+    /// Tüm tokenlar için varsayılan royalty'yi ayarlar (sadece ADMIN rolü yapabilir)
+    pub fn set_default_royalty(&mut self, caller: String, receiver: String, bps: u16) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_default_royalty");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Royalty Update Failed")?;
+        self.ensure_timelocked("Royalty Update Failed")?;
+        self.ensure_multisig("Royalty Update Failed")?;
+
+        self.default_royalty = Some(RoyaltyInfo::new(receiver.clone(), bps)?);
+        log_event("Default Royalty Set", &format!("Receiver: {}, Bps: {}", receiver, bps));
+        self.after_mutation(
+            LogEntry::new("DefaultRoyaltySet")
+                .from(&caller)
+                .to(&receiver)
+                .details(format!("Bps: {}", bps)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Tek bir token için varsayılanı ezen royalty ayarlar (sadece ADMIN rolü yapabilir)
+    pub fn set_token_royalty(&mut self, caller: String, token_id: u64, receiver: String, bps: u16) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_token_royalty");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Royalty Update Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+
+        self.token_royalties.insert(token_id, RoyaltyInfo::new(receiver.clone(), bps)?);
+        log_event(
+            "Token Royalty Set",
+            &format!("Token ID: {}, Receiver: {}, Bps: {}", token_id, receiver, bps),
+        );
+        self.after_mutation(
+            LogEntry::new("TokenRoyaltySet")
+                .token(token_id)
+                .from(&caller)
+                .to(&receiver)
+                .details(format!("Bps: {}", bps)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// ERC-2981: satış fiyatına göre royalty alıcısını ve miktarını döner
+    pub fn royalty_info(&self, token_id: u64, sale_price: u64) -> Option<RoyaltyPayment> {
+        self.token_royalties
+            .get(&token_id)
+            .or(self.default_royalty.as_ref())
+            .map(|royalty| RoyaltyPayment {
+                receiver: royalty.receiver.clone(),
+                amount: royalty.amount(sale_price),
+            })
+    }
+
+    /// Koleksiyon düzeyi metadata URI'sini ayarlar (sadece ADMIN rolü)
+    pub fn set_contract_uri(&mut self, caller: String, uri: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_contract_uri");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Contract URI Update Failed")?;
+        self.ensure_valid_uri(&uri, "Contract URI Update Failed")?;
+
+        self.contract_uri = Some(uri.clone());
+        log_event("Contract URI Updated", &format!("URI: {}", uri));
+        self.after_mutation(LogEntry::new("ContractURIUpdated").from(&caller).details(uri));
+        timer.finish(Ok(()))
+    }
+
+    /// Koleksiyon düzeyi metadata URI'sini döner
+    pub fn contract_uri(&self) -> Option<String> {
+        self.contract_uri.clone()
+    }
+
+    /// Vitrin bilgilerini (açıklama, görsel, bağlantı, ücret alıcısı) ayarlar (sadece ADMIN rolü)
+    pub fn set_contract_metadata(&mut self, caller: String, metadata: ContractMetadata) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_contract_metadata");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Contract Metadata Update Failed")?;
+
+        self.contract_metadata = metadata;
+        log_event("Contract Metadata Updated", &format!("Caller: {}", caller));
+        self.after_mutation(LogEntry::new("ContractMetadataUpdated").from(&caller));
+        timer.finish(Ok(()))
+    }
+
+    /// Vitrin bilgilerini döner
+    pub fn contract_metadata(&self) -> ContractMetadata {
+        self.contract_metadata.clone()
+    }
+
+    /// `contractURI()` adresinde yayınlanacak OpenSea uyumlu koleksiyon JSON'unu döner
+    pub fn contract_metadata_json(&self) -> String {
+        self.contract_metadata.to_storefront_json(self.default_royalty.as_ref())
+    }
+
+    /// Maksimum arzı ayarlar (sadece ADMIN rolü, ilk mint'ten önce yapabilir)
+    pub fn set_max_supply(&mut self, caller: String, max_supply: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_max_supply");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Max Supply Update Failed")?;
+
+        if self.total_minted > 0 {
+            log_event("Max Supply Update Failed", "Tokens already minted");
+            return Err("Max supply can only be set before the first mint".to_string());
+        }
+
+        self.max_supply = Some(max_supply);
+        log_event("Max Supply Set", &format!("Max supply: {}", max_supply));
+        self.after_mutation(
+            LogEntry::new("MaxSupplySet")
+                .from(&caller)
+                .details(format!("Max supply: {}", max_supply)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Maksimum arzı döner (sınırsızsa boş)
+    pub fn max_supply(&self) -> Option<u64> {
+        self.max_supply
+    }
+
+    /// Cüzdan başına mint sınırını ayarlar; boş değer sınırı kaldırır (sadece ADMIN rolü)
+    pub fn set_mint_limit_per_wallet(&mut self, caller: String, limit: Option<u64>) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_mint_limit_per_wallet");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Mint Limit Update Failed")?;
+
+        self.mint_limit_per_wallet = limit;
+        let details = match limit {
+            Some(limit) => format!("Limit: {}", limit),
+            None => "Limit removed".to_string(),
+        };
+        log_event("Mint Limit Set", &details);
+        self.after_mutation(LogEntry::new("MintLimitSet").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Cüzdan başına mint sınırını döner (sınırsızsa boş)
+    pub fn mint_limit_per_wallet(&self) -> Option<u64> {
+        self.mint_limit_per_wallet
+    }
+
+    /// Adrese şu ana kadar mint edilen token sayısını döner (toplu mintler dahil)
+    pub fn minted_by(&self, wallet: String) -> u64 {
+        self.minted_per_wallet.get(&wallet).copied().unwrap_or(0)
+    }
+
+    /// Mint aşaması ekler veya aynı isimli aşamayı günceller (sadece ADMIN rolü)
+    ///
+    /// En az bir aşama tanımlandığında mint yalnızca aktif bir aşama sırasında yapılabilir.
+    pub fn configure_phase(
+        &mut self,
+        caller: String,
+        name: String,
+        start_ts: u64,
+        end_ts: u64,
+        price: u64,
+        per_wallet_limit: Option<u64>,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("configure_phase");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Phase Configuration Failed")?;
+
+        self.phases.configure(MintPhase {
+            name: name.clone(),
+            start_ts,
+            end_ts,
+            price,
+            per_wallet_limit,
+        })?;
+        let details = format!("Phase: {}, Start: {}, End: {}, Price: {}", name, start_ts, end_ts, price);
+        log_event("Phase Configured", &details);
+        self.after_mutation(LogEntry::new("PhaseConfigured").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Verilen anda aktif olan mint aşamasını döner
+    pub fn current_phase(&self, now: u64) -> Option<MintPhase> {
+        self.phases.active(now).cloned()
+    }
+
+    /// Kontrat saatine göre aktif olan mint aşamasını döner
+    pub fn active_phase(&self) -> Option<MintPhase> {
+        self.current_phase(self.clock.now())
+    }
+
+    /// Tanımlı tüm mint aşamalarını başlangıç sırasıyla döner
+    pub fn mint_phases(&self) -> Vec<MintPhase> {
+        self.phases.all().to_vec()
+    }
+
+    /// Adrese belirtilen aşamada mint edilen token sayısını döner
+    pub fn minted_in_phase(&self, phase: String, wallet: String) -> u64 {
+        self.phase_minted
+            .get(&phase)
+            .and_then(|wallets| wallets.get(&wallet).copied())
+            .unwrap_or(0)
+    }
+
+    /// Simüle edilen blok zamanını ayarlar (Unix saniye)
+    pub fn set_block_timestamp(&mut self, timestamp: u64) {
+        let _timer = self.perf.start("set_block_timestamp");
+        self.clock.set(timestamp);
+        self.roles.sync_clock(&self.clock);
+    }
+
+    /// Simüle edilen blok zamanını ileri sarar
+    pub fn advance_time(&mut self, seconds: u64) {
+        let _timer = self.perf.start("advance_time");
+        self.clock.advance(seconds);
+        self.roles.sync_clock(&self.clock);
+    }
+
+    /// Simülasyonu bırakıp sistem saatine döner
+    pub fn use_system_time(&mut self) {
+        let _timer = self.perf.start("use_system_time");
+        self.clock.use_system_time();
+        self.roles.sync_clock(&self.clock);
+    }
+
+    /// Kontrat saatine göre şu anki zamanı döner (Unix saniye)
+    pub fn block_timestamp(&self) -> u64 {
+        self.clock.now()
+    }
+
+    /// Daha mint edilebilecek token sayısını döner (sınırsızsa boş)
+    pub fn remaining_supply(&self) -> Option<u64> {
+        self.max_supply.map(|max| max.saturating_sub(self.total_minted))
+    }
+
+    /// Şu ana kadar mint edilen toplam token sayısını döner (yakılanlar dahil)
+    pub fn total_minted(&self) -> u64 {
+        self.total_minted
+    }
+
+    /// Mevcut (yakılmamış) token sayısını döner
+    pub fn total_supply(&self) -> u64 {
+        self.total_minted - self.total_burned
+    }
+
+    /// Kontratı duraklatır (sadece PAUSER rolü yapabilir)
+    pub fn pause(&mut self, caller: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("pause");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, PAUSER_ROLE, "Pause Failed")?;
+        self.ensure_timelocked("Pause Failed")?;
+        self.ensure_multisig("Pause Failed")?;
+
+        self.paused = true;
+        log_event("Paused", "Mints, transfers and burns are disabled");
+        self.after_mutation(LogEntry::new("Paused").from(&caller));
+        timer.finish(Ok(()))
+    }
+
+    /// Kontratı zaman kilidi ve çoklu imza beklemeden hemen duraklatır (sadece GUARDIAN rolü)
+    ///
+    /// Koruyucu başka yetkili işlem yapamaz; duraklatmayı kaldırmak yine PAUSER rolüne kalır.
+    pub fn emergency_pause(&mut self, caller: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("emergency_pause");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, GUARDIAN_ROLE, "Emergency Pause Failed")?;
+
+        self.paused = true;
+        log_event("Emergency Paused", &format!("Guardian: {}", caller));
+        self.after_mutation(LogEntry::new("Paused").from(&caller).details("Emergency".to_string()));
+        timer.finish(Ok(()))
+    }
+
+    /// Kontratı yeniden aktifleştirir (sadece PAUSER rolü yapabilir)
+    pub fn unpause(&mut self, caller: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("unpause");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, PAUSER_ROLE, "Unpause Failed")?;
+        self.ensure_timelocked("Unpause Failed")?;
+        self.ensure_multisig("Unpause Failed")?;
+
+        self.paused = false;
+        log_event("Unpaused", "Mints, transfers and burns are enabled");
+        self.after_mutation(LogEntry::new("Unpaused").from(&caller));
+        timer.finish(Ok(()))
+    }
+
+    /// Zaman kilidi gecikmesini ayarlar (sadece ADMIN rolü)
+    ///
+    /// Gecikme sıfırdan büyükken taban URI, varsayılan royalty, duraklatma ve gecikmenin kendisi
+    /// yalnızca `queue_admin_action` ile sıraya alınıp süre dolduktan sonra uygulanabilir.
+    pub fn set_timelock_delay(&mut self, caller: String, delay: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_timelock_delay");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Timelock Update Failed")?;
+        self.ensure_timelocked("Timelock Update Failed")?;
+        self.ensure_multisig("Timelock Update Failed")?;
+
+        self.timelock.set_delay(delay);
+        log_event("Timelock Delay Updated", &format!("Delay: {}", delay));
+        self.after_mutation(LogEntry::new("TimelockDelayUpdated").from(&caller).details(format!("Delay: {}", delay)));
+        timer.finish(Ok(()))
+    }
+
+    /// Zaman kilidi gecikmesini (saniye) döner
+    pub fn timelock_delay(&self) -> u64 {
+        self.timelock.delay()
+    }
+
+    /// Yetkili işlemleri `threshold`-of-N imzacı onayına bağlar; eşik 0 ise kapatır (sadece ADMIN rolü)
+    ///
+    /// Etkinken taban URI, varsayılan royalty, duraklatma, zaman kilidi ve çoklu imza ayarları
+    /// yalnızca `propose_multisig_action` ile önerilip eşik kadar onaylandıktan sonra uygulanır.
+    /// Öneriler `expiry` saniye sonra geçersiz olur; yeniden yapılandırma bekleyen önerileri siler.
+    pub fn configure_multisig(
+        &mut self,
+        caller: String,
+        signers: Vec<String>,
+        threshold: u32,
+        expiry: u64,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("configure_multisig");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Multisig Update Failed")?;
+        self.ensure_timelocked("Multisig Update Failed")?;
+        self.ensure_multisig("Multisig Update Failed")?;
+
+        let details = format!("Signers: {:?}, Threshold: {}, Expiry: {}", signers, threshold, expiry);
+        self.multisig
+            .configure(signers, threshold, expiry)
+            .inspect_err(|err| log_event("Multisig Update Failed", err))?;
+        log_event("Multisig Configured", &details);
+        self.after_mutation(LogEntry::new("MultisigConfigured").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Çoklu imza imzacılarını alfabetik sırayla döner
+    pub fn multisig_signers(&self) -> Vec<String> {
+        self.multisig.signers()
+    }
+
+    /// Çoklu imza eşiğini döner (0 ise devre dışı)
+    pub fn multisig_threshold(&self) -> u32 {
+        self.multisig.threshold()
+    }
+
+    /// `{action, ...}` yetkili işlemini çoklu imza onayına sunar ve işlem ID'sini döner
+    pub fn propose_multisig_action(
+        &mut self,
+        caller: String,
+        #[wasm_bindgen(unchecked_param_type = "AdminAction")] action: JsValue,
+    ) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("propose_multisig_action");
+        self.ensure_sender(&caller)?;
+        let action: AdminAction =
+            serde_wasm_bindgen::from_value(action).map_err(|e| format!("Invalid admin action: {}", e))?;
+        timer.finish(self.propose_multisig(caller, action))
+    }
+
+    /// Bekleyen işlemi imzacı olarak onaylar
+    pub fn confirm_multisig_action(&mut self, caller: String, action_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("confirm_multisig_action");
+        self.ensure_sender(&caller)?;
+        let confirmations = self
+            .multisig
+            .confirm(action_id, &caller, self.clock.now())
+            .inspect_err(|err| log_event("Multisig Confirmation Failed", err))?;
+        let details = format!("Action ID: {}, Confirmations: {}", action_id, confirmations);
+        log_event("Multisig Action Confirmed", &details);
+        self.after_mutation(LogEntry::new("MultisigActionConfirmed").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// İmzacının bekleyen işlemdeki onayını geri çeker
+    pub fn revoke_multisig_confirmation(&mut self, caller: String, action_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("revoke_multisig_confirmation");
+        self.ensure_sender(&caller)?;
+        let confirmations = self
+            .multisig
+            .revoke(action_id, &caller, self.clock.now())
+            .inspect_err(|err| log_event("Multisig Revocation Failed", err))?;
+        let details = format!("Action ID: {}, Confirmations: {}", action_id, confirmations);
+        log_event("Multisig Confirmation Revoked", &details);
+        self.after_mutation(LogEntry::new("MultisigConfirmationRevoked").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Eşik kadar onaylanmış işlemi, önerenin yetkisiyle uygular; herkes çağırabilir
+    ///
+    /// Zaman kilidi etkinse işlem uygulanmak yerine zaman kilidi sırasına alınır.
+    pub fn execute_multisig_action(&mut self, caller: String, action_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("execute_multisig_action");
+        self.ensure_sender(&caller)?;
+        let pending = self
+            .multisig
+            .take_approved(action_id, self.clock.now())
+            .inspect_err(|err| log_event("Multisig Execution Failed", err))?;
+
+        self.multisig.set_executing(true);
+        let proposer = pending.proposer.clone();
+        let result = if self.timelock.delay() > 0 {
+            self.as_contract(|erc721| erc721.queue_action(proposer, pending.action.clone())).map(|_| ())
+        } else {
+            self.apply_admin_action(proposer, pending.action.clone())
+        };
+        self.multisig.set_executing(false);
+
+        if let Err(err) = result {
+            self.multisig.restore(action_id, pending);
+            return Err(err);
+        }
+        log_event("Multisig Action Executed", &format!("Action ID: {}", action_id));
+        self.after_mutation(
+            LogEntry::new("MultisigActionExecuted")
+                .from(&caller)
+                .details(format!("Action ID: {}", action_id)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Bekleyen işlemi onaylayan imzacıları alfabetik sırayla döner
+    pub fn multisig_confirmations(&self, action_id: u64) -> Vec<String> {
+        self.multisig
+            .get(action_id)
+            .map(|pending| pending.confirmations.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// `{action, ...}` yetkili işlemini sıraya alır ve işlem ID'sini döner
+    pub fn queue_admin_action(
+        &mut self,
+        caller: String,
+        #[wasm_bindgen(unchecked_param_type = "AdminAction")] action: JsValue,
+    ) -> Result<u64, String> {
+        let timer = self.perf.start_fallible("queue_admin_action");
+        self.ensure_sender(&caller)?;
+        let action: AdminAction =
+            serde_wasm_bindgen::from_value(action).map_err(|e| format!("Invalid admin action: {}", e))?;
+        timer.finish(self.queue_action(caller, action))
+    }
+
+    /// Süresi dolmuş işlemi, sıraya alanın yetkisiyle uygular; herkes çağırabilir
+    pub fn execute_admin_action(&mut self, caller: String, action_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("execute_admin_action");
+        self.ensure_sender(&caller)?;
+        let queued = self
+            .timelock
+            .take_ready(action_id, self.clock.now())
+            .inspect_err(|err| log_event("Timelock Execution Failed", err))?;
+
+        // Sıradaki işlemler, çoklu imza etkinse sıraya alınırken zaten onaylanmıştır
+        self.timelock.set_executing(true);
+        self.multisig.set_executing(true);
+        let result = self.apply_admin_action(queued.proposer.clone(), queued.action.clone());
+        self.timelock.set_executing(false);
+        self.multisig.set_executing(false);
+
+        if let Err(err) = result {
+            self.timelock.requeue(action_id, queued);
+            return Err(err);
+        }
+        log_event("Timelock Action Executed", &format!("Action ID: {}", action_id));
+        self.after_mutation(
+            LogEntry::new("TimelockActionExecuted")
+                .from(&caller)
+                .details(format!("Action ID: {}", action_id)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Sıradaki işlemi iptal eder (sıraya alan veya ADMIN rolü)
+    pub fn cancel_admin_action(&mut self, caller: String, action_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("cancel_admin_action");
+        self.ensure_sender(&caller)?;
+        let proposer = self.timelock.get(action_id).ok_or("Action is not queued")?.proposer.clone();
+        if proposer != caller {
+            self.ensure_role(&caller, ADMIN_ROLE, "Timelock Cancel Failed")?;
+        }
+
+        self.timelock.cancel(action_id)?;
+        log_event("Timelock Action Cancelled", &format!("Action ID: {}", action_id));
+        self.after_mutation(
+            LogEntry::new("TimelockActionCancelled")
+                .from(&caller)
+                .details(format!("Action ID: {}", action_id)),
+        );
+        timer.finish(Ok(()))
+    }
+
+    /// Zaman kilidi sırasındaki işlemi uygulanmadan önce veto eder (sadece GUARDIAN rolü)
+    pub fn veto_admin_action(&mut self, caller: String, action_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("veto_admin_action");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, GUARDIAN_ROLE, "Timelock Veto Failed")?;
+
+        let queued = self.timelock.cancel(action_id).inspect_err(|err| log_event("Timelock Veto Failed", err))?;
+        let details = format!("Action ID: {}, Action: {:?}", action_id, queued.action);
+        log_event("Timelock Action Vetoed", &details);
+        self.after_mutation(LogEntry::new("TimelockActionVetoed").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Sıradaki işlemin uygulanabileceği en erken anı döner
+    pub fn admin_action_eta(&self, action_id: u64) -> Option<u64> {
+        self.timelock.get(action_id).map(|queued| queued.eta)
+    }
+
+    /// Mint öncesi çalışacak JS kancası ekler; `false` dönmesi veya hata fırlatması mint'i iptal eder
+    pub fn on_before_mint(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "HookCallback")] callback: js_sys::Function,
+    ) {
+        let _timer = self.perf.start("on_before_mint");
+        self.hooks.add_js(HookKind::BeforeMint, callback);
+    }
+
+    /// Mint sonrası çalışacak JS kancası ekler
+    pub fn on_after_mint(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "HookCallback")] callback: js_sys::Function,
+    ) {
+        let _timer = self.perf.start("on_after_mint");
+        self.hooks.add_js(HookKind::AfterMint, callback);
+    }
+
+    /// Transfer öncesi çalışacak JS kancası ekler; `false` dönmesi veya hata fırlatması transferi iptal eder
+    pub fn on_before_transfer(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "HookCallback")] callback: js_sys::Function,
+    ) {
+        let _timer = self.perf.start("on_before_transfer");
+        self.hooks.add_js(HookKind::BeforeTransfer, callback);
+    }
+
+    /// Transfer sonrası çalışacak JS kancası ekler
+    pub fn on_after_transfer(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "HookCallback")] callback: js_sys::Function,
+    ) {
+        let _timer = self.perf.start("on_after_transfer");
+        self.hooks.add_js(HookKind::AfterTransfer, callback);
+    }
+
+    /// Burn öncesi çalışacak JS kancası ekler; `false` dönmesi veya hata fırlatması burn'ü iptal eder
+    pub fn on_before_burn(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "HookCallback")] callback: js_sys::Function,
+    ) {
+        let _timer = self.perf.start("on_before_burn");
+        self.hooks.add_js(HookKind::BeforeBurn, callback);
+    }
+
+    /// Burn sonrası çalışacak JS kancası ekler
+    pub fn on_after_burn(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "HookCallback")] callback: js_sys::Function,
+    ) {
+        let _timer = self.perf.start("on_after_burn");
+        self.hooks.add_js(HookKind::AfterBurn, callback);
+    }
+
+    /// Kayıtlı tüm kancaları kaldırır
+    pub fn clear_hooks(&mut self) {
+        let _timer = self.perf.start("clear_hooks");
+        self.hooks.clear();
+    }
+
+    /// Kayıtlı kanca sayısını döner
+    pub fn hook_count(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Kontratın duraklatılıp duraklatılmadığını döner
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Adresi yasaklı listeye ekler; bu adrese/adresten mint ve transfer yapılamaz (sadece COMPLIANCE rolü)
+    pub fn add_to_denylist(&mut self, caller: String, address: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("add_to_denylist");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, COMPLIANCE_ROLE, "Denylist Update Failed")?;
+
+        if !self.denylist.insert(address.clone()) {
+            return Err("Address is already denylisted".to_string());
+        }
+        log_event("Address Denylisted", &format!("Address: {}", address));
+        self.after_mutation(LogEntry::new("Denylisted").from(&caller).to(&address));
+        timer.finish(Ok(()))
+    }
+
+    /// Adresi yasaklı listeden çıkarır (sadece COMPLIANCE rolü)
+    pub fn remove_from_denylist(&mut self, caller: String, address: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("remove_from_denylist");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, COMPLIANCE_ROLE, "Denylist Update Failed")?;
+
+        if !self.denylist.remove(&address) {
+            return Err("Address is not denylisted".to_string());
+        }
+        log_event("Address Removed From Denylist", &format!("Address: {}", address));
+        self.after_mutation(LogEntry::new("DenylistRemoved").from(&caller).to(&address));
+        timer.finish(Ok(()))
+    }
+
+    /// Adresin yasaklı listede olup olmadığını döner
+    pub fn is_denylisted(&self, address: String) -> bool {
+        self.denylist.contains(&address)
+    }
+
+    /// Token'ı transfer edilemez (soulbound) yapar (sadece ADMIN rolü); mint ve burn etkilenmez
+    pub fn lock(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("lock");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Lock Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+
+        self.locked_tokens.insert(token_id);
+        log_event("Locked", &format!("Token ID: {}", token_id));
+        self.after_mutation(LogEntry::new("Locked").token(token_id).from(&caller));
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın transfer kilidini kaldırır (sadece ADMIN rolü)
+    pub fn unlock(&mut self, caller: String, token_id: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("unlock");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Unlock Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+
+        self.locked_tokens.remove(&token_id);
+        log_event("Unlocked", &format!("Token ID: {}", token_id));
+        self.after_mutation(LogEntry::new("Unlocked").token(token_id).from(&caller));
+        timer.finish(Ok(()))
+    }
+
+    /// Tüm koleksiyonu soulbound moduna alır veya moddan çıkarır (sadece ADMIN rolü)
+    pub fn set_soulbound(&mut self, caller: String, soulbound: bool) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_soulbound");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Soulbound Update Failed")?;
+
+        self.soulbound = soulbound;
+        log_event("Soulbound Updated", &format!("Soulbound: {}", soulbound));
+        self.after_mutation(LogEntry::new("SoulboundUpdated").from(&caller).details(soulbound.to_string()));
+        timer.finish(Ok(()))
+    }
+
+    /// Token'ın kilitli olup olmadığını döner (ERC-5192); token yoksa hata döner
+    pub fn locked(&self, token_id: u64) -> Result<bool, String> {
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+        Ok(self.is_locked(token_id))
+    }
+
+    /// Kontrat rol yöneticisi üzerinden kullanıcıya rol atar (sahip veya rolün yöneticileri yapabilir)
+    pub fn assign_role(&mut self, caller: String, role: String, user: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("assign_role");
+        self.ensure_sender(&caller)?;
+        self.roles.assign_role(caller, role, user)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Kontrat rol yöneticisi üzerinden kullanıcıdan rolü geri alır (sahip veya rolün yöneticileri yapabilir)
+    pub fn remove_role(&mut self, caller: String, role: String, user: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("remove_role");
+        self.ensure_sender(&caller)?;
+        self.roles.remove_role(caller, role, user)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Rolü kullanıcı listesine tek seferde atar; herhangi bir adres geçersizse hiçbiri atanmaz
+    pub fn assign_role_batch(&mut self, caller: String, role: String, users: Vec<String>) -> Result<(), String> {
+        let timer = self.perf.start_fallible("assign_role_batch");
+        self.ensure_sender(&caller)?;
+        self.roles.assign_role_batch(caller, role, users)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Rolü kullanıcı listesinden tek seferde geri alır; rolü taşımayan bir adres varsa hiçbiri alınmaz
+    pub fn remove_role_batch(&mut self, caller: String, role: String, users: Vec<String>) -> Result<(), String> {
+        let timer = self.perf.start_fallible("remove_role_batch");
+        self.ensure_sender(&caller)?;
+        self.roles.remove_role_batch(caller, role, users)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Kullanıcının kendi rolünden vazgeçmesi (çağıran `user`'ın kendisidir)
+    pub fn renounce_role(&mut self, user: String, role: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("renounce_role");
+        self.ensure_sender(&user)?;
+        self.roles.renounce_role(user, role)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Kontrat saatine göre `expires_at` (Unix saniye) anına kadar geçerli bir rol atar
+    pub fn assign_role_until(
+        &mut self,
+        caller: String,
+        role: String,
+        user: String,
+        expires_at: u64,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("assign_role_until");
+        self.ensure_sender(&caller)?;
+        self.roles.assign_role_until(caller, role, user, expires_at)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Süreli bir rol atamasının bitiş zamanını ileri alır
+    pub fn extend_role(&mut self, caller: String, role: String, user: String, expires_at: u64) -> Result<(), String> {
+        let timer = self.perf.start_fallible("extend_role");
+        self.ensure_sender(&caller)?;
+        self.roles.extend_role(caller, role, user, expires_at)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Süresi dolmuş rol atamalarını kaldırır ve kaldırılan atama sayısını döner
+    pub fn revoke_expired_roles(&mut self) -> u32 {
+        let _timer = self.perf.start("revoke_expired_roles");
+        let revoked = self.roles.revoke_expired();
+        if revoked > 0 {
+            self.persist(false);
+        }
+        revoked
+    }
+
+    /// Süreli rol atamasının bitiş zamanını döner (kalıcı atamalarda `None`)
+    pub fn get_role_expiration(&self, role: String, user: String) -> Option<u64> {
+        self.roles.get_role_expiration(role, user)
+    }
+
+    /// `parent_role` üyelerinin `child_role` erişim kontrollerini de geçmesini sağlar (geçişlidir)
+    pub fn set_role_implication(
+        &mut self,
+        caller: String,
+        parent_role: String,
+        child_role: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_role_implication");
+        self.ensure_sender(&caller)?;
+        self.roles.set_role_implication(caller, parent_role, child_role)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// `parent_role`'ün `child_role`'ü doğrudan kapsamasını kaldırır
+    pub fn remove_role_implication(
+        &mut self,
+        caller: String,
+        parent_role: String,
+        child_role: String,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("remove_role_implication");
+        self.ensure_sender(&caller)?;
+        self.roles.remove_role_implication(caller, parent_role, child_role)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Rolün doğrudan kapsadığı rolleri alfabetik sırayla listeler
+    pub fn get_role_implications(&self, role: String) -> Vec<String> {
+        self.roles.get_role_implications(role)
+    }
+
+    /// Rolün yönetici rolünü döner (belirlenmemişse `DEFAULT_ADMIN_ROLE`)
+    pub fn get_role_admin(&self, role: String) -> String {
+        self.roles.get_role_admin(role)
+    }
+
+    /// Rolün yönetici rolünü değiştirir (sahip veya rolün mevcut yöneticileri yapabilir)
+    pub fn set_role_admin(&mut self, caller: String, role: String, admin_role: String) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_role_admin");
+        self.ensure_sender(&caller)?;
+        self.roles.set_role_admin(caller, role, admin_role)?;
+        self.persist(false);
+        timer.finish(Ok(()))
+    }
+
+    /// Rolün kullanıcılarını atanma sırasına göre sayfalı döner
+    pub fn list_role_users_page(&self, role: String, offset: u32, limit: u32) -> AddressPage {
+        self.roles.list_role_users_page(role, offset, limit)
+    }
+
+    /// Rolün kullanıcılarını ara `Vec<String>` kopyası olmadan JS dizisi olarak döner
+    #[wasm_bindgen(unchecked_return_type = "string[]")]
+    pub fn list_role_users_array(&self, role: String) -> js_sys::Array {
+        self.roles.list_role_users_array(role)
+    }
+
+    /// Role sahip kullanıcı sayısını döner
+    pub fn get_role_member_count(&self, role: String) -> u32 {
+        self.roles.get_role_member_count(role)
+    }
+
+    /// Rolün `index` sıradaki üyesini atanma sırasına göre döner
+    pub fn get_role_member(&self, role: String, index: u32) -> Option<String> {
+        self.roles.get_role_member(role, index)
+    }
+
+    /// Kontrat üzerinde en az bir üyesi olan rolleri alfabetik sırayla listeler
+    pub fn list_roles(&self) -> Vec<String> {
+        self.roles.list_roles()
+    }
+
+    /// Kullanıcının kontrat üzerindeki rollerini alfabetik sırayla listeler
+    pub fn roles_of(&self, user: String) -> Vec<String> {
+        self.roles.roles_of(user)
+    }
+
+    /// Kontratın rol ve sahiplik değişikliklerinde çağrılacak JS dinleyicisi ekler
+    pub fn on_role_event(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "RoleEventCallback")] callback: js_sys::Function,
+    ) {
+        self.roles.on_role_event(callback);
+    }
+
+    /// Kontratın rol değişikliği denetim günlüğünü sırayla döner
+    ///
+    /// Rol ve sahiplik değişiklikleri yalnızca bu günlüğe yazılır; `history` ve `get_events` bunları içermez.
+    pub fn role_audit_log(&self) -> Vec<LogEntry> {
+        self.roles.role_audit_log()
+    }
+
+    /// Kontratın rol değişikliği denetim günlüğünü JSON dizisi olarak dışa aktarır
+    pub fn export_role_audit_log(&self) -> String {
+        self.roles.export_role_audit_log()
+    }
+
+    /// Kullanıcının kontrat üzerinde belirtilen role sahip olup olmadığını kontrol eder
+    pub fn has_role(&self, role: String, user: String) -> bool {
+        self.roles.role_based_access(user, role)
+    }
+
+    /// Çağıranın belirtilen role sahip olmasını zorunlu kılar
+    fn ensure_role(&self, caller: &str, role: &str, event: &str) -> Result<(), String> {
+        if !self.roles.has_role(role, caller) {
+            log_event(event, "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", role));
+        }
+        Ok(())
+    }
+
+    /// (token_id, sahip) çiftleri üzerinden hesaplanan Merkle kökünü döner
+    pub fn state_root(&self) -> String {
+        to_hex(&self.ownership_tree().0.root())
+    }
+
+    /// Token sahipliği için `verify_proof` ile doğrulanabilir Merkle kanıtı döner
+    pub fn prove_ownership(&self, token_id: u64) -> Result<Vec<String>, String> {
+        let (tree, token_ids) = self.ownership_tree();
+        let index = token_ids.binary_search(&token_id).map_err(|_| "Token does not exist".to_string())?;
+        Ok(tree.proof(index).iter().map(|hash| to_hex(hash)).collect())
+    }
+
+    /// Token'ı etkileyen tüm işlemleri sırayla döner
+    pub fn history(&self, token_id: u64) -> Vec<LogEntry> {
+        self.audit_log.history(token_id, self.clock.now_ms())
+    }
+
+    /// Adresin gönderen veya alıcı olduğu tüm işlemleri sırayla döner
+    pub fn history_by_address(&self, address: String) -> Vec<LogEntry> {
+        self.audit_log.history_by_address(&address, self.clock.now_ms())
+    }
+
+    /// `[from, to)` sıra numarası aralığındaki işlemleri döner
+    pub fn log_range(&self, from: u64, to: u64) -> Vec<LogEntry> {
+        self.audit_log.range(from, to, self.clock.now_ms())
+    }
+
+    /// Filtreyle eşleşen olayları sırayla döner (`eth_getLogs` karşılığı); filtre verilmezse tümü
+    pub fn get_events(
+        &self,
+        #[wasm_bindgen(unchecked_param_type = "EventFilter | undefined")] filter: JsValue,
+    ) -> Result<Vec<LogEntry>, String> {
+        let filter = if filter.is_undefined() || filter.is_null() {
+            EventFilter::default()
+        } else {
+            serde_wasm_bindgen::from_value(filter).map_err(|e| format!("Invalid event filter: {}", e))?
+        };
+        Ok(self.events(&filter))
+    }
+
+    /// Denetim günlüğünü en fazla `max_entries` kayıt ve `ttl_ms` milisaniyeden yeni kayıtlarla sınırlar
+    ///
+    /// Sınırı aşan en eski kayıtlar hemen atılır; sıra numaraları boşluksuz artmaya devam eder.
+    /// `None` verilen sınır uygulanmaz (sadece ADMIN rolü).
+    pub fn set_event_retention(
+        &mut self,
+        caller: String,
+        max_entries: Option<u64>,
+        ttl_ms: Option<u64>,
+    ) -> Result<(), String> {
+        let timer = self.perf.start_fallible("set_event_retention");
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Event Retention Update Failed")?;
+        let retention = Retention::new(max_entries, ttl_ms)
+            .inspect_err(|err| log_event("Event Retention Update Failed", err))?;
+
+        self.audit_log.set_retention(retention, self.clock.now_ms());
+        let details = format!("Max Entries: {:?}, TTL: {:?}", max_entries, ttl_ms);
+        log_event("Event Retention Updated", &details);
+        self.after_mutation(LogEntry::new("RetentionUpdated").from(&caller).details(details));
+        timer.finish(Ok(()))
+    }
+
+    /// Adres tekilleştirmesinin bellek kullanımına etkisini döner
+    pub fn memory_stats(&self) -> MemoryStats {
+        let references = self.token_owner.values().copied().chain(self.owned_tokens.owner_ids());
+        MemoryStats::new(&self.addresses, references)
+    }
+
+    /// İşlem başına çağrı sayısı ve süreleri döner: `{ transfer: { count, total_ms, max_ms }, ... }`
+    ///
+    /// Durumu değiştiren tüm genel işlemler ile `tokens_of_owner` ölçülür.
+    #[wasm_bindgen(unchecked_return_type = "PerfReport")]
+    pub fn perf_report(&self) -> JsValue {
+        self.perf
+            .report()
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .unwrap_or(JsValue::NULL)
+    }
+
+    /// Performans sayaçlarını sıfırlar
+    pub fn reset_perf(&self) {
+        self.perf.reset();
+    }
+
+    /// Mint, transfer, onay ve yakma sayaçları, başarısız işlem sayısı ve durum tablosu boyutları
+    ///
+    /// `{ mints: { count, failures, total_ms }, ..., failed_operations, map_sizes: { token_owner, ... } }`
+    #[wasm_bindgen(unchecked_return_type = "Metrics")]
+    pub fn metrics(&self) -> JsValue {
+        self.metrics_snapshot()
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .unwrap_or(JsValue::NULL)
+    }
+
+    /// İşlem sayaçlarını sıfırlar (`perf_report` ile ortaktır); tablo boyutları etkilenmez
+    pub fn reset_metrics(&self) {
+        self.perf.reset();
+    }
+
+    /// Denetim günlüğündeki süresi dolmamış kayıt sayısını döner
+    pub fn log_len(&self) -> u64 {
+        self.audit_log.live_len(self.clock.now_ms())
+    }
+
+    /// Mevcut durumu geri dönülebilir bir nokta olarak saklar ve kimliğini döner
+    pub fn checkpoint(&mut self) -> CheckpointId {
+        let _timer = self.perf.start("checkpoint");
+        let id = self.next_checkpoint_id;
+        self.next_checkpoint_id += 1;
+        let state = self.state_snapshot();
+        self.checkpoints.0.push(Checkpoint { id, state });
+        log_event("Checkpoint Created", &format!("Checkpoint ID: {}", id));
+        id
+    }
+
+    /// Durumu belirtilen noktaya geri sarar; sonraki noktalar silinir, bu nokta korunur
+    pub fn rollback_to(&mut self, checkpoint_id: CheckpointId) -> Result<(), String> {
+        let timer = self.perf.start_fallible("rollback_to");
+        let index = self
+            .checkpoints
+            .0
+            .iter()
+            .position(|checkpoint| checkpoint.id == checkpoint_id)
+            .ok_or("Checkpoint does not exist")?;
+
+        self.checkpoints.0.truncate(index + 1);
+        let state = self.checkpoints.0[index].state.clone();
+        self.restore_state(state);
+        log_event("Rolled Back", &format!("Checkpoint ID: {}", checkpoint_id));
+        self.after_mutation(LogEntry::new("Rollback").details(format!("Checkpoint ID: {}", checkpoint_id)));
+        timer.finish(Ok(()))
+    }
+
+    /// Saklanan tüm geri dönüş noktalarını siler
+    pub fn clear_checkpoints(&mut self) {
+        let _timer = self.perf.start("clear_checkpoints");
+        self.checkpoints.0.clear();
+    }
+
+    /// Saklanan geri dönüş noktası sayısını döner
+    pub fn checkpoint_count(&self) -> usize {
+        self.checkpoints.0.len()
+    }
+
+    /// Kontratın tüm durumunu deterministik JSON olarak dışa aktarır
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("contract state is always serializable")
+    }
+
+    /// JSON'dan kontrat durumunu yükler (mint işlemlerini yeniden oynatmadan)
+    pub fn from_json(json: String) -> Result<ERC721, String> {
+        serde_json::from_str(&json).map_err(|e| format!("Invalid ERC721 JSON: {}", e))
+    }
+
+    /// Kontrat durumunu kompakt ikili formatta dışa aktarır (JS tarafında Uint8Array)
+    ///
+    /// Çıktı şema sürümünü taşır; sonraki crate sürümleri bunu `migrate` ile yükleyebilir.
+    pub fn snapshot(&self) -> Vec<u8> {
+        migration::encode(bincode::serialize(self).expect("contract state is always serializable"))
+    }
+
+    /// `snapshot`'ın Promise sürümü: serileştirmeden önce olay döngüsüne döner
+    ///
+    /// Serileştirmenin kendisi tek adımda yapılır; büyük durumlar için parça parça yazan
+    /// `save_async` tercih edilebilir.
+    pub async fn snapshot_async(&self) -> js_sys::Uint8Array {
+        yield_now().await;
+        js_sys::Uint8Array::from(self.snapshot().as_slice())
+    }
+
+    /// İkili anlık görüntüden kontrat durumunu yükler (eski sürümler önce güncel şemaya taşınır)
+    pub fn restore(bytes: &[u8]) -> Result<ERC721, String> {
+        ERC721::migrate(bytes)
+    }
+
+    /// Eski bir anlık görüntüyü yerleşik sürüm adımlarıyla güncel şemaya taşıyıp yükler
+    ///
+    /// Özel adımlar gerekiyorsa Rust tarafında `Migrations::register` kullanılabilir.
+    pub fn migrate(old_bytes: &[u8]) -> Result<ERC721, String> {
+        Migrations::default().migrate(old_bytes)
+    }
+
+    /// Token, sahiplik, bakiye ve yapılandırma durumunun keccak256 özetini döner (`0x` önekli hex)
+    ///
+    /// Durum ara tampon ayrılmadan özetlenir. Denetim günlükleri, saat ve zaman damgalı kayıtlar
+    /// dahil olmadığından aynı işlemleri oynatan istemcilerde saatlerinden bağımsız olarak eşittir.
+    pub fn state_hash(&self) -> String {
+        let _timer = self.perf.start("state_hash");
+        let mut hasher = KeccakWriter::new();
+        bincode::serialize_into(&mut hasher, &self.canonical_state()).expect("contract state is always serializable");
+        to_hex(&hasher.finalize())
+    }
+
+    /// Anlık görüntünün şema sürümünü döner (sürüm başlığı eklenmeden önceki biçim için 0)
+    pub fn snapshot_version(bytes: &[u8]) -> u32 {
+        migration::decode(bytes).0
+    }
+
+    /// İki ikili anlık görüntü arasındaki token farklarını JSON uyumlu nesne olarak döner
+    ///
+    /// `{ minted: [{ token_id, before, after }], burned, transferred, uri_changes, approval_changes,
+    /// operator_changes: [{ owner, operator, approved }] }`
+    #[wasm_bindgen(unchecked_return_type = "StateDiff")]
+    pub fn diff(snapshot_a: &[u8], snapshot_b: &[u8]) -> Result<JsValue, String> {
+        let (before, after) = (ERC721::restore(snapshot_a)?, ERC721::restore(snapshot_b)?);
+        before
+            .state_diff(&after)
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| format!("State diff serialization failed: {}", e))
+    }
+
+    /// Yetki kontrolü yapılmış bir mint'i arz, cüzdan ve aşama kurallarına göre uygular
+    pub(crate) fn mint_to(&mut self, operator: &str, owner: String, token_id: u64) -> Result<(), String> {
+        let mint = self.check_mint(operator, &owner, token_id)?;
+        self.apply_mint(mint);
+        Ok(())
+    }
+
+    /// Mint'i arz, cüzdan ve aşama kurallarına göre durumu değiştirmeden doğrular ve "before"
+    /// kancalarını çalıştırır
+    pub(crate) fn check_mint(&self, operator: &str, owner: &str, token_id: u64) -> Result<PreparedMint, String> {
+        if self.token_owner.contains_key(&token_id) || self.batch_containing(token_id).is_some() {
+            log_event("Minting Failed", &format!("Token ID {} already exists", token_id));
+            return Err("Token ID already exists".to_string());
+        }
+
+        self.ensure_not_denylisted(&[owner], "Minting Failed")?;
+        self.ensure_supply(1, "Minting Failed")?;
+        self.ensure_wallet_limit(owner, 1, "Minting Failed")?;
+        self.ensure_mint_phase(owner, 1, "Minting Failed")?;
+        let context = HookContext::new(HookKind::BeforeMint, token_id, operator).to(owner);
+        self.run_hooks(&context, "Minting Failed")?;
+        Ok(PreparedMint {
+            owner: owner.to_string(),
+            context,
+        })
+    }
+
+    /// `check_mint` ile doğrulanmış mint'i uygular; "after" kancaları işlemi iptal edemez
+    pub(crate) fn apply_mint(&mut self, mint: PreparedMint) {
+        let PreparedMint { owner, context } = mint;
+        let token_id = context.token_id;
+        self.snapshot_holders(token_id, None, Some(&owner));
+        self.record_mint(&owner, 1);
+        let owner_id = self.addresses.intern(&owner);
+        self.token_owner.insert(token_id, owner_id);
+        self.owned_tokens.insert(owner_id, token_id);
+        log_event("Token Minted", &format!("Token ID: {}, Owner: {}", token_id, owner));
+        self.after_mutation(LogEntry::new("Mint").token(token_id).to(&owner));
+        self.hooks.run(&context.with_kind(HookKind::AfterMint)).expect("after hooks cannot veto");
+    }
+
+    /// Maksimum arz aşılacaksa hata döner
+    fn ensure_supply(&self, quantity: u64, event: &str) -> Result<(), String> {
+        if let Some(remaining) = self.remaining_supply() {
+            if quantity > remaining {
+                log_event(event, "Max supply reached");
+                return Err("Max supply exceeded".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Alıcının cüzdan başına mint sınırı aşılacaksa hata döner
+    fn ensure_wallet_limit(&self, recipient: &str, quantity: u64, event: &str) -> Result<(), String> {
+        if let Some(limit) = self.mint_limit_per_wallet {
+            let minted = self.minted_per_wallet.get(recipient).copied().unwrap_or(0);
+            if minted.saturating_add(quantity) > limit {
+                log_event(event, &format!("Mint limit reached for {}", recipient));
+                return Err("Mint limit per wallet exceeded".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Aşamalar tanımlıysa aktif aşama ve aşama cüzdan sınırı dışında mint'i reddeder
+    fn ensure_mint_phase(&self, recipient: &str, quantity: u64, event: &str) -> Result<(), String> {
+        if self.phases.is_empty() {
+            return Ok(());
+        }
+
+        let phase = match self.phases.active(self.clock.now()) {
+            Some(phase) => phase,
+            None => {
+                log_event(event, "No active mint phase");
+                return Err("Minting is not open".to_string());
+            }
+        };
+
+        if let Some(limit) = phase.per_wallet_limit {
+            let minted = self.minted_in_phase(phase.name.clone(), recipient.to_string());
+            if minted.saturating_add(quantity) > limit {
+                log_event(event, &format!("Phase limit reached for {}", recipient));
+                return Err(format!("Mint limit for phase {} exceeded", phase.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Mint sayaçlarını günceller
+    fn record_mint(&mut self, recipient: &str, quantity: u64) {
+        self.total_minted += quantity;
+        *self.minted_per_wallet.entry(recipient.to_string()).or_default() += quantity;
+        self.votes.transfer_units(None, Some(recipient), quantity, self.clock.now());
+
+        if let Some(phase) = self.phases.active(self.clock.now()) {
+            *self
+                .phase_minted
+                .entry(phase.name.clone())
+                .or_default()
+                .entry(recipient.to_string())
+                .or_default() += quantity;
+        }
+    }
+
+    /// Henüz kesinleşmemiş (şimdiki veya gelecek) bir an sorgulanıyorsa hata döner
+    fn ensure_past_timepoint(&self, timepoint: u64) -> Result<(), String> {
+        if timepoint >= self.clock.now() {
+            return Err(format!("Future lookup: {}", timepoint));
+        }
+        Ok(())
+    }
+
+    /// Satıcı token'ın sahibi değilse veya pazaryeri operatörü onaylı değilse hata döner
+    fn ensure_sale_allowed(&self, seller: &str, token_id: u64, event: &str) -> Result<(), String> {
+        if self.owner_lookup(token_id) != Some(seller) {
+            log_event(event, "Seller does not own the token");
+            return Err("Only the token owner can sell".to_string());
+        }
+        if !self.is_approved_or_owner(MARKETPLACE_OPERATOR.to_string(), token_id) {
+            log_event(event, "Marketplace is not approved");
+            return Err("Marketplace operator is not approved for the token".to_string());
+        }
+        Ok(())
+    }
+
+    /// Emri doğrular ve özetini hex olarak döner
+    fn check_order(&self, order: &Order, signature: &str) -> Result<String, String> {
+        if self.clock.now() >= order.end_time {
+            return Err("Order has expired".to_string());
+        }
+        let digest = order.digest(&self.domain)?;
+        let digest_hex = to_hex(&digest);
+        if self.closed_orders.contains(&digest_hex) {
+            return Err("Order is already filled or cancelled".to_string());
+        }
+        if order.nonce != self.order_nonce(order.offerer.clone()) {
+            return Err("Order nonce is stale".to_string());
+        }
+        let signer = recover_address(&digest, signature)?;
+        if !signer.eq_ignore_ascii_case(&order.offerer) {
+            return Err("Order is not signed by the offerer".to_string());
+        }
+
+        let owner = self.owner_lookup(order.token_id).ok_or("Token does not exist")?;
+        if !owner.eq_ignore_ascii_case(&order.offerer) {
+            return Err("Offerer does not own the token".to_string());
+        }
+        if !self.is_approved_or_owner(MARKETPLACE_OPERATOR.to_string(), order.token_id) {
+            return Err("Marketplace operator is not approved for the token".to_string());
+        }
+
+        let total = order.total()?;
+        if total == 0 {
+            return Err("Order must have a consideration".to_string());
+        }
+        if let Some(royalty) = self.royalty_info(order.token_id, total).filter(|royalty| royalty.amount > 0) {
+            let paid: u64 = order
+                .consideration
+                .iter()
+                .filter(|item| item.recipient.eq_ignore_ascii_case(&royalty.receiver))
+                .map(|item| item.amount)
+                .sum();
+            if paid < royalty.amount {
+                return Err(format!("Consideration must pay a royalty of {}", royalty.amount));
+            }
+        }
+        Ok(digest_hex)
+    }
+
+    /// Alt token kaydını ekler ve olayı yayınlar
+    fn record_child(&mut self, caller: String, parent_id: u64, contract: String, child_id: u64) {
+        let details = format!("Child: {}:{}", contract, child_id);
+        self.composables.attach(
+            parent_id,
+            ChildToken {
+                contract,
+                token_id: child_id,
+            },
+        );
+        log_event("Child Attached", &format!("Parent ID: {}, {}", parent_id, details));
+        self.after_mutation(LogEntry::new("ChildAttached").token(parent_id).from(&caller).details(details));
+    }
+
+    /// Alt token kaydını kaldırır ve olayı yayınlar
+    fn remove_child(&mut self, parent_id: u64, contract: &str, child_id: u64, to: &str) {
+        self.composables.detach(parent_id, contract, child_id);
+        let details = format!("Child: {}:{}", contract, child_id);
+        log_event("Child Detached", &format!("Parent ID: {}, {}", parent_id, details));
+        self.after_mutation(LogEntry::new("ChildDetached").token(parent_id).to(to).details(details));
+    }
+
+    /// Çağıran üst token'ı yönetemiyorsa veya alt token bağlı değilse hata döner
+    fn ensure_child_attached(&self, caller: &str, parent_id: u64, contract: &str, child_id: u64) -> Result<(), String> {
+        if !self.is_approved_or_owner(caller.to_string(), parent_id) {
+            log_event("Detach Child Failed", "Unauthorized attempt");
+            return Err("Only the parent owner can detach children".to_string());
+        }
+        let attached = self
+            .composables
+            .children_of(parent_id)
+            .iter()
+            .any(|child| child.contract.eq_ignore_ascii_case(contract) && child.token_id == child_id);
+        if !attached {
+            return Err("Child is not attached to the token".to_string());
+        }
+        Ok(())
+    }
+
+    /// Token bu koleksiyondaki bir üst token'a bağlıysa üst token'ın ID'sini döner
+    fn own_parent_of(&self, token_id: u64) -> Option<u64> {
+        composable::parent_of_holder(&self.domain.verifying_contract, self.owner_lookup(token_id)?)
+    }
+
+    /// Süresi dolmamış takası döner
+    fn active_swap(&self, swap_id: u64) -> Result<&Swap, String> {
+        let swap = self.swaps.get(swap_id).ok_or("Swap does not exist")?;
+        if self.clock.now() >= swap.expiry {
+            return Err("Swap has expired".to_string());
+        }
+        Ok(swap)
+    }
+
+    /// Takas tarafının tokenlarının pazaryeri operatörüne devredilebileceğini ve tutarın emanete
+    /// alınabileceğini doğrular
+    fn check_swap_escrow(&self, owner: &str, side: &SwapSide) -> Result<Vec<PreparedTransfer>, String> {
+        let mut transfers = Vec::with_capacity(side.tokens.len());
+        for &token_id in &side.tokens {
+            self.ensure_sale_allowed(owner, token_id, "Swap Escrow Failed")?;
+            transfers.push(self.check_transfer(MARKETPLACE_OPERATOR, MARKETPLACE_OPERATOR, token_id)?);
+        }
+        self.bank.ensure_funds(owner, side.funds).inspect_err(|err| log_event("Swap Escrow Failed", err))?;
+        Ok(transfers)
+    }
+
+    /// `check_swap_escrow` ile doğrulanmış tarafın tokenlarını devreder ve tutarını emanete alır
+    fn apply_swap_escrow(
+        &mut self,
+        owner: &str,
+        side: &SwapSide,
+        transfers: Vec<PreparedTransfer>,
+    ) -> Result<(), String> {
+        for transfer in transfers {
+            self.apply_transfer(transfer);
+        }
+        self.bank.escrow(owner, side.funds)
+    }
+
+    /// Emanetteki tokenların ve tutarın alıcıya bırakılabileceğini doğrular
+    fn check_swap_release(&self, to: &str, side: &SwapSide) -> Result<Vec<PreparedTransfer>, String> {
+        let transfers = side
+            .tokens
+            .iter()
+            .map(|&token_id| self.check_transfer(MARKETPLACE_OPERATOR, to, token_id))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.bank.ensure_release(to, side.funds).inspect_err(|err| log_event("Swap Release Failed", err))?;
+        Ok(transfers)
+    }
+
+    /// `check_swap_release` ile doğrulanmış emanetteki tokenları ve tutarı alıcıya bırakır
+    fn apply_swap_release(
+        &mut self,
+        to: &str,
+        side: &SwapSide,
+        transfers: Vec<PreparedTransfer>,
+    ) -> Result<(), String> {
+        for transfer in transfers {
+            self.apply_transfer(transfer);
+        }
+        self.bank.release_escrow(to, side.funds)
+    }
+
+    /// Teklif tutarını emanetten alıcıya iade eder
+    fn refund_offer(&mut self, offer: &Offer) -> Result<(), String> {
+        self.bank.release_escrow(&offer.buyer, offer.amount)?;
+        log_event(
+            "Offer Refunded",
+            &format!("Token ID: {}, Buyer: {}, Amount: {}", offer.token_id, offer.buyer, offer.amount),
+        );
+        Ok(())
+    }
+
+    /// Satış bedelinin dağılımını döner: varsa royalty alıcısının payı ve kalanı satıcıya
+    fn sale_payouts(&self, seller: &str, token_id: u64, price: u64) -> Vec<(String, u64)> {
+        let mut payouts = Vec::with_capacity(2);
+        let royalty = self.royalty_info(token_id, price);
+        let royalty_amount = royalty.as_ref().map(|royalty| royalty.amount).unwrap_or(0);
+        if let Some(royalty) = royalty.filter(|royalty| royalty.amount > 0) {
+            payouts.push((royalty.receiver, royalty.amount));
+        }
+        payouts.push((seller.to_string(), price - royalty_amount));
+        payouts
+    }
+
+    /// Satış bedelini alıcıdan tahsil eder; royalty alıcısına ve kalanını satıcıya öder
+    fn pay_sale(&mut self, buyer: &str, seller: &str, token_id: u64, price: u64) -> Result<(), String> {
+        let payouts = self.sale_payouts(seller, token_id, price);
+        self.bank.pay_out(buyer, &payouts)
+    }
+
+    /// Zaman kilidi etkinken işlem sıradan gelmiyorsa hata döner
+    fn ensure_timelocked(&self, event: &str) -> Result<(), String> {
+        self.timelock.ensure_scheduled().inspect_err(|err| log_event(event, err))
+    }
+
+    /// Çoklu imza etkinken işlem onaylanmış bir öneriden gelmiyorsa hata döner
+    fn ensure_multisig(&self, event: &str) -> Result<(), String> {
+        self.multisig.ensure_approved().inspect_err(|err| log_event(event, err))
+    }
+
+    /// Bağlı bir çağıran varsa işlemi yapan adresin o olduğunu doğrular (`msg.sender`)
+    fn ensure_sender(&self, caller: &str) -> Result<(), String> {
+        self.session.ensure(caller).inspect_err(|err| log_event("Caller Rejected", err))
+    }
+
+    /// Kontratın kendi adına yaptığı iç çağrıyı (pazaryeri aktarımı, onaylı yetkili işlem vb.)
+    /// bağlı çağıran denetimi olmadan çalıştırır
+    fn as_contract<T>(&mut self, call: impl FnOnce(&mut ERC721) -> T) -> T {
+        let session = self.session.suspend();
+        let result = call(self);
+        self.session.resume(session);
+        result
+    }
+
+    /// Transferi durumu değiştirmeden doğrular ve "before" kancalarını çalıştırır
+    pub(crate) fn check_transfer(&self, operator: &str, to: &str, token_id: u64) -> Result<PreparedTransfer, String> {
+        self.ensure_not_paused("Transfer Failed")?;
+
+        let owner = self
+            .owner_lookup(token_id)
+            .map(str::to_string)
+            .ok_or("Token does not exist")
+            .inspect_err(|err| log_event("Transfer Failed", err))?;
+        self.ensure_transferable(token_id, "Transfer Failed")?;
+        self.ensure_not_denylisted(&[operator, &owner, to], "Transfer Failed")?;
+        if operator != owner {
+            self.ensure_operator_allowed(operator, "Transfer Failed")?;
+        }
+
+        if owner != operator && !self.is_approved_or_owner(operator.to_string(), token_id) {
+            log_event("Transfer Failed", "Unauthorized attempt");
+            return Err("Unauthorized transfer attempt".to_string());
+        }
+        let context = HookContext::new(HookKind::BeforeTransfer, token_id, operator)
+            .from(&owner)
+            .to(to);
+        self.run_hooks(&context, "Transfer Failed")?;
+        Ok(PreparedTransfer {
+            owner,
+            to: to.to_string(),
+            context,
+        })
+    }
+
+    /// `check_transfer` ile doğrulanmış transferi uygular; "after" kancaları işlemi iptal edemez
+    pub(crate) fn apply_transfer(&mut self, transfer: PreparedTransfer) {
+        let PreparedTransfer { owner, to, context } = transfer;
+        let token_id = context.token_id;
+        self.snapshot_holders(token_id, Some(&owner), Some(&to));
+        self.remove_token_from_owner(owner.clone(), token_id);
+        let to_id = self.addresses.intern(&to);
+        self.token_owner.insert(token_id, to_id);
+        self.owned_tokens.insert(to_id, token_id);
+        *self.permit_nonces.entry(token_id).or_default() += 1;
+        self.last_transfers.insert(token_id, self.clock.now());
+        self.votes.transfer_units(Some(&owner), Some(&to), 1, self.clock.now());
+        if owner != to {
+            self.approvals.remove(&token_id);
+            self.rentals.remove(&token_id);
+            self.marketplace.remove(token_id);
+        }
+        log_event(
+            "Token Transferred",
+            &format!("Token ID: {}, From: {}, To: {}", token_id, context.operator, to),
+        );
+        self.after_mutation(
+            LogEntry::new("Transfer")
+                .token(token_id)
+                .from(&owner)
+                .to(&to)
+                .details(format!("Operator: {}", context.operator)),
+        );
+        self.hooks.run(&context.with_kind(HookKind::AfterTransfer)).expect("after hooks cannot veto");
+    }
+
+    /// Token'ı pazaryeri operatörü olarak aktarır
+    fn operator_transfer(&mut self, to: String, token_id: u64) -> Result<(), String> {
+        self.as_contract(|erc721| erc721.transfer(MARKETPLACE_OPERATOR.to_string(), to, token_id))
+    }
+
+    /// Yetkili işlemi `proposer`'ın yetkisiyle uygular (zaman kilidi ve çoklu imza yürütmesi için)
+    fn apply_admin_action(&mut self, proposer: String, action: AdminAction) -> Result<(), String> {
+        self.as_contract(|erc721| match action {
+            AdminAction::SetBaseUri { uri } => erc721.set_base_uri(proposer, uri),
+            AdminAction::SetDefaultRoyalty { receiver, bps } => erc721.set_default_royalty(proposer, receiver, bps),
+            AdminAction::Pause => erc721.pause(proposer),
+            AdminAction::Unpause => erc721.unpause(proposer),
+            AdminAction::UpdateDelay { delay } => erc721.set_timelock_delay(proposer, delay),
+            AdminAction::ConfigureMultisig { signers, threshold, expiry } => {
+                erc721.configure_multisig(proposer, signers, threshold, expiry)
+            }
+        })
+    }
+
+    /// Kontrat duraklatılmışsa hata döner
+    fn ensure_not_paused(&self, event: &str) -> Result<(), String> {
+        if self.paused {
+            log_event(event, "Contract is paused");
+            return Err("Contract is paused".to_string());
+        }
+        Ok(())
+    }
+
+    fn is_operator(&self, owner: &str, operator: &str) -> bool {
+        self.operator_approvals.contains(&(owner.to_string(), operator.to_string()))
+    }
+
+    /// Kayıtlı kancaları çalıştırır; "before" kancası veto ederse hata döner
+    fn run_hooks(&self, context: &HookContext, event: &str) -> Result<(), String> {
+        self.hooks.run(context).inspect_err(|err| log_event(event, &format!("Rejected by hook: {}", err)))
+    }
+
+    fn attributes_value(&self, token_id: u64) -> serde_json::Value {
+        serde_json::Value::Array(self.attributes.get(token_id).iter().map(Attribute::to_json).collect())
+    }
+
+    /// Token'ın metadata'sının değiştiğini bildiren ERC-4906 olayını günlüğe ekler
+    fn emit_metadata_update(&mut self, token_id: u64) {
+        log_event("MetadataUpdate", &format!("Token ID: {}", token_id));
+        self.after_mutation(LogEntry::new("MetadataUpdate").token(token_id));
+    }
+
+    /// `[from, to]` aralığındaki tokenların metadata'sının değiştiğini bildiren ERC-4906 olayını günlüğe ekler
+    fn emit_batch_metadata_update(&mut self, from: u64, to: u64) {
+        log_event("BatchMetadataUpdate", &format!("Token IDs: {}..={}", from, to));
+        self.after_mutation(LogEntry::new("BatchMetadataUpdate").token_range(from, to));
+    }
+
+    /// Metadata (belirtilmişse token'ın URI'si) sabitlenmişse hata döner
+    fn ensure_metadata_mutable(&self, token_id: Option<u64>, event: &str) -> Result<(), String> {
+        let frozen = match token_id {
+            Some(token_id) => self.uri_frozen(token_id),
+            None => self.metadata_frozen,
+        };
+        if frozen {
+            log_event(event, "Metadata is frozen");
+            return Err("Metadata is frozen".to_string());
+        }
+        Ok(())
+    }
+
+    /// URI geçersizse (hatalı CID, Arweave kimliği veya katı modda HTTP adresi) hata döner
+    fn ensure_valid_uri(&self, uri: &str, event: &str) -> Result<(), String> {
+        validate_uri(uri, self.decentralized_uris).inspect_err(|err| log_event(event, err))
+    }
+
+    /// Operatör filtre listesindeyse hata döner
+    fn ensure_operator_allowed(&self, operator: &str, event: &str) -> Result<(), String> {
+        if self.blocked_operators.contains(operator) {
+            log_event(event, &format!("Blocked operator: {}", operator));
+            return Err(format!("Operator is blocked: {}", operator));
+        }
+        Ok(())
+    }
+
+    /// Adreslerden biri yasaklı listedeyse hata döner
+    fn ensure_not_denylisted(&self, addresses: &[&str], event: &str) -> Result<(), String> {
+        if let Some(address) = addresses.iter().find(|address| self.denylist.contains(**address)) {
+            log_event(event, &format!("Denylisted address: {}", address));
+            return Err(format!("Address is denylisted: {}", address));
+        }
+        Ok(())
+    }
+
+    /// Token'ı transfer etmeye engel bir kilit (soulbound, stake, vesting) olup olmadığını kontrol eder
+    fn ensure_transferable(&self, token_id: u64, event: &str) -> Result<(), String> {
+        if self.is_locked(token_id) {
+            log_event(event, &format!("Token ID: {} is locked", token_id));
+            return Err("Token is soulbound".to_string());
+        }
+        if self.staking.is_staked(token_id) {
+            log_event(event, &format!("Token ID: {} is staked", token_id));
+            return Err("Token is staked".to_string());
+        }
+        if let Some(&unlock_at) = self.vesting_unlocks.get(&token_id) {
+            if self.clock.now() < unlock_at {
+                log_event(event, &format!("Token ID: {} vests at {}", token_id, unlock_at));
+                return Err(format!("Token is vesting until {}", unlock_at));
+            }
+        }
+        let next_at = self.next_transferable_at(token_id);
+        if self.clock.now() < next_at {
+            log_event(event, &format!("Token ID: {} cooling down until {}", token_id, next_at));
+            return Err(format!("Token is in transfer cooldown until {}", next_at));
+        }
+        Ok(())
+    }
+
+    fn is_locked(&self, token_id: u64) -> bool {
+        self.soulbound || self.locked_tokens.contains(&token_id)
+    }
+
+    /// Diğer modüllerin ödemeleri simüle ETH defterinden yapabilmesi için erişim
+    pub(crate) fn bank_mut(&mut self) -> &mut Bank {
+        &mut self.bank
+    }
+
+    /// Geri dönüş noktaları hariç kontrat durumunun kopyasını alır
+    pub(crate) fn state_snapshot(&self) -> ERC721 {
+        let mut state = self.clone();
+        state.persistence = None;
+        state
+    }
+
+    /// Kopyalanan durumu geri yükler; geri dönüş noktaları, kalıcılık ayarları, kancalar, rol
+    /// dinleyicileri ve bağlı çağıran korunur
+    fn restore_state(&mut self, state: ERC721) {
+        let checkpoints = std::mem::take(&mut self.checkpoints);
+        let persistence = self.persistence.take();
+        let next_checkpoint_id = self.next_checkpoint_id;
+        let hooks = std::mem::take(&mut self.hooks);
+        let role_listeners = self.roles.take_listeners();
+        let perf = std::mem::take(&mut self.perf);
+        let session = self.session.suspend();
+        *self = state;
+        self.checkpoints = checkpoints;
+        self.persistence = persistence;
+        self.next_checkpoint_id = next_checkpoint_id;
+        self.hooks = hooks;
+        self.roles.set_listeners(role_listeners);
+        self.perf = perf;
+        self.session.resume(session);
+    }
+
+    /// Başarılı her değişiklikten sonra çağrılır: işlemi denetim günlüğüne ekler ve durumu kaydeder
+    fn after_mutation(&mut self, entry: LogEntry) {
+        self.audit_log.record(entry, self.clock.now_ms());
+        self.persist(false);
+    }
+
+    /// Durumu yapılandırılmışsa localStorage'a yazar (`force` değilse debounce uygulanır)
+    fn persist(&mut self, force: bool) {
+        let key = match self.persistence.as_mut() {
+            Some(persistence) => {
+                if !persistence.should_save(force) {
+                    return;
+                }
+                persistence.key.clone()
+            }
+            None => return,
+        };
+
+        match storage::save(&key, &self.to_json()) {
+            Ok(()) => {
+                if let Some(persistence) = self.persistence.as_mut() {
+                    persistence.mark_saved();
+                }
+            }
+            Err(err) => log_event("Storage Write Failed", &err),
+        }
+    }
+
+    /// Token'ın güncel sahibini bulur (açık kayıt yoksa toplu mint aralığına bakar)
+    fn owner_lookup(&self, token_id: u64) -> Option<&str> {
+        if let Some(&owner) = self.token_owner.get(&token_id) {
+            return Some(self.addresses.resolve(owner));
+        }
+        if self.burned_batch_tokens.contains(&token_id) {
+            return None;
+        }
+        self.batch_containing(token_id).map(|batch| batch.owner.as_str())
+    }
+
+    fn canonical_state(&self) -> CanonicalState<'_> {
+        CanonicalState {
+            token_owner: self.token_owner.iter().map(|(&id, &owner)| (id, self.addresses.resolve(owner))).collect(),
+            batches: &self.batches,
+            burned_batch_tokens: &self.burned_batch_tokens,
+            approvals: &self.approvals,
+            operator_approvals: &self.operator_approvals,
+            locked_tokens: &self.locked_tokens,
+            total_minted: self.total_minted,
+            total_burned: self.total_burned,
+            token_uris: &self.token_uris,
+            base_uri: &self.base_uri,
+            uri_suffix: &self.uri_suffix,
+            frozen_uris: &self.frozen_uris,
+            metadata_frozen: self.metadata_frozen,
+            provenance_hash: &self.provenance_hash,
+            placeholder_uri: &self.placeholder_uri,
+            reveal_offset: self.reveal_offset,
+            attributes: &self.attributes,
+            token_royalties: &self.token_royalties,
+            default_royalty: &self.default_royalty,
+            bank: &self.bank,
+            access: self.roles.access_config(),
+            blocked_operators: &self.blocked_operators,
+            denylist: &self.denylist,
+            decentralized_uris: self.decentralized_uris,
+            contract_uri: &self.contract_uri,
+            contract_metadata: &self.contract_metadata,
+            domain: &self.domain,
+            max_supply: self.max_supply,
+            mint_price: self.mint_price,
+            mint_limit_per_wallet: self.mint_limit_per_wallet,
+            treasury: &self.treasury,
+            allowlist_root: self.allowlist_root,
+            transfer_cooldown: self.transfer_cooldown,
+            paused: self.paused,
+            soulbound: self.soulbound,
+        }
+    }
+
+    /// Mevcut tüm tokenları sahipleriyle birlikte Token ID sırasına göre döner
+    fn sorted_token_owners(&self) -> Vec<(u64, &str)> {
+        let mut tokens: Vec<(u64, &str)> = self.token_owner_entries().collect();
+        tokens.sort_unstable_by_key(|&(id, _)| id);
+        tokens
+    }
+
+    /// Mevcut tüm tokenları sahipleriyle döner: önce açık kayıtlar, sonra toplu mint aralıkları (ikisi de ID sırasıyla)
+    fn token_owner_entries(&self) -> impl Iterator<Item = (u64, &str)> + '_ {
+        let explicit = self.token_owner.iter().map(move |(&id, &owner)| (id, self.addresses.resolve(owner)));
+        let batched = self.batches.iter().flat_map(move |(&start, batch)| {
+            self.unclaimed_batch_tokens(start, batch.end).map(move |id| (id, batch.owner.as_str()))
+        });
+        explicit.chain(batched)
+    }
+
+    /// Sahibin tokenları: önce açık kayıtlar (edinme sırasıyla), sonra toplu mint aralıklarında kalanlar
+    fn owned_token_ids<'a>(&'a self, owner: &'a str) -> impl Iterator<Item = u64> + 'a {
+        let explicit = self.addresses.get(owner).into_iter().flat_map(move |id| self.owned_tokens.iter(id));
+        let batched = self
+            .batches
+            .iter()
+            .filter(move |(_, batch)| batch.owner == owner)
+            .flat_map(move |(&start, batch)| self.unclaimed_batch_tokens(start, batch.end));
+        explicit.chain(batched)
+    }
+
+    /// Toplu mint aralığında hâlâ toplu kayıtla sahiplenilen (transfer edilmemiş, yakılmamış) tokenlar
+    fn unclaimed_batch_tokens(&self, start: u64, end: u64) -> impl Iterator<Item = u64> + '_ {
+        (start..=end).filter(move |id| !self.token_owner.contains_key(id) && !self.burned_batch_tokens.contains(id))
+    }
+
+    /// `start_after`'dan büyük en küçük `limit` Token ID'yi artan sırada döner (imleçler için)
+    ///
+    /// Tüm listeyi sıralamaz: toplu mint aralıkları sırayla gezilir, açık kayıtlardan yalnızca
+    /// en küçük `limit` tanesi seçilir.
+    pub(crate) fn tokens_after(&self, owner: Option<&str>, start_after: Option<u64>, limit: usize) -> Vec<u64> {
+        let after = |id: &u64| start_after.map(|start| *id > start).unwrap_or(true);
+        let mut explicit: Vec<u64> = match owner {
+            Some(owner) => match self.addresses.get(owner) {
+                Some(owner_id) => self.owned_tokens.iter(owner_id).filter(after).collect(),
+                None => Vec::new(),
+            },
+            None => self.token_owner.keys().copied().filter(after).collect(),
+        };
+        if explicit.len() > limit {
+            explicit.select_nth_unstable(limit);
+            explicit.truncate(limit);
+        }
+        explicit.sort_unstable();
+
+        let first = start_after.map(|start| start.saturating_add(1)).unwrap_or(0);
+        let from = self
+            .batches
+            .range(..=first)
+            .next_back()
+            .filter(|(_, batch)| batch.end >= first)
+            .map(|(&start, _)| start)
+            .unwrap_or(first);
+        let mut implicit = Vec::new();
+        for (&start, batch) in self.batches.range(from..) {
+            if owner.map(|owner| batch.owner != owner).unwrap_or(false) {
+                continue;
+            }
+            let ids = (start.max(first)..=batch.end)
+                .filter(|id| !self.token_owner.contains_key(id) && !self.burned_batch_tokens.contains(id));
+            implicit.extend(ids.take(limit - implicit.len()));
+            if implicit.len() == limit {
+                break;
+            }
+        }
+
+        let mut tokens = Vec::with_capacity(limit);
+        let (mut explicit, mut implicit) = (explicit.into_iter().peekable(), implicit.into_iter().peekable());
+        while tokens.len() < limit {
+            let next = match (explicit.peek(), implicit.peek()) {
+                (Some(a), Some(b)) if a < b => explicit.next(),
+                (Some(_), None) => explicit.next(),
+                (_, Some(_)) => implicit.next(),
+                (None, None) => break,
+            };
+            tokens.extend(next);
+        }
+        tokens
+    }
+
+    /// Sahiplik Merkle ağacını ve yaprak sırasındaki Token ID'leri döner
+    fn ownership_tree(&self) -> (MerkleTree, Vec<u64>) {
+        let tokens = self.sorted_token_owners();
+        let leaves = tokens.iter().map(|&(id, owner)| ownership_leaf(id, owner)).collect();
+        (MerkleTree::new(leaves), tokens.into_iter().map(|(id, _)| id).collect())
+    }
+
+    /// Token ID'yi içeren toplu mint aralığını döner
+    fn batch_containing(&self, token_id: u64) -> Option<&MintBatch> {
+        self.batches
+            .range(..=token_id)
+            .next_back()
+            .map(|(_, batch)| batch)
+            .filter(|batch| token_id <= batch.end)
+    }
+
+    fn permit_hash(&self, spender: &str, token_id: u64, deadline: u64) -> Result<[u8; 32], String> {
+        let struct_hash = hash_struct(
+            "Permit(address spender,uint256 tokenId,uint256 nonce,uint256 deadline)",
+            &[
+                encode_address(spender)?,
+                encode_u64(token_id),
+                encode_u64(self.nonces(token_id)),
+                encode_u64(deadline),
+            ],
+        );
+        self.domain.digest(&struct_hash)
+    }
+
+    fn meta_tx_hash(&self, signer: &str, call: &MetaCall, nonce: u64) -> Result<[u8; 32], String> {
+        let encoded = serde_json::to_vec(call).map_err(|e| e.to_string())?;
+        let struct_hash = hash_struct(
+            "MetaTransaction(uint256 nonce,address from,bytes functionSignature)",
+            &[encode_u64(nonce), encode_address(signer)?, keccak256(&encoded)],
+        );
+        self.domain.digest(&struct_hash)
+    }
+
+    /// Sahiplik değişmeden önce eski sahibi ve etkilenen bakiyeleri snapshot'lara kopyalar
+    fn snapshot_holders(&mut self, token_id: u64, from: Option<&str>, to: Option<&str>) {
+        self.holder_snapshots.record_owner(token_id, from);
+        for account in from.into_iter().chain(to) {
+            self.snapshot_balance(account);
+        }
+    }
+
+    fn snapshot_balance(&mut self, account: &str) {
+        if self.holder_snapshots.needs_balance(account) {
+            let balance = self.votes.units(account);
+            self.holder_snapshots.record_balance(account, balance);
+        }
+    }
+
+    fn ensure_snapshot_exists(&self, snapshot_id: SnapshotId) -> Result<(), String> {
+        if !self.holder_snapshots.exists(snapshot_id) {
+            return Err("Snapshot does not exist".to_string());
+        }
+        Ok(())
+    }
+
+    /// Token sahibinden token'ı kaldırır (Transfer sırasında kullanılır)
+    fn remove_token_from_owner(&mut self, owner: String, token_id: u64) {
+        if let Some(owner_id) = self.addresses.get(&owner) {
+            self.owned_tokens.remove(owner_id, token_id);
+        }
+    }
+}
+
+impl ERC721 {
+    /// `perf_report` verisini Rust tarafında döner
+    pub fn perf_timings(&self) -> BTreeMap<&'static str, OpTiming> {
+        self.perf.report()
+    }
+
+    /// `metrics` verisini Rust tarafında döner
+    pub fn metrics_snapshot(&self) -> Metrics {
+        let map_sizes = BTreeMap::from([
+            ("token_owner", self.token_owner.len() as u64),
+            ("owned_tokens", self.owned_tokens.owner_ids().count() as u64),
+            ("batches", self.batches.len() as u64),
+            ("approvals", self.approvals.len() as u64),
+            ("operator_approvals", self.operator_approvals.len() as u64),
+            ("token_uris", self.token_uris.len() as u64),
+            ("addresses", self.addresses.len() as u64),
+            ("audit_log", self.audit_log.len()),
+        ]);
+        Metrics::new(&self.perf.outermost_report(), map_sizes)
+    }
+
+    /// Bu durumdan `other` durumuna token farklarını döner (`diff`'in Rust sürümü)
+    pub fn state_diff(&self, other: &ERC721) -> StateDiff {
+        StateDiff::new(&self.state_view(), &other.state_view())
+    }
+
+    /// Farkı alınan alanları Token ID sırasıyla toplar
+    fn state_view(&self) -> StateView<'_> {
+        let owners: BTreeMap<u64, &str> = self.token_owner_entries().collect();
+        StateView {
+            uris: owners.keys().filter_map(|&id| Some((id, self.token_uri(id)?))).collect(),
+            owners,
+            approvals: self.approvals.iter().map(|(&id, approved)| (id, approved.as_str())).collect(),
+            operators: self.operator_approvals.iter().map(|(owner, op)| (owner.as_str(), op.as_str())).collect(),
+        }
+    }
+
+    /// Rust kancası ekler (JS tarafı için `on_before_transfer` vb.); `Err` dönen "before" kancası işlemi iptal eder
+    pub fn add_hook<F>(&mut self, kind: HookKind, hook: F)
+    where
+        F: Fn(&HookContext) -> Result<(), String> + 'static,
+    {
+        let hook: RustHook = std::rc::Rc::new(hook);
+        self.hooks.add_rust(kind, hook);
+    }
+
+    /// Rol olayları için Rust dinleyicisi ekler (JS tarafı için `on_role_event`)
+    pub fn add_role_listener<F>(&mut self, listener: F)
+    where
+        F: Fn(&LogEntry) + 'static,
+    {
+        self.roles.add_role_listener(listener);
+    }
+
+    /// Özellik kalıtım kuralını Rust fonksiyonu olarak ayarlar (Rust tarafı için `set_merge_rule`)
+    pub fn set_merge_fn<F>(&mut self, rule: F)
+    where
+        F: Fn(&[Attribute], &[Attribute]) -> Result<Vec<Attribute>, String> + 'static,
+    {
+        self.merge_rule.set_rust(std::rc::Rc::new(rule));
+    }
+
+    /// Token'a tipli özellik atar (Rust tarafı için `set_attribute`)
+    pub fn set_attribute_value(
+        &mut self,
+        caller: String,
+        token_id: u64,
+        trait_type: String,
+        value: AttributeValue,
+    ) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, ADMIN_ROLE, "Attribute Update Failed")?;
+        self.owner_lookup(token_id).ok_or("Token does not exist")?;
+        self.ensure_metadata_mutable(Some(token_id), "Attribute Update Failed")?;
+
+        let details = format!("{}: {:?}", trait_type, value);
+        self.attributes.set(token_id, trait_type, value);
+        self.rarity = None;
+        log_event("Attribute Set", &format!("Token ID: {}, {}", token_id, details));
+        self.after_mutation(LogEntry::new("AttributeSet").token(token_id).from(&caller).details(details));
+        self.emit_metadata_update(token_id);
+        Ok(())
+    }
+
+    /// Token'ın özelliklerini eklenme sırasıyla döner (Rust tarafı için `get_attributes`)
+    pub fn attributes(&self, token_id: u64) -> Vec<Attribute> {
+        self.attributes.get(token_id)
+    }
+
+    /// Transfer listesini atomik olarak uygular (Rust tarafı için `transfer_batch`)
+    pub fn apply_transfer_batch(&mut self, caller: String, transfers: Vec<BatchTransfer>) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        let snapshot = self.state_snapshot();
+
+        for (index, item) in transfers.into_iter().enumerate() {
+            let result = if self.owner_lookup(item.token_id) == Some(item.from.as_str()) {
+                self.transfer(caller.clone(), item.to, item.token_id)
+            } else {
+                Err("Sender does not own the token".to_string())
+            };
+
+            if let Err(err) = result {
+                self.restore_state(snapshot);
+                self.persist(true);
+                log_event("Batch Transfer Reverted", &format!("Transfer #{}: {}", index, err));
+                return Err(format!("Transfer #{} failed: {}", index, err));
+            }
+        }
+
+        log_event("Batch Transfer Completed", &format!("Caller: {}", caller));
+        Ok(())
+    }
+
+    /// Filtreyle eşleşen olayları sırayla döner (Rust tarafı için `get_events`)
+    pub fn events(&self, filter: &EventFilter) -> Vec<LogEntry> {
+        self.audit_log.query(filter, self.clock.now_ms())
+    }
+
+    /// İmzalı meta-işlemi doğrulayıp çalıştırır (Rust tarafı için `execute_meta_tx`)
+    ///
+    /// Nonce yalnızca çağrı başarılı olursa artar; başarısız çağrı tekrar denenebilir.
+    pub fn apply_meta_tx(&mut self, signer: String, call: MetaCall, nonce: u64, signature: String) -> Result<(), String> {
+        let expected = self.meta_nonce(signer.clone());
+        if nonce != expected {
+            log_event("Meta Transaction Failed", &format!("Signer: {}, Nonce: {}", signer, nonce));
+            return Err(format!("Invalid nonce: expected {}", expected));
+        }
+
+        let digest = self.meta_tx_hash(&signer, &call, nonce)?;
+        let recovered = recover_address(&digest, &signature)?;
+        if !recovered.eq_ignore_ascii_case(&signer) {
+            log_event("Meta Transaction Failed", &format!("Unauthorized signer: {}", recovered));
+            return Err("Signature does not match signer".to_string());
+        }
+
+        // İmzacı imzayla doğrulandığı için çağrı, işlemi ileten aktarıcının oturumundan bağımsız çalışır
+        let sender = signer.clone();
+        self.as_contract(|erc721| match call {
+            MetaCall::Transfer { to, token_id } => erc721.transfer(sender, to, token_id),
+            MetaCall::Approve { approved, token_id } => erc721.approve(sender, approved, token_id),
+            MetaCall::Burn { token_id } => erc721.burn(sender, token_id),
+        })?;
+
+        self.meta_nonces.insert(signer.to_lowercase(), nonce + 1);
+        log_event("Meta Transaction Executed", &format!("Signer: {}, Nonce: {}", signer, nonce));
+        self.persist(false);
+        Ok(())
+    }
+
+    /// Yetkili işlemi zaman kilidi sırasına alır (Rust tarafı için `queue_admin_action`)
+    pub fn queue_action(&mut self, caller: String, action: AdminAction) -> Result<u64, String> {
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, action.required_role(), "Timelock Queue Failed")?;
+        self.ensure_multisig("Timelock Queue Failed")?;
+
+        let action_id = self.timelock.queue(action.clone(), &caller, self.clock.now());
+        log_event("Timelock Action Queued", &format!("Action ID: {}, Action: {:?}", action_id, action));
+        self.after_mutation(
+            LogEntry::new("TimelockActionQueued")
+                .from(&caller)
+                .details(format!("Action ID: {}, Action: {:?}", action_id, action)),
+        );
+        Ok(action_id)
+    }
+
+    /// Yetkili işlemi çoklu imza onayına sunar (Rust tarafı için `propose_multisig_action`)
+    ///
+    /// Öneren bir imzacı olmalı ve işlemin gerektirdiği role sahip olmalıdır; onayı otomatik sayılır.
+    pub fn propose_multisig(&mut self, caller: String, action: AdminAction) -> Result<u64, String> {
+        self.ensure_sender(&caller)?;
+        self.ensure_role(&caller, action.required_role(), "Multisig Proposal Failed")?;
+
+        let action_id = self
+            .multisig
+            .propose(action.clone(), &caller, self.clock.now())
+            .inspect_err(|err| log_event("Multisig Proposal Failed", err))?;
+        let details = format!("Action ID: {}, Action: {:?}", action_id, action);
+        log_event("Multisig Action Proposed", &details);
+        self.after_mutation(LogEntry::new("MultisigActionProposed").from(&caller).details(details));
+        Ok(action_id)
+    }
+
+    /// İşlem listesini yönetişim teklifi olarak sunar (Rust tarafı için `propose`)
+    ///
+    /// Yönetişim kontrat sahibi adına hareket eder; işlemler yalnızca sahip tarafından yapılabilecek türdendir.
+    pub fn propose_actions(
+        &mut self,
+        proposer: String,
+        description: String,
+        actions: Vec<Operation>,
+    ) -> Result<u64, String> {
+        self.ensure_sender(&proposer)?;
+        let owner = self.roles.owner();
+        if let Some(action) = actions.iter().find(|action| action.actor() != owner) {
+            log_event("Proposal Failed", &format!("Action is not performed by the owner: {:?}", action));
+            return Err("Proposal actions must be performed by the contract owner".to_string());
+        }
+        let proposal_id = self
+            .governor
+            .propose(&proposer, description.clone(), actions, &self.votes, self.clock.now())
+            .inspect_err(|err| log_event("Proposal Failed", err))?;
+        log_event("Proposal Created", &format!("Proposal: {}, Proposer: {}", proposal_id, proposer));
+        self.after_mutation(
+            LogEntry::new("ProposalCreated")
+                .from(&proposer)
+                .details(format!("Proposal: {}, Description: {}", proposal_id, description)),
+        );
+        Ok(proposal_id)
+    }
+
+    /// İşlem listesini atomik olarak uygular (Rust tarafı için `execute_batch`)
+    pub fn apply_operations(&mut self, ops: Vec<Operation>) -> Result<(), String> {
+        let snapshot = self.state_snapshot();
+
+        for (index, op) in ops.into_iter().enumerate() {
+            let result = match op {
+                Operation::Mint { owner, token_id } => self.mint(owner, token_id),
+                Operation::Transfer { from, to, token_id } => self.transfer(from, to, token_id),
+                Operation::Burn { caller, token_id } => self.burn(caller, token_id),
+                Operation::Approve { owner, approved, token_id } => self.approve(owner, approved, token_id),
+            };
+
+            if let Err(err) = result {
+                self.restore_state(snapshot);
+                self.persist(true);
+                log_event("Batch Execution Reverted", &format!("Operation #{}: {}", index, err));
+                return Err(format!("Operation #{} failed: {}", index, err));
+            }
+        }
+
+        log_event("Batch Execution Completed", "");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::tests::{arweave_id, cid};
+
+    /// Like `ERC721::new`, but the owner's role grants are stamped with the simulated clock too,
+    /// so separately built contracts export identical state
+    fn contract_at(timestamp: u64) -> ERC721 {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.set_block_timestamp(timestamp);
+        for role in [PAUSER_ROLE, ADMIN_ROLE, MINTER_ROLE, BURNER_ROLE] {
+            roles.assign_role("owner".to_string(), role.to_string(), "owner".to_string()).unwrap();
+        }
+        let mut erc721 = ERC721::with_access_control(roles);
+        erc721.set_block_timestamp(timestamp);
+        erc721
+    }
+
+    #[test]
+    fn test_mint_and_transfer() {
+        let mut erc721 = ERC721::new("owner".to_string());
+
+        // Mint token
+        assert!(erc721.mint("owner".to_string(), 1).is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), "owner".to_string());
+
+        // Transfer token
+        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 1).is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), "user1".to_string());
+
+        // Unauthorized transfer should fail
+        assert!(erc721.transfer("owner".to_string(), "user2".to_string(), 1).is_err());
+    }
+
+    #[test]
+    fn test_approval_and_transfer() {
+        let mut erc721 = ERC721::new("owner".to_string());
+
+        // Mint token
+        erc721.mint("owner".to_string(), 1).unwrap();
+
+        // Approve transfer
+        assert!(erc721.approve("owner".to_string(), "user1".to_string(), 1).is_ok());
+        assert_eq!(erc721.get_approved(1).unwrap(), "user1".to_string());
+
+        // Approved user can transfer
+        assert!(erc721.transfer("user1".to_string(), "user2".to_string(), 1).is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), "user2".to_string());
+    }
+
+    #[test]
+    fn test_pause_blocks_mutations() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+
+        // Only a pauser can pause
+        assert!(erc721.pause("user1".to_string()).is_err());
+        assert!(erc721.pause("owner".to_string()).is_ok());
+        assert!(erc721.paused());
+
+        // Mutations fail, views keep working
+        assert!(erc721.mint("owner".to_string(), 2).is_err());
+        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 1).is_err());
+        assert!(erc721.burn("owner".to_string(), 1).is_err());
+        assert_eq!(erc721.owner_of(1).unwrap(), "owner".to_string());
+        assert_eq!(erc721.tokens_of_owner("owner".to_string()), vec![1]);
+
+        // Granted pauser can unpause
+        erc721.assign_role("owner".to_string(), PAUSER_ROLE.to_string(), "user1".to_string()).unwrap();
+        assert!(erc721.unpause("user1".to_string()).is_ok());
+        assert!(erc721.burn("owner".to_string(), 1).is_ok());
+        assert!(erc721.owner_of(1).is_none());
+    }
+
+    #[test]
+    fn test_mint_batch_and_transfer_out() {
+        let mut erc721 = ERC721::new("owner".to_string());
+
+        // Only the contract owner can batch mint
+        assert!(erc721.mint_batch("user1".to_string(), 100, 10, format!("ipfs://{}/", cid("base"))).is_err());
+        assert!(erc721.mint_batch("owner".to_string(), 100, 10, format!("ipfs://{}/", cid("base"))).is_ok());
+        assert_eq!(erc721.owner_of(105).unwrap(), "owner".to_string());
+        assert_eq!(erc721.token_uri(109).unwrap(), format!("ipfs://{}/109", cid("base")));
+        assert!(erc721.owner_of(110).is_none());
+
+        // Overlapping ranges and single mints are rejected
+        assert!(erc721.mint_batch("owner".to_string(), 109, 5, format!("ipfs://{}/", cid("other"))).is_err());
+        assert!(erc721.mint("owner".to_string(), 100).is_err());
+
+        // Transfer a token out of the batch
+        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 103).is_ok());
+        assert_eq!(erc721.owner_of(103).unwrap(), "user1".to_string());
+        assert_eq!(erc721.owner_of(104).unwrap(), "owner".to_string());
+        assert_eq!(erc721.tokens_of_owner("user1".to_string()), vec![103]);
+        assert_eq!(erc721.tokens_of_owner("owner".to_string()).len(), 9);
+
+        // Transferred tokens keep their batch URI, burned ones disappear
+        assert_eq!(erc721.token_uri(103).unwrap(), format!("ipfs://{}/103", cid("base")));
+        assert!(erc721.burn("owner".to_string(), 104).is_ok());
+        assert!(erc721.owner_of(104).is_none());
+        assert!(erc721.token_uri(104).is_none());
+        assert_eq!(erc721.tokens_of_owner("owner".to_string()).len(), 8);
+
+        // A batch may end right before an individually minted token but not cover it
+        erc721.mint("owner".to_string(), 200).unwrap();
+        assert!(erc721.mint_batch("owner".to_string(), 196, 5, String::new()).is_err());
+        assert!(erc721.mint_batch("owner".to_string(), 195, 5, String::new()).is_ok());
+    }
+
+    #[test]
+    fn test_approved_transfer_out_of_batch() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 5, format!("ipfs://{}/", cid("base"))).unwrap();
+
+        erc721.approve("owner".to_string(), "user1".to_string(), 2).unwrap();
+        assert!(erc721.transfer("user1".to_string(), "user2".to_string(), 2).is_ok());
+        assert_eq!(erc721.owner_of(2).unwrap(), "user2".to_string());
+        assert_eq!(erc721.tokens_of_owner("owner".to_string()), vec![1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_transfer_batch_is_atomic() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 3, format!("ipfs://{}/", cid("base"))).unwrap();
+
+        let item = |from: &str, to: &str, token_id| BatchTransfer {
+            from: from.to_string(),
+            to: to.to_string(),
+            token_id,
+        };
+
+        // Second transfer fails, so the first one must be rolled back
+        let result = erc721.apply_transfer_batch(
+            "owner".to_string(),
+            vec![item("owner", "user1", 1), item("user2", "user1", 2)],
+        );
+        assert!(result.is_err());
+        assert_eq!(erc721.owner_of(1).unwrap(), "owner".to_string());
+        assert!(erc721.tokens_of_owner("user1".to_string()).is_empty());
+
+        // Valid batches are applied in full
+        let result = erc721.apply_transfer_batch(
+            "owner".to_string(),
+            vec![item("owner", "user1", 1), item("owner", "user2", 2)],
+        );
+        assert!(result.is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), "user1".to_string());
+        assert_eq!(erc721.owner_of(2).unwrap(), "user2".to_string());
+    }
+
+    #[test]
+    fn test_royalties() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+
+        // No royalty configured yet
+        assert!(erc721.royalty_info(1, 1_000).is_none());
+
+        // Only an admin can configure royalties
+        assert!(erc721.set_default_royalty("user1".to_string(), "artist".to_string(), 500).is_err());
+        assert!(erc721.set_default_royalty("owner".to_string(), "artist".to_string(), 500).is_ok());
+        assert!(erc721.set_token_royalty("owner".to_string(), 2, "collab".to_string(), 1_000).is_ok());
+        assert!(erc721.set_token_royalty("owner".to_string(), 3, "collab".to_string(), 1_000).is_err());
+        assert!(erc721.set_default_royalty("owner".to_string(), "artist".to_string(), 10_001).is_err());
+
+        let royalty = erc721.royalty_info(1, 1_000).unwrap();
+        assert_eq!((royalty.receiver.as_str(), royalty.amount), ("artist", 50));
+        let royalty = erc721.royalty_info(2, 1_000).unwrap();
+        assert_eq!((royalty.receiver.as_str(), royalty.amount), ("collab", 100));
+
+        // Burning resets the token royalty
+        erc721.burn("owner".to_string(), 2).unwrap();
+        assert_eq!(erc721.royalty_info(2, 1_000).unwrap().receiver, "artist".to_string());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        for token_id in [5, 3, 9, 1] {
+            erc721.mint("owner".to_string(), token_id).unwrap();
+        }
+        erc721.mint_batch("owner".to_string(), 100, 5, format!("ipfs://{}/", cid("base"))).unwrap();
+        erc721.transfer("owner".to_string(), "user1".to_string(), 102).unwrap();
+        erc721.burn("owner".to_string(), 103).unwrap();
+        erc721.approve("owner".to_string(), "user2".to_string(), 3).unwrap();
+        erc721.set_default_royalty("owner".to_string(), "artist".to_string(), 500).unwrap();
+
+        let json = erc721.to_json();
+        let restored = ERC721::from_json(json.clone()).unwrap();
+
+        // Export is deterministic and the restored contract behaves identically
+        assert_eq!(restored.to_json(), json);
+        assert_eq!(restored.owner_of(102).unwrap(), "user1".to_string());
+        assert!(restored.owner_of(103).is_none());
+        assert_eq!(restored.get_approved(3).unwrap(), "user2".to_string());
+        assert_eq!(restored.tokens_of_owner("owner".to_string()), erc721.tokens_of_owner("owner".to_string()));
+        assert_eq!(restored.royalty_info(1, 100).unwrap().amount, 5);
+
+        assert!(ERC721::from_json("not json".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_binary_snapshot_roundtrip() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 0, 100_000, format!("ipfs://{}/", cid("base"))).unwrap();
+        for token_id in 0..1_000 {
+            erc721.mint("owner".to_string(), 200_000 + token_id).unwrap();
+        }
+        erc721.transfer("owner".to_string(), "user1".to_string(), 42).unwrap();
+
+        let bytes = erc721.snapshot();
+        let restored = ERC721::restore(&bytes).unwrap();
+
+        assert_eq!(restored.snapshot(), bytes);
+        assert_eq!(restored.owner_of(42).unwrap(), "user1".to_string());
+        assert_eq!(restored.owner_of(99_999).unwrap(), "owner".to_string());
+        assert_eq!(restored.to_json(), erc721.to_json());
+
+        assert!(ERC721::restore(&bytes[..bytes.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_exports_are_reproducible() {
+        let build = || {
+            let mut erc721 = contract_at(1_700_000_000);
+            erc721.configure_phase("owner".to_string(), "public".to_string(), 0, u64::MAX, 0, Some(5)).unwrap();
+            for (token_id, wallet) in ["carol", "alice", "dave", "bob", "erin"].iter().enumerate() {
+                erc721.mint_to("owner", wallet.to_string(), token_id as u64).unwrap();
+                erc721.approve(wallet.to_string(), "market".to_string(), token_id as u64).unwrap();
+                erc721.set_approval_for_all(wallet.to_string(), "market".to_string(), true).unwrap();
+            }
+            erc721
+        };
+
+        // Separately built contracts must export identical bytes, including nested per-phase maps
+        let (a, b) = (build(), build());
+        assert_eq!(a.to_json(), b.to_json());
+        assert_eq!(a.snapshot(), b.snapshot());
+        let wallets: Vec<&str> = a.token_owner_entries().map(|(_, owner)| owner).collect();
+        assert_eq!(wallets, ["carol", "alice", "dave", "bob", "erin"]);
+    }
+
+    #[test]
+    fn test_state_hash() {
+        let build = || {
+            let mut erc721 = contract_at(1_700_000_000);
+            erc721.mint_batch("owner".to_string(), 1, 100, String::new()).unwrap();
+            erc721.transfer("owner".to_string(), "alice".to_string(), 7).unwrap();
+            erc721
+        };
+
+        let (mut a, b) = (build(), build());
+        let hash = a.state_hash();
+        assert_eq!(hash, b.state_hash());
+        assert_eq!(ERC721::restore(&a.snapshot()).unwrap().state_hash(), hash);
+
+        a.transfer("alice".to_string(), "bob".to_string(), 7).unwrap();
+        assert_ne!(a.state_hash(), hash);
+    }
+
+    #[test]
+    fn test_state_hash_ignores_clock() {
+        // Both replicas run on the system clock, so their audit entries and role grants differ in time
+        let replay = || {
+            let mut erc721 = ERC721::new("owner".to_string());
+            erc721.mint_batch("owner".to_string(), 1, 10, String::new()).unwrap();
+            erc721.transfer("owner".to_string(), "alice".to_string(), 3).unwrap();
+            erc721.assign_role("owner".to_string(), GAME_ROLE.to_string(), "game".to_string()).unwrap();
+            erc721.burn("owner".to_string(), 4).unwrap();
+            erc721
+        };
+
+        let a = replay();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let b = replay();
+        assert_ne!(a.snapshot(), b.snapshot());
+        assert_eq!(a.state_hash(), b.state_hash());
+    }
+
+    #[test]
+    fn test_storage_persistence() {
+        let mut erc721 = ERC721::with_storage("owner".to_string(), "test-collection".to_string()).unwrap();
+        erc721.mint("owner".to_string(), 1).unwrap();
+
+        // Changes within the debounce window stay pending until flushed
+        erc721.set_storage_debounce(60_000.0).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        assert!(erc721.has_unsaved_changes());
+        let restored = ERC721::with_storage("other".to_string(), "test-collection".to_string()).unwrap();
+        assert!(restored.owner_of(2).is_none());
+
+        erc721.flush_storage().unwrap();
+        assert!(!erc721.has_unsaved_changes());
+        let restored = ERC721::with_storage("other".to_string(), "test-collection".to_string()).unwrap();
+        assert_eq!(restored.tokens_of_owner("owner".to_string()), vec![1, 2]);
+
+        // Contracts without storage don't persist
+        assert!(ERC721::new("owner".to_string()).flush_storage().is_err());
+    }
+
+    #[test]
+    fn test_state_root_and_ownership_proofs() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 4, format!("ipfs://{}/", cid("base"))).unwrap();
+        erc721.mint("owner".to_string(), 10).unwrap();
+        erc721.transfer("owner".to_string(), "user1".to_string(), 2).unwrap();
+
+        let root = erc721.state_root();
+        let proof = erc721.prove_ownership(2).unwrap();
+        assert!(crate::verify_proof(root.clone(), 2, "user1".to_string(), proof.clone()));
+        assert!(!crate::verify_proof(root.clone(), 2, "owner".to_string(), proof));
+        assert!(erc721.prove_ownership(99).is_err());
+
+        // Any ownership change moves the root
+        erc721.transfer("user1".to_string(), "user2".to_string(), 2).unwrap();
+        assert_ne!(erc721.state_root(), root);
+    }
+
+    #[test]
+    fn test_audit_log() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 5, format!("ipfs://{}/", cid("base"))).unwrap();
+        erc721.approve("owner".to_string(), "user1".to_string(), 2).unwrap();
+        erc721.transfer("user1".to_string(), "user2".to_string(), 2).unwrap();
+        erc721.burn("user2".to_string(), 2).unwrap();
+        erc721.assign_role("owner".to_string(), "MINTER".to_string(), "user1".to_string()).unwrap();
+
+        // Failed operations are not recorded
+        assert!(erc721.transfer("user1".to_string(), "user3".to_string(), 3).is_err());
+
+        let kinds: Vec<String> = erc721.history(2).into_iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, vec!["BatchMint", "Approval", "Transfer", "Burn"]);
+
+        let transfer = &erc721.history_by_address("user2".to_string())[0];
+        assert_eq!(transfer.from.as_deref(), Some("owner"));
+        assert_eq!(transfer.details, "Operator: user1");
+
+        assert_eq!(erc721.log_len(), 4);
+        let range = erc721.log_range(2, 5);
+        assert_eq!((range[0].seq, range[1].kind.as_str()), (2, "Burn"));
+
+        // Role changes are recorded once, in the role manager's log
+        let granted = erc721.role_audit_log().pop().unwrap();
+        assert_eq!((granted.kind.as_str(), granted.to.as_deref()), ("RoleGranted", Some("user1")));
+        assert!(erc721.events(&EventFilter::default()).iter().all(|e| !e.kind.starts_with("Role")));
+    }
+
+    #[test]
+    fn test_checkpoint_and_rollback() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+
+        let first = erc721.checkpoint();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+
+        let second = erc721.checkpoint();
+        erc721.burn("user1".to_string(), 1).unwrap();
+
+        // Undo the burn
+        assert!(erc721.rollback_to(second).is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), "user1".to_string());
+
+        // Undo everything after the first checkpoint; the later one is discarded
+        assert!(erc721.rollback_to(first).is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), "owner".to_string());
+        assert!(erc721.owner_of(2).is_none());
+        assert!(erc721.rollback_to(second).is_err());
+        assert_eq!(erc721.checkpoint_count(), 1);
+
+        // Checkpoint IDs are never reused
+        assert!(erc721.checkpoint() > second);
+    }
+
+    #[test]
+    fn test_execute_batch_is_atomic() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 3).unwrap();
+
+        // Burn A, mint B, transfer C; the transfer is unauthorized so everything is undone
+        let result = erc721.apply_operations(vec![
+            Operation::Burn { caller: "owner".to_string(), token_id: 1 },
+            Operation::Mint { owner: "owner".to_string(), token_id: 2 },
+            Operation::Transfer { from: "user1".to_string(), to: "user2".to_string(), token_id: 3 },
+        ]);
+        assert!(result.is_err());
+        assert_eq!(erc721.owner_of(1).unwrap(), "owner".to_string());
+        assert!(erc721.owner_of(2).is_none());
+
+        let result = erc721.apply_operations(vec![
+            Operation::Burn { caller: "owner".to_string(), token_id: 1 },
+            Operation::Mint { owner: "owner".to_string(), token_id: 2 },
+            Operation::Transfer { from: "owner".to_string(), to: "user2".to_string(), token_id: 3 },
+        ]);
+        assert!(result.is_ok());
+        assert!(erc721.owner_of(1).is_none());
+        assert_eq!(erc721.owner_of(3).unwrap(), "user2".to_string());
+    }
+
+    #[test]
+    fn test_manual_transaction() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        assert!(erc721.commit().is_err());
+
+        erc721.begin_tx().unwrap();
+        assert!(erc721.begin_tx().is_err());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.revert().unwrap();
+        assert!(erc721.owner_of(1).is_none());
+        assert!(!erc721.in_transaction());
+
+        erc721.begin_tx().unwrap();
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.commit().unwrap();
+        assert_eq!(erc721.owner_of(1).unwrap(), "owner".to_string());
+        assert_eq!(erc721.checkpoint_count(), 0);
+    }
+
+    #[test]
+    fn test_max_supply() {
+        let mut erc721 = ERC721::with_max_supply("owner".to_string(), 5);
+        assert_eq!(erc721.remaining_supply(), Some(5));
+
+        erc721.mint_batch("owner".to_string(), 1, 3, format!("ipfs://{}/", cid("base"))).unwrap();
+        assert!(erc721.mint_batch("owner".to_string(), 10, 3, format!("ipfs://{}/", cid("base"))).is_err());
+        erc721.mint("owner".to_string(), 10).unwrap();
+        erc721.mint("owner".to_string(), 11).unwrap();
+        assert_eq!(erc721.remaining_supply(), Some(0));
+        assert!(erc721.mint("owner".to_string(), 12).is_err());
+
+        // Burning does not free up supply
+        erc721.burn("owner".to_string(), 10).unwrap();
+        assert_eq!(erc721.total_supply(), 4);
+        assert!(erc721.mint("owner".to_string(), 12).is_err());
+
+        // The cap can't be changed after minting started
+        assert!(erc721.set_max_supply("owner".to_string(), 10).is_err());
+        let mut fresh = ERC721::new("owner".to_string());
+        assert_eq!(fresh.remaining_supply(), None);
+        assert!(fresh.set_max_supply("user1".to_string(), 10).is_err());
+        assert!(fresh.set_max_supply("owner".to_string(), 10).is_ok());
+        assert_eq!(fresh.max_supply(), Some(10));
+    }
+
+    #[test]
+    fn test_mint_limit_per_wallet() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        assert!(erc721.set_mint_limit_per_wallet("user1".to_string(), Some(3)).is_err());
+        erc721.set_mint_limit_per_wallet("owner".to_string(), Some(3)).unwrap();
+
+        // Batch mints count towards the limit
+        erc721.mint_batch("owner".to_string(), 1, 2, format!("ipfs://{}/", cid("base"))).unwrap();
+        assert!(erc721.mint_batch("owner".to_string(), 10, 2, format!("ipfs://{}/", cid("base"))).is_err());
+        erc721.mint("owner".to_string(), 3).unwrap();
+        assert!(erc721.mint("owner".to_string(), 4).is_err());
+        assert_eq!(erc721.minted_by("owner".to_string()), 3);
+
+        // Transfers don't reset the counter, removing the limit does
+        erc721.transfer("owner".to_string(), "user1".to_string(), 3).unwrap();
+        assert!(erc721.mint("owner".to_string(), 4).is_err());
+        erc721.set_mint_limit_per_wallet("owner".to_string(), None).unwrap();
+        assert!(erc721.mint("owner".to_string(), 4).is_ok());
+    }
+
+    #[test]
+    fn test_mint_phases() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(50);
+
+        assert!(erc721.configure_phase("user1".to_string(), "presale".to_string(), 100, 200, 5, Some(2)).is_err());
+        erc721.configure_phase("owner".to_string(), "presale".to_string(), 100, 200, 5, Some(2)).unwrap();
+        erc721.configure_phase("owner".to_string(), "public".to_string(), 200, 300, 10, None).unwrap();
+
+        // Closed before the first phase
+        assert!(erc721.active_phase().is_none());
+        assert!(erc721.mint("owner".to_string(), 1).is_err());
+
+        // Presale enforces its own wallet limit
+        erc721.advance_time(50);
+        assert_eq!(erc721.current_phase(erc721.block_timestamp()).unwrap().name, "presale");
+        erc721.mint_batch("owner".to_string(), 1, 2, format!("ipfs://{}/", cid("base"))).unwrap();
+        assert!(erc721.mint("owner".to_string(), 3).is_err());
+        assert_eq!(erc721.minted_in_phase("presale".to_string(), "owner".to_string()), 2);
+
+        // Public sale is unlimited until it closes
+        erc721.set_block_timestamp(250);
+        assert_eq!(erc721.active_phase().unwrap().price, 10);
+        erc721.mint("owner".to_string(), 3).unwrap();
+        erc721.mint("owner".to_string(), 4).unwrap();
+
+        erc721.set_block_timestamp(300);
+        assert!(erc721.mint("owner".to_string(), 5).is_err());
+    }
+
+    #[test]
+    fn test_allowlist_mint() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        let allowlist = crate::Allowlist::new(vec!["user1".to_string(), "user2".to_string(), "user3".to_string()]);
+        let proof = allowlist.proof("user2".to_string()).unwrap();
+
+        assert!(erc721.mint_allowlisted("user2".to_string(), 1, proof.clone()).is_err());
+        assert!(erc721.set_allowlist_root("user1".to_string(), Some(allowlist.root())).is_err());
+        erc721.set_allowlist_root("owner".to_string(), Some(allowlist.root())).unwrap();
+        assert_eq!(erc721.allowlist_root(), Some(allowlist.root()));
+
+        // Valid proof mints to the allowlisted address, reused proofs don't work for others
+        assert!(erc721.mint_allowlisted("user2".to_string(), 1, proof.clone()).is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), "user2".to_string());
+        assert!(erc721.mint_allowlisted("user4".to_string(), 2, proof).is_err());
+
+        // Wallet limits still apply
+        erc721.set_mint_limit_per_wallet("owner".to_string(), Some(1)).unwrap();
+        let proof = allowlist.proof("user2".to_string()).unwrap();
+        assert!(erc721.mint_allowlisted("user2".to_string(), 2, proof).is_err());
+    }
+
+    #[test]
+    fn test_redeem_voucher() {
+        use k256::ecdsa::SigningKey;
+
+        let mut erc721 = ERC721::new("owner".to_string());
+        let key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let recipient = format!("0x{}", "22".repeat(20));
+        let voucher = NFTVoucher::new(1, format!("ipfs://{}", cid("token-1")), 100, recipient.clone());
+
+        let digest = parse_hash(&erc721.voucher_digest(&voucher).unwrap()).unwrap();
+        let (signature, signer) = crate::eip712::tests::sign(&key, &digest);
+
+        // Signer must hold the SIGNER role
+        assert!(erc721.redeem_voucher(&voucher, signature.clone()).is_err());
+        erc721.assign_role("owner".to_string(), SIGNER_ROLE.to_string(), signer.to_uppercase().replace("0X", "0x")).unwrap();
+
+        // The recipient pays the voucher price; a failed redemption leaves no trace
+        assert!(erc721.redeem_voucher(&voucher, signature.clone()).is_err());
+        erc721.deposit(recipient.clone(), 150).unwrap();
+        erc721.add_hook(HookKind::BeforeMint, |_| Err("Frozen".to_string()));
+        assert!(erc721.redeem_voucher(&voucher, signature.clone()).is_err());
+        assert!(erc721.token_uris.is_empty() && erc721.redeemed_vouchers.is_empty());
+        assert_eq!(erc721.eth_balance(recipient.clone()), 150);
+
+        erc721.clear_hooks();
+        assert!(erc721.redeem_voucher(&voucher, signature.clone()).is_ok());
+        assert_eq!(erc721.owner_of(1).unwrap(), recipient);
+        assert_eq!(erc721.token_uri(1).unwrap(), format!("ipfs://{}", cid("token-1")));
+        assert_eq!(erc721.eth_balance(recipient.clone()), 50);
+        assert_eq!(erc721.contract_balance(), 100);
+
+        // Vouchers can't be replayed, even after a burn
+        erc721.burn(recipient.clone(), 1).unwrap();
+        assert!(erc721.redeem_voucher(&voucher, signature.clone()).is_err());
+
+        // A modified voucher no longer matches the signature
+        let forged = NFTVoucher::new(2, format!("ipfs://{}", cid("token-2")), 0, recipient);
+        assert!(erc721.redeem_voucher(&forged, signature).is_err());
+    }
+
+    #[test]
+    fn test_permit() {
+        use k256::ecdsa::SigningKey;
+
+        let mut erc721 = ERC721::new("owner".to_string());
+        let key = SigningKey::from_slice(&[9u8; 32]).unwrap();
+        let (_, holder) = crate::eip712::tests::sign(&key, &[0u8; 32]);
+        let spender = format!("0x{}", "33".repeat(20));
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.transfer("owner".to_string(), holder.clone(), 1).unwrap();
+        erc721.set_block_timestamp(1_000);
+
+        let digest = parse_hash(&erc721.permit_digest(spender.clone(), 1, 2_000).unwrap()).unwrap();
+        let (signature, _) = crate::eip712::tests::sign(&key, &digest);
+        assert!(erc721.permit(spender.clone(), 1, 2_000, signature.clone()).is_ok());
+        assert_eq!(erc721.get_approved(1), Some(spender.clone()));
+        assert_eq!(erc721.nonces(1), 2);
+
+        // The nonce moved on, so the same signature can't be replayed
+        assert!(erc721.permit(spender.clone(), 1, 2_000, signature).is_err());
+
+        // Expired permits are rejected
+        let digest = parse_hash(&erc721.permit_digest(spender.clone(), 1, 500).unwrap()).unwrap();
+        let (signature, _) = crate::eip712::tests::sign(&key, &digest);
+        assert_eq!(erc721.permit(spender, 1, 500, signature), Err("Permit expired".to_string()));
+    }
+
+    #[test]
+    fn test_meta_transaction() {
+        use k256::ecdsa::SigningKey;
+
+        let mut erc721 = ERC721::new("owner".to_string());
+        let key = SigningKey::from_slice(&[5u8; 32]).unwrap();
+        let (_, signer) = crate::eip712::tests::sign(&key, &[0u8; 32]);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.transfer("owner".to_string(), signer.clone(), 1).unwrap();
+
+        let call = MetaCall::Transfer { to: "user2".to_string(), token_id: 1 };
+        let digest = erc721.meta_tx_hash(&signer, &call, 0).unwrap();
+        let (signature, _) = crate::eip712::tests::sign(&key, &digest);
+
+        // A relayer can't change the nonce or the call
+        assert!(erc721.apply_meta_tx(signer.clone(), call.clone(), 1, signature.clone()).is_err());
+        let other = MetaCall::Burn { token_id: 1 };
+        assert!(erc721.apply_meta_tx(signer.clone(), other, 0, signature.clone()).is_err());
+
+        erc721.apply_meta_tx(signer.clone(), call.clone(), 0, signature.clone()).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user2".to_string()));
+        assert_eq!(erc721.meta_nonce(signer.clone()), 1);
+
+        // Replays are rejected
+        assert!(erc721.apply_meta_tx(signer, call, 0, signature).is_err());
+    }
+
+    #[test]
+    fn test_soulbound_tokens() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+
+        // Only admins can lock tokens
+        assert!(erc721.lock("user1".to_string(), 1).is_err());
+        erc721.lock("owner".to_string(), 1).unwrap();
+        assert_eq!(erc721.locked(1), Ok(true));
+        assert!(erc721.locked(99).is_err());
+        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 1).is_err());
+        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 2).is_ok());
+
+        // Collection-wide mode locks every token, but minting and burning still work
+        erc721.set_soulbound("owner".to_string(), true).unwrap();
+        assert!(erc721.transfer("user1".to_string(), "owner".to_string(), 2).is_err());
+        erc721.mint("owner".to_string(), 3).unwrap();
+        assert_eq!(erc721.locked(3), Ok(true));
+        assert!(erc721.burn("owner".to_string(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_rental_user_expires() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_block_timestamp(1_000);
+
+        assert!(erc721.set_user("user1".to_string(), 1, "renter".to_string(), 2_000).is_err());
+        erc721.set_user("owner".to_string(), 1, "renter".to_string(), 2_000).unwrap();
+        assert_eq!(erc721.user_of(1), Some("renter".to_string()));
+        assert_eq!(erc721.owner_of(1), Some("owner".to_string()));
+
+        // User rights lapse once the clock passes the expiry
+        erc721.advance_time(1_001);
+        assert_eq!(erc721.user_of(1), None);
+        assert_eq!(erc721.user_expires(1), 2_000);
+
+        // Transferring the token clears the rental
+        erc721.set_user("owner".to_string(), 1, "renter".to_string(), 5_000).unwrap();
+        erc721.transfer("owner".to_string(), "user2".to_string(), 1).unwrap();
+        assert_eq!(erc721.user_of(1), None);
+        assert_eq!(erc721.user_expires(1), 0);
+    }
+
+    #[test]
+    fn test_staking_blocks_transfers() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_block_timestamp(1_000);
+
+        assert!(erc721.stake("user1".to_string(), 1).is_err());
+        erc721.stake("owner".to_string(), 1).unwrap();
+        assert_eq!(erc721.staked_tokens("owner".to_string()), vec![1]);
+        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 1).is_err());
+        assert!(erc721.burn("owner".to_string(), 1).is_err());
+
+        erc721.advance_time(60);
+        assert_eq!(erc721.stake_duration(1), Some(60));
+        erc721.unstake("owner".to_string(), 1).unwrap();
+        assert_eq!(erc721.stake_duration(1), None);
+        assert!(erc721.transfer("owner".to_string(), "user1".to_string(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_staking_rewards() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_block_timestamp(1_000);
+
+        assert!(erc721.set_reward_rate("user1".to_string(), 5).is_err());
+        erc721.set_reward_rate("owner".to_string(), 5).unwrap();
+        erc721.stake("owner".to_string(), 1).unwrap();
+
+        erc721.advance_time(100);
+        assert_eq!(erc721.pending_rewards("owner".to_string()), 500);
+        assert_eq!(erc721.claim_rewards("owner".to_string()), Ok(500));
+        assert_eq!(erc721.reward_balance("owner".to_string()), 500);
+        assert!(erc721.claim_rewards("owner".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_vested_mint() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+
+        assert!(erc721.mint_vested("user1".to_string(), "team".to_string(), 1, 2_000).is_err());
+        erc721.mint_vested("owner".to_string(), "team".to_string(), 1, 2_000).unwrap();
+        assert_eq!(erc721.tokens_of_owner("team".to_string()), vec![1]);
+        assert_eq!(erc721.vesting_unlock(1), Some(2_000));
+
+        // Locked until the unlock time passes
+        assert!(erc721.transfer("team".to_string(), "user1".to_string(), 1).is_err());
+        erc721.set_block_timestamp(2_000);
+        assert!(erc721.transfer("team".to_string(), "user1".to_string(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_transfer_cooldown() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_block_timestamp(1_000);
+        assert!(erc721.set_transfer_cooldown("user1".to_string(), 60).is_err());
+        erc721.set_transfer_cooldown("owner".to_string(), 60).unwrap();
+
+        assert_eq!(erc721.next_transferable_at(1), 0);
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        assert_eq!(erc721.next_transferable_at(1), 1_060);
+        assert!(erc721.transfer("user1".to_string(), "user2".to_string(), 1).is_err());
+
+        erc721.advance_time(60);
+        assert!(erc721.transfer("user1".to_string(), "user2".to_string(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_denylist() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+
+        // Only the compliance role manages the denylist
+        assert!(erc721.add_to_denylist("owner".to_string(), "bad".to_string()).is_err());
+        erc721.assign_role("owner".to_string(), COMPLIANCE_ROLE.to_string(), "compliance".to_string()).unwrap();
+        erc721.add_to_denylist("compliance".to_string(), "bad".to_string()).unwrap();
+        assert!(erc721.is_denylisted("bad".to_string()));
+
+        assert!(erc721.transfer("owner".to_string(), "bad".to_string(), 1).is_err());
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        erc721.add_to_denylist("compliance".to_string(), "user1".to_string()).unwrap();
+        assert!(erc721.transfer("user1".to_string(), "user2".to_string(), 1).is_err());
+        assert!(erc721.mint_vested("owner".to_string(), "bad".to_string(), 3, 0).is_err());
+
+        erc721.remove_from_denylist("compliance".to_string(), "user1".to_string()).unwrap();
+        assert!(erc721.transfer("user1".to_string(), "user2".to_string(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_operator_filter() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+
+        // Approved-for-all operators can move any of the owner's tokens
+        erc721.set_approval_for_all("owner".to_string(), "market".to_string(), true).unwrap();
+        assert!(erc721.is_approved_for_all("owner".to_string(), "market".to_string()));
+        erc721.transfer("market".to_string(), "user1".to_string(), 1).unwrap();
+
+        assert!(erc721.block_operator("user1".to_string(), "market".to_string()).is_err());
+        erc721.block_operator("owner".to_string(), "market".to_string()).unwrap();
+        assert!(erc721.transfer("market".to_string(), "user1".to_string(), 2).is_err());
+        assert!(erc721.approve("owner".to_string(), "market".to_string(), 2).is_err());
+        assert!(erc721.set_approval_for_all("user1".to_string(), "market".to_string(), true).is_err());
+
+        // Owners can still transfer directly
+        assert!(erc721.transfer("owner".to_string(), "user2".to_string(), 2).is_ok());
+        erc721.unblock_operator("owner".to_string(), "market".to_string()).unwrap();
+        assert!(erc721.approve("user2".to_string(), "market".to_string(), 2).is_ok());
+    }
+
+    #[test]
+    fn test_transfer_hooks() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut erc721 = ERC721::new("owner".to_string());
+        let transfers = Rc::new(RefCell::new(Vec::new()));
+        let observed = transfers.clone();
+        erc721.add_hook(HookKind::BeforeTransfer, |ctx| match ctx.to.as_deref() {
+            Some("blocked") => Err("Recipient rejected".to_string()),
+            _ => Ok(()),
+        });
+        erc721.add_hook(HookKind::AfterTransfer, move |ctx| {
+            observed.borrow_mut().push(ctx.token_id);
+            Ok(())
+        });
+        erc721.add_hook(HookKind::BeforeBurn, |_| Err("Burning disabled".to_string()));
+        assert_eq!(erc721.hook_count(), 3);
+
+        erc721.mint("owner".to_string(), 1).unwrap();
+        assert_eq!(
+            erc721.transfer("owner".to_string(), "blocked".to_string(), 1),
+            Err("Recipient rejected".to_string())
+        );
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        assert_eq!(*transfers.borrow(), vec![1]);
+        assert!(erc721.burn("user1".to_string(), 1).is_err());
+
+        erc721.clear_hooks();
+        assert!(erc721.burn("user1".to_string(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_contract_metadata() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        assert!(erc721.set_contract_uri("user1".to_string(), format!("ipfs://{}", cid("collection"))).is_err());
+        erc721.set_contract_uri("owner".to_string(), format!("ipfs://{}", cid("collection"))).unwrap();
+        assert_eq!(erc721.contract_uri(), Some(format!("ipfs://{}", cid("collection"))));
+
+        let mut metadata = ContractMetadata::new();
+        metadata.description = Some("Spawn collection".to_string());
+        metadata.fee_recipient = Some("treasury".to_string());
+        erc721.set_contract_metadata("owner".to_string(), metadata).unwrap();
+        erc721.set_default_royalty("owner".to_string(), "artist".to_string(), 250).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&erc721.contract_metadata_json()).unwrap();
+        assert_eq!(json["description"], "Spawn collection");
+        assert_eq!(json["seller_fee_basis_points"], 250);
+        assert_eq!(json["fee_recipient"], "treasury");
+    }
+
+    #[test]
+    fn test_base_uri_composition() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 7).unwrap();
+        erc721.mint_batch("owner".to_string(), 100, 5, format!("ipfs://{}/", cid("batch"))).unwrap();
+        assert_eq!(erc721.token_uri(7), None);
+
+        assert!(erc721.set_base_uri("user1".to_string(), format!("ipfs://{}/", cid("base"))).is_err());
+        erc721.set_base_uri("owner".to_string(), format!("ipfs://{}/", cid("base"))).unwrap();
+        erc721.set_uri_suffix("owner".to_string(), ".json".to_string()).unwrap();
+        assert_eq!(erc721.token_uri(7), Some(format!("ipfs://{}/7.json", cid("base"))));
+        assert_eq!(erc721.token_uri(101), Some(format!("ipfs://{}/101.json", cid("batch"))));
+
+        // Per-token overrides win over the base URI
+        erc721.set_token_uri("owner".to_string(), 7, format!("ar://{}", arweave_id("custom"))).unwrap();
+        assert_eq!(erc721.token_uri(7), Some(format!("ar://{}", arweave_id("custom"))));
+        assert!(erc721.set_token_uri("owner".to_string(), 99, format!("ar://{}", arweave_id("missing"))).is_err());
+    }
+
+    #[test]
+    fn test_metadata_freezing() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        erc721.set_base_uri("owner".to_string(), format!("ipfs://{}/", cid("v1"))).unwrap();
+
+        erc721.freeze_token_uri("owner".to_string(), 1).unwrap();
+        assert!(erc721.uri_frozen(1));
+        assert!(erc721.set_token_uri("owner".to_string(), 1, format!("ipfs://{}", cid("other"))).is_err());
+
+        // A frozen token keeps its URI even if the base URI moves
+        erc721.set_base_uri("owner".to_string(), format!("ipfs://{}/", cid("v2"))).unwrap();
+        assert_eq!(erc721.token_uri(1), Some(format!("ipfs://{}/1", cid("v1"))));
+        assert_eq!(erc721.token_uri(2), Some(format!("ipfs://{}/2", cid("v2"))));
+
+        erc721.freeze_all_metadata("owner".to_string()).unwrap();
+        assert!(erc721.uri_frozen(2));
+        assert!(erc721.set_base_uri("owner".to_string(), format!("ipfs://{}/", cid("v3"))).is_err());
+        assert!(erc721.set_token_uri("owner".to_string(), 2, format!("ipfs://{}", cid("other"))).is_err());
+        assert!(erc721.freeze_all_metadata("owner".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_metadata_update_events() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+
+        erc721.set_token_uri("owner".to_string(), 1, format!("ipfs://{}", cid("one"))).unwrap();
+        let update = erc721.log_range(erc721.log_len() - 1, erc721.log_len()).remove(0);
+        assert_eq!(update.kind, "MetadataUpdate");
+        assert_eq!(update.token_id, Some(1));
+
+        // Base URI changes invalidate every token
+        erc721.set_base_uri("owner".to_string(), format!("ipfs://{}/", cid("base"))).unwrap();
+        let update = erc721.log_range(erc721.log_len() - 1, erc721.log_len()).remove(0);
+        assert_eq!(update.kind, "BatchMetadataUpdate");
+        assert_eq!((update.token_id, update.range_end), (Some(0), Some(u64::MAX)));
+        assert!(erc721.history(12_345).iter().any(|entry| entry.kind == "BatchMetadataUpdate"));
+    }
+
+    #[test]
+    fn test_token_attributes() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+
+        let level = AttributeValue::Number(5.0);
+        assert!(erc721.set_attribute_value("user1".to_string(), 1, "Level".to_string(), level.clone()).is_err());
+        assert!(erc721.set_attribute_value("owner".to_string(), 2, "Level".to_string(), level.clone()).is_err());
+        erc721.set_attribute_value("owner".to_string(), 1, "Level".to_string(), level).unwrap();
+        erc721
+            .set_attribute_value("owner".to_string(), 1, "Birthday".to_string(), AttributeValue::Date(1_546_360_800))
+            .unwrap();
+        assert_eq!(erc721.attributes(1).len(), 2);
+
+        let json: serde_json::Value = serde_json::from_str(&erc721.attributes_json(1)).unwrap();
+        assert_eq!(json[0]["trait_type"], "Level");
+        assert_eq!(json[0]["display_type"], "number");
+        assert_eq!(json[1]["value"], 1_546_360_800u64);
+
+        erc721.remove_attribute("owner".to_string(), 1, "Level".to_string()).unwrap();
+        assert_eq!(erc721.attributes(1).len(), 1);
+
+        // Frozen metadata covers attributes too
+        erc721.freeze_all_metadata("owner".to_string()).unwrap();
+        assert!(erc721.remove_attribute("owner".to_string(), 1, "Birthday".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_tokens_with_trait() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 10, String::new()).unwrap();
+        for token_id in 1..=10 {
+            let color = AttributeValue::String(if token_id % 2 == 0 { "Red" } else { "Blue" }.to_string());
+            erc721.set_attribute_value("owner".to_string(), token_id, "Color".to_string(), color).unwrap();
+        }
+
+        let first = erc721.tokens_with_trait("Color".to_string(), "Red".to_string(), None, 3);
+        assert_eq!(first, vec![2, 4, 6]);
+        let next = erc721.tokens_with_trait("Color".to_string(), "Red".to_string(), first.last().copied(), 3);
+        assert_eq!(next, vec![8, 10]);
+
+        // The index follows attribute changes and burns
+        erc721.burn("owner".to_string(), 2).unwrap();
+        let red = AttributeValue::String("Red".to_string());
+        erc721.set_attribute_value("owner".to_string(), 3, "Color".to_string(), red).unwrap();
+        assert_eq!(erc721.count_with_trait("Color".to_string(), "Red".to_string()), 5);
+        assert_eq!(erc721.count_with_trait("Color".to_string(), "Blue".to_string()), 4);
+    }
+
+    #[test]
+    fn test_rarity_scores() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 4, String::new()).unwrap();
+        for token_id in 1..=4 {
+            let background = AttributeValue::String(if token_id == 2 { "Gold" } else { "Grey" }.to_string());
+            erc721.set_attribute_value("owner".to_string(), token_id, "Background".to_string(), background).unwrap();
+        }
+
+        assert_eq!(erc721.rarity_rank(2), None);
+        assert_eq!(erc721.compute_rarity_scores(RarityMethod::InformationContent), 4);
+        assert_eq!(erc721.rarity_rank(2), Some(1));
+        assert_eq!(erc721.top_n_by_rarity(2), vec![2, 1]);
+
+        // Changing traits invalidates the cached ranking
+        erc721.burn("owner".to_string(), 2).unwrap();
+        assert!(erc721.top_n_by_rarity(2).is_empty());
+    }
+
+    #[test]
+    fn test_render_token_svg() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        assert!(erc721.render_token(1).is_err());
+
+        let circle = "<circle r=\"10\" fill=\"{value}\"/>".to_string();
+        let layer = |caller: &str, erc721: &mut ERC721| {
+            erc721.set_svg_layer(caller.to_string(), "Color".to_string(), "*".to_string(), circle.clone())
+        };
+        assert!(layer("user1", &mut erc721).is_err());
+        layer("owner", &mut erc721).unwrap();
+        let color = AttributeValue::String("teal".to_string());
+        erc721.set_attribute_value("owner".to_string(), 1, "Color".to_string(), color).unwrap();
+
+        let svg = erc721.render_token_svg(1).unwrap();
+        assert!(svg.contains("<circle r=\"10\" fill=\"teal\"/>"));
+        assert_eq!(erc721.render_token(1), Ok(svg::to_data_uri(&svg)));
+        assert!(erc721.render_token(2).is_err());
+    }
+
+    #[test]
+    fn test_build_metadata() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        let mut collection = ContractMetadata::new();
+        collection.name = Some("Spawn".to_string());
+        collection.image = Some(format!("ipfs://{}", cid("cover")));
+        erc721.set_contract_metadata("owner".to_string(), collection).unwrap();
+        erc721.set_attribute_value("owner".to_string(), 1, "Level".to_string(), AttributeValue::Number(3.0)).unwrap();
+
+        let json = erc721.build_metadata(1).unwrap();
+        assert!(crate::metadata::validate_metadata(json.clone()).is_empty());
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(json["name"], "Spawn #1");
+        assert_eq!(json["image"], format!("ipfs://{}", cid("cover")));
+        assert_eq!(json["attributes"][0]["value"], 3.0);
+        assert!(erc721.build_metadata(2).is_err());
+    }
+
+    #[test]
+    fn test_uri_validation() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+
+        // A mistyped CID never reaches the state
+        let typo = format!("ipfs://{}x/", cid("base"));
+        assert!(erc721.set_base_uri("owner".to_string(), typo).is_err());
+        assert!(erc721.mint_batch("owner".to_string(), 10, 2, "ipfs://base/".to_string()).is_err());
+        assert_eq!(erc721.base_uri(), None);
+
+        let http = "https://example.com/1.json".to_string();
+        erc721.set_token_uri("owner".to_string(), 1, http.clone()).unwrap();
+
+        assert!(erc721.set_decentralized_uris("user1".to_string(), true).is_err());
+        erc721.set_decentralized_uris("owner".to_string(), true).unwrap();
+        assert!(erc721.decentralized_uris());
+        assert!(erc721.set_token_uri("owner".to_string(), 1, http).is_err());
+        assert!(erc721.set_contract_uri("owner".to_string(), "https://example.com".to_string()).is_err());
+        erc721.set_token_uri("owner".to_string(), 1, format!("ar://{}", arweave_id("1"))).unwrap();
+    }
+
+    #[test]
+    fn test_delayed_reveal() {
+        let mut erc721 = ERC721::with_max_supply("owner".to_string(), 10);
+        let provenance = to_hex(&keccak256(b"metadata hashes in order"));
+        assert!(erc721.set_provenance_hash("user1".to_string(), provenance.clone()).is_err());
+        assert!(erc721.set_provenance_hash("owner".to_string(), "0x1234".to_string()).is_err());
+        erc721.set_provenance_hash("owner".to_string(), provenance.clone()).unwrap();
+        assert_eq!(erc721.provenance_hash(), Some(provenance.clone()));
+        let filter = EventFilter {
+            kinds: Some(vec!["ProvenanceHashSet".to_string()]),
+            ..EventFilter::default()
+        };
+        assert_eq!(erc721.events(&filter).len(), 1);
+
+        erc721.set_base_uri("owner".to_string(), format!("ipfs://{}/", cid("final"))).unwrap();
+        erc721.set_placeholder_uri("owner".to_string(), format!("ipfs://{}", cid("hidden"))).unwrap();
+        for token_id in 0..10 {
+            erc721.mint("owner".to_string(), token_id).unwrap();
+        }
+
+        // The commitment is locked once minting starts
+        assert!(erc721.set_provenance_hash("owner".to_string(), provenance).is_err());
+        assert_eq!(erc721.token_uri(3), Some(format!("ipfs://{}", cid("hidden"))));
+        assert!(!erc721.revealed());
+
+        erc721.reveal("owner".to_string(), 27).unwrap();
+        assert!(erc721.revealed());
+        assert_eq!(erc721.metadata_index(3), 0);
+        assert_eq!(erc721.token_uri(3), Some(format!("ipfs://{}/0", cid("final"))));
+        assert_eq!(erc721.token_uri(9), Some(format!("ipfs://{}/6", cid("final"))));
+
+        // Every metadata index is used exactly once
+        let mut indices: Vec<u64> = (0..10).map(|token_id| erc721.metadata_index(token_id)).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..10).collect::<Vec<_>>());
+        assert!(erc721.reveal("owner".to_string(), 1).is_err());
+        assert!(erc721.set_placeholder_uri("owner".to_string(), format!("ipfs://{}", cid("hidden"))).is_err());
+    }
+
+    #[test]
+    fn test_seeded_reveal_and_raffle() {
+        let mut erc721 = ERC721::with_max_supply("owner".to_string(), 10);
+        erc721.mint_batch("owner".to_string(), 0, 10, String::new()).unwrap();
+
+        let winners = erc721.draw_raffle("block-123".to_string(), 3);
+        assert_eq!(winners, erc721.draw_raffle("block-123".to_string(), 3));
+        assert_eq!(winners.len(), 3);
+        assert!(winners.iter().all(|&token_id| token_id < 10));
+
+        // The offset is derived from the seed, so the reveal can be replayed
+        let mut replay = erc721.clone();
+        erc721.reveal_with_seed("owner".to_string(), "vrf-output".to_string()).unwrap();
+        replay.reveal_with_seed("owner".to_string(), "vrf-output".to_string()).unwrap();
+        assert_eq!(erc721.metadata_index(0), replay.metadata_index(0));
+        assert!(erc721.revealed());
+    }
+
+    #[test]
+    fn test_votes_follow_transfers() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint_batch("owner".to_string(), 1, 3, String::new()).unwrap();
+        erc721.delegate("owner".to_string(), "owner".to_string()).unwrap();
+        erc721.delegate("user1".to_string(), "user2".to_string()).unwrap();
+        assert_eq!(erc721.get_votes("owner".to_string()), 3);
+
+        erc721.advance_time(10);
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        erc721.advance_time(10);
+        erc721.burn("owner".to_string(), 2).unwrap();
+        erc721.advance_time(10);
+
+        assert_eq!(erc721.get_votes("owner".to_string()), 1);
+        assert_eq!(erc721.get_votes("user2".to_string()), 1);
+        assert_eq!(erc721.delegates("user1".to_string()), Some("user2".to_string()));
+        assert_eq!(erc721.get_past_votes("owner".to_string(), 1_005), Ok(3));
+        assert_eq!(erc721.get_past_votes("owner".to_string(), 1_015), Ok(2));
+        assert_eq!(erc721.get_past_total_supply(1_015), Ok(3));
+        assert_eq!(erc721.get_past_total_supply(1_025), Ok(2));
+        assert!(erc721.get_past_votes("owner".to_string(), 1_030).is_err());
+        assert_eq!(erc721.num_checkpoints("owner".to_string()), 3);
+    }
+
+    #[test]
+    fn test_governor_executes_passed_proposal() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint_batch("owner".to_string(), 1, 3, String::new()).unwrap();
+        erc721.transfer("owner".to_string(), "user1".to_string(), 3).unwrap();
+        erc721.delegate("owner".to_string(), "owner".to_string()).unwrap();
+        erc721.delegate("user1".to_string(), "user1".to_string()).unwrap();
+        erc721.set_governor_settings("owner".to_string(), 10, 100, 1, 5_000).unwrap();
+        erc721.advance_time(1);
+
+        let actions = vec![Operation::Transfer {
+            from: "owner".to_string(),
+            to: "user2".to_string(),
+            token_id: 1,
+        }];
+        assert!(erc721.propose_actions("user2".to_string(), "No votes".to_string(), actions.clone()).is_err());
+        let foreign = vec![Operation::Burn {
+            caller: "user1".to_string(),
+            token_id: 3,
+        }];
+        assert!(erc721.propose_actions("user1".to_string(), "Burn #3".to_string(), foreign).is_err());
+        let id = erc721.propose_actions("user1".to_string(), "Gift #1".to_string(), actions).unwrap();
+        assert_eq!(erc721.proposal_state(id), Ok(ProposalState::Pending));
+
+        erc721.advance_time(11);
+        assert_eq!(erc721.cast_vote(id, "owner".to_string(), VoteType::For), Ok(2));
+        assert_eq!(erc721.cast_vote(id, "user1".to_string(), VoteType::Against), Ok(1));
+        assert!(erc721.has_voted(id, "owner".to_string()));
+        assert!(erc721.execute_proposal("user1".to_string(), id).is_err());
+
+        erc721.advance_time(100);
+        assert_eq!(erc721.proposal_votes(id), Ok(vec![1, 2, 0]));
+        assert_eq!(erc721.proposal_state(id), Ok(ProposalState::Succeeded));
+        erc721.execute_proposal("user1".to_string(), id).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user2".to_string()));
+        assert_eq!(erc721.proposal_state(id), Ok(ProposalState::Executed));
+        assert!(erc721.execute_proposal("user1".to_string(), id).is_err());
+    }
+
+    #[test]
+    fn test_timelocked_admin_actions() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.set_timelock_delay("owner".to_string(), 3_600).unwrap();
+        assert!(erc721.pause("owner".to_string()).is_err());
+        assert!(erc721.set_timelock_delay("owner".to_string(), 0).is_err());
+
+        assert!(erc721.queue_action("user1".to_string(), AdminAction::Pause).is_err());
+        let pause = erc721.queue_action("owner".to_string(), AdminAction::Pause).unwrap();
+        let royalty = AdminAction::SetDefaultRoyalty {
+            receiver: "artist".to_string(),
+            bps: 500,
+        };
+        let royalty = erc721.queue_action("owner".to_string(), royalty).unwrap();
+        assert_eq!(erc721.admin_action_eta(pause), Some(4_600));
+
+        assert!(erc721.execute_admin_action("user1".to_string(), pause).is_err());
+        erc721.advance_time(3_600);
+        assert!(erc721.cancel_admin_action("user1".to_string(), royalty).is_err());
+        erc721.cancel_admin_action("owner".to_string(), royalty).unwrap();
+        assert!(erc721.execute_admin_action("user1".to_string(), royalty).is_err());
+
+        erc721.execute_admin_action("user1".to_string(), pause).unwrap();
+        assert!(erc721.paused());
+        assert!(erc721.execute_admin_action("user1".to_string(), pause).is_err());
+    }
+
+    #[test]
+    fn test_paid_mint_and_withdraw() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.set_mint_price("owner".to_string(), 50).unwrap();
+        erc721.set_treasury("owner".to_string(), "treasury".to_string()).unwrap();
+        erc721.deposit("user1".to_string(), 120).unwrap();
+
+        assert!(erc721.mint_paid("user1".to_string(), 1, 49).is_err());
+        assert!(erc721.mint_paid("user1".to_string(), 1, 200).is_err());
+        erc721.mint_paid("user1".to_string(), 1, 60).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user1".to_string()));
+        assert_eq!(erc721.eth_balance("user1".to_string()), 60);
+
+        // Failed mints do not take the payment
+        assert!(erc721.mint_paid("user1".to_string(), 1, 50).is_err());
+        assert_eq!(erc721.contract_balance(), 60);
+
+        // An active phase overrides the default price
+        erc721.configure_phase("owner".to_string(), "presale".to_string(), 1_000, 2_000, 10, None).unwrap();
+        assert_eq!(erc721.mint_price(), 10);
+        erc721.mint_paid("user1".to_string(), 2, 10).unwrap();
+
+        assert!(erc721.withdraw("user1".to_string()).is_err());
+        assert_eq!(erc721.withdraw("treasury".to_string()), Ok(70));
+        assert_eq!(erc721.eth_balance("treasury".to_string()), 70);
+        assert_eq!(erc721.contract_balance(), 0);
+    }
+
+    #[test]
+    fn test_marketplace_sale() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        erc721.set_default_royalty("owner".to_string(), "artist".to_string(), 1_000).unwrap();
+        erc721.deposit("user2".to_string(), 150).unwrap();
+
+        // The marketplace needs approval before the token can be listed
+        assert!(erc721.list("user1".to_string(), 1, 100).is_err());
+        erc721.set_approval_for_all("user1".to_string(), erc721.marketplace_operator(), true).unwrap();
+        assert!(erc721.list("user2".to_string(), 1, 100).is_err());
+        erc721.list("user1".to_string(), 1, 100).unwrap();
+        assert_eq!(erc721.get_listing(1).unwrap().price, 100);
+
+        assert!(erc721.buy("user3".to_string(), 1).is_err());
+        erc721.buy("user2".to_string(), 1).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user2".to_string()));
+        assert_eq!(erc721.eth_balance("user2".to_string()), 50);
+        assert_eq!(erc721.eth_balance("user1".to_string()), 90);
+        assert_eq!(erc721.eth_balance("artist".to_string()), 10);
+        assert!(erc721.get_listing(1).is_none());
+        assert!(erc721.buy("user2".to_string(), 1).is_err());
+    }
+
+    #[test]
+    fn test_sale_clears_token_approval() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.approve("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), 1).unwrap();
+        erc721.list("owner".to_string(), 1, 100).unwrap();
+        erc721.deposit("user1".to_string(), 100).unwrap();
+        erc721.buy("user1".to_string(), 1).unwrap();
+
+        // The previous owner's approval does not carry over to the buyer's token
+        assert_eq!(erc721.get_approved(1), None);
+        let operator = MARKETPLACE_OPERATOR.to_string();
+        assert!(erc721.transfer(operator.clone(), operator, 1).is_err());
+        assert_eq!(erc721.owner_of(1), Some("user1".to_string()));
+    }
+
+    #[test]
+    fn test_purchase_is_atomic() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.approve("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), 1).unwrap();
+        erc721.list("owner".to_string(), 1, 100).unwrap();
+        erc721.deposit("user1".to_string(), 100).unwrap();
+
+        // The seller's balance overflowing is caught before the token moves
+        erc721.deposit("owner".to_string(), u64::MAX).unwrap();
+        assert!(erc721.buy("user1".to_string(), 1).is_err());
+        assert_eq!(erc721.owner_of(1), Some("owner".to_string()));
+        assert_eq!(erc721.eth_balance("user1".to_string()), 100);
+        assert!(erc721.get_listing(1).is_some());
+    }
+
+    #[test]
+    fn test_stale_listing() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.approve("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), 1).unwrap();
+        erc721.list("owner".to_string(), 1, 100).unwrap();
+        assert!(erc721.cancel_listing("user1".to_string(), 1).is_err());
+
+        // Moving the token elsewhere drops its listing
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        erc721.deposit("user2".to_string(), 100).unwrap();
+        assert!(erc721.buy("user2".to_string(), 1).is_err());
+        assert_eq!(erc721.eth_balance("user2".to_string()), 100);
+        assert!(erc721.listed_tokens().is_empty());
+    }
+
+    #[test]
+    fn test_offers_with_escrow() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_default_royalty("owner".to_string(), "artist".to_string(), 500).unwrap();
+        erc721.deposit("user1".to_string(), 100).unwrap();
+        erc721.deposit("user2".to_string(), 300).unwrap();
+
+        let first = erc721.make_offer("user1".to_string(), 1, 100, 2_000).unwrap();
+        assert_eq!(erc721.eth_balance("user1".to_string()), 0);
+        assert!(erc721.make_offer("user2".to_string(), 1, 100, 2_000).is_err());
+
+        // Outbidding refunds the previous offer
+        let second = erc721.make_offer("user2".to_string(), 1, 200, 2_000).unwrap();
+        assert_eq!(erc721.eth_balance("user1".to_string()), 100);
+        assert!(erc721.get_offer(first).is_none());
+        assert_eq!(erc721.escrowed_funds(), 200);
+
+        assert!(erc721.accept_offer("owner".to_string(), second).is_err());
+        erc721.approve("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), 1).unwrap();
+        assert!(erc721.accept_offer("user1".to_string(), second).is_err());
+        erc721.accept_offer("owner".to_string(), second).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user2".to_string()));
+        assert_eq!(erc721.eth_balance("owner".to_string()), 190);
+        assert_eq!(erc721.eth_balance("artist".to_string()), 10);
+        assert_eq!(erc721.escrowed_funds(), 0);
+    }
+
+    #[test]
+    fn test_offer_cancel_and_expiry() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        erc721.approve("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), 1).unwrap();
+        erc721.deposit("user1".to_string(), 100).unwrap();
+
+        let cancelled = erc721.make_offer("user1".to_string(), 1, 40, 1_500).unwrap();
+        assert!(erc721.cancel_offer("user2".to_string(), cancelled).is_err());
+        erc721.cancel_offer("user1".to_string(), cancelled).unwrap();
+        assert_eq!(erc721.eth_balance("user1".to_string()), 100);
+
+        let expiring = erc721.make_offer("user1".to_string(), 1, 40, 1_500).unwrap();
+        erc721.make_offer("user1".to_string(), 2, 60, 3_000).unwrap();
+        erc721.advance_time(500);
+        assert!(erc721.accept_offer("owner".to_string(), expiring).is_err());
+        assert_eq!(erc721.refund_expired_offers(), Ok(1));
+        assert_eq!(erc721.eth_balance("user1".to_string()), 40);
+        assert_eq!(erc721.best_offer(2).unwrap().amount, 60);
+    }
+
+    #[test]
+    fn test_english_auction() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_auction_settings("owner".to_string(), 1_000, 300).unwrap();
+        erc721.deposit("user1".to_string(), 500).unwrap();
+        erc721.deposit("user2".to_string(), 500).unwrap();
+
+        assert!(erc721.create_auction("owner".to_string(), 1, 100, 3_600).is_err());
+        erc721.set_approval_for_all("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        erc721.create_auction("owner".to_string(), 1, 100, 3_600).unwrap();
+        assert_eq!(erc721.owner_of(1), Some(MARKETPLACE_OPERATOR.to_string()));
+
+        assert!(erc721.bid("user1".to_string(), 1, 99).is_err());
+        erc721.bid("user1".to_string(), 1, 100).unwrap();
+        assert!(erc721.bid("user2".to_string(), 1, 109).is_err());
+        erc721.advance_time(3_500);
+        erc721.bid("user2".to_string(), 1, 110).unwrap();
+        assert_eq!(erc721.eth_balance("user1".to_string()), 500);
+        assert_eq!(erc721.get_auction(1).unwrap().end_time, 4_800);
+
+        erc721.advance_time(100);
+        assert!(erc721.settle_auction("user1".to_string(), 1).is_err());
+        erc721.advance_time(200);
+        erc721.settle_auction("user1".to_string(), 1).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user2".to_string()));
+        assert_eq!(erc721.eth_balance("owner".to_string()), 110);
+        assert_eq!(erc721.eth_balance("user2".to_string()), 390);
+        assert!(erc721.get_auction(1).is_none());
+    }
+
+    #[test]
+    fn test_auction_without_bids() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        erc721.set_approval_for_all("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        erc721.create_auction("owner".to_string(), 1, 100, 60).unwrap();
+        erc721.create_auction("owner".to_string(), 2, 100, 60).unwrap();
+
+        assert!(erc721.cancel_auction("user1".to_string(), 1).is_err());
+        erc721.cancel_auction("owner".to_string(), 1).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("owner".to_string()));
+
+        erc721.advance_time(60);
+        erc721.settle_auction("user1".to_string(), 2).unwrap();
+        assert_eq!(erc721.owner_of(2), Some("owner".to_string()));
+    }
+
+    #[test]
+    fn test_dutch_auction_mint() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint_batch("owner".to_string(), 2, 3, String::new()).unwrap();
+        erc721.burn("owner".to_string(), 3).unwrap();
+        erc721.deposit("user1".to_string(), 2_000).unwrap();
+        assert!(erc721.mint_dutch("user1".to_string(), 1_000).is_err());
+
+        assert!(erc721.configure_dutch_auction("user1".to_string(), 1_000, 200, 800, 100).is_err());
+        erc721.configure_dutch_auction("owner".to_string(), 1_000, 200, 800, 100).unwrap();
+        assert_eq!(erc721.current_price(1_050), Some(1_000));
+        assert_eq!(erc721.current_price(1_400), Some(600));
+
+        assert!(erc721.mint_dutch("user1".to_string(), 999).is_err());
+        assert_eq!(erc721.mint_dutch("user1".to_string(), 1_000), Ok(1));
+
+        // Overpayment is refunded; existing and burned batch IDs are skipped
+        erc721.advance_time(400);
+        assert_eq!(erc721.mint_dutch("user1".to_string(), 900), Ok(5));
+        assert_eq!(erc721.eth_balance("user1".to_string()), 400);
+        assert_eq!(erc721.contract_balance(), 1_600);
+    }
+
+    #[test]
+    fn test_escrowed_swap() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint_batch("owner".to_string(), 1, 3, String::new()).unwrap();
+        erc721.transfer("owner".to_string(), "user1".to_string(), 3).unwrap();
+        for user in ["owner", "user1"] {
+            erc721.set_approval_for_all(user.to_string(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        }
+        erc721.deposit("user1".to_string(), 100).unwrap();
+
+        // The maker cannot offer tokens it does not own; nothing is escrowed on failure
+        let bad = SwapSide::new(vec![1, 3], 0);
+        assert!(erc721.create_swap("owner".to_string(), "user1".to_string(), bad, SwapSide::default(), 2_000).is_err());
+        assert_eq!(erc721.owner_of(1), Some("owner".to_string()));
+
+        let offered = SwapSide::new(vec![1, 2], 0);
+        let requested = SwapSide::new(vec![3], 50);
+        let id = erc721.create_swap("owner".to_string(), "user1".to_string(), offered, requested, 2_000).unwrap();
+        assert_eq!(erc721.owner_of(2), Some(MARKETPLACE_OPERATOR.to_string()));
+
+        assert!(erc721.deposit_swap("user2".to_string(), id).is_err());
+        erc721.deposit_swap("user1".to_string(), id).unwrap();
+        assert_eq!(erc721.eth_balance("user1".to_string()), 50);
+        assert!(erc721.confirm_swap("user2".to_string(), id).is_err());
+        erc721.confirm_swap("owner".to_string(), id).unwrap();
+        assert_eq!(erc721.owner_of(3), Some(MARKETPLACE_OPERATOR.to_string()));
+
+        // A rejected release of the taker's token is caught before the maker's tokens move
+        erc721.add_hook(HookKind::BeforeTransfer, |ctx| match ctx.token_id {
+            3 => Err("Frozen".to_string()),
+            _ => Ok(()),
+        });
+        assert!(erc721.confirm_swap("user1".to_string(), id).is_err());
+        assert_eq!(erc721.owner_of(1), Some(MARKETPLACE_OPERATOR.to_string()));
+        assert_eq!(erc721.eth_balance("owner".to_string()), 0);
+
+        erc721.clear_hooks();
+        erc721.confirm_swap("user1".to_string(), id).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user1".to_string()));
+        assert_eq!(erc721.owner_of(3), Some("owner".to_string()));
+        assert_eq!(erc721.eth_balance("owner".to_string()), 50);
+        assert!(erc721.get_swap(id).is_none());
+    }
+
+    #[test]
+    fn test_swap_refund_on_expiry() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_approval_for_all("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        erc721.deposit("owner".to_string(), 30).unwrap();
+
+        let offered = SwapSide::new(vec![1], 30);
+        let requested = SwapSide::default();
+        let id = erc721.create_swap("owner".to_string(), "user1".to_string(), offered, requested, 1_100).unwrap();
+        assert!(erc721.cancel_swap("user2".to_string(), id).is_err());
+
+        erc721.advance_time(100);
+        assert!(erc721.confirm_swap("owner".to_string(), id).is_err());
+        erc721.cancel_swap("user2".to_string(), id).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("owner".to_string()));
+        assert_eq!(erc721.eth_balance("owner".to_string()), 30);
+    }
+
+    #[test]
+    fn test_bundle_purchase_is_atomic() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 3, String::new()).unwrap();
+        erc721.set_token_royalty("owner".to_string(), 2, "artist".to_string(), 1_000).unwrap();
+        erc721.set_approval_for_all("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        erc721.deposit("user1".to_string(), 1_000).unwrap();
+
+        let id = erc721.list_bundle("owner".to_string(), vec![1, 2, 3], 300).unwrap();
+        assert!(erc721.list_bundle("owner".to_string(), vec![3, 4], 300).is_err());
+
+        // A hook rejecting the last token is caught before any token or payment moves
+        erc721.add_hook(HookKind::BeforeTransfer, |ctx| match ctx.token_id {
+            3 => Err("Frozen".to_string()),
+            _ => Ok(()),
+        });
+        assert!(erc721.buy_bundle("user1".to_string(), id).is_err());
+        assert_eq!(erc721.owner_of(1), Some("owner".to_string()));
+        assert_eq!(erc721.eth_balance("user1".to_string()), 1_000);
+        assert!(erc721.get_bundle(id).is_some());
+
+        erc721.clear_hooks();
+        erc721.buy_bundle("user1".to_string(), id).unwrap();
+        assert_eq!(erc721.tokens_of_owner("user1".to_string()).len(), 3);
+        assert_eq!(erc721.eth_balance("artist".to_string()), 10);
+        assert_eq!(erc721.eth_balance("owner".to_string()), 290);
+        assert!(erc721.get_bundle(id).is_none());
+    }
+
+    #[test]
+    fn test_fulfill_signed_order() {
+        use crate::order::ConsiderationItem;
+        use k256::ecdsa::SigningKey;
+
+        let mut erc721 = ERC721::new("owner".to_string());
+        let key = SigningKey::from_slice(&[5u8; 32]).unwrap();
+        let (_, offerer) = crate::eip712::tests::sign(&key, &[0u8; 32]);
+        let artist = format!("0x{}", "aa".repeat(20));
+        erc721.set_block_timestamp(100);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.transfer("owner".to_string(), offerer.clone(), 1).unwrap();
+        erc721.set_token_royalty("owner".to_string(), 1, artist.clone(), 1_000).unwrap();
+        erc721.set_approval_for_all(offerer.clone(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        erc721.deposit("user1".to_string(), 1_000).unwrap();
+
+        let sign = |erc721: &ERC721, order: &Order| {
+            let digest = parse_hash(&erc721.order_hash(order).unwrap()).unwrap();
+            crate::eip712::tests::sign(&key, &digest).0
+        };
+        let seller_only = vec![ConsiderationItem::new(offerer.clone(), 100)];
+        let order = Order::new(offerer.clone(), 1, seller_only, 0, 1_000);
+        let signature = sign(&erc721, &order);
+        assert_eq!(erc721.validate_order(&order, signature), Err("Consideration must pay a royalty of 10".to_string()));
+
+        let consideration = vec![
+            ConsiderationItem::new(offerer.clone(), 90),
+            ConsiderationItem::new(artist.clone(), 10),
+        ];
+        let order = Order::new(offerer.clone(), 1, consideration, 0, 1_000);
+        let signature = sign(&erc721, &order);
+        let mut forged = order.clone();
+        forged.consideration[0].amount = 1;
+        assert!(erc721.fulfill_order("user1".to_string(), &forged, signature.clone()).is_err());
+
+        // A payment that cannot be credited is caught before the token moves
+        let mut overflowing = erc721.clone();
+        overflowing.deposit(artist.clone(), u64::MAX - 5).unwrap();
+        let result = overflowing.fulfill_order("user1".to_string(), &order, signature.clone());
+        assert_eq!(result, Err("Balance overflow".to_string()));
+        assert_eq!(overflowing.owner_of(1), Some(offerer.clone()));
+        assert_eq!(overflowing.eth_balance("user1".to_string()), 1_000);
+
+        erc721.fulfill_order("user1".to_string(), &order, signature.clone()).unwrap();
+        assert_eq!(erc721.owner_of(1), Some("user1".to_string()));
+        assert_eq!(erc721.eth_balance(offerer.clone()), 90);
+        assert_eq!(erc721.eth_balance(artist), 10);
+        assert_eq!(erc721.validate_order(&order, signature), Err("Order is already filled or cancelled".to_string()));
+
+        // Incrementing the nonce invalidates every outstanding order
+        assert_eq!(erc721.increment_order_nonce(offerer.clone()).unwrap(), 1);
+        let stale = Order::new(offerer, 1, vec![], 0, 1_000);
+        assert_eq!(erc721.validate_order(&stale, sign(&erc721, &stale)), Err("Order nonce is stale".to_string()));
+    }
+
+    #[test]
+    fn test_composable_children() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 3, String::new()).unwrap();
+        let mut items = ERC721::new("owner".to_string());
+        let items_address = format!("0x{}", "11".repeat(20));
+        let (name, version) = ("Items".to_string(), "1".to_string());
+        items.set_eip712_domain("owner".to_string(), name, version, 1, items_address.clone()).unwrap();
+        items.mint("owner".to_string(), 7).unwrap();
+
+        erc721.attach_child("owner".to_string(), 1, &mut items, 7).unwrap();
+        erc721.attach_own_child("owner".to_string(), 1, 2).unwrap();
+        erc721.attach_own_child("owner".to_string(), 2, 3).unwrap();
+        assert!(erc721.attach_own_child("owner".to_string(), 3, 1).is_err());
+        assert_eq!(items.owner_of(7), Some(erc721.child_holder(1)));
+        assert_eq!(erc721.children_of(1).len(), 2);
+        assert_eq!(erc721.children_of(1)[0].contract, items_address);
+
+        // Children travel with the parent: the new owner controls them, the old one does not
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        assert!(erc721.burn("user1".to_string(), 1).is_err());
+        assert!(erc721.detach_child("owner".to_string(), 1, &mut items, 7, "owner".to_string()).is_err());
+        erc721.detach_child("user1".to_string(), 1, &mut items, 7, "user1".to_string()).unwrap();
+        erc721.detach_own_child("user1".to_string(), 1, 2, "user1".to_string()).unwrap();
+        assert_eq!(items.owner_of(7), Some("user1".to_string()));
+        assert_eq!(erc721.owner_of(2), Some("user1".to_string()));
+        assert!(erc721.children_of(1).is_empty());
+        assert_eq!(erc721.children_of(2).len(), 1);
+    }
+
+    #[test]
+    fn test_merge_tokens() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint_batch("owner".to_string(), 1, 3, String::new()).unwrap();
+        erc721.set_attribute_value("owner".to_string(), 1, "Power".to_string(), AttributeValue::Number(4.0)).unwrap();
+        erc721.set_attribute_value("owner".to_string(), 2, "Power".to_string(), AttributeValue::Number(8.0)).unwrap();
+        erc721.set_merge_cooldown("owner".to_string(), 60).unwrap();
+
+        assert!(erc721.merge("user1".to_string(), 1, 2).is_err());
+
+        // A rejected output mint is caught before either input is burned
+        erc721.add_hook(HookKind::BeforeMint, |_| Err("Closed".to_string()));
+        assert!(erc721.merge("owner".to_string(), 1, 2).is_err());
+        assert_eq!((erc721.total_supply(), erc721.owner_of(1)), (3, Some("owner".to_string())));
+        erc721.clear_hooks();
+
+        let merged = erc721.merge("owner".to_string(), 1, 2).unwrap();
+        assert_eq!(merged, 4);
+        assert_eq!(erc721.owner_of(1), None);
+        assert_eq!(erc721.attributes(merged)[0].value, AttributeValue::Number(6.0));
+
+        // The output must wait out the cooldown, and a custom rule replaces the default averaging
+        assert!(erc721.merge("owner".to_string(), merged, 3).is_err());
+        erc721.advance_time(60);
+        erc721.set_merge_fn(|a, b| {
+            let mut attributes = a.to_vec();
+            attributes.extend_from_slice(b);
+            attributes.truncate(1);
+            Ok(attributes)
+        });
+        let merged = erc721.merge("owner".to_string(), merged, 3).unwrap();
+        assert_eq!(merged, 5);
+        assert_eq!(erc721.attributes(merged).len(), 1);
+    }
+
+    #[test]
+    fn test_merge_id_overflow() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 2, String::new()).unwrap();
+        erc721.mint_batch("owner".to_string(), u64::MAX - 1, 2, String::new()).unwrap();
+        erc721.merging.record([0, 0], u64::MAX - 2, 0);
+
+        // Every ID from the next output ID up to u64::MAX is taken
+        assert_eq!(erc721.merge("owner".to_string(), 1, 2), Err("Token ID overflow".to_string()));
+        assert_eq!(erc721.owner_of(1), Some("owner".to_string()));
+        assert_eq!(erc721.owner_of(2), Some("owner".to_string()));
+    }
+
+    #[test]
+    fn test_xp_progression() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_xp_curve("owner".to_string(), vec![50, 150]).unwrap();
+
+        assert!(erc721.add_xp("user1".to_string(), 1, 10).is_err());
+        erc721.assign_role("owner".to_string(), GAME_ROLE.to_string(), "game".to_string()).unwrap();
+        assert!(erc721.add_xp("game".to_string(), 2, 10).is_err());
+
+        let updates = |erc721: &ERC721| erc721.history(1).iter().filter(|entry| entry.kind == "MetadataUpdate").count();
+        assert_eq!(erc721.add_xp("game".to_string(), 1, 49), Ok(1));
+        assert_eq!(updates(&erc721), 0);
+        assert_eq!(erc721.add_xp("game".to_string(), 1, 101), Ok(3));
+        assert_eq!(updates(&erc721), 1);
+        assert_eq!(erc721.level_of(1), 3);
+        assert_eq!(erc721.xp_of(1), 150);
+    }
+
+    #[test]
+    fn test_consumable_tokens() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 2, String::new()).unwrap();
+        erc721.assign_role("owner".to_string(), GAME_ROLE.to_string(), "game".to_string()).unwrap();
+        assert!(erc721.set_uses("user1".to_string(), 1, 2, false).is_err());
+        erc721.set_uses("game".to_string(), 1, 2, true).unwrap();
+        erc721.set_uses("game".to_string(), 2, 1, false).unwrap();
+
+        assert!(erc721.consume("user1".to_string(), 1).is_err());
+        assert_eq!(erc721.consume("owner".to_string(), 1), Ok(1));
+        assert_eq!(erc721.consume("owner".to_string(), 1), Ok(0));
+        assert_eq!(erc721.owner_of(1), None);
+        assert_eq!(erc721.remaining_uses(1), None);
+
+        // Without auto-burn the token is kept, but cannot be used again
+        assert_eq!(erc721.consume("owner".to_string(), 2), Ok(0));
+        assert!(erc721.consume("owner".to_string(), 2).is_err());
+        assert_eq!(erc721.remaining_uses(2), Some(0));
+    }
+
+    #[test]
+    fn test_airdrop_claims() {
+        use crate::airdrop::AirdropList;
+
+        let mut erc721 = ERC721::new("owner".to_string());
+        let recipients: Vec<String> = (0..3).map(|i| format!("user{}", i)).collect();
+        let list = AirdropList::new(recipients);
+        assert!(erc721.claim("user1".to_string(), 1, list.proof(1)).is_err());
+        assert!(erc721.create_airdrop("user1".to_string(), list.root(), 100, 102).is_err());
+        erc721.create_airdrop("owner".to_string(), list.root(), 100, 102).unwrap();
+
+        assert!(erc721.claim("user2".to_string(), 1, list.proof(1)).is_err());
+        assert_eq!(erc721.claim("user1".to_string(), 1, list.proof(1)), Ok(101));
+        assert_eq!(erc721.owner_of(101), Some("user1".to_string()));
+        assert!(erc721.is_claimed(1) && !erc721.is_claimed(0));
+        assert!(erc721.claim("user1".to_string(), 1, list.proof(1)).is_err());
+    }
+
+    #[test]
+    fn test_holder_snapshots() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        assert!(erc721.take_holder_snapshot("alice".to_string()).is_err());
+        let before = erc721.take_holder_snapshot("owner".to_string()).unwrap();
+
+        erc721.transfer("owner".to_string(), "alice".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        erc721.mint_batch("owner".to_string(), 10, 3, String::new()).unwrap();
+        let after = erc721.take_holder_snapshot("owner".to_string()).unwrap();
+        erc721.transfer("owner".to_string(), "alice".to_string(), 11).unwrap();
+        erc721.burn("owner".to_string(), 12).unwrap();
+
+        assert_eq!(erc721.owner_of_at(before, 1), Ok(Some("owner".to_string())));
+        assert_eq!(erc721.owner_of_at(before, 2), Ok(None));
+        assert_eq!(erc721.owner_of_at(before, 10), Ok(None));
+        assert_eq!(erc721.balance_of_at(before, "owner".to_string()), Ok(1));
+        assert_eq!(erc721.balance_of_at(before, "alice".to_string()), Ok(0));
+        assert_eq!(erc721.owner_of_at(after, 1), Ok(Some("alice".to_string())));
+        assert_eq!(erc721.owner_of_at(after, 11), Ok(Some("owner".to_string())));
+        assert_eq!(erc721.owner_of_at(after, 12), Ok(Some("owner".to_string())));
+        assert_eq!(erc721.balance_of_at(after, "owner".to_string()), Ok(4));
+        assert_eq!(erc721.balance_of_at(after, "alice".to_string()), Ok(1));
+        assert!(erc721.owner_of_at(3, 1).is_err());
+    }
+
+    #[test]
+    fn test_holder_analytics() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 6, String::new()).unwrap();
+        for (token_id, to) in [(1, "alice"), (2, "alice"), (3, "bob")] {
+            erc721.transfer("owner".to_string(), to.to_string(), token_id).unwrap();
+        }
+        erc721.burn("bob".to_string(), 3).unwrap();
+
+        assert_eq!(erc721.holder_count(), 2);
+        let top = erc721.top_holders(1);
+        assert_eq!((top[0].address.as_str(), top[0].balance), ("owner", 3));
+        let buckets: Vec<(u64, u64)> = erc721
+            .ownership_distribution()
+            .into_iter()
+            .map(|bucket| (bucket.tokens, bucket.wallets))
+            .collect();
+        assert_eq!(buckets, vec![(2, 1), (3, 1)]);
+        // Entries recorded in the same millisecond collapse into one point
+        let history = erc721.unique_holders_over_time();
+        assert_eq!(history.last().map(|point| point.holders), Some(2));
+    }
+
+    #[test]
+    fn test_paginated_queries() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 10, 5, String::new()).unwrap();
+        erc721.mint("owner".to_string(), 3).unwrap();
+        erc721.transfer("owner".to_string(), "alice".to_string(), 12).unwrap();
+
+        let page = erc721.tokens_of_owner_page("owner".to_string(), 0, 3);
+        assert_eq!((page.items, page.total), (vec![3, 10, 11], 5));
+        let page = erc721.tokens_of_owner_page("owner".to_string(), 3, 3);
+        assert_eq!(page.items, vec![13, 14]);
+        assert_eq!(erc721.all_tokens_page(2, 2).items, vec![11, 12]);
+
+        erc721.assign_role("owner".to_string(), GAME_ROLE.to_string(), "game1".to_string()).unwrap();
+        erc721.assign_role("owner".to_string(), GAME_ROLE.to_string(), "game2".to_string()).unwrap();
+        let page = erc721.list_role_users_page(GAME_ROLE.to_string(), 1, 10);
+        assert_eq!((page.items, page.total), (vec!["game2".to_string()], 2));
+    }
+
+    #[test]
+    fn test_token_cursor() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 10, 5, String::new()).unwrap();
+        erc721.mint("owner".to_string(), 3).unwrap();
+        erc721.mint("owner".to_string(), 20).unwrap();
+        erc721.transfer("owner".to_string(), "alice".to_string(), 12).unwrap();
+        erc721.transfer("owner".to_string(), "alice".to_string(), 3).unwrap();
+
+        let mut cursor = erc721.token_cursor();
+        assert_eq!(cursor.next_chunk(&erc721, 3), vec![3, 10, 11]);
+        // Tokens burned behind or ahead of the cursor do not disturb it
+        erc721.burn("owner".to_string(), 13).unwrap();
+        assert_eq!(cursor.next_chunk(&erc721, 3), vec![12, 14, 20]);
+        assert!(!cursor.is_done());
+        assert!(cursor.next_chunk(&erc721, 3).is_empty());
+        assert!(cursor.is_done());
+
+        let mut cursor = erc721.owner_token_cursor("alice".to_string());
+        assert_eq!(cursor.next_chunk(&erc721, 10), vec![3, 12]);
+        assert!(cursor.is_done());
+        cursor.reset();
+        assert_eq!(cursor.next_chunk(&erc721, 1), vec![3]);
+    }
+
+    #[test]
+    fn test_interned_owners() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        for token_id in 1..=50 {
+            erc721.mint("owner".to_string(), token_id).unwrap();
+        }
+        for token_id in 1..=10 {
+            erc721.transfer("owner".to_string(), "alice".to_string(), token_id).unwrap();
+        }
+
+        let stats = erc721.memory_stats();
+        assert_eq!(stats.unique_addresses, 2);
+        assert_eq!(stats.address_references, 100);
+        assert!(stats.bytes_saved > stats.address_bytes);
+
+        let restored = ERC721::from_json(erc721.to_json()).unwrap();
+        assert_eq!(restored.owner_of(3), Some("alice".to_string()));
+        assert_eq!(restored.tokens_of_owner("alice".to_string()), erc721.tokens_of_owner("alice".to_string()));
+    }
+
+    #[test]
+    fn test_perf_report() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 100, String::new()).unwrap();
+        erc721.mint("owner".to_string(), 500).unwrap();
+        erc721.transfer("owner".to_string(), "alice".to_string(), 1).unwrap();
+        assert!(erc721.transfer("bob".to_string(), "alice".to_string(), 2).is_err());
+        erc721.tokens_of_owner("owner".to_string());
+
+        let timings = erc721.perf_timings();
+        assert_eq!(timings["transfer"].count, 2);
+        assert_eq!(timings["mint"].count, 1);
+        assert_eq!(timings["mint_batch"].count, 1);
+        assert_eq!(timings["tokens_of_owner"].count, 1);
+        erc721.reset_perf();
+        assert!(erc721.perf_timings().is_empty());
+    }
+
+    #[test]
+    fn test_metrics() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 10, String::new()).unwrap();
+        erc721.mint("owner".to_string(), 20).unwrap();
+        assert!(erc721.mint("bob".to_string(), 21).is_err());
+        erc721.transfer("owner".to_string(), "alice".to_string(), 1).unwrap();
+        erc721.approve("alice".to_string(), "bob".to_string(), 1).unwrap();
+        erc721.burn("owner".to_string(), 20).unwrap();
+        assert!(erc721.burn("owner".to_string(), 20).is_err());
+
+        // Failures are counted from the returned error, not from the logged event's name
+        crate::caller::set_test_mode(true);
+        erc721.connect(&Caller::impersonate("owner".to_string()).unwrap());
+        assert!(erc721.transfer("alice".to_string(), "bob".to_string(), 1).is_err());
+        erc721.disconnect();
+
+        let metrics = erc721.metrics_snapshot();
+        assert_eq!((metrics.mints.count, metrics.mints.failures), (3, 1));
+        assert_eq!((metrics.transfers.count, metrics.transfers.failures), (2, 1));
+        assert_eq!(metrics.approvals.count, 1);
+        assert_eq!((metrics.burns.count, metrics.burns.failures), (2, 1));
+        assert_eq!(metrics.failed_operations, 3);
+        assert_eq!(metrics.map_sizes["token_owner"], 1);
+        assert_eq!(metrics.map_sizes["approvals"], 1);
+        assert_eq!(metrics.map_sizes["batches"], 1);
+
+        erc721.reset_metrics();
+        let metrics = erc721.metrics_snapshot();
+        assert_eq!((metrics.mints.count, metrics.failed_operations), (0, 0));
+        assert_eq!(metrics.map_sizes["token_owner"], 1);
+
+        // A sale counts as one transfer op
+        erc721.set_approval_for_all("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        erc721.list("owner".to_string(), 2, 100).unwrap();
+        erc721.deposit("alice".to_string(), 100).unwrap();
+        erc721.buy("alice".to_string(), 2).unwrap();
+        let metrics = erc721.metrics_snapshot();
+        assert_eq!((metrics.transfers.count, metrics.failed_operations), (1, 0));
+        assert_eq!(erc721.perf_timings()["buy"].count, 1);
+    }
+
+    #[test]
+    fn test_clone_with() {
+        let mut template = ERC721::new("owner".to_string());
+        template.assign_role("owner".to_string(), "minter".to_string(), "alice".to_string()).unwrap();
+        template.set_default_royalty("owner".to_string(), "artist".to_string(), 500).unwrap();
+        template.mint_batch("owner".to_string(), 1, 10, String::new()).unwrap();
+        template.configure_phase("owner".to_string(), "public".to_string(), 0, u64::MAX, 10, Some(2)).unwrap();
+
+        let clone = template.clone_with("Second".to_string(), "SEC".to_string());
+        assert_eq!((clone.name(), clone.symbol()), (Some("Second".to_string()), Some("SEC".to_string())));
+        assert!(clone.has_role("minter".to_string(), "alice".to_string()));
+        assert_eq!(clone.royalty_info(1, 10_000).unwrap().amount, 500);
+        assert_eq!(clone.active_phase().unwrap().name, "public");
+        assert_eq!((clone.total_supply(), clone.owner_of(1), clone.log_len()), (0, None, 0));
+        assert_ne!(clone.domain.name, template.domain.name);
+        assert_eq!(template.total_supply(), 10);
+    }
+
+    // Outside the browser `yield_now` is ready immediately, so a single poll completes the future
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+        match std::pin::pin!(future).as_mut().poll(&mut context) {
+            std::task::Poll::Ready(output) => output,
+            std::task::Poll::Pending => panic!("future yielded outside the browser"),
+        }
+    }
+
+    #[test]
+    fn test_async_variants() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_max_supply("owner".to_string(), 40_000).unwrap();
+        block_on(erc721.mint_batch_async("owner".to_string(), 1, 25_000, String::new())).unwrap();
+        assert_eq!(erc721.total_supply(), 25_000);
+        assert_eq!(erc721.owner_of(25_000), Some("owner".to_string()));
+
+        // A failing chunk reverts the chunks minted before it
+        let result = block_on(erc721.mint_batch_async("owner".to_string(), 30_001, 20_000, String::new()));
+        assert_eq!(result, Err("Max supply exceeded".to_string()));
+        assert_eq!(erc721.total_supply(), 25_000);
+        assert_eq!(erc721.owner_of(30_001), None);
+
+        for token_id in 1..=3 {
+            let hat = AttributeValue::String(if token_id == 2 { "Crown" } else { "Cap" }.to_string());
+            erc721.set_attribute_value("owner".to_string(), token_id, "Hat".to_string(), hat).unwrap();
+        }
+        assert_eq!(block_on(erc721.compute_rarity_scores_async(RarityMethod::RarityScore)), 3);
+        assert_eq!(erc721.top_n_by_rarity(1), vec![2]);
+    }
+
+    #[test]
+    fn test_expiring_roles_follow_contract_clock() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.set_block_timestamp(1_000);
+        erc721.assign_role_until("owner".to_string(), PAUSER_ROLE.to_string(), "contractor".to_string(), 2_000).unwrap();
+        assert!(erc721.pause("contractor".to_string()).is_ok());
+
+        // Contractor access lapses with the contract clock, including across snapshots
+        erc721.advance_time(1_000);
+        assert!(erc721.unpause("contractor".to_string()).is_err());
+        let restored = ERC721::restore(&erc721.snapshot()).unwrap();
+        assert!(!restored.has_role(PAUSER_ROLE.to_string(), "contractor".to_string()));
+        assert_eq!(erc721.revoke_expired_roles(), 1);
+    }
+
+    #[test]
+    fn test_with_access_control() {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.assign_role("owner".to_string(), MINTER_ROLE.to_string(), "minter".to_string()).unwrap();
+        roles.assign_role("owner".to_string(), BURNER_ROLE.to_string(), "burner".to_string()).unwrap();
+        let mut erc721 = ERC721::with_access_control(roles);
+
+        // The owner string no longer grants minting or pausing by itself
+        assert!(erc721.mint("owner".to_string(), 1).is_err());
+        assert!(erc721.pause("owner".to_string()).is_err());
+        erc721.mint("minter".to_string(), 1).unwrap();
+        erc721.mint_batch("minter".to_string(), 10, 5, String::new()).unwrap();
+
+        // Burners can burn any token; holders keep burning their own
+        assert!(erc721.burn("someone".to_string(), 1).is_err());
+        erc721.burn("burner".to_string(), 1).unwrap();
+        erc721.burn("minter".to_string(), 10).unwrap();
+        assert_eq!(erc721.total_supply(), 4);
+
+        erc721.assign_role("owner".to_string(), PAUSER_ROLE.to_string(), "pauser".to_string()).unwrap();
+        assert!(erc721.pause("pauser".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_multisig_admin_actions() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        for signer in ["alice", "bob", "carol"] {
+            erc721.assign_role("owner".to_string(), ADMIN_ROLE.to_string(), signer.to_string()).unwrap();
+            erc721.assign_role("owner".to_string(), PAUSER_ROLE.to_string(), signer.to_string()).unwrap();
+        }
+        let signers = vec!["alice".to_string(), "bob".to_string(), "carol".to_string()];
+        erc721.configure_multisig("owner".to_string(), signers, 2, 3_600).unwrap();
+
+        // Single keys can no longer act alone, including through the timelock
+        assert!(erc721.pause("owner".to_string()).is_err());
+        assert!(erc721.queue_action("owner".to_string(), AdminAction::Pause).is_err());
+        assert!(erc721.propose_multisig("owner".to_string(), AdminAction::Pause).is_err());
+
+        let pause = erc721.propose_multisig("alice".to_string(), AdminAction::Pause).unwrap();
+        assert!(erc721.execute_multisig_action("anyone".to_string(), pause).is_err());
+        erc721.confirm_multisig_action("bob".to_string(), pause).unwrap();
+        erc721.revoke_multisig_confirmation("bob".to_string(), pause).unwrap();
+        assert!(erc721.execute_multisig_action("anyone".to_string(), pause).is_err());
+        erc721.confirm_multisig_action("carol".to_string(), pause).unwrap();
+        assert_eq!(erc721.multisig_confirmations(pause), vec!["alice".to_string(), "carol".to_string()]);
+        erc721.execute_multisig_action("anyone".to_string(), pause).unwrap();
+        assert!(erc721.paused());
+
+        // Proposals lapse after the expiry, and the multisig governs its own settings
+        let unpause = erc721.propose_multisig("alice".to_string(), AdminAction::Unpause).unwrap();
+        erc721.advance_time(3_600);
+        assert!(erc721.confirm_multisig_action("bob".to_string(), unpause).is_err());
+        let disable = AdminAction::ConfigureMultisig {
+            signers: Vec::new(),
+            threshold: 0,
+            expiry: 0,
+        };
+        let disable = erc721.propose_multisig("alice".to_string(), disable).unwrap();
+        erc721.confirm_multisig_action("bob".to_string(), disable).unwrap();
+        erc721.execute_multisig_action("anyone".to_string(), disable).unwrap();
+        assert_eq!(erc721.multisig_threshold(), 0);
+        assert!(erc721.unpause("owner".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_guardian() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.assign_role("owner".to_string(), GUARDIAN_ROLE.to_string(), "guardian".to_string()).unwrap();
+        erc721.set_timelock_delay("owner".to_string(), 3_600).unwrap();
+
+        // Guardians veto queued actions but cannot queue or apply admin operations themselves
+        let royalty = AdminAction::SetDefaultRoyalty {
+            receiver: "attacker".to_string(),
+            bps: 9_000,
+        };
+        let royalty = erc721.queue_action("owner".to_string(), royalty).unwrap();
+        assert!(erc721.veto_admin_action("owner".to_string(), royalty).is_err());
+        erc721.veto_admin_action("guardian".to_string(), royalty).unwrap();
+        assert_eq!(erc721.admin_action_eta(royalty), None);
+        assert!(erc721.queue_action("guardian".to_string(), AdminAction::Unpause).is_err());
+        assert!(erc721.set_base_uri("guardian".to_string(), "ipfs://x/".to_string()).is_err());
+
+        // The emergency pause skips the timelock; lifting it still needs a pauser
+        assert!(erc721.emergency_pause("owner".to_string()).is_err());
+        erc721.emergency_pause("guardian".to_string()).unwrap();
+        assert!(erc721.paused());
+        assert!(erc721.unpause("guardian".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_connected_caller() {
+        crate::caller::set_test_mode(true);
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        erc721.set_approval_for_all("owner".to_string(), MARKETPLACE_OPERATOR.to_string(), true).unwrap();
+        erc721.list("owner".to_string(), 1, 100).unwrap();
+        erc721.delegate("owner".to_string(), "owner".to_string()).unwrap();
+        erc721.set_governor_settings("owner".to_string(), 10, 100, 1, 5_000).unwrap();
+        erc721.advance_time(1);
+        let actions = vec![Operation::Transfer {
+            from: "owner".to_string(),
+            to: "user2".to_string(),
+            token_id: 2,
+        }];
+        let proposal_id = erc721.propose_actions("owner".to_string(), "Gift #2".to_string(), actions).unwrap();
+        erc721.advance_time(11);
+
+        erc721.connect(&Caller::impersonate("user1".to_string()).unwrap());
+        assert_eq!(erc721.sender(), Some("user1".to_string()));
+        assert!(erc721.deposit("user2".to_string(), 100).is_err());
+        assert!(erc721.mint("owner".to_string(), 3).is_err());
+
+        // Another maker's approved tokens cannot be escrowed, nor another holder's votes spent
+        let offered = SwapSide::new(vec![2], 0);
+        let swap = erc721.create_swap("owner".to_string(), "user1".to_string(), offered, SwapSide::default(), 2_000);
+        assert!(swap.is_err());
+        assert_eq!(erc721.owner_of(2), Some("owner".to_string()));
+        assert!(erc721.cast_vote(proposal_id, "owner".to_string(), VoteType::For).is_err());
+
+        // Marketplace transfers run on the contract's behalf, not the connected caller's
+        erc721.deposit("user1".to_string(), 100).unwrap();
+        erc721.buy("user1".to_string(), 1).unwrap();
+        assert_eq!(erc721.owner_of(1).unwrap(), "user1".to_string());
+        assert!(erc721.transfer("owner".to_string(), "user2".to_string(), 1).is_err());
+
+        erc721.disconnect();
+        assert!(erc721.mint("owner".to_string(), 3).is_ok());
+        assert!(erc721.cast_vote(proposal_id, "owner".to_string(), VoteType::For).is_ok());
+    }
+
+    #[test]
+    fn test_event_queries_and_retention() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.set_block_timestamp(1_000);
+        erc721.mint("owner".to_string(), 1).unwrap();
+        erc721.mint("owner".to_string(), 2).unwrap();
+        erc721.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+
+        let filter = EventFilter {
+            kinds: Some(vec!["Transfer".to_string()]),
+            addresses: Some(vec!["user1".to_string()]),
+            ..EventFilter::default()
+        };
+        let transfers = erc721.events(&filter);
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].token_id, Some(1));
+        let filter = EventFilter {
+            token_id: Some(1),
+            ..EventFilter::default()
+        };
+        assert_eq!(erc721.events(&filter).len(), 2);
+
+        let last_seq = erc721.log_len() - 1;
+        assert!(erc721.set_event_retention("user1".to_string(), Some(2), Some(60_000)).is_err());
+        erc721.set_event_retention("owner".to_string(), Some(2), Some(60_000)).unwrap();
+        assert_eq!(erc721.log_len(), 2);
+        let update = erc721.log_range(last_seq + 1, last_seq + 2).remove(0);
+        assert_eq!((update.kind.as_str(), update.from), ("RetentionUpdated", Some("owner".to_string())));
+        erc721.advance_time(61);
+        assert!(erc721.events(&EventFilter::default()).is_empty());
+        assert!(erc721.history(1).is_empty() && erc721.history_by_address("user1".to_string()).is_empty());
+        assert!(erc721.log_range(0, u64::MAX).is_empty());
+        assert_eq!(erc721.log_len(), 0);
+
+        erc721.mint("owner".to_string(), 3).unwrap();
+        let events = erc721.events(&EventFilter::default());
+        assert_eq!((events.len(), events[0].seq), (1, last_seq + 2));
+        assert!(erc721.set_event_retention("owner".to_string(), Some(0), None).is_err());
     }
 }
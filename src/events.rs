@@ -0,0 +1,338 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::migration::{deserialize_since, serialize_since};
+
+/// Denetim günlüğündeki tek bir kayıt
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub seq: u64, // Sıra numarası (0'dan başlar, boşluksuz artar)
+    pub timestamp: u64, // Unix zamanı (ms)
+    pub kind: String, // Mint, Transfer, Approval, Burn, RoleGranted ...
+    pub token_id: Option<u64>,
+    pub range_end: Option<u64>, // Toplu işlemlerde aralığın son Token ID'si (dahil)
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub details: String,
+}
+
+impl LogEntry {
+    /// Sıra numarası ve zaman damgası günlüğe eklenirken atanan yeni bir kayıt oluşturur
+    pub(crate) fn new(kind: &str) -> LogEntry {
+        LogEntry {
+            seq: 0,
+            timestamp: 0,
+            kind: kind.to_string(),
+            token_id: None,
+            range_end: None,
+            from: None,
+            to: None,
+            details: String::new(),
+        }
+    }
+
+    pub(crate) fn token(mut self, token_id: u64) -> LogEntry {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    pub(crate) fn token_range(mut self, start: u64, end: u64) -> LogEntry {
+        self.token_id = Some(start);
+        self.range_end = Some(end);
+        self
+    }
+
+    pub(crate) fn from(mut self, address: &str) -> LogEntry {
+        self.from = Some(address.to_string());
+        self
+    }
+
+    pub(crate) fn to(mut self, address: &str) -> LogEntry {
+        self.to = Some(address.to_string());
+        self
+    }
+
+    pub(crate) fn details(mut self, details: String) -> LogEntry {
+        self.details = details;
+        self
+    }
+
+    /// Kaydın belirtilen token'ı kapsayıp kapsamadığını döner
+    fn involves_token(&self, token_id: u64) -> bool {
+        match (self.token_id, self.range_end) {
+            (Some(start), Some(end)) => (start..=end).contains(&token_id),
+            (Some(id), None) => id == token_id,
+            _ => false,
+        }
+    }
+
+    /// Kaydın belirtilen adresi içerip içermediğini döner
+    fn involves_address(&self, address: &str) -> bool {
+        self.from.as_deref() == Some(address) || self.to.as_deref() == Some(address)
+    }
+}
+
+/// `get_events` sorgusu (`eth_getLogs` karşılığı); verilmeyen alanlar filtrelemez
+///
+/// `kinds` ve `addresses` listelerinden herhangi biriyle eşleşen kayıtlar döner; adres gönderen
+/// veya alıcı olabilir. Sıra numarası aralığı iki uçta da dahildir.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Tsify)]
+#[serde(default)]
+pub struct EventFilter {
+    #[tsify(optional)]
+    pub kinds: Option<Vec<String>>,
+    #[tsify(optional)]
+    pub addresses: Option<Vec<String>>,
+    #[tsify(optional)]
+    pub token_id: Option<u64>,
+    #[tsify(optional)]
+    pub from_seq: Option<u64>,
+    #[tsify(optional)]
+    pub to_seq: Option<u64>,
+    #[tsify(optional)]
+    pub limit: Option<usize>, // En fazla bu kadar kayıt (en eskiden başlayarak)
+}
+
+impl EventFilter {
+    fn matches(&self, entry: &LogEntry) -> bool {
+        self.kinds.as_ref().is_none_or(|kinds| kinds.contains(&entry.kind))
+            && self
+                .addresses
+                .as_ref()
+                .is_none_or(|addresses| addresses.iter().any(|address| entry.involves_address(address)))
+            && self.token_id.is_none_or(|token_id| entry.involves_token(token_id))
+            && self.from_seq.is_none_or(|from| entry.seq >= from)
+            && self.to_seq.is_none_or(|to| entry.seq <= to)
+    }
+}
+
+/// Günlükte tutulacak kayıtların sınırları; sınır yoksa günlük sınırsız büyür
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Retention {
+    max_entries: Option<u64>,
+    ttl_ms: Option<u64>, // Bu süreden eski kayıtlar atılır
+}
+
+impl Retention {
+    pub(crate) fn new(max_entries: Option<u64>, ttl_ms: Option<u64>) -> Result<Retention, String> {
+        if max_entries == Some(0) || ttl_ms == Some(0) {
+            return Err("Retention limits must be greater than zero".to_string());
+        }
+        Ok(Retention { max_entries, ttl_ms })
+    }
+
+    fn expired(&self, entry: &LogEntry, now: u64) -> bool {
+        self.ttl_ms.is_some_and(|ttl| now.saturating_sub(entry.timestamp) > ttl)
+    }
+}
+
+/// Sona ekleme yapılan, isteğe bağlı olarak boyut ve yaş sınırlı (halka tampon) denetim günlüğü
+///
+/// Sıra numaraları eski kayıtlar atılsa da boşluksuz artmaya devam eder.
+/// (Günlük düzeni sürüm 8'de `retention` ve `next_seq` ile genişledi.)
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct AuditLog {
+    entries: VecDeque<LogEntry>,
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<8, _, _>",
+        deserialize_with = "deserialize_since::<8, _, _>"
+    )]
+    retention: Retention,
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<8, _, _>",
+        deserialize_with = "deserialize_since::<8, _, _>"
+    )]
+    next_seq: u64, // Günlük boşaldığında verilecek sıra numarası
+}
+
+impl AuditLog {
+    /// Kayda sıra numarası ve zaman damgası atayıp günlüğe ekler, sınırları aşan eski kayıtları
+    /// atar ve eklenen kaydı döner
+    pub(crate) fn record(&mut self, mut entry: LogEntry, timestamp: u64) -> &LogEntry {
+        entry.seq = self.next_seq();
+        entry.timestamp = timestamp;
+        self.next_seq = entry.seq + 1;
+        self.entries.push_back(entry);
+        self.prune(timestamp);
+        self.entries.back().expect("entry was just pushed")
+    }
+
+    /// Sınırları ayarlar ve `now` anına göre aşan kayıtları hemen atar
+    pub(crate) fn set_retention(&mut self, retention: Retention, now: u64) {
+        self.next_seq = self.next_seq();
+        self.retention = retention;
+        self.prune(now);
+    }
+
+    fn prune(&mut self, now: u64) {
+        let max_entries = self.retention.max_entries.unwrap_or(u64::MAX);
+        while self.len() > max_entries || self.entries.front().is_some_and(|e| self.retention.expired(e, now)) {
+            self.entries.pop_front();
+        }
+    }
+
+    fn next_seq(&self) -> u64 {
+        self.entries.back().map_or(self.next_seq, |entry| entry.seq + 1)
+    }
+
+    /// `now` anında süresi dolmamış kayıtlar; atılmayı bekleyen eski kayıtlar okumalarda görünmez
+    fn live(&self, now: u64) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().filter(move |entry| !self.retention.expired(entry, now))
+    }
+
+    /// Filtreyle eşleşen ve `now` anında süresi dolmamış kayıtları sırayla döner
+    pub(crate) fn query(&self, filter: &EventFilter, now: u64) -> Vec<LogEntry> {
+        self.live(now)
+            .filter(|entry| filter.matches(entry))
+            .take(filter.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+
+    /// Saklanan (süresi dolmuş ama henüz atılmamışlar dahil) kayıt sayısı
+    pub(crate) fn len(&self) -> u64 {
+        self.entries.len() as u64
+    }
+
+    /// `now` anında süresi dolmamış kayıt sayısı
+    pub(crate) fn live_len(&self, now: u64) -> u64 {
+        self.live(now).count() as u64
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+
+    /// Belirtilen token'ı etkileyen (süresi dolmamış) kayıtları döner
+    pub(crate) fn history(&self, token_id: u64, now: u64) -> Vec<LogEntry> {
+        self.live(now).filter(|e| e.involves_token(token_id)).cloned().collect()
+    }
+
+    /// Belirtilen adresin gönderen veya alıcı olduğu (süresi dolmamış) kayıtları döner
+    pub(crate) fn history_by_address(&self, address: &str, now: u64) -> Vec<LogEntry> {
+        self.live(now).filter(|e| e.involves_address(address)).cloned().collect()
+    }
+
+    /// `[from, to)` sıra numarası aralığındaki atılmamış ve süresi dolmamış kayıtları döner
+    pub(crate) fn range(&self, from: u64, to: u64, now: u64) -> Vec<LogEntry> {
+        let first = self.entries.front().map_or(0, |entry| entry.seq);
+        let end = to.saturating_sub(first).min(self.len()) as usize;
+        let start = (from.saturating_sub(first) as usize).min(end);
+        self.entries
+            .range(start..end)
+            .filter(|entry| !self.retention.expired(entry, now))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Rust tarafından kaydedilen olay dinleyicisi
+pub type RustListener = Rc<dyn Fn(&LogEntry)>;
+
+#[derive(Clone)]
+enum Listener {
+    Rust(RustListener),
+    // Fırlatılan hatalar yok sayılır
+    Js(js_sys::Function),
+}
+
+/// Günlüğe eklenen kayıtları kayıt sırasıyla bildirilen dinleyiciler
+#[derive(Clone, Default)]
+pub(crate) struct Listeners {
+    entries: Vec<Listener>,
+}
+
+impl Listeners {
+    pub(crate) fn add_rust(&mut self, listener: RustListener) {
+        self.entries.push(Listener::Rust(listener));
+    }
+
+    pub(crate) fn add_js(&mut self, function: js_sys::Function) {
+        self.entries.push(Listener::Js(function));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn notify(&self, entry: &LogEntry) {
+        for listener in &self.entries {
+            match listener {
+                Listener::Rust(listener) => listener(entry),
+                Listener::Js(function) => {
+                    let _ = function.call1(&JsValue::NULL, &JsValue::from(entry.clone()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_log_queries() {
+        let mut log = AuditLog::default();
+        log.record(LogEntry::new("BatchMint").token_range(1, 10).to("owner"), 100);
+        log.record(LogEntry::new("Transfer").token(3).from("owner").to("user1"), 200);
+        log.record(LogEntry::new("Mint").token(20).to("owner"), 300);
+
+        assert_eq!(log.history(3, 300).len(), 2);
+        assert_eq!(log.history(20, 300)[0].seq, 2);
+        assert_eq!(log.history_by_address("user1", 300)[0].timestamp, 200);
+        assert_eq!(log.range(1, 99, 300).len(), 2);
+        assert!(log.range(5, 2, 300).is_empty());
+
+        let filter = EventFilter {
+            kinds: Some(vec!["Transfer".to_string(), "Mint".to_string()]),
+            addresses: Some(vec!["owner".to_string()]),
+            ..EventFilter::default()
+        };
+        assert_eq!(log.query(&filter, 300).iter().map(|e| e.seq).collect::<Vec<_>>(), vec![1, 2]);
+        let filter = EventFilter {
+            token_id: Some(5),
+            to_seq: Some(1),
+            ..EventFilter::default()
+        };
+        assert_eq!(log.query(&filter, 300).len(), 1);
+    }
+
+    #[test]
+    fn test_bounded_audit_log() {
+        let mut log = AuditLog::default();
+        log.set_retention(Retention::new(Some(2), Some(1_000)).unwrap(), 0);
+        for timestamp in [100, 200, 300] {
+            log.record(LogEntry::new("Mint"), timestamp);
+        }
+
+        // The oldest entry is evicted but sequence numbers keep counting
+        assert_eq!(log.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(log.range(0, 2, 300).len(), 1);
+
+        // Entries older than the ttl are hidden from every read and dropped on the next write
+        assert_eq!(log.query(&EventFilter::default(), 1_250).len(), 1);
+        assert_eq!(log.range(0, 3, 1_250).len(), 1);
+        assert_eq!((log.len(), log.live_len(1_250)), (2, 1));
+        log.record(LogEntry::new("Transfer").token(7).to("user1"), 1_300);
+        assert!(log.history(7, 2_400).is_empty());
+        assert!(log.history_by_address("user1", 2_400).is_empty());
+        assert_eq!(log.history(7, 2_000).len(), 1);
+        log.record(LogEntry::new("Burn"), 2_000);
+        assert_eq!(log.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![3, 4]);
+        log.set_retention(Retention::default(), 5_000);
+        assert_eq!(log.record(LogEntry::new("Mint"), 5_000).seq, 5);
+
+        assert!(Retention::new(Some(0), None).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+use crate::erc721::ERC721;
+use crate::utils::log_event;
+
+/// Parçalanan tokenların kaynak koleksiyonda tutulduğu adres
+pub const FRACTION_VAULT: &str = "fraction-vault";
+
+/// Tek bir token'ın kasası ve ERC-20 benzeri pay defteri
+#[derive(Clone, Debug)]
+struct Vault {
+    curator: String, // Token'ı parçalayan adres
+    reserve_price: u64, // Tüm payların toplam satın alma bedeli (simüle ETH)
+    total_shares: u64,
+    balances: BTreeMap<String, u64>, // Adres -> Pay Miktarı
+}
+
+impl Vault {
+    fn balance_of(&self, holder: &str) -> u64 {
+        self.balances.get(holder).copied().unwrap_or(0)
+    }
+
+    /// Payların `reserve_price` üzerinden değeri (aşağı yuvarlanır)
+    fn value_of(&self, shares: u64) -> u64 {
+        (self.reserve_price as u128 * shares as u128 / self.total_shares as u128) as u64
+    }
+}
+
+/// Tokenları kasaya kilitleyip takas edilebilir paylara bölen modül
+///
+/// Tüm payları satın alan (veya zaten elinde tutan) adres token'ı kasadan geri alır.
+/// Her çağrıda aynı kaynak örneği verilmelidir.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct Fractionalizer {
+    vaults: BTreeMap<u64, Vault>, // Token ID -> Kasa
+}
+
+#[wasm_bindgen]
+impl Fractionalizer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Fractionalizer {
+        Fractionalizer::default()
+    }
+
+    /// Token'ı kasaya kilitler ve tüm payları yatırana verir
+    pub fn fractionalize(
+        &mut self,
+        owner: String,
+        source: &mut ERC721,
+        token_id: u64,
+        shares: u64,
+        reserve_price: u64,
+    ) -> Result<(), String> {
+        if shares == 0 {
+            return Err("Shares must be greater than zero".to_string());
+        }
+        source
+            .transfer(owner.clone(), FRACTION_VAULT.to_string(), token_id)
+            .inspect_err(|err| log_event("Fractionalization Failed", err))?;
+
+        self.vaults.insert(
+            token_id,
+            Vault {
+                curator: owner.clone(),
+                reserve_price,
+                total_shares: shares,
+                balances: BTreeMap::from([(owner.clone(), shares)]),
+            },
+        );
+        log_event(
+            "Token Fractionalized",
+            &format!("Token ID: {}, Curator: {}, Shares: {}, Reserve: {}", token_id, owner, shares, reserve_price),
+        );
+        Ok(())
+    }
+
+    /// Payları başka bir adrese aktarır
+    pub fn transfer_shares(&mut self, from: String, to: String, token_id: u64, amount: u64) -> Result<(), String> {
+        let vault = self.vaults.get_mut(&token_id).ok_or("Token is not fractionalized")?;
+        let balance = vault.balance_of(&from);
+        if balance < amount {
+            return Err(format!("Insufficient shares: {}", balance));
+        }
+
+        if balance == amount {
+            vault.balances.remove(&from);
+        } else {
+            vault.balances.insert(from.clone(), balance - amount);
+        }
+        *vault.balances.entry(to.clone()).or_default() += amount;
+        log_event(
+            "Shares Transferred",
+            &format!("Token ID: {}, From: {}, To: {}, Amount: {}", token_id, from, to, amount),
+        );
+        Ok(())
+    }
+
+    /// Alıcının diğer pay sahiplerine ödemesi gereken toplam tutarı döner
+    pub fn buyout_price(&self, bidder: String, token_id: u64) -> Result<u64, String> {
+        let vault = self.vaults.get(&token_id).ok_or("Token is not fractionalized")?;
+        Ok(vault
+            .balances
+            .iter()
+            .filter(|(holder, _)| **holder != bidder)
+            .map(|(_, &shares)| vault.value_of(shares))
+            .sum())
+    }
+
+    /// Kalan tüm payları satın alır ve token'ı alıcıya geri birleştirir (atomik)
+    ///
+    /// Her pay sahibine payı oranında `reserve_price`'tan ödeme yapılır, ardından paylar yakılır.
+    pub fn buyout(&mut self, bidder: String, source: &mut ERC721, token_id: u64) -> Result<(), String> {
+        let vault = self.vaults.get(&token_id).ok_or("Token is not fractionalized")?;
+        let price = self.buyout_price(bidder.clone(), token_id)?;
+
+        // Ödemeler ve token'ın kasadan çıkışı durum değişmeden önce doğrulanır
+        let payouts: Vec<(String, u64)> = vault
+            .balances
+            .iter()
+            .filter(|(holder, _)| **holder != bidder)
+            .map(|(holder, &shares)| (holder.clone(), vault.value_of(shares)))
+            .collect();
+        source
+            .bank_mut()
+            .ensure_payouts(&bidder, &payouts)
+            .inspect_err(|err| log_event("Buyout Failed", err))?;
+        let release = source
+            .check_transfer(FRACTION_VAULT, &bidder, token_id)
+            .inspect_err(|err| log_event("Buyout Failed", err))?;
+        source.bank_mut().pay_out(&bidder, &payouts)?;
+        source.apply_transfer(release);
+
+        self.vaults.remove(&token_id);
+        log_event("Token Bought Out", &format!("Token ID: {}, Buyer: {}, Price: {}", token_id, bidder, price));
+        Ok(())
+    }
+
+    /// Adresin token için sahip olduğu pay miktarını döner
+    pub fn share_balance(&self, token_id: u64, holder: String) -> u64 {
+        self.vaults.get(&token_id).map(|vault| vault.balance_of(&holder)).unwrap_or(0)
+    }
+
+    /// Token'ın toplam pay arzını döner (parçalanmamışsa 0)
+    pub fn total_shares(&self, token_id: u64) -> u64 {
+        self.vaults.get(&token_id).map(|vault| vault.total_shares).unwrap_or(0)
+    }
+
+    /// Token'ı parçalayan adresi döner
+    pub fn curator(&self, token_id: u64) -> Option<String> {
+        self.vaults.get(&token_id).map(|vault| vault.curator.clone())
+    }
+
+    /// Tüm payların toplam satın alma bedelini döner
+    pub fn reserve_price(&self, token_id: u64) -> Option<u64> {
+        self.vaults.get(&token_id).map(|vault| vault.reserve_price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::HookKind;
+
+    #[test]
+    fn test_fractionalize_and_buyout() {
+        let mut source = ERC721::new("owner".to_string());
+        source.mint("owner".to_string(), 1).unwrap();
+        let mut fractions = Fractionalizer::new();
+
+        assert!(fractions.fractionalize("user1".to_string(), &mut source, 1, 100, 1_000).is_err());
+        fractions.fractionalize("owner".to_string(), &mut source, 1, 100, 1_000).unwrap();
+        assert_eq!(source.owner_of(1), Some(FRACTION_VAULT.to_string()));
+
+        fractions.transfer_shares("owner".to_string(), "user1".to_string(), 1, 30).unwrap();
+        fractions.transfer_shares("owner".to_string(), "user2".to_string(), 1, 10).unwrap();
+        assert!(fractions.transfer_shares("user2".to_string(), "user1".to_string(), 1, 11).is_err());
+
+        // user1 already holds 30 shares, so it only pays for the other 70
+        assert_eq!(fractions.buyout_price("user1".to_string(), 1), Ok(700));
+        source.deposit("user1".to_string(), 699).unwrap();
+        assert!(fractions.buyout("user1".to_string(), &mut source, 1).is_err());
+
+        // A vault release rejected by a hook is caught before any holder is paid
+        source.deposit("user1".to_string(), 1).unwrap();
+        source.add_hook(HookKind::BeforeTransfer, |_| Err("Frozen".to_string()));
+        assert!(fractions.buyout("user1".to_string(), &mut source, 1).is_err());
+        assert_eq!(source.eth_balance("user1".to_string()), 700);
+
+        source.clear_hooks();
+        fractions.buyout("user1".to_string(), &mut source, 1).unwrap();
+        assert_eq!(source.owner_of(1), Some("user1".to_string()));
+        assert_eq!(source.eth_balance("owner".to_string()), 600);
+        assert_eq!(source.eth_balance("user2".to_string()), 100);
+        assert_eq!(fractions.total_shares(1), 0);
+    }
+}
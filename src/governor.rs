@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use wasm_bindgen::prelude::*;
+
+use crate::erc721::Operation;
+use crate::votes::Votes;
+
+/// Teklifin yaşam döngüsündeki durumu
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalState {
+    Pending, // Oylama henüz başlamadı
+    Active,
+    Defeated, // Yeter sayıya ulaşılamadı veya ret oyları kazandı
+    Succeeded,
+    Executed,
+}
+
+/// Oy tercihi (OpenZeppelin `GovernorCountingSimple` ile aynı)
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoteType {
+    Against,
+    For,
+    Abstain, // Yeter sayıya dahil edilir ama sonucu etkilemez
+}
+
+/// Yönetişim ayarları
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct GovernorSettings {
+    pub(crate) voting_delay: u64, // Teklif ile oylamanın başlaması arasındaki süre (saniye)
+    pub(crate) voting_period: u64, // Oylamanın süresi (saniye)
+    pub(crate) proposal_threshold: u64, // Teklif verebilmek için gereken oy gücü
+    pub(crate) quorum_bps: u32, // Yeter sayı: anlık görüntüdeki toplam arzın baz puanı
+}
+
+impl Default for GovernorSettings {
+    fn default() -> GovernorSettings {
+        GovernorSettings {
+            voting_delay: 1,
+            voting_period: 7 * 24 * 60 * 60,
+            proposal_threshold: 0,
+            quorum_bps: 400,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Proposal {
+    proposer: String,
+    description: String,
+    actions: Vec<Operation>,
+    snapshot: u64, // Oy gücünün ölçüldüğü an; oylama bu andan sonra başlar
+    deadline: u64, // Oylamanın son anı (dahil)
+    votes: [u64; 3], // Ret, Kabul, Çekimser
+    voters: BTreeSet<String>,
+    executed: bool,
+}
+
+/// Oy gücünü ERC721Votes checkpoint'lerinden alan teklif ve oylama modülü
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Governor {
+    settings: GovernorSettings,
+    proposals: BTreeMap<u64, Proposal>, // Teklif ID -> Teklif
+    next_proposal_id: u64,
+}
+
+impl Governor {
+    /// Ayarları aynı, teklifi olmayan kopya (`clone_with` için)
+    pub(crate) fn settings_only(&self) -> Governor {
+        Governor {
+            settings: self.settings.clone(),
+            ..Governor::default()
+        }
+    }
+
+    pub(crate) fn configure(&mut self, settings: GovernorSettings) -> Result<(), String> {
+        if settings.voting_period == 0 {
+            return Err("Voting period must be greater than zero".to_string());
+        }
+        if settings.quorum_bps > 10_000 {
+            return Err("Quorum cannot exceed 10000 basis points".to_string());
+        }
+        self.settings = settings;
+        Ok(())
+    }
+
+    /// Teklif oluşturur ve ID'sini döner; teklif verenin bir önceki saniyedeki oy gücü eşiği geçmelidir
+    pub(crate) fn propose(
+        &mut self,
+        proposer: &str,
+        description: String,
+        actions: Vec<Operation>,
+        votes: &Votes,
+        now: u64,
+    ) -> Result<u64, String> {
+        if actions.is_empty() {
+            return Err("Proposal must contain at least one action".to_string());
+        }
+        let power = votes.past_votes(proposer, now.saturating_sub(1));
+        if power < self.settings.proposal_threshold {
+            return Err(format!("Proposer votes below threshold: {}", power));
+        }
+
+        let snapshot = now.saturating_add(self.settings.voting_delay);
+        let id = self.next_proposal_id;
+        self.next_proposal_id += 1;
+        self.proposals.insert(
+            id,
+            Proposal {
+                proposer: proposer.to_string(),
+                description,
+                actions,
+                snapshot,
+                deadline: snapshot.saturating_add(self.settings.voting_period),
+                votes: [0; 3],
+                voters: BTreeSet::new(),
+                executed: false,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Oyu kaydeder ve kullanılan oy gücünü döner
+    pub(crate) fn cast_vote(
+        &mut self,
+        id: u64,
+        voter: &str,
+        support: VoteType,
+        votes: &Votes,
+        now: u64,
+    ) -> Result<u64, String> {
+        if self.state(id, votes, now)? != ProposalState::Active {
+            return Err("Proposal is not active".to_string());
+        }
+        let proposal = self.proposals.get_mut(&id).expect("proposal exists");
+        if !proposal.voters.insert(voter.to_string()) {
+            return Err("Account has already voted".to_string());
+        }
+        let weight = votes.past_votes(voter, proposal.snapshot);
+        proposal.votes[support as usize] += weight;
+        Ok(weight)
+    }
+
+    pub(crate) fn state(&self, id: u64, votes: &Votes, now: u64) -> Result<ProposalState, String> {
+        let proposal = self.proposals.get(&id).ok_or("Proposal does not exist")?;
+        let [against, for_votes, abstain] = proposal.votes;
+        Ok(if proposal.executed {
+            ProposalState::Executed
+        } else if now <= proposal.snapshot {
+            ProposalState::Pending
+        } else if now <= proposal.deadline {
+            ProposalState::Active
+        } else if for_votes + abstain >= self.quorum(votes, proposal.snapshot) && for_votes > against {
+            ProposalState::Succeeded
+        } else {
+            ProposalState::Defeated
+        })
+    }
+
+    /// Anlık görüntüdeki toplam arza göre gereken yeter sayı
+    pub(crate) fn quorum(&self, votes: &Votes, timepoint: u64) -> u64 {
+        (votes.past_total_supply(timepoint) as u128 * self.settings.quorum_bps as u128 / 10_000) as u64
+    }
+
+    /// Başarılı teklifin işlemlerini döner; işlemler uygulandıktan sonra `mark_executed` çağrılmalıdır
+    pub(crate) fn executable_actions(&self, id: u64, votes: &Votes, now: u64) -> Result<Vec<Operation>, String> {
+        if self.state(id, votes, now)? != ProposalState::Succeeded {
+            return Err("Proposal has not succeeded".to_string());
+        }
+        Ok(self.proposals[&id].actions.clone())
+    }
+
+    pub(crate) fn mark_executed(&mut self, id: u64) {
+        if let Some(proposal) = self.proposals.get_mut(&id) {
+            proposal.executed = true;
+        }
+    }
+
+    /// Ret, kabul ve çekimser oy toplamlarını döner
+    pub(crate) fn proposal_votes(&self, id: u64) -> Option<[u64; 3]> {
+        self.proposals.get(&id).map(|proposal| proposal.votes)
+    }
+
+    pub(crate) fn has_voted(&self, id: u64, account: &str) -> bool {
+        self.proposals
+            .get(&id)
+            .map(|proposal| proposal.voters.contains(account))
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn proposer(&self, id: u64) -> Option<&str> {
+        self.proposals.get(&id).map(|proposal| proposal.proposer.as_str())
+    }
+
+    pub(crate) fn description(&self, id: u64) -> Option<&str> {
+        self.proposals.get(&id).map(|proposal| proposal.description.as_str())
+    }
+
+    /// Teklifin oylama başlangıcı ve bitişi
+    pub(crate) fn schedule(&self, id: u64) -> Option<(u64, u64)> {
+        self.proposals.get(&id).map(|proposal| (proposal.snapshot, proposal.deadline))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action() -> Vec<Operation> {
+        vec![Operation::Mint { owner: "owner".to_string(), token_id: 1 }]
+    }
+
+    #[test]
+    fn test_proposal_lifecycle() {
+        let mut votes = Votes::default();
+        votes.transfer_units(None, Some("alice"), 6, 0);
+        votes.transfer_units(None, Some("bob"), 4, 0);
+        votes.delegate("alice", "alice", 0);
+        votes.delegate("bob", "bob", 0);
+
+        let mut governor = Governor::default();
+        governor
+            .configure(GovernorSettings {
+                voting_delay: 10,
+                voting_period: 100,
+                proposal_threshold: 5,
+                quorum_bps: 5_000,
+            })
+            .unwrap();
+        assert!(governor.propose("bob", "Too small".to_string(), action(), &votes, 5).is_err());
+        let id = governor.propose("alice", "Mint #1".to_string(), action(), &votes, 5).unwrap();
+
+        assert_eq!(governor.state(id, &votes, 15), Ok(ProposalState::Pending));
+        assert!(governor.cast_vote(id, "alice", VoteType::For, &votes, 15).is_err());
+        assert_eq!(governor.cast_vote(id, "alice", VoteType::For, &votes, 16), Ok(6));
+        assert!(governor.cast_vote(id, "alice", VoteType::Against, &votes, 17).is_err());
+        assert_eq!(governor.cast_vote(id, "bob", VoteType::Against, &votes, 17), Ok(4));
+        assert_eq!(governor.state(id, &votes, 115), Ok(ProposalState::Active));
+
+        assert_eq!(governor.state(id, &votes, 116), Ok(ProposalState::Succeeded));
+        assert_eq!(governor.executable_actions(id, &votes, 116).unwrap().len(), 1);
+        governor.mark_executed(id);
+        assert_eq!(governor.state(id, &votes, 116), Ok(ProposalState::Executed));
+    }
+
+    #[test]
+    fn test_quorum_not_reached() {
+        let mut votes = Votes::default();
+        votes.transfer_units(None, Some("alice"), 1, 0);
+        votes.transfer_units(None, Some("bob"), 9, 0);
+        votes.delegate("alice", "alice", 0);
+
+        let mut governor = Governor::default();
+        let id = governor.propose("alice", "Mint #1".to_string(), action(), &votes, 5).unwrap();
+        governor.configure(GovernorSettings { quorum_bps: 2_000, ..GovernorSettings::default() }).unwrap();
+        governor.cast_vote(id, "alice", VoteType::For, &votes, 7).unwrap();
+
+        let (_, deadline) = governor.schedule(id).unwrap();
+        assert_eq!(governor.quorum(&votes, 6), 2);
+        assert_eq!(governor.state(id, &votes, deadline + 1), Ok(ProposalState::Defeated));
+        assert!(governor.executable_actions(id, &votes, deadline + 1).is_err());
+    }
+}
@@ -0,0 +1,272 @@
+use tiny_keccak::{Hasher, Keccak};
+use wasm_bindgen::prelude::*;
+
+use crate::bytes::{decode_hex, to_hex};
+
+/// Ethereum ile uyumlu keccak256 özeti
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+/// EIP-191 `personal_sign` özeti: `keccak256("\x19Ethereum Signed Message:\n" + uzunluk + mesaj)`
+pub fn personal_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    data.extend_from_slice(message);
+    keccak256(&data)
+}
+
+/// Solidity `abi.encodePacked` karşılığı: her değeri türüne göre dolgusuz kodlayıp art arda ekler
+///
+/// Desteklenen türler: `address`, `bool`, `string`, `bytes`, `bytes1`..`bytes32`, `uint8`..`uint256`
+/// ve `int8`..`int256`. Sayılar ondalık veya `0x` önekli hex, baytlar hex olarak verilir.
+pub fn encode_packed(types: &[String], values: &[String]) -> Result<Vec<u8>, String> {
+    if types.len() != values.len() {
+        return Err(format!("Expected {} values, got {}", types.len(), values.len()));
+    }
+
+    let mut data = Vec::new();
+    for (kind, value) in types.iter().zip(values) {
+        data.extend(encode_packed_value(kind, value)?);
+    }
+    Ok(data)
+}
+
+fn encode_packed_value(kind: &str, value: &str) -> Result<Vec<u8>, String> {
+    match kind {
+        "address" => match decode_hex(value) {
+            Ok(bytes) if bytes.len() == 20 => Ok(bytes),
+            _ => Err(format!("Invalid address: {}", value)),
+        },
+        "bool" => match value {
+            "true" => Ok(vec![1]),
+            "false" => Ok(vec![0]),
+            _ => Err(format!("Invalid bool: {}", value)),
+        },
+        "string" => Ok(value.as_bytes().to_vec()),
+        "bytes" => Ok(decode_hex(value)?),
+        _ => {
+            if let Some(size) = kind.strip_prefix("bytes") {
+                let size = parse_size(kind, size, 1..=32)?;
+                let bytes = decode_hex(value)?;
+                if bytes.len() != size {
+                    return Err(format!("Expected {} bytes for {}, got {}", size, kind, bytes.len()));
+                }
+                Ok(bytes)
+            } else if let Some(bits) = kind.strip_prefix("uint") {
+                let bytes = parse_bits(kind, bits)? / 8;
+                let word = parse_uint(value)?;
+                if word[..32 - bytes].iter().any(|&byte| byte != 0) {
+                    return Err(format!("Value {} does not fit in {}", value, kind));
+                }
+                Ok(word[32 - bytes..].to_vec())
+            } else if let Some(bits) = kind.strip_prefix("int") {
+                encode_int(kind, parse_bits(kind, bits)? / 8, value)
+            } else {
+                Err(format!("Unsupported type: {}", kind))
+            }
+        }
+    }
+}
+
+/// İşaretli tamsayıyı `bytes` baytlık ikiye tümleyen (two's complement) gösterimle kodlar
+fn encode_int(kind: &str, bytes: usize, value: &str) -> Result<Vec<u8>, String> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let mut word = parse_uint(digits)?;
+
+    // |değer| en fazla 2^(8*bytes-1) - 1 (pozitif) veya 2^(8*bytes-1) (negatif) olabilir
+    let start = 32 - bytes;
+    let sign_bit = word[start] & 0x80 != 0;
+    let is_min = negative && word[start] == 0x80 && word[start + 1..].iter().all(|&byte| byte == 0);
+    if word[..start].iter().any(|&byte| byte != 0) || (sign_bit && !is_min) {
+        return Err(format!("Value {} does not fit in {}", value, kind));
+    }
+
+    if negative {
+        for byte in word.iter_mut() {
+            *byte = !*byte;
+        }
+        for byte in word.iter_mut().rev() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                break;
+            }
+        }
+    }
+    Ok(word[start..].to_vec())
+}
+
+/// Ondalık veya `0x` önekli hex işaretsiz tamsayıyı 32 baytlık big-endian kelimeye çevirir
+fn parse_uint(value: &str) -> Result<[u8; 32], String> {
+    let invalid = || format!("Invalid integer: {}", value);
+    let mut word = [0u8; 32];
+
+    if value.starts_with("0x") {
+        let bytes = decode_hex(&pad_hex(value)).map_err(|_| invalid())?;
+        if bytes.len() > 32 {
+            return Err(invalid());
+        }
+        word[32 - bytes.len()..].copy_from_slice(&bytes);
+        return Ok(word);
+    }
+
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    for digit in value.bytes().map(|byte| u32::from(byte - b'0')) {
+        // word = word * 10 + digit
+        let mut carry = digit;
+        for byte in word.iter_mut().rev() {
+            let product = u32::from(*byte) * 10 + carry;
+            *byte = product as u8;
+            carry = product >> 8;
+        }
+        if carry != 0 {
+            return Err(invalid());
+        }
+    }
+    Ok(word)
+}
+
+/// Tek sayıda hex basamağını başına sıfır ekleyerek çift sayıya tamamlar (`0x1` -> `0x01`)
+fn pad_hex(value: &str) -> String {
+    let digits = &value[2..];
+    if !digits.len().is_multiple_of(2) {
+        format!("0x0{}", digits)
+    } else {
+        value.to_string()
+    }
+}
+
+/// `uintN`/`intN` bit sayısı: 8'in katı, 8..=256; yazılmazsa 256
+fn parse_bits(kind: &str, bits: &str) -> Result<usize, String> {
+    if bits.is_empty() {
+        return Ok(256);
+    }
+    let bits = parse_size(kind, bits, 8..=256)?;
+    if !bits.is_multiple_of(8) {
+        return Err(format!("Unsupported type: {}", kind));
+    }
+    Ok(bits)
+}
+
+/// Tür adındaki boyutu okur; başında sıfır olan (`uint08`) veya aralık dışı boyutları reddeder
+fn parse_size(kind: &str, text: &str, range: std::ops::RangeInclusive<usize>) -> Result<usize, String> {
+    match text.parse::<usize>() {
+        Ok(size) if range.contains(&size) && size.to_string() == text => Ok(size),
+        _ => Err(format!("Unsupported type: {}", kind)),
+    }
+}
+
+/// keccak256 özetini `0x` önekli hex olarak döner
+#[wasm_bindgen(js_name = keccak256)]
+pub fn keccak256_hex(data: &[u8]) -> String {
+    to_hex(&keccak256(data))
+}
+
+/// Metnin EIP-191 `personal_sign` özetini döner (ethers `hashMessage` karşılığı)
+#[wasm_bindgen]
+pub fn hash_message(message: String) -> String {
+    to_hex(&personal_message_hash(message.as_bytes()))
+}
+
+/// `abi.encodePacked` ile kodlanmış baytları `0x` önekli hex olarak döner
+#[wasm_bindgen]
+pub fn solidity_packed(types: Vec<String>, values: Vec<String>) -> Result<String, String> {
+    encode_packed(&types, &values).map(|data| to_hex(&data))
+}
+
+/// `keccak256(abi.encodePacked(...))` özetini döner
+#[wasm_bindgen]
+pub fn solidity_packed_keccak256(types: Vec<String>, values: Vec<String>) -> Result<String, String> {
+    encode_packed(&types, &values).map(|data| to_hex(&keccak256(&data)))
+}
+
+/// Yazılan baytları ara tampon ayırmadan keccak256 özetine katan `io::Write` (serileştiriciler için)
+pub(crate) struct KeccakWriter(Keccak);
+
+impl KeccakWriter {
+    pub(crate) fn new() -> KeccakWriter {
+        KeccakWriter(Keccak::v256())
+    }
+
+    pub(crate) fn finalize(self) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        self.0.finalize(&mut output);
+        output
+    }
+}
+
+impl std::io::Write for KeccakWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keccak256() {
+        assert_eq!(
+            to_hex(&keccak256(b"")),
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        // Reference vector from ethers `hashMessage("hello")`
+        assert_eq!(
+            hash_message("hello".to_string()),
+            "0x50b2c43fd39106bafbba0da34fc430e1f91e3c96ea2acee2bc34119f92b37750"
+        );
+    }
+
+    fn packed(types: &[&str], values: &[&str]) -> Result<String, String> {
+        let types = types.iter().map(|kind| kind.to_string()).collect();
+        solidity_packed(types, values.iter().map(|value| value.to_string()).collect())
+    }
+
+    #[test]
+    fn test_solidity_packed() {
+        assert_eq!(
+            packed(
+                &["int16", "uint48", "bool", "bytes2", "string"],
+                &["-1", "0x12", "true", "0xabcd", "Hi"]
+            )
+            .unwrap(),
+            "0xffff00000000001201abcd4869"
+        );
+        assert_eq!(packed(&["int8", "int8"], &["-128", "127"]).unwrap(), "0x807f");
+        assert_eq!(
+            packed(&["address"], &["0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"]).unwrap(),
+            "0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826"
+        );
+
+        // Out-of-range values, malformed types and mismatched lengths are rejected
+        assert!(packed(&["uint8"], &["256"]).is_err());
+        assert!(packed(&["int8"], &["128"]).is_err());
+        assert!(packed(&["int8"], &["-129"]).is_err());
+        assert!(packed(&["uint7"], &["1"]).is_err());
+        assert!(packed(&["uint08"], &["1"]).is_err());
+        assert!(packed(&["bytes4"], &["0xabcd"]).is_err());
+        assert!(packed(&["uint256[]"], &["1"]).is_err());
+        assert!(packed(&["uint256"], &["1", "2"]).is_err());
+
+        // Matches the Merkle ownership leaf: keccak256(abi.encodePacked(uint256 tokenId, string owner))
+        let leaf = crate::merkle::ownership_leaf(7, "user1");
+        let types = vec!["uint256".to_string(), "string".to_string()];
+        let values = vec!["7".to_string(), "user1".to_string()];
+        assert_eq!(solidity_packed_keccak256(types, values).unwrap(), to_hex(&leaf));
+    }
+}
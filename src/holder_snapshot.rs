@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+
+/// Snapshot kimliği; 1'den başlar, 0 "henüz snapshot yok" anlamına gelir
+pub type SnapshotId = u64;
+
+/// Bir değerin snapshot'lardaki eski halleri: `(snapshot, o snapshot'taki değer)`
+///
+/// Değer yalnızca bir snapshot'tan sonraki ilk değişiklikte kopyalanır (copy-on-write).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct Versions<T>(Vec<(SnapshotId, T)>);
+
+impl<T> Versions<T> {
+    fn needs_copy(&self, current: SnapshotId) -> bool {
+        self.0.last().map(|(id, _)| *id < current).unwrap_or(true)
+    }
+
+    /// `snapshot` anındaki değer; sonrasında değişmediyse `None` (güncel değer geçerlidir)
+    fn at(&self, snapshot: SnapshotId) -> Option<&T> {
+        let index = self.0.partition_point(|(id, _)| *id < snapshot);
+        self.0.get(index).map(|(_, value)| value)
+    }
+}
+
+/// Sahip ve bakiye bilgisinin snapshot anlarındaki hallerini tutar
+///
+/// Snapshot almak yalnızca sayacı artırır; veriler ilk değişiklikte kopyalanır.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct HolderSnapshots {
+    current: SnapshotId,
+    owners: BTreeMap<u64, Versions<Option<String>>>, // Token ID -> Sahip Geçmişi
+    balances: BTreeMap<String, Versions<u64>>, // Adres -> Bakiye Geçmişi
+    batches: BTreeMap<u64, SnapshotId>, // Toplu Mint Başlangıcı -> Mint Öncesi Son Snapshot
+}
+
+impl HolderSnapshots {
+    pub(crate) fn take(&mut self) -> SnapshotId {
+        self.current += 1;
+        self.current
+    }
+
+    pub(crate) fn current(&self) -> SnapshotId {
+        self.current
+    }
+
+    pub(crate) fn exists(&self, snapshot: SnapshotId) -> bool {
+        snapshot > 0 && snapshot <= self.current
+    }
+
+    /// Token'ın sahibi değişmeden önce eski sahibini kaydeder (mint'te boş)
+    pub(crate) fn record_owner(&mut self, token_id: u64, owner: Option<&str>) {
+        if self.current == 0 {
+            return;
+        }
+        let versions = self.owners.entry(token_id).or_default();
+        if versions.needs_copy(self.current) {
+            versions.0.push((self.current, owner.map(str::to_string)));
+        }
+    }
+
+    /// Bakiye kaydının gerekip gerekmediğini döner; eski bakiyeyi hesaplamadan önce sorulur
+    pub(crate) fn needs_balance(&self, account: &str) -> bool {
+        self.current > 0
+            && self
+                .balances
+                .get(account)
+                .map(|versions| versions.needs_copy(self.current))
+                .unwrap_or(true)
+    }
+
+    /// Adresin bakiyesi değişmeden önce eski bakiyesini kaydeder
+    pub(crate) fn record_balance(&mut self, account: &str, balance: u64) {
+        if self.needs_balance(account) {
+            self.balances.entry(account.to_string()).or_default().0.push((self.current, balance));
+        }
+    }
+
+    /// Toplu mint'i kaydeder; aralıktaki tokenlar mint öncesi snapshot'larda yoktur
+    pub(crate) fn record_batch(&mut self, start_id: u64) {
+        if self.current > 0 {
+            self.batches.insert(start_id, self.current);
+        }
+    }
+
+    /// Snapshot anındaki sahibi döner; `current_owner` ve `batch_start` güncel durumdan gelir
+    pub(crate) fn owner_at(
+        &self,
+        snapshot: SnapshotId,
+        token_id: u64,
+        current_owner: Option<String>,
+        batch_start: Option<u64>,
+    ) -> Option<String> {
+        if let Some(owner) = self.owners.get(&token_id).and_then(|versions| versions.at(snapshot)) {
+            return owner.clone();
+        }
+        let minted_later = batch_start
+            .and_then(|start| self.batches.get(&start))
+            .map(|&last_before| last_before >= snapshot)
+            .unwrap_or(false);
+        if minted_later {
+            return None;
+        }
+        current_owner
+    }
+
+    /// Snapshot anındaki bakiyeyi döner; `current_balance` güncel bakiyedir
+    pub(crate) fn balance_at(&self, snapshot: SnapshotId, account: &str, current_balance: u64) -> u64 {
+        self.balances
+            .get(account)
+            .and_then(|versions| versions.at(snapshot))
+            .copied()
+            .unwrap_or(current_balance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_on_write() {
+        let mut snapshots = HolderSnapshots::default();
+        // Nothing is copied before the first snapshot
+        snapshots.record_owner(1, None);
+        assert!(snapshots.owners.is_empty());
+
+        let first = snapshots.take();
+        snapshots.record_owner(1, Some("alice"));
+        snapshots.record_owner(1, Some("bob"));
+        snapshots.record_balance("alice", 1);
+        let second = snapshots.take();
+        let third = snapshots.take();
+        snapshots.record_owner(1, Some("carol"));
+
+        assert_eq!(snapshots.owner_at(first, 1, Some("dave".to_string()), None), Some("alice".to_string()));
+        assert_eq!(snapshots.owner_at(second, 1, Some("dave".to_string()), None), Some("carol".to_string()));
+        assert_eq!(snapshots.owner_at(third, 1, Some("dave".to_string()), None), Some("carol".to_string()));
+        assert_eq!(snapshots.balance_at(first, "alice", 0), 1);
+        assert_eq!(snapshots.balance_at(second, "alice", 0), 0);
+        assert_eq!(snapshots.owners[&1].0.len(), 2);
+        assert!(!snapshots.exists(0) && !snapshots.exists(4));
+    }
+}
@@ -0,0 +1,158 @@
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+/// Kancanın (hook) bağlandığı işlem ve aşaması
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HookKind {
+    BeforeMint,
+    AfterMint,
+    BeforeTransfer,
+    AfterTransfer,
+    BeforeBurn,
+    AfterBurn,
+}
+
+impl HookKind {
+    /// "Before" kancaları işlemi veto edebilir; "after" kancaları yalnızca gözlemler
+    fn can_veto(self) -> bool {
+        matches!(self, HookKind::BeforeMint | HookKind::BeforeTransfer | HookKind::BeforeBurn)
+    }
+}
+
+/// Kancaya iletilen işlem bilgisi
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HookContext {
+    pub kind: HookKind,
+    pub token_id: u64,
+    pub range_end: Option<u64>, // Toplu mint'te aralığın son Token ID'si (dahil)
+    pub from: Option<String>, // Mint'te boş
+    pub to: Option<String>, // Burn'de boş
+    pub operator: String, // İşlemi başlatan adres
+}
+
+impl HookContext {
+    pub(crate) fn new(kind: HookKind, token_id: u64, operator: &str) -> HookContext {
+        HookContext {
+            kind,
+            token_id,
+            range_end: None,
+            from: None,
+            to: None,
+            operator: operator.to_string(),
+        }
+    }
+
+    pub(crate) fn range_end(mut self, end: u64) -> HookContext {
+        self.range_end = Some(end);
+        self
+    }
+
+    pub(crate) fn from(mut self, address: &str) -> HookContext {
+        self.from = Some(address.to_string());
+        self
+    }
+
+    pub(crate) fn to(mut self, address: &str) -> HookContext {
+        self.to = Some(address.to_string());
+        self
+    }
+
+    /// Bağlamı farklı bir aşama için kopyalar (ör. BeforeTransfer -> AfterTransfer)
+    pub(crate) fn with_kind(&self, kind: HookKind) -> HookContext {
+        HookContext { kind, ..self.clone() }
+    }
+}
+
+/// Rust tarafından kaydedilen kanca; `Err` dönen "before" kancası işlemi iptal eder
+pub type RustHook = Rc<dyn Fn(&HookContext) -> Result<(), String>>;
+
+#[derive(Clone)]
+enum Callback {
+    Rust(RustHook),
+    // `false` dönen veya hata fırlatan "before" kancası işlemi iptal eder
+    Js(js_sys::Function),
+}
+
+impl Callback {
+    fn call(&self, context: &HookContext) -> Result<(), String> {
+        match self {
+            Callback::Rust(hook) => hook(context),
+            Callback::Js(function) => {
+                let result = function
+                    .call1(&JsValue::NULL, &JsValue::from(context.clone()))
+                    .map_err(|err| err.as_string().unwrap_or_else(|| "Hook threw an error".to_string()))?;
+                if result.as_bool() == Some(false) {
+                    return Err("Rejected by hook".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Kayıt sırasıyla çalıştırılan kanca hattı
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    entries: Vec<(HookKind, Callback)>,
+}
+
+impl Hooks {
+    pub(crate) fn add_rust(&mut self, kind: HookKind, hook: RustHook) {
+        self.entries.push((kind, Callback::Rust(hook)));
+    }
+
+    pub(crate) fn add_js(&mut self, kind: HookKind, function: js_sys::Function) {
+        self.entries.push((kind, Callback::Js(function)));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Bağlamın aşamasına kayıtlı kancaları çalıştırır
+    ///
+    /// "Before" kancalarında ilk hata işlemi durdurur; "after" kancalarının hataları yok sayılır.
+    pub(crate) fn run(&self, context: &HookContext) -> Result<(), String> {
+        for (_, callback) in self.entries.iter().filter(|(kind, _)| *kind == context.kind) {
+            let result = callback.call(context);
+            if context.kind.can_veto() {
+                result?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_hook_pipeline() {
+        let mut hooks = Hooks::default();
+        let seen = Rc::new(Cell::new(0));
+        let counter = seen.clone();
+        hooks.add_rust(HookKind::AfterMint, Rc::new(move |_| {
+            counter.set(counter.get() + 1);
+            Err("ignored".to_string())
+        }));
+        hooks.add_rust(HookKind::BeforeMint, Rc::new(|ctx| {
+            if ctx.token_id == 13 {
+                return Err("Unlucky token".to_string());
+            }
+            Ok(())
+        }));
+
+        let context = HookContext::new(HookKind::BeforeMint, 13, "owner").to("owner");
+        assert_eq!(hooks.run(&context), Err("Unlucky token".to_string()));
+        assert!(hooks.run(&context.with_kind(HookKind::AfterMint)).is_ok());
+        assert_eq!(seen.get(), 1);
+    }
+}
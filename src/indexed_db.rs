@@ -0,0 +1,134 @@
+use js_sys::{Promise, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use web_sys::{IdbDatabase, IdbObjectStore, IdbRequest, IdbTransaction, IdbTransactionMode};
+
+use crate::erc721::ERC721;
+use crate::utils::log_event;
+
+const DB_NAME: &str = "spawn-wasm-erc721";
+const DB_VERSION: u32 = 1;
+const STORE_NAME: &str = "snapshots";
+
+/// Tek bir IndexedDB işleminde yazılan en büyük parça (1 MiB)
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+#[wasm_bindgen]
+impl ERC721 {
+    /// Kontrat durumunu IndexedDB'ye parçalar halinde kaydeder (Promise döner)
+    ///
+    /// Her parça ayrı bir işlemde yazılır; böylece büyük durumlar ana iş parçacığını
+    /// uzun süre bloklamaz. Parça sayısı en son yazılır.
+    #[wasm_bindgen(unchecked_return_type = "Promise<void>")]
+    pub fn save_async(&self, key: String) -> Promise {
+        let bytes = self.snapshot();
+        future_to_promise(async move {
+            save_chunks(&key, &bytes).await?;
+            log_event("State Saved", &format!("Key: {}, Bytes: {}", key, bytes.len()));
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// IndexedDB'den kontrat durumunu yükler (ERC721 ile çözülen Promise döner)
+    #[wasm_bindgen(unchecked_return_type = "Promise<ERC721>")]
+    pub fn load_async(key: String) -> Promise {
+        future_to_promise(async move {
+            let bytes = load_chunks(&key).await?;
+            let erc721 = ERC721::restore(&bytes)?;
+            log_event("State Loaded", &format!("Key: {}, Bytes: {}", key, bytes.len()));
+            Ok(JsValue::from(erc721))
+        })
+    }
+}
+
+fn chunk_key(key: &str, index: usize) -> JsValue {
+    JsValue::from_str(&format!("{}:{}", key, index))
+}
+
+async fn save_chunks(key: &str, bytes: &[u8]) -> Result<(), JsValue> {
+    let db = open_database().await?;
+    let chunks: Vec<&[u8]> = bytes.chunks(CHUNK_SIZE).collect();
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        let (tx, store) = store_transaction(&db, IdbTransactionMode::Readwrite)?;
+        store.put_with_key(&Uint8Array::from(*chunk), &chunk_key(key, index))?;
+        await_transaction(&tx).await?;
+    }
+
+    let (tx, store) = store_transaction(&db, IdbTransactionMode::Readwrite)?;
+    store.put_with_key(&JsValue::from_f64(chunks.len() as f64), &JsValue::from_str(key))?;
+    await_transaction(&tx).await?;
+    db.close();
+    Ok(())
+}
+
+async fn load_chunks(key: &str) -> Result<Vec<u8>, JsValue> {
+    let db = open_database().await?;
+
+    let (_, store) = store_transaction(&db, IdbTransactionMode::Readonly)?;
+    let count = await_request(&store.get(&JsValue::from_str(key))?)
+        .await?
+        .as_f64()
+        .ok_or_else(|| JsValue::from_str("No saved state for key"))? as usize;
+
+    let mut bytes = Vec::new();
+    for index in 0..count {
+        let (_, store) = store_transaction(&db, IdbTransactionMode::Readonly)?;
+        let chunk = await_request(&store.get(&chunk_key(key, index))?).await?;
+        let chunk = chunk
+            .dyn_into::<Uint8Array>()
+            .map_err(|_| JsValue::from_str("Saved state is corrupted"))?;
+        bytes.extend(chunk.to_vec());
+    }
+    db.close();
+    Ok(bytes)
+}
+
+async fn open_database() -> Result<IdbDatabase, JsValue> {
+    let factory = web_sys::window()
+        .ok_or("No window available")?
+        .indexed_db()?
+        .ok_or("IndexedDB is not available")?;
+    let request = factory.open_with_u32(DB_NAME, DB_VERSION)?;
+
+    let upgrade_request = request.clone();
+    let on_upgrade = Closure::<dyn FnMut()>::new(move || {
+        if let Some(db) = upgrade_request.result().ok().and_then(|db| db.dyn_into::<IdbDatabase>().ok()) {
+            if !db.object_store_names().contains(STORE_NAME) {
+                let _ = db.create_object_store(STORE_NAME);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+
+    let db = await_request(&request).await;
+    request.set_onupgradeneeded(None);
+    db?.dyn_into::<IdbDatabase>()
+}
+
+fn store_transaction(db: &IdbDatabase, mode: IdbTransactionMode) -> Result<(IdbTransaction, IdbObjectStore), JsValue> {
+    let tx = db.transaction_with_str_and_mode(STORE_NAME, mode)?;
+    let store = tx.object_store(STORE_NAME)?;
+    Ok((tx, store))
+}
+
+/// IndexedDB isteğini Promise'e çevirip sonucunu bekler
+async fn await_request(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(promise).await?;
+    request.result()
+}
+
+/// IndexedDB işleminin tamamlanmasını bekler
+async fn await_transaction(tx: &IdbTransaction) -> Result<(), JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        tx.set_oncomplete(Some(&resolve));
+        tx.set_onerror(Some(&reject));
+        tx.set_onabort(Some(&reject));
+    });
+    JsFuture::from(promise).await.map(|_| ())
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+/// Tekilleştirilmiş adresin kimliği; token başına kayıtlarda adres metni yerine saklanır
+pub(crate) type AddressId = u32;
+
+/// Adres metinlerini bir kez saklayıp küçük kimliklerle temsil eden sözlük
+///
+/// Kimlikler kalıcıdır; adresin tüm tokenları gitse de kaydı silinmez. Serileştirilmiş
+/// biçimi kimlik sırasıyla adres listesidir.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<String>", into = "Vec<String>")]
+pub(crate) struct AddressInterner {
+    addresses: Vec<Rc<str>>, // Kimlik -> Adres
+    ids: HashMap<Rc<str>, AddressId>, // Adres -> Kimlik (metin `addresses` ile paylaşılır)
+}
+
+impl AddressInterner {
+    /// Adresin kimliğini döner; adres ilk kez görülüyorsa kaydeder
+    pub(crate) fn intern(&mut self, address: &str) -> AddressId {
+        if let Some(&id) = self.ids.get(address) {
+            return id;
+        }
+        let id = self.addresses.len() as AddressId;
+        let address: Rc<str> = Rc::from(address);
+        self.addresses.push(address.clone());
+        self.ids.insert(address, id);
+        id
+    }
+
+    /// Kayıtlı adresin kimliğini döner; sorgularda yeni kayıt açmamak için kullanılır
+    pub(crate) fn get(&self, address: &str) -> Option<AddressId> {
+        self.ids.get(address).copied()
+    }
+
+    pub(crate) fn resolve(&self, id: AddressId) -> &str {
+        &self.addresses[id as usize]
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    /// Adres metinlerinin kapladığı bayt sayısı
+    pub(crate) fn text_bytes(&self) -> u64 {
+        self.addresses.iter().map(|address| address.len() as u64).sum()
+    }
+}
+
+impl From<Vec<String>> for AddressInterner {
+    fn from(addresses: Vec<String>) -> AddressInterner {
+        let mut interner = AddressInterner::default();
+        for address in addresses {
+            interner.intern(&address);
+        }
+        interner
+    }
+}
+
+impl From<AddressInterner> for Vec<String> {
+    fn from(interner: AddressInterner) -> Vec<String> {
+        interner.addresses.iter().map(|address| address.to_string()).collect()
+    }
+}
+
+/// Adres tekilleştirmesinin bellek üzerindeki etkisi
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MemoryStats {
+    pub unique_addresses: u32,
+    pub address_bytes: u64, // Tekil adres metinlerinin toplam boyutu
+    pub address_references: u64, // Token kayıtlarında adres yerine tutulan kimlik sayısı
+    pub bytes_saved: u64, // Tahmini: her referans için adres kopyası yerine 4 baytlık kimlik
+}
+
+impl MemoryStats {
+    /// `references` her kimlik kullanımının işaret ettiği adresi sırayla verir
+    pub(crate) fn new(interner: &AddressInterner, references: impl Iterator<Item = AddressId>) -> MemoryStats {
+        let id_size = std::mem::size_of::<AddressId>() as u64;
+        let copy_size = std::mem::size_of::<String>() as u64;
+        let (mut count, mut saved) = (0, 0);
+        for id in references {
+            count += 1;
+            saved += (copy_size + interner.resolve(id).len() as u64).saturating_sub(id_size);
+        }
+        MemoryStats {
+            unique_addresses: interner.len() as u32,
+            address_bytes: interner.text_bytes(),
+            address_references: count,
+            bytes_saved: saved,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interner_roundtrip() {
+        let mut interner = AddressInterner::default();
+        let alice = interner.intern("alice");
+        assert_eq!(interner.intern("bob"), alice + 1);
+        assert_eq!(interner.intern("alice"), alice);
+        assert_eq!(interner.get("carol"), None);
+
+        let restored = AddressInterner::from(Vec::from(interner.clone()));
+        assert_eq!(restored.resolve(alice), "alice");
+        assert_eq!(restored.get("bob"), Some(alice + 1));
+
+        let stats = MemoryStats::new(&interner, vec![alice, alice, alice].into_iter());
+        assert_eq!((stats.unique_addresses, stats.address_bytes, stats.address_references), (2, 8, 3));
+    }
+}
@@ -1,4 +1,115 @@
+mod airdrop;
+mod analytics;
+mod attributes;
+mod auction;
+mod bank;
+mod bytes;
+mod caller;
+mod clock;
+mod composable;
+mod consumable;
+mod contract_metadata;
+mod cursor;
+mod dutch_auction;
+mod eip712;
+mod erc1155;
+mod erc20;
 mod erc721;
+mod events;
+mod fractionalizer;
+mod governor;
+mod hash;
+mod holder_snapshot;
+mod hooks;
+#[cfg(target_arch = "wasm32")]
+mod indexed_db;
+mod interner;
+mod logger;
+mod marketplace;
+mod merge;
+mod merkle;
+mod metadata;
+mod metrics;
+mod migration;
+mod multisig;
+mod order;
+mod owned_index;
+mod pagination;
+mod payment_splitter;
+mod perf;
+mod phases;
+mod progression;
+mod rand;
+mod rarity;
+mod registry;
+mod role_manager;
+mod royalty;
+mod staking;
+mod state_diff;
+mod storage;
+mod svg;
+mod swap;
+mod timelock;
+mod token_account;
+mod typescript;
+mod uri;
 mod utils;
+mod votes;
+mod voucher;
+mod worker;
+mod wrapped;
 
-pub use erc721::ERC721;
+pub use airdrop::AirdropList;
+pub use analytics::{DistributionBucket, HolderBalance, HolderCountPoint};
+pub use attributes::{Attribute, AttributeValue};
+pub use auction::Auction;
+pub use bytes::{bytes_to_hex, decode_fixed, decode_hex, hex_to_bytes, parse_hash, to_hex, Bytes32, BytesError};
+pub use caller::{set_test_mode, test_mode, Caller};
+pub use composable::ChildToken;
+pub use contract_metadata::ContractMetadata;
+pub use cursor::TokenCursor;
+pub use dutch_auction::DutchAuction;
+pub use eip712::{address_from_pubkey, ecrecover, recover_signer, Eip712Domain};
+pub use erc1155::ERC1155;
+pub use erc20::ERC20;
+pub use erc721::{BatchTransfer, CheckpointId, MetaCall, Operation, ERC721};
+pub use events::{EventFilter, LogEntry};
+pub use fractionalizer::{Fractionalizer, FRACTION_VAULT};
+pub use governor::{ProposalState, VoteType};
+pub use hash::{
+    encode_packed, hash_message, keccak256, keccak256_hex, personal_message_hash, solidity_packed, solidity_packed_keccak256,
+};
+pub use holder_snapshot::SnapshotId;
+pub use hooks::{HookContext, HookKind, RustHook};
+pub use interner::MemoryStats;
+pub use logger::{clear_logger, set_logger, set_rust_logger, LogRecord, RustLogger};
+pub use marketplace::{Bundle, Listing, Offer, MARKETPLACE_OPERATOR};
+pub use merge::MergeFn;
+pub use merkle::{verify_proof, Allowlist};
+pub use metadata::validate_metadata;
+pub use metrics::{Metrics, OpMetrics};
+pub use migration::{MigrationStep, Migrations, STATE_VERSION};
+pub use order::{ConsiderationItem, Order};
+pub use pagination::{AddressPage, TokenPage};
+pub use payment_splitter::PaymentSplitter;
+pub use perf::OpTiming;
+#[cfg(all(target_arch = "wasm32", feature = "tracing"))]
+pub use perf::init_tracing;
+pub use phases::MintPhase;
+pub use rand::SeededRng;
+pub use rarity::RarityMethod;
+pub use registry::{ContractKind, ContractRegistry};
+pub use role_manager::{
+    RoleManager, ADMIN_ROLE, BURNER_ROLE, COMPLIANCE_ROLE, DEFAULT_ADMIN_ROLE, GAME_ROLE, GUARDIAN_ROLE, MINTER_ROLE,
+    PAUSER_ROLE, SIGNER_ROLE,
+};
+pub use royalty::{RoyaltyInfo, RoyaltyPayment, FEE_DENOMINATOR};
+pub use state_diff::{OperatorChange, StateDiff, TokenChange};
+pub use swap::{Swap, SwapSide};
+pub use timelock::AdminAction;
+pub use token_account::{AccountRegistry, TokenAccount};
+pub use uri::validate_uri;
+pub use utils::{log_level, set_log_level, LogLevel};
+pub use voucher::NFTVoucher;
+pub use worker::DetachedState;
+pub use wrapped::{WrappedERC721, WRAP_VAULT};
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+use crate::utils::LogLevel;
+
+/// Kayıtlı günlük fonksiyonuna iletilen olay
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    pub event: String,
+    pub details: String,
+    pub timestamp: u64, // Unix zamanı (ms)
+}
+
+/// Rust tarafından kaydedilen günlük fonksiyonu
+pub type RustLogger = Rc<dyn Fn(&LogRecord)>;
+
+#[derive(Clone)]
+enum Logger {
+    Rust(RustLogger),
+    // Fırlatılan hatalar yok sayılır
+    Js(js_sys::Function),
+}
+
+thread_local! {
+    static LOGGER: RefCell<Option<Logger>> = const { RefCell::new(None) };
+}
+
+/// Olayları konsol yerine JS fonksiyonuna yönlendirir; `null` konsola geri döner
+///
+/// Fonksiyon `{ level, event, details, timestamp }` alanlı bir `LogRecord` alır.
+#[wasm_bindgen]
+pub fn set_logger(#[wasm_bindgen(unchecked_param_type = "LoggerCallback | null")] logger: Option<js_sys::Function>) {
+    install(logger.map(Logger::Js));
+}
+
+/// Olayları Rust fonksiyonuna yönlendirir (Rust tarafı için `set_logger`)
+pub fn set_rust_logger<F>(logger: F)
+where
+    F: Fn(&LogRecord) + 'static,
+{
+    install(Some(Logger::Rust(Rc::new(logger))));
+}
+
+/// Kayıtlı günlük fonksiyonunu kaldırır; olaylar yeniden konsola yazılır
+pub fn clear_logger() {
+    install(None);
+}
+
+fn install(logger: Option<Logger>) {
+    LOGGER.with(|slot| *slot.borrow_mut() = logger);
+}
+
+/// Kaydı kayıtlı fonksiyona iletir; fonksiyon yoksa `false` döner
+///
+/// Fonksiyon çağrılmadan önce kopyalanır, böylece fonksiyon içinden `set_logger` çağrılabilir.
+#[cfg_attr(not(feature = "logging"), allow(dead_code))]
+pub(crate) fn dispatch(record: LogRecord) -> bool {
+    let logger = match LOGGER.with(|slot| slot.borrow().clone()) {
+        Some(logger) => logger,
+        None => return false,
+    };
+    match logger {
+        Logger::Rust(logger) => logger(&record),
+        Logger::Js(function) => {
+            let _ = function.call1(&JsValue::NULL, &JsValue::from(record));
+        }
+    }
+    true
+}
+
+#[cfg(all(test, feature = "logging"))]
+mod tests {
+    use super::*;
+    use crate::utils::{log_event, set_log_level};
+
+    #[test]
+    fn test_rust_logger_receives_records() {
+        let records = Rc::new(RefCell::new(Vec::new()));
+        let sink = records.clone();
+        set_rust_logger(move |record| sink.borrow_mut().push(record.clone()));
+
+        log_event("Token Minted", "Token ID: 1");
+        set_log_level(LogLevel::Error);
+        log_event("Token Minted", "Token ID: 2");
+        log_event("Minting Failed", "Max supply reached");
+        set_log_level(LogLevel::Info);
+        clear_logger();
+        log_event("Token Minted", "Token ID: 3");
+
+        let records = records.borrow();
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].level, records[0].event.as_str()), (LogLevel::Info, "Token Minted"));
+        assert_eq!((records[1].level, records[1].details.as_str()), (LogLevel::Error, "Max supply reached"));
+        assert!(records[0].timestamp > 0);
+    }
+}
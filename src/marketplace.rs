@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+
+/// Satışları yürüten pazaryeri operatörünün adresi; satıcı bu adrese onay vermelidir
+pub const MARKETPLACE_OPERATOR: &str = "marketplace";
+
+/// Sabit fiyatlı satış ilanı
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Listing {
+    pub seller: String,
+    pub price: u64, // Simüle ETH cinsinden
+}
+
+/// Birden fazla token'ın tek fiyatla, birlikte satıldığı ilan
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bundle {
+    pub seller: String,
+    pub token_ids: Vec<u64>,
+    pub price: u64, // Simüle ETH cinsinden, tüm paket için
+}
+
+impl Bundle {
+    /// Paket fiyatının token başına düşen payları; artan kısım son token'a eklenir
+    pub(crate) fn shares(&self) -> Vec<(u64, u64)> {
+        let count = self.token_ids.len() as u64;
+        let share = self.price / count;
+        let remainder = self.price % count;
+        self.token_ids
+            .iter()
+            .enumerate()
+            .map(|(i, &token_id)| {
+                let extra = if i as u64 == count - 1 { remainder } else { 0 };
+                (token_id, share + extra)
+            })
+            .collect()
+    }
+}
+
+/// İlanda olmayan bir token için verilen, tutarı emanette bekleyen teklif
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Offer {
+    pub buyer: String,
+    pub token_id: u64,
+    pub amount: u64, // Simüle ETH cinsinden
+    pub expiry: u64, // Bu andan sonra kabul edilemez (Unix saniye)
+}
+
+/// Aktif satış ilanları ve teklifler
+///
+/// Her token için yalnızca en yüksek geçerli teklif tutulur; geçilen teklif iade edilir.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Marketplace {
+    listings: BTreeMap<u64, Listing>, // Token ID -> İlan
+    offers: BTreeMap<u64, Offer>, // Teklif ID -> Teklif
+    best_offers: BTreeMap<u64, u64>, // Token ID -> En Yüksek Teklifin ID'si
+    next_offer_id: u64,
+    bundles: BTreeMap<u64, Bundle>, // Paket ID -> Paket
+    bundled_tokens: BTreeMap<u64, u64>, // Token ID -> Paket ID
+    next_bundle_id: u64,
+}
+
+impl Marketplace {
+    pub(crate) fn list(&mut self, token_id: u64, seller: &str, price: u64) -> Result<(), String> {
+        if price == 0 {
+            return Err("Price must be greater than zero".to_string());
+        }
+        self.listings.insert(
+            token_id,
+            Listing {
+                seller: seller.to_string(),
+                price,
+            },
+        );
+        Ok(())
+    }
+
+    pub(crate) fn listing(&self, token_id: u64) -> Option<&Listing> {
+        self.listings.get(&token_id)
+    }
+
+    /// Token'ın ilanını ve içinde bulunduğu paketi kaldırır
+    pub(crate) fn remove(&mut self, token_id: u64) -> Option<Listing> {
+        if let Some(&bundle_id) = self.bundled_tokens.get(&token_id) {
+            self.remove_bundle(bundle_id);
+        }
+        self.listings.remove(&token_id)
+    }
+
+    /// Paketi kaydeder; tokenlar tekrarsız olmalı ve başka bir pakette bulunmamalıdır
+    pub(crate) fn list_bundle(&mut self, bundle: Bundle) -> Result<u64, String> {
+        if bundle.token_ids.len() < 2 {
+            return Err("Bundle must contain at least two tokens".to_string());
+        }
+        if bundle.price < bundle.token_ids.len() as u64 {
+            return Err("Price must cover at least one unit per token".to_string());
+        }
+        let mut unique = bundle.token_ids.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        if unique.len() != bundle.token_ids.len() {
+            return Err("Bundle contains duplicate tokens".to_string());
+        }
+        if let Some(token_id) = unique.iter().find(|token_id| self.bundled_tokens.contains_key(token_id)) {
+            return Err(format!("Token {} is already in a bundle", token_id));
+        }
+
+        let id = self.next_bundle_id;
+        self.next_bundle_id += 1;
+        for &token_id in &bundle.token_ids {
+            self.bundled_tokens.insert(token_id, id);
+        }
+        self.bundles.insert(id, bundle);
+        Ok(id)
+    }
+
+    pub(crate) fn bundle(&self, bundle_id: u64) -> Option<&Bundle> {
+        self.bundles.get(&bundle_id)
+    }
+
+    pub(crate) fn remove_bundle(&mut self, bundle_id: u64) -> Option<Bundle> {
+        let bundle = self.bundles.remove(&bundle_id)?;
+        for token_id in &bundle.token_ids {
+            self.bundled_tokens.remove(token_id);
+        }
+        Some(bundle)
+    }
+
+    /// Teklifi kaydeder; token'ın önceki teklifi varsa yenisi ondan yüksek olmalıdır (süresi geçmişse gerekmez)
+    ///
+    /// Yeni teklifin ID'sini ve iade edilmesi gereken önceki teklifi döner.
+    pub(crate) fn add_offer(&mut self, offer: Offer, now: u64) -> Result<(u64, Option<Offer>), String> {
+        if offer.amount == 0 {
+            return Err("Offer amount must be greater than zero".to_string());
+        }
+        if offer.expiry <= now {
+            return Err("Offer expiry must be in the future".to_string());
+        }
+        if let Some(current) = self.best_offer(offer.token_id) {
+            if current.expiry > now && offer.amount <= current.amount {
+                return Err(format!("Offer must exceed the current offer of {}", current.amount));
+            }
+        }
+
+        let id = self.next_offer_id;
+        self.next_offer_id += 1;
+        let token_id = offer.token_id;
+        self.offers.insert(id, offer);
+        let replaced = self.best_offers.insert(token_id, id).and_then(|previous| self.offers.remove(&previous));
+        Ok((id, replaced))
+    }
+
+    pub(crate) fn offer(&self, offer_id: u64) -> Option<&Offer> {
+        self.offers.get(&offer_id)
+    }
+
+    pub(crate) fn best_offer(&self, token_id: u64) -> Option<&Offer> {
+        self.best_offers.get(&token_id).and_then(|id| self.offers.get(id))
+    }
+
+    pub(crate) fn remove_offer(&mut self, offer_id: u64) -> Option<Offer> {
+        let offer = self.offers.remove(&offer_id)?;
+        self.best_offers.remove(&offer.token_id);
+        Some(offer)
+    }
+
+    /// Süresi geçmiş teklifleri kaldırıp döner
+    pub(crate) fn remove_expired_offers(&mut self, now: u64) -> Vec<Offer> {
+        let expired: Vec<u64> = self
+            .offers
+            .iter()
+            .filter(|(_, offer)| offer.expiry <= now)
+            .map(|(&id, _)| id)
+            .collect();
+        expired.into_iter().filter_map(|id| self.remove_offer(id)).collect()
+    }
+
+    /// İlanlı Token ID'leri artan sırada döner
+    pub(crate) fn listed_tokens(&self) -> Vec<u64> {
+        self.listings.keys().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listings() {
+        let mut marketplace = Marketplace::default();
+        assert!(marketplace.list(1, "alice", 0).is_err());
+        marketplace.list(2, "alice", 100).unwrap();
+        marketplace.list(1, "bob", 50).unwrap();
+        assert_eq!(marketplace.listed_tokens(), vec![1, 2]);
+        assert_eq!(marketplace.remove(2).unwrap().price, 100);
+        assert!(marketplace.listing(2).is_none());
+    }
+
+    #[test]
+    fn test_offers_outbid_and_expire() {
+        let mut marketplace = Marketplace::default();
+        let offer = |buyer: &str, amount, expiry| Offer {
+            buyer: buyer.to_string(),
+            token_id: 1,
+            amount,
+            expiry,
+        };
+        let (first, replaced) = marketplace.add_offer(offer("alice", 100, 50), 10).unwrap();
+        assert!(replaced.is_none());
+        assert!(marketplace.add_offer(offer("bob", 100, 50), 10).is_err());
+        assert!(marketplace.add_offer(offer("bob", 200, 10), 10).is_err());
+
+        let (second, replaced) = marketplace.add_offer(offer("bob", 150, 50), 10).unwrap();
+        assert_eq!(replaced.unwrap().buyer, "alice");
+        assert!(marketplace.offer(first).is_none());
+        assert_eq!(marketplace.best_offer(1).unwrap().buyer, "bob");
+
+        // A lower offer can replace one that has expired
+        let (_, replaced) = marketplace.add_offer(offer("carol", 10, 80), 50).unwrap();
+        assert_eq!(replaced.unwrap().amount, 150);
+        assert!(marketplace.offer(second).is_none());
+        assert_eq!(marketplace.remove_expired_offers(80).len(), 1);
+        assert!(marketplace.best_offer(1).is_none());
+    }
+
+    #[test]
+    fn test_bundles() {
+        let mut marketplace = Marketplace::default();
+        let bundle = |token_ids: Vec<u64>, price| Bundle {
+            seller: "alice".to_string(),
+            token_ids,
+            price,
+        };
+        assert!(marketplace.list_bundle(bundle(vec![1], 100)).is_err());
+        assert!(marketplace.list_bundle(bundle(vec![1, 1], 100)).is_err());
+        let id = marketplace.list_bundle(bundle(vec![1, 2, 3], 100)).unwrap();
+        assert!(marketplace.list_bundle(bundle(vec![3, 4], 100)).is_err());
+        assert_eq!(marketplace.bundle(id).unwrap().shares(), vec![(1, 33), (2, 33), (3, 34)]);
+
+        // Removing any token's listing invalidates the whole bundle
+        marketplace.remove(2);
+        assert!(marketplace.bundle(id).is_none());
+        marketplace.list_bundle(bundle(vec![3, 4], 100)).unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+use crate::attributes::{Attribute, AttributeValue};
+
+/// Rust tarafından tanımlanan özellik kalıtım kuralı: iki girdinin özelliklerinden çıktının özelliklerini üretir
+pub type MergeFn = Rc<dyn Fn(&[Attribute], &[Attribute]) -> Result<Vec<Attribute>, String>>;
+
+#[derive(Clone)]
+enum Rule {
+    Rust(MergeFn),
+    // OpenSea biçimindeki iki özellik dizisini alır, aynı biçimde bir dizi döner
+    Js(js_sys::Function),
+}
+
+/// Birleştirmede kullanılan kalıtım kuralı; tanımlanmamışsa `default_inherit` uygulanır
+#[derive(Clone, Default)]
+pub(crate) struct MergeRule(Option<Rule>);
+
+impl MergeRule {
+    pub(crate) fn set_rust(&mut self, rule: MergeFn) {
+        self.0 = Some(Rule::Rust(rule));
+    }
+
+    pub(crate) fn set_js(&mut self, function: js_sys::Function) {
+        self.0 = Some(Rule::Js(function));
+    }
+
+    pub(crate) fn apply(&self, a: &[Attribute], b: &[Attribute]) -> Result<Vec<Attribute>, String> {
+        match &self.0 {
+            None => Ok(default_inherit(a, b)),
+            Some(Rule::Rust(rule)) => rule(a, b),
+            Some(Rule::Js(function)) => {
+                let result = function
+                    .call2(&JsValue::NULL, &to_js(a)?, &to_js(b)?)
+                    .map_err(|err| err.as_string().unwrap_or_else(|| "Merge rule threw an error".to_string()))?;
+                let values: Vec<serde_json::Value> = serde_wasm_bindgen::from_value(result)
+                    .map_err(|_| "Merge rule must return an array of attributes".to_string())?;
+                values.iter().map(Attribute::from_json).collect()
+            }
+        }
+    }
+}
+
+fn to_js(attributes: &[Attribute]) -> Result<JsValue, String> {
+    use serde::Serialize as _;
+    let values: Vec<serde_json::Value> = attributes.iter().map(Attribute::to_json).collect();
+    values
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|err| err.to_string())
+}
+
+/// Varsayılan kalıtım: iki girdide de sayısal olan özelliklerin ortalaması alınır,
+/// diğer özelliklerde önce ilk girdinin değeri kullanılır
+pub(crate) fn default_inherit(a: &[Attribute], b: &[Attribute]) -> Vec<Attribute> {
+    let mut result: Vec<Attribute> = a.to_vec();
+    for attribute in b {
+        match result.iter_mut().find(|existing| existing.trait_type == attribute.trait_type) {
+            Some(existing) => match (&existing.value, &attribute.value) {
+                (AttributeValue::Number(x), AttributeValue::Number(y)) => {
+                    existing.value = AttributeValue::Number((x + y) / 2.0)
+                }
+                (AttributeValue::Boost(x), AttributeValue::Boost(y)) => {
+                    existing.value = AttributeValue::Boost((x + y) / 2.0)
+                }
+                _ => {}
+            },
+            None => result.push(attribute.clone()),
+        }
+    }
+    result
+}
+
+/// Birleştirme ayarları ve birleştirmeyle üretilen tokenların bekleme süreleri
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Merging {
+    cooldown: u64, // Birleştirmeyle üretilen token'ın tekrar birleştirilebilmesi için beklenecek süre (saniye)
+    ready_at: BTreeMap<u64, u64>, // Token ID -> Tekrar Birleştirilebileceği An
+    next_token_id: u64, // Birleştirme çıktısına verilecek sıradaki Token ID
+}
+
+impl Default for Merging {
+    fn default() -> Merging {
+        Merging {
+            cooldown: 0,
+            ready_at: BTreeMap::new(),
+            next_token_id: 1,
+        }
+    }
+}
+
+impl Merging {
+    /// Bekleme süresi aynı, birleştirme kaydı olmayan kopya (`clone_with` için)
+    pub(crate) fn settings_only(&self) -> Merging {
+        Merging {
+            cooldown: self.cooldown,
+            ..Merging::default()
+        }
+    }
+
+    pub(crate) fn set_cooldown(&mut self, seconds: u64) {
+        self.cooldown = seconds;
+    }
+
+    pub(crate) fn cooldown(&self) -> u64 {
+        self.cooldown
+    }
+
+    /// Token'ın birleştirilebileceği ilk anı döner (bekleme yoksa 0)
+    pub(crate) fn ready_at(&self, token_id: u64) -> u64 {
+        self.ready_at.get(&token_id).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn next_token_id(&self) -> u64 {
+        self.next_token_id
+    }
+
+    /// Girdileri kayıttan siler ve çıktının bekleme süresini başlatır
+    pub(crate) fn record(&mut self, inputs: [u64; 2], output: u64, now: u64) {
+        for token_id in inputs {
+            self.ready_at.remove(&token_id);
+        }
+        if self.cooldown > 0 {
+            self.ready_at.insert(output, now.saturating_add(self.cooldown));
+        }
+        self.next_token_id = output.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute(trait_type: &str, value: AttributeValue) -> Attribute {
+        Attribute {
+            trait_type: trait_type.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_default_inherit() {
+        let a = vec![
+            attribute("Power", AttributeValue::Number(10.0)),
+            attribute("Color", AttributeValue::String("Red".to_string())),
+        ];
+        let b = vec![
+            attribute("Color", AttributeValue::String("Blue".to_string())),
+            attribute("Power", AttributeValue::Number(20.0)),
+            attribute("Wings", AttributeValue::String("Yes".to_string())),
+        ];
+        let merged = default_inherit(&a, &b);
+        assert_eq!(merged[0].value, AttributeValue::Number(15.0));
+        assert_eq!(merged[1].value, AttributeValue::String("Red".to_string()));
+        assert_eq!(merged[2].trait_type, "Wings");
+
+        let mut merging = Merging::default();
+        merging.set_cooldown(60);
+        merging.record([1, 2], 3, 100);
+        assert_eq!(merging.ready_at(3), 160);
+        assert_eq!(merging.next_token_id(), 4);
+    }
+}
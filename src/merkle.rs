@@ -0,0 +1,157 @@
+use wasm_bindgen::prelude::*;
+
+use crate::hash::keccak256;
+use crate::bytes::{decode_hex, parse_hash, to_hex};
+
+/// Sıralı çiftlerle (OpenZeppelin `MerkleProof` uyumlu) oluşturulan Merkle ağacı
+pub(crate) struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>, // Yapraklardan köke doğru katmanlar
+}
+
+impl MerkleTree {
+    /// Verilen yapraklardan ağacı kurar (tek kalan düğüm bir üst katmana aynen taşınır)
+    pub(crate) fn new(leaves: Vec<[u8; 32]>) -> MerkleTree {
+        let mut layers = vec![leaves];
+        while layers.last().map(|layer| layer.len() > 1).unwrap_or(false) {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        MerkleTree { layers }
+    }
+
+    /// Ağacın kökünü döner (boş ağaç için sıfır özet)
+    pub(crate) fn root(&self) -> [u8; 32] {
+        self.layers.last().and_then(|layer| layer.first()).copied().unwrap_or([0u8; 32])
+    }
+
+    /// Belirtilen sıradaki yaprak için kardeş özetlerden oluşan kanıtı döner
+    pub(crate) fn proof(&self, mut index: usize) -> Vec<[u8; 32]> {
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+/// İki düğümü küçükten büyüğe sıralayıp birlikte özetler
+pub(crate) fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(first);
+    data[32..].copy_from_slice(second);
+    keccak256(&data)
+}
+
+/// Yaprağın kanıt ile köke ulaşıp ulaşmadığını kontrol eder
+pub(crate) fn verify(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
+
+/// Sahiplik yaprağı: keccak256(abi.encodePacked(uint256 tokenId, string owner))
+pub(crate) fn ownership_leaf(token_id: u64, owner: &str) -> [u8; 32] {
+    let mut data = vec![0u8; 24];
+    data.extend_from_slice(&token_id.to_be_bytes());
+    data.extend_from_slice(owner.as_bytes());
+    keccak256(&data)
+}
+
+/// Allowlist yaprağı: 20 baytlık hex adresler için keccak256(abi.encodePacked(address)),
+/// diğer adresler için keccak256(utf8(adres))
+pub(crate) fn allowlist_leaf(address: &str) -> [u8; 32] {
+    match decode_hex(address) {
+        Ok(bytes) if address.starts_with("0x") && bytes.len() == 20 => keccak256(&bytes),
+        _ => keccak256(address.as_bytes()),
+    }
+}
+
+/// Adres listesinden allowlist Merkle ağacı ve kanıtları üretir
+#[wasm_bindgen]
+pub struct Allowlist {
+    addresses: Vec<String>,
+    tree: MerkleTree,
+}
+
+#[wasm_bindgen]
+impl Allowlist {
+    /// Adres listesinden ağacı kurar (yapraklar verilen sırayla dizilir)
+    #[wasm_bindgen(constructor)]
+    pub fn new(addresses: Vec<String>) -> Allowlist {
+        let tree = MerkleTree::new(addresses.iter().map(|address| allowlist_leaf(address)).collect());
+        Allowlist { addresses, tree }
+    }
+
+    /// `set_allowlist_root` için kullanılacak kökü döner
+    pub fn root(&self) -> String {
+        to_hex(&self.tree.root())
+    }
+
+    /// Adres için kanıtı döner (listede yoksa boş)
+    pub fn proof(&self, address: String) -> Option<Vec<String>> {
+        let index = self.addresses.iter().position(|a| a == &address)?;
+        Some(self.tree.proof(index).iter().map(|hash| to_hex(hash)).collect())
+    }
+}
+
+/// `prove_ownership` ile üretilen kanıtı kontrat durumundan bağımsız olarak doğrular
+#[wasm_bindgen]
+pub fn verify_proof(root: String, token_id: u64, owner: String, proof: Vec<String>) -> bool {
+    let root = match parse_hash(&root) {
+        Ok(root) => root,
+        Err(_) => return false,
+    };
+    let proof: Result<Vec<[u8; 32]>, _> = proof.iter().map(|hash| parse_hash(hash)).collect();
+    match proof {
+        Ok(proof) => verify(&root, ownership_leaf(token_id, &owner), &proof),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proofs_for_odd_tree() {
+        let leaves: Vec<[u8; 32]> = (0..5).map(|i| ownership_leaf(i, "owner")).collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            assert!(verify(&tree.root(), *leaf, &tree.proof(index)));
+        }
+        assert!(!verify(&tree.root(), ownership_leaf(9, "owner"), &tree.proof(0)));
+        assert_eq!(MerkleTree::new(Vec::new()).root(), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_allowlist_proofs() {
+        let address = "0x5B38Da6a701c568545dCfcB03FcB875f56beddC4".to_string();
+        let allowlist = Allowlist::new(vec!["user1".to_string(), address.clone(), "user2".to_string()]);
+        let root = parse_hash(&allowlist.root()).unwrap();
+
+        let proof: Vec<[u8; 32]> = allowlist
+            .proof(address.clone())
+            .unwrap()
+            .iter()
+            .map(|h| parse_hash(h).unwrap())
+            .collect();
+        assert!(verify(&root, allowlist_leaf(&address), &proof));
+        assert!(!verify(&root, allowlist_leaf("user3"), &proof));
+        assert!(allowlist.proof("user3".to_string()).is_none());
+
+        // Hex addresses hash their raw 20 bytes like abi.encodePacked(address)
+        assert_eq!(allowlist_leaf(&address), keccak256(&decode_hex(&address).unwrap()));
+    }
+}
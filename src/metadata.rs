@@ -0,0 +1,139 @@
+use serde_json::{json, Map, Value};
+use wasm_bindgen::prelude::*;
+
+/// OpenSea metadata standardında izin verilen `display_type` değerleri
+const DISPLAY_TYPES: [&str; 4] = ["number", "boost_number", "boost_percentage", "date"];
+
+/// Token metadata JSON'unu `{name, description?, image?, attributes}` biçiminde oluşturur
+pub(crate) fn build(
+    name: String,
+    description: Option<String>,
+    image: Option<String>,
+    attributes: Vec<Value>,
+) -> String {
+    let mut object = Map::new();
+    object.insert("name".to_string(), json!(name));
+    if let Some(description) = description {
+        object.insert("description".to_string(), json!(description));
+    }
+    if let Some(image) = image {
+        object.insert("image".to_string(), json!(image));
+    }
+    object.insert("attributes".to_string(), Value::Array(attributes));
+    Value::Object(object).to_string()
+}
+
+/// Metadata JSON'unu OpenSea standardına göre doğrular ve bulunan ihlalleri döner (boşsa geçerli)
+#[wasm_bindgen]
+pub fn validate_metadata(json: String) -> Vec<String> {
+    let value: Value = match serde_json::from_str(&json) {
+        Ok(value) => value,
+        Err(e) => return vec![format!("Invalid JSON: {}", e)],
+    };
+    let object = match value.as_object() {
+        Some(object) => object,
+        None => return vec!["Metadata must be a JSON object".to_string()],
+    };
+
+    let mut errors = Vec::new();
+    match object.get("name") {
+        Some(Value::String(_)) => {}
+        Some(_) => errors.push("name must be a string".to_string()),
+        None => errors.push("name is required".to_string()),
+    }
+    for field in ["description", "image", "external_url", "animation_url"] {
+        if object.get(field).is_some_and(|value| !value.is_string()) {
+            errors.push(format!("{} must be a string", field));
+        }
+    }
+    if let Some(color) = object.get("background_color") {
+        let valid = color
+            .as_str()
+            .is_some_and(|color| color.len() == 6 && color.chars().all(|c| c.is_ascii_hexdigit()));
+        if !valid {
+            errors.push("background_color must be six hex digits without '#'".to_string());
+        }
+    }
+    match object.get("attributes") {
+        None => {}
+        Some(Value::Array(attributes)) => {
+            for (index, attribute) in attributes.iter().enumerate() {
+                validate_attribute(index, attribute, &mut errors);
+            }
+        }
+        Some(_) => errors.push("attributes must be an array".to_string()),
+    }
+    errors
+}
+
+fn validate_attribute(index: usize, attribute: &Value, errors: &mut Vec<String>) {
+    let attribute = match attribute.as_object() {
+        Some(attribute) => attribute,
+        None => return errors.push(format!("attributes[{}] must be an object", index)),
+    };
+    if attribute.get("trait_type").is_some_and(|value| !value.is_string()) {
+        errors.push(format!("attributes[{}].trait_type must be a string", index));
+    }
+
+    let value = match attribute.get("value") {
+        Some(value) => value,
+        None => return errors.push(format!("attributes[{}].value is required", index)),
+    };
+    match attribute.get("display_type") {
+        None if !value.is_string() && !value.is_number() => {
+            errors.push(format!("attributes[{}].value must be a string or a number", index))
+        }
+        None => {}
+        Some(Value::String(display_type)) if DISPLAY_TYPES.contains(&display_type.as_str()) => {
+            if !value.is_number() {
+                errors.push(format!("attributes[{}].value must be a number for {}", index, display_type));
+            } else if display_type == "date" && !value.is_u64() {
+                errors.push(format!("attributes[{}].value must be a Unix timestamp", index));
+            }
+        }
+        Some(_) => errors.push(format!("attributes[{}].display_type is not supported", index)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_metadata() {
+        let json = build("Spawn #1".to_string(), None, Some("ipfs://image".to_string()), vec![]);
+        assert_eq!(json, r#"{"attributes":[],"image":"ipfs://image","name":"Spawn #1"}"#);
+        assert!(validate_metadata(json).is_empty());
+    }
+
+    #[test]
+    fn test_validate_metadata() {
+        assert_eq!(validate_metadata("[]".to_string()), vec!["Metadata must be a JSON object".to_string()]);
+        assert_eq!(validate_metadata("{".to_string()).len(), 1);
+
+        let errors = validate_metadata(
+            r##"{
+                "image": 5,
+                "background_color": "#fff",
+                "attributes": [
+                    {"trait_type": "Level", "value": 5, "display_type": "number"},
+                    {"trait_type": "Born", "value": "yesterday", "display_type": "date"},
+                    {"trait_type": "Mood", "value": "happy", "display_type": "emoji"},
+                    {"trait_type": "Empty"}
+                ]
+            }"##
+            .to_string(),
+        );
+        assert_eq!(
+            errors,
+            vec![
+                "name is required".to_string(),
+                "image must be a string".to_string(),
+                "background_color must be six hex digits without '#'".to_string(),
+                "attributes[1].value must be a number for date".to_string(),
+                "attributes[2].display_type is not supported".to_string(),
+                "attributes[3].value is required".to_string(),
+            ]
+        );
+    }
+}
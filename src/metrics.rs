@@ -0,0 +1,95 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tsify::Tsify;
+
+use crate::perf::OpTiming;
+
+/// Her metrik grubuna sayılan genel işlemler: token basan, sahibini değiştiren veya yakan tüm işlemler
+///
+/// Bir işlem birden fazla gruba girebilir (`merge` tokenları yakıp yenisini basar). Farklı türde
+/// değişiklikler yapabilen genel yürütücüler (`execute_batch`, `execute_meta_tx`,
+/// `execute_proposal`) transfer grubunda sayılır.
+const MINT_OPS: [&str; 10] = [
+    "mint",
+    "mint_batch",
+    "mint_batch_async",
+    "mint_paid",
+    "mint_allowlisted",
+    "mint_dutch",
+    "mint_vested",
+    "claim",
+    "redeem_voucher",
+    "merge",
+];
+const TRANSFER_OPS: [&str; 20] = [
+    "transfer",
+    "transfer_batch",
+    "buy",
+    "buy_bundle",
+    "accept_offer",
+    "create_auction",
+    "settle_auction",
+    "cancel_auction",
+    "create_swap",
+    "deposit_swap",
+    "confirm_swap",
+    "cancel_swap",
+    "fulfill_order",
+    "attach_child",
+    "attach_own_child",
+    "detach_child",
+    "detach_own_child",
+    "execute_batch",
+    "execute_meta_tx",
+    "execute_proposal",
+];
+const APPROVAL_OPS: [&str; 3] = ["approve", "set_approval_for_all", "permit"];
+const BURN_OPS: [&str; 3] = ["burn", "consume", "merge"];
+
+/// Bir işlem grubunun toplam çağrı, başarısızlık ve süre değerleri
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Tsify)]
+pub struct OpMetrics {
+    pub count: u64, // Başarısız çağrılar dahil
+    pub failures: u64,
+    pub total_ms: f64,
+}
+
+impl OpMetrics {
+    fn sum<'a>(timings: &BTreeMap<&'static str, OpTiming>, ops: impl IntoIterator<Item = &'a str>) -> OpMetrics {
+        let mut metrics = OpMetrics::default();
+        for timing in ops.into_iter().filter_map(|op| timings.get(op)) {
+            metrics.count += timing.count;
+            metrics.failures += timing.failures;
+            metrics.total_ms += timing.total_ms;
+        }
+        metrics
+    }
+}
+
+/// Geliştirici panoları için işlem sayaçları ve durum boyutları
+///
+/// İşlem değerleri yalnızca en dıştaki çağrılardan türetilir; böylece bileşik bir işlemin (ör. `buy`)
+/// içinde yapılan `transfer` ikinci kez sayılmaz. `failed_operations` tüm genel işlemleri kapsar.
+/// `map_sizes` çağrı anındaki kayıt sayılarıdır ve sıfırlanmaz.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Tsify)]
+pub struct Metrics {
+    pub mints: OpMetrics,
+    pub transfers: OpMetrics,
+    pub approvals: OpMetrics,
+    pub burns: OpMetrics,
+    pub failed_operations: u64,
+    pub map_sizes: BTreeMap<&'static str, u64>, // Durum tablosu -> Kayıt sayısı
+}
+
+impl Metrics {
+    pub(crate) fn new(timings: &BTreeMap<&'static str, OpTiming>, map_sizes: BTreeMap<&'static str, u64>) -> Metrics {
+        Metrics {
+            mints: OpMetrics::sum(timings, MINT_OPS),
+            transfers: OpMetrics::sum(timings, TRANSFER_OPS),
+            approvals: OpMetrics::sum(timings, APPROVAL_OPS),
+            burns: OpMetrics::sum(timings, BURN_OPS),
+            failed_operations: timings.values().map(|timing| timing.failures).sum(),
+            map_sizes,
+        }
+    }
+}
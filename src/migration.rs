@@ -0,0 +1,267 @@
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
+use crate::erc721::ERC721;
+use crate::utils::log_event;
+
+/// İkili anlık görüntülerin şema sürümü
+///
+/// `ERC721`'in serileştirilen düzeni değiştiğinde artırılır ve önceki sürümden yenisine
+/// dönüştüren adım `Migrations::default` içine eklenir.
+pub const STATE_VERSION: u32 = 9;
+
+/// Sürümlü anlık görüntülerin başlığı; ardından küçük uçlu (little-endian) `u32` sürüm gelir
+///
+/// Sürümsüz (0) anlık görüntüler `owner` dizesinin uzunluğuyla başlar; bu başlık 4 GiB'tan
+/// uzun bir sahip adresine karşılık geleceğinden iki biçim karışmaz.
+const MAGIC: [u8; 4] = *b"SWE\xff";
+const HEADER_LEN: usize = MAGIC.len() + 4;
+
+thread_local! {
+    // Çözülen veya yazılan yükün düzen sürümü; eski düzenleri tanıyan serde uygulamaları okur
+    static LAYOUT_VERSION: Cell<u32> = const { Cell::new(STATE_VERSION) };
+}
+
+/// Şu an çözülen veya yazılan yükün düzen sürümü (migrasyon dışında her zaman `STATE_VERSION`)
+pub(crate) fn layout_version() -> u32 {
+    LAYOUT_VERSION.with(Cell::get)
+}
+
+/// `f`'i verilen düzen sürümüyle çalıştırır (eski düzeni okuyan/yazan migrasyon adımları için)
+pub(crate) fn with_layout<T>(version: u32, f: impl FnOnce() -> T) -> T {
+    let previous = LAYOUT_VERSION.with(|layout| layout.replace(version));
+    let result = f();
+    LAYOUT_VERSION.with(|layout| layout.set(previous));
+    result
+}
+
+/// `SINCE` düzeninde eklenmiş bir alanı yazar; daha eski düzenlerde alan hiç yer kaplamaz
+///
+/// `#[serde(serialize_with = "...::serialize_since::<N, _, _>")]` ile kullanılır.
+pub(crate) fn serialize_since<const SINCE: u32, T: Serialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if layout_version() < SINCE {
+        return serializer.serialize_unit();
+    }
+    value.serialize(serializer)
+}
+
+/// `SINCE` düzeninde eklenmiş bir alanı okur; daha eski düzenlerde varsayılan değeri döner
+pub(crate) fn deserialize_since<'de, const SINCE: u32, T: Deserialize<'de> + Default, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    if layout_version() < SINCE {
+        return Ok(T::default());
+    }
+    T::deserialize(deserializer)
+}
+
+/// `UNTIL` düzeninde kaldırılmış bir alanın yerini yazar; eski düzenlerde `T`'nin varsayılanı yazılır
+///
+/// Alan `()` olarak tutulur ve `removed_since` ile birlikte kullanılır; yeni düzenlerde hiç yazılmaz.
+pub(crate) fn serialize_until<const UNTIL: u32, T: Serialize + Default, S: Serializer>(
+    _: &(),
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if layout_version() < UNTIL {
+        return T::default().serialize(serializer);
+    }
+    serializer.serialize_unit()
+}
+
+/// `UNTIL` düzeninde kaldırılmış bir alanı okuyup atar; yeni ikili düzenlerde hiçbir şey okunmaz
+///
+/// JSON gibi okunabilir biçimlerde eski dışa aktarımlardaki değer de atlanır.
+pub(crate) fn deserialize_until<'de, const UNTIL: u32, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<(), D::Error> {
+    if layout_version() < UNTIL {
+        T::deserialize(deserializer)?;
+    } else if deserializer.is_human_readable() {
+        IgnoredAny::deserialize(deserializer)?;
+    }
+    Ok(())
+}
+
+/// Alanın `UNTIL` düzeninde kaldırıldığını ve artık yazılmayacağını söyler (`skip_serializing_if` için)
+pub(crate) fn removed_since<const UNTIL: u32>(_: &()) -> bool {
+    layout_version() >= UNTIL
+}
+
+/// `from` sürümündeki bincode yükünü `from + 1` sürümüne dönüştüren adım
+pub type MigrationStep = fn(Vec<u8>) -> Result<Vec<u8>, String>;
+
+/// Sürümlü başlığı bincode yükünün önüne ekler
+pub(crate) fn encode(payload: Vec<u8>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.extend_from_slice(&MAGIC);
+    bytes.extend_from_slice(&STATE_VERSION.to_le_bytes());
+    bytes.extend(payload);
+    bytes
+}
+
+/// Anlık görüntünün şema sürümünü ve bincode yükünü ayırır (başlıksızsa sürüm 0)
+pub(crate) fn decode(bytes: &[u8]) -> (u32, &[u8]) {
+    match bytes.strip_prefix(&MAGIC[..]) {
+        Some(rest) if rest.len() >= 4 => {
+            let (version, payload) = rest.split_at(4);
+            (u32::from_le_bytes([version[0], version[1], version[2], version[3]]), payload)
+        }
+        _ => (0, bytes),
+    }
+}
+
+/// Eski anlık görüntüleri güncel şemaya taşıyan sürüm adımları
+///
+/// Her adım bir sürümü bir sonrakine dönüştürür; yükleme sırasında anlık görüntünün sürümünden
+/// `STATE_VERSION`'a kadar sırayla uygulanır.
+#[derive(Clone, Debug)]
+pub struct Migrations {
+    steps: BTreeMap<u32, MigrationStep>, // Kaynak sürüm -> Dönüştürme adımı
+}
+
+impl Default for Migrations {
+    fn default() -> Migrations {
+        let mut migrations = Migrations { steps: BTreeMap::new() };
+        // Sürüm 0, başlık eklenmeden önceki biçimdir; yük düzeni sürüm 1 ile aynıdır
+        migrations.register(0, Ok);
+        migrations.register(1, add_role_admins);
+        migrations.register(2, add_role_expirations);
+        migrations.register(3, add_role_implications);
+        migrations.register(4, add_multisig);
+        migrations.register(5, add_role_audit_log);
+        migrations.register(6, add_pending_owner);
+        migrations.register(7, add_event_retention);
+        migrations.register(8, remove_contract_owner);
+        migrations
+    }
+}
+
+/// Sürüm 1 → 2: rol yöneticisine rol başına yönetici rolleri eklendi
+fn add_role_admins(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(1, &payload)
+}
+
+/// Sürüm 2 → 3: rol yöneticisine süreli atamalar ve saat eklendi
+fn add_role_expirations(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(2, &payload)
+}
+
+/// Sürüm 3 → 4: rol yöneticisine rol kapsamaları eklendi
+fn add_role_implications(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(3, &payload)
+}
+
+/// Sürüm 4 → 5: kontrata çoklu imza (multisig) yönetimi eklendi
+fn add_multisig(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(4, &payload)
+}
+
+/// Sürüm 5 → 6: rol yöneticisine rol değişikliği denetim günlüğü eklendi
+fn add_role_audit_log(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(5, &payload)
+}
+
+/// Sürüm 6 → 7: rol yöneticisine iki adımlı sahiplik devri eklendi
+fn add_pending_owner(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(6, &payload)
+}
+
+/// Sürüm 7 → 8: denetim günlükleri boyut ve yaş sınırı (halka tampon) kazandı
+fn add_event_retention(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(7, &payload)
+}
+
+/// Sürüm 8 → 9: kontratın rol yöneticisinden ayrı tuttuğu sahip alanı kaldırıldı
+fn remove_contract_owner(payload: Vec<u8>) -> Result<Vec<u8>, String> {
+    relayout(8, &payload)
+}
+
+/// Yükü `from` düzeninde çözüp yeni alanları varsayılanlarıyla `from + 1` düzeninde yeniden yazar
+fn relayout(from: u32, payload: &[u8]) -> Result<Vec<u8>, String> {
+    let state: ERC721 =
+        with_layout(from, || bincode::deserialize(payload)).map_err(|e| format!("Invalid ERC721 snapshot: {}", e))?;
+    Ok(with_layout(from + 1, || bincode::serialize(&state)).expect("contract state is always serializable"))
+}
+
+impl Migrations {
+    /// `from_version` sürümünden bir sonrakine dönüştüren adımı kaydeder (varsa değiştirir)
+    pub fn register(&mut self, from_version: u32, step: MigrationStep) {
+        self.steps.insert(from_version, step);
+    }
+
+    /// Anlık görüntüyü güncel sürüme taşıyıp kontratı yükler
+    pub fn migrate(&self, bytes: &[u8]) -> Result<ERC721, String> {
+        let (from_version, payload) = decode(bytes);
+        if from_version > STATE_VERSION {
+            return Err(format!(
+                "Snapshot version {} is newer than supported version {}",
+                from_version, STATE_VERSION
+            ));
+        }
+
+        let mut payload = payload.to_vec();
+        for version in from_version..STATE_VERSION {
+            let step = self
+                .steps
+                .get(&version)
+                .ok_or_else(|| format!("No migration registered from state version {}", version))?;
+            payload = step(payload)?;
+        }
+        if from_version < STATE_VERSION {
+            log_event("State Migrated", &format!("From: {}, To: {}", from_version, STATE_VERSION));
+        }
+
+        bincode::deserialize(&payload).map_err(|e| format!("Invalid ERC721 snapshot: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versioned_snapshots() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 10, String::new()).unwrap();
+        erc721.transfer("owner".to_string(), "alice".to_string(), 3).unwrap();
+
+        let bytes = erc721.snapshot();
+        assert_eq!(ERC721::snapshot_version(&bytes), STATE_VERSION);
+
+        // Snapshots written before the version header keep loading through the built-in steps
+        let legacy = with_layout(0, || bincode::serialize(&erc721)).unwrap();
+        assert_eq!(ERC721::snapshot_version(&legacy), 0);
+        let migrated = ERC721::migrate(&legacy).unwrap();
+        assert_eq!(migrated.owner_of(3), Some("alice".to_string()));
+        assert_eq!(migrated.state_root(), erc721.state_root());
+        assert_eq!(ERC721::snapshot_version(&migrated.snapshot()), STATE_VERSION);
+        // Fields introduced by later layouts start empty
+        assert!(migrated.role_audit_log().is_empty());
+        assert_eq!(erc721.role_audit_log().len(), 4);
+
+        let mut migrations = Migrations::default();
+        migrations.register(0, |_| Err("Unsupported legacy layout".to_string()));
+        assert_eq!(migrations.migrate(&legacy).err(), Some("Unsupported legacy layout".to_string()));
+        assert!(migrations.migrate(&bytes).is_ok());
+
+        // The contract owner kept next to the role manager before layout 9 is dropped on load
+        let mut v8 = MAGIC.to_vec();
+        v8.extend_from_slice(&8u32.to_le_bytes());
+        v8.extend(bincode::serialize("stale-owner").unwrap());
+        v8.extend(bincode::serialize(&erc721).unwrap());
+        let migrated = ERC721::migrate(&v8).unwrap();
+        assert_eq!(migrated.treasury(), "owner");
+        assert_eq!(migrated.snapshot(), bytes);
+        let json = erc721.to_json().replacen('{', "{\"owner\":\"stale-owner\",", 1);
+        assert_eq!(ERC721::from_json(json).unwrap().snapshot(), bytes);
+
+        let mut future = bytes.clone();
+        future[MAGIC.len()..HEADER_LEN].copy_from_slice(&(STATE_VERSION + 1).to_le_bytes());
+        assert!(ERC721::migrate(&future).err().unwrap().contains("newer"));
+    }
+}
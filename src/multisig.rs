@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::timelock::AdminAction;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct PendingAction {
+    pub(crate) action: AdminAction,
+    pub(crate) proposer: String, // İşlem bu adresin yetkisiyle uygulanır
+    pub(crate) confirmations: BTreeSet<String>, // Onay veren imzacılar
+    pub(crate) expires_at: u64, // Bu andan sonra onaylanamaz ve uygulanamaz (Unix saniye)
+}
+
+/// Yetkili işlemleri M-of-N imzacı onayına bağlayan çoklu imza (Gnosis Safe benzeri)
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Multisig {
+    signers: BTreeSet<String>,
+    threshold: u32, // 0 ise çoklu imza devre dışıdır
+    expiry: u64, // Bekleyen işlemin geçerlilik süresi (saniye)
+    pending: BTreeMap<u64, PendingAction>, // İşlem ID -> Onay Bekleyen İşlem
+    next_id: u64,
+    #[serde(skip)]
+    executing: bool, // Onaylanmış bir işlem uygulanıyor mu
+}
+
+impl Multisig {
+    /// İmzacıları ve eşiği aynı, bekleyen işlemi olmayan kopya (`clone_with` için)
+    pub(crate) fn settings_only(&self) -> Multisig {
+        Multisig {
+            signers: self.signers.clone(),
+            threshold: self.threshold,
+            expiry: self.expiry,
+            ..Multisig::default()
+        }
+    }
+
+    /// İmzacıları, eşiği ve geçerlilik süresini doğrulayıp ayarlar; eşik 0 ise devre dışı bırakır
+    ///
+    /// Bekleyen işlemler yeni imzacı kümesiyle geçersiz olacağından silinir.
+    pub(crate) fn configure(&mut self, signers: Vec<String>, threshold: u32, expiry: u64) -> Result<(), String> {
+        let signers: BTreeSet<String> = signers.into_iter().collect();
+        if threshold > 0 {
+            if signers.iter().any(String::is_empty) {
+                return Err("Signer address cannot be empty".to_string());
+            }
+            if threshold as usize > signers.len() {
+                return Err("Threshold cannot exceed the number of signers".to_string());
+            }
+            if expiry == 0 {
+                return Err("Expiry must be greater than zero".to_string());
+            }
+        }
+
+        self.signers = if threshold > 0 { signers } else { BTreeSet::new() };
+        self.threshold = threshold;
+        self.expiry = expiry;
+        self.pending.clear();
+        Ok(())
+    }
+
+    pub(crate) fn enabled(&self) -> bool {
+        self.threshold > 0
+    }
+
+    pub(crate) fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    pub(crate) fn signers(&self) -> Vec<String> {
+        self.signers.iter().cloned().collect()
+    }
+
+    /// Çoklu imza etkinse ve işlem onaylanmış bir öneriden gelmiyorsa hata döner
+    pub(crate) fn ensure_approved(&self) -> Result<(), String> {
+        if self.enabled() && !self.executing {
+            return Err("Action must be approved by the multisig".to_string());
+        }
+        Ok(())
+    }
+
+    fn ensure_signer(&self, caller: &str) -> Result<(), String> {
+        if !self.signers.contains(caller) {
+            return Err("Caller is not a multisig signer".to_string());
+        }
+        Ok(())
+    }
+
+    /// Yeni işlem önerir; öneren imzacının onayı otomatik sayılır
+    pub(crate) fn propose(&mut self, action: AdminAction, proposer: &str, now: u64) -> Result<u64, String> {
+        if !self.enabled() {
+            return Err("Multisig is not enabled".to_string());
+        }
+        self.ensure_signer(proposer)?;
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.pending.insert(
+            id,
+            PendingAction {
+                action,
+                proposer: proposer.to_string(),
+                confirmations: BTreeSet::from([proposer.to_string()]),
+                expires_at: now.saturating_add(self.expiry),
+            },
+        );
+        Ok(id)
+    }
+
+    /// Süresi dolmamış bekleyen işlemi döner
+    fn live_mut(&mut self, id: u64, now: u64) -> Result<&mut PendingAction, String> {
+        let pending = self.pending.get_mut(&id).ok_or("Action is not pending")?;
+        if now >= pending.expires_at {
+            return Err("Action has expired".to_string());
+        }
+        Ok(pending)
+    }
+
+    /// Onay ekler ve güncel onay sayısını döner
+    pub(crate) fn confirm(&mut self, id: u64, signer: &str, now: u64) -> Result<u32, String> {
+        self.ensure_signer(signer)?;
+        let pending = self.live_mut(id, now)?;
+        if !pending.confirmations.insert(signer.to_string()) {
+            return Err("Action is already confirmed by this signer".to_string());
+        }
+        Ok(pending.confirmations.len() as u32)
+    }
+
+    /// Onayı geri çeker ve güncel onay sayısını döner
+    pub(crate) fn revoke(&mut self, id: u64, signer: &str, now: u64) -> Result<u32, String> {
+        self.ensure_signer(signer)?;
+        let pending = self.live_mut(id, now)?;
+        if !pending.confirmations.remove(signer) {
+            return Err("Action is not confirmed by this signer".to_string());
+        }
+        Ok(pending.confirmations.len() as u32)
+    }
+
+    /// Eşiğe ulaşmış işlemi sıradan çıkarır ve döner; uygulama başarısız olursa `restore` ile geri konmalıdır
+    pub(crate) fn take_approved(&mut self, id: u64, now: u64) -> Result<PendingAction, String> {
+        let threshold = self.threshold;
+        let confirmations = self.live_mut(id, now)?.confirmations.len() as u32;
+        if confirmations < threshold {
+            return Err(format!("Action has {} of {} required confirmations", confirmations, threshold));
+        }
+        Ok(self.pending.remove(&id).expect("action is pending"))
+    }
+
+    pub(crate) fn restore(&mut self, id: u64, pending: PendingAction) {
+        self.pending.insert(id, pending);
+    }
+
+    pub(crate) fn get(&self, id: u64) -> Option<&PendingAction> {
+        self.pending.get(&id)
+    }
+
+    pub(crate) fn set_executing(&mut self, executing: bool) {
+        self.executing = executing;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirm_and_take() {
+        let mut multisig = Multisig::default();
+        assert!(multisig.ensure_approved().is_ok());
+        assert!(multisig.configure(vec!["a".to_string()], 2, 100).is_err());
+
+        let signers = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        multisig.configure(signers, 2, 100).unwrap();
+        assert!(multisig.ensure_approved().is_err());
+        assert!(multisig.propose(AdminAction::Pause, "mallory", 1_000).is_err());
+
+        let id = multisig.propose(AdminAction::Pause, "a", 1_000).unwrap();
+        assert!(multisig.take_approved(id, 1_000).is_err());
+        assert!(multisig.confirm(id, "a", 1_000).is_err());
+        assert_eq!(multisig.confirm(id, "b", 1_050).unwrap(), 2);
+        assert_eq!(multisig.revoke(id, "b", 1_050).unwrap(), 1);
+        assert!(multisig.take_approved(id, 1_050).is_err());
+
+        // Confirmations and execution stop at the expiry
+        assert!(multisig.confirm(id, "c", 1_100).is_err());
+        let id = multisig.propose(AdminAction::Unpause, "a", 1_100).unwrap();
+        multisig.confirm(id, "c", 1_150).unwrap();
+        assert!(multisig.take_approved(id, 1_200).is_err());
+        assert_eq!(multisig.take_approved(id, 1_199).unwrap().action, AdminAction::Unpause);
+    }
+}
@@ -0,0 +1,123 @@
+use wasm_bindgen::prelude::*;
+
+use crate::eip712::{encode_address, encode_u64, hash_struct, Eip712Domain};
+use crate::hash::keccak256;
+
+const CONSIDERATION_TYPE: &str = "ConsiderationItem(address recipient,uint256 amount)";
+const ORDER_TYPE: &str = "Order(address offerer,uint256 tokenId,ConsiderationItem[] consideration,uint256 nonce,\
+                          uint256 endTime)ConsiderationItem(address recipient,uint256 amount)";
+
+/// Siparişi karşılayan alıcının yapması gereken tek bir ödeme
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsiderationItem {
+    pub recipient: String, // 0x önekli 20 baytlık adres
+    pub amount: u64, // Simüle ETH cinsinden
+}
+
+#[wasm_bindgen]
+impl ConsiderationItem {
+    #[wasm_bindgen(constructor)]
+    pub fn new(recipient: String, amount: u64) -> ConsiderationItem {
+        ConsiderationItem { recipient, amount }
+    }
+}
+
+/// Token sahibinin zincir dışında imzaladığı satış emri (Seaport benzeri)
+///
+/// Emri karşılayan alıcı tüm `consideration` ödemelerini yapar ve token'ı alır.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Order {
+    pub offerer: String, // 0x önekli 20 baytlık adres, token'ın sahibi
+    pub token_id: u64,
+    pub consideration: Vec<ConsiderationItem>,
+    pub nonce: u64, // Emri verenin güncel sayacına eşit olmalıdır
+    pub end_time: u64, // Bu andan sonra karşılanamaz (Unix saniye)
+}
+
+#[wasm_bindgen]
+impl Order {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        offerer: String,
+        token_id: u64,
+        consideration: Vec<ConsiderationItem>,
+        nonce: u64,
+        end_time: u64,
+    ) -> Order {
+        Order {
+            offerer,
+            token_id,
+            consideration,
+            nonce,
+            end_time,
+        }
+    }
+
+    /// Alıcının ödeyeceği toplam tutarı döner
+    pub fn total(&self) -> Result<u64, String> {
+        self.consideration
+            .iter()
+            .try_fold(0u64, |total, item| total.checked_add(item.amount))
+            .ok_or_else(|| "Consideration overflow".to_string())
+    }
+}
+
+impl Order {
+    /// Emrin alan ayırıcısıyla birlikte imzalanacak EIP-712 özetini hesaplar
+    ///
+    /// Dizi alanı, elemanların yapı özetlerinin art arda eklenmesinin keccak256 özetiyle kodlanır.
+    pub(crate) fn digest(&self, domain: &Eip712Domain) -> Result<[u8; 32], String> {
+        let mut items = Vec::with_capacity(self.consideration.len() * 32);
+        for item in &self.consideration {
+            let item_hash = hash_struct(
+                CONSIDERATION_TYPE,
+                &[encode_address(&item.recipient)?, encode_u64(item.amount)],
+            );
+            items.extend_from_slice(&item_hash);
+        }
+        let struct_hash = hash_struct(
+            ORDER_TYPE,
+            &[
+                encode_address(&self.offerer)?,
+                encode_u64(self.token_id),
+                keccak256(&items),
+                encode_u64(self.nonce),
+                encode_u64(self.end_time),
+            ],
+        );
+        domain.digest(&struct_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip712::recover_address;
+    use crate::eip712::tests::sign;
+    use k256::ecdsa::SigningKey;
+
+    #[test]
+    fn test_order_digest() {
+        let key = SigningKey::from_slice(&[9u8; 32]).unwrap();
+        let domain = Eip712Domain::default();
+        let recipient = format!("0x{}", "33".repeat(20));
+        let consideration = vec![ConsiderationItem::new(recipient.clone(), 90), ConsiderationItem::new(recipient, 10)];
+        let order = Order::new(format!("0x{}", "11".repeat(20)), 1, consideration, 0, 1_000);
+        assert_eq!(order.total(), Ok(100));
+
+        let digest = order.digest(&domain).unwrap();
+        let (signature, address) = sign(&key, &digest);
+        assert_eq!(recover_address(&digest, &signature).unwrap(), address);
+
+        // Reordering the consideration changes the digest
+        let mut reordered = order.clone();
+        reordered.consideration.reverse();
+        assert_ne!(reordered.digest(&domain).unwrap(), digest);
+
+        let mut overflow = order;
+        overflow.consideration[0].amount = u64::MAX;
+        assert!(overflow.total().is_err());
+    }
+}
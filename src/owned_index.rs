@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::interner::AddressId;
+
+/// Token'ın bulunduğu liste ve listedeki komşuları
+#[derive(Clone, Debug)]
+struct Link {
+    owner: AddressId,
+    prev: Option<u64>,
+    next: Option<u64>,
+}
+
+/// Bir sahibin listesinin uçları
+#[derive(Clone, Copy, Debug)]
+struct Ends {
+    head: u64,
+    tail: u64,
+    len: usize,
+}
+
+/// Sahip -> Token listesi indeksi; her sahip için eklenme sırasını koruyan çift bağlı liste
+///
+/// Sahipler tekilleştirilmiş adres kimlikleriyle tutulur. Ekleme ve çıkarma O(1)'dir.
+/// Serileştirilmiş biçimi `Sahip Kimliği -> [Token ID]` eşlemesidir.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "BTreeMap<AddressId, Vec<u64>>", into = "BTreeMap<AddressId, Vec<u64>>")]
+pub(crate) struct OwnedIndex {
+    ends: HashMap<AddressId, Ends>, // Sahip -> Liste Uçları
+    links: HashMap<u64, Link>, // Token ID -> Liste Kaydı (her token tek bir listededir)
+}
+
+impl OwnedIndex {
+    /// Token'ı sahibin listesinin sonuna ekler
+    pub(crate) fn insert(&mut self, owner: AddressId, token_id: u64) {
+        let mut link = Link {
+            owner,
+            prev: None,
+            next: None,
+        };
+        match self.ends.get_mut(&owner) {
+            Some(ends) => {
+                self.links.get_mut(&ends.tail).expect("tail is linked").next = Some(token_id);
+                link.prev = Some(ends.tail);
+                self.links.insert(token_id, link);
+                ends.tail = token_id;
+                ends.len += 1;
+            }
+            None => {
+                self.links.insert(token_id, link);
+                let ends = Ends {
+                    head: token_id,
+                    tail: token_id,
+                    len: 1,
+                };
+                self.ends.insert(owner, ends);
+            }
+        }
+    }
+
+    /// Token'ı sahibin listesinden çıkarır; token listede yoksa `false` döner
+    pub(crate) fn remove(&mut self, owner: AddressId, token_id: u64) -> bool {
+        if !self.contains(owner, token_id) {
+            return false;
+        }
+        let link = self.links.remove(&token_id).expect("token is linked");
+        if let Some(prev) = link.prev {
+            self.links.get_mut(&prev).expect("neighbour is linked").next = link.next;
+        }
+        if let Some(next) = link.next {
+            self.links.get_mut(&next).expect("neighbour is linked").prev = link.prev;
+        }
+
+        let ends = self.ends.get_mut(&owner).expect("owner has a list");
+        ends.len -= 1;
+        match (link.prev, link.next) {
+            (None, None) => {
+                self.ends.remove(&owner);
+            }
+            (None, Some(next)) => ends.head = next,
+            (Some(prev), None) => ends.tail = prev,
+            (Some(_), Some(_)) => {}
+        }
+        true
+    }
+
+    /// Token'ın sahibin listesinde olup olmadığını döner
+    pub(crate) fn contains(&self, owner: AddressId, token_id: u64) -> bool {
+        self.links.get(&token_id).map(|link| link.owner == owner).unwrap_or(false)
+    }
+
+    pub(crate) fn len(&self, owner: AddressId) -> usize {
+        self.ends.get(&owner).map(|ends| ends.len).unwrap_or(0)
+    }
+
+    /// Her kaydın sahip kimliğini döner (bellek istatistikleri için)
+    pub(crate) fn owner_ids(&self) -> impl Iterator<Item = AddressId> + '_ {
+        self.links.values().map(|link| link.owner)
+    }
+
+    /// Sahibin tokenlarını eklenme sırasıyla döner
+    pub(crate) fn iter<'a>(&'a self, owner: AddressId) -> impl Iterator<Item = u64> + 'a {
+        let mut current = self.ends.get(&owner).map(|ends| ends.head);
+        std::iter::from_fn(move || {
+            let id = current?;
+            current = self.links[&id].next;
+            Some(id)
+        })
+    }
+}
+
+impl From<BTreeMap<AddressId, Vec<u64>>> for OwnedIndex {
+    fn from(lists: BTreeMap<AddressId, Vec<u64>>) -> OwnedIndex {
+        let mut index = OwnedIndex::default();
+        for (owner, tokens) in lists {
+            for token_id in tokens {
+                index.insert(owner, token_id);
+            }
+        }
+        index
+    }
+}
+
+impl From<OwnedIndex> for BTreeMap<AddressId, Vec<u64>> {
+    fn from(index: OwnedIndex) -> BTreeMap<AddressId, Vec<u64>> {
+        index
+            .ends
+            .keys()
+            .map(|&owner| (owner, index.iter(owner).collect()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owned_index_order() {
+        const ALICE: AddressId = 0;
+        const BOB: AddressId = 1;
+        let mut index = OwnedIndex::default();
+        for token_id in 1..=5 {
+            index.insert(ALICE, token_id);
+        }
+        index.insert(BOB, 6);
+
+        assert!(index.remove(ALICE, 3));
+        assert!(index.remove(ALICE, 1));
+        assert!(index.remove(ALICE, 5));
+        assert!(!index.remove(ALICE, 6));
+        assert!(!index.remove(ALICE, 3));
+        index.insert(ALICE, 7);
+        assert_eq!(index.iter(ALICE).collect::<Vec<_>>(), vec![2, 4, 7]);
+        assert_eq!(index.len(ALICE), 3);
+
+        let lists: BTreeMap<AddressId, Vec<u64>> = index.clone().into();
+        assert_eq!(lists[&BOB], vec![6]);
+        assert!(index.remove(BOB, 6));
+        assert_eq!(OwnedIndex::from(lists).len(BOB), 1);
+        assert_eq!(index.iter(BOB).count(), 0);
+    }
+}
@@ -0,0 +1,54 @@
+use wasm_bindgen::prelude::*;
+
+/// Token ID listesinin bir sayfası
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenPage {
+    pub items: Vec<u64>,
+    pub offset: u32,
+    pub total: u32, // Sayfalamadan önceki toplam kayıt sayısı
+}
+
+/// Adres listesinin bir sayfası
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddressPage {
+    pub items: Vec<String>,
+    pub offset: u32,
+    pub total: u32, // Sayfalamadan önceki toplam kayıt sayısı
+}
+
+/// Sıralı kayıtlardan `offset`'ten başlayan en fazla `limit` kaydı ve toplam sayıyı döner
+pub(crate) fn page<T>(items: impl ExactSizeIterator<Item = T>, offset: u32, limit: u32) -> (Vec<T>, u32) {
+    let total = items.len() as u32;
+    let items = items.skip(offset as usize).take(limit as usize).collect();
+    (items, total)
+}
+
+impl TokenPage {
+    pub(crate) fn new(tokens: impl ExactSizeIterator<Item = u64>, offset: u32, limit: u32) -> TokenPage {
+        let (items, total) = page(tokens, offset, limit);
+        TokenPage { items, offset, total }
+    }
+}
+
+impl AddressPage {
+    pub(crate) fn new(addresses: impl ExactSizeIterator<Item = String>, offset: u32, limit: u32) -> AddressPage {
+        let (items, total) = page(addresses, offset, limit);
+        AddressPage { items, offset, total }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_bounds() {
+        let tokens: Vec<u64> = (1..=10).collect();
+        let page = TokenPage::new(tokens.iter().copied(), 8, 5);
+        assert_eq!(page.items, vec![9, 10]);
+        assert_eq!(page.total, 10);
+        assert!(TokenPage::new(tokens.into_iter(), 20, 5).items.is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+use crate::utils::log_event;
+
+/// Tek bir paylaşım havuzu: alıcı payları ve serbest bırakılan miktarlar
+#[derive(Clone, Default)]
+struct Split {
+    payees: Vec<String>, // Kayıt sırasına göre alıcılar
+    shares: HashMap<String, u64>, // Alıcı Adresi -> Pay
+    total_shares: u64,
+    total_received: u64,
+    released: HashMap<String, u64>, // Alıcı Adresi -> Serbest Bırakılan Miktar
+}
+
+impl Split {
+    fn add_payee(&mut self, payee: String, shares: u64) -> Result<(), String> {
+        if shares == 0 {
+            return Err("Shares must be greater than zero".to_string());
+        }
+        if self.shares.contains_key(&payee) {
+            return Err("Payee already has shares".to_string());
+        }
+
+        self.payees.push(payee.clone());
+        self.shares.insert(payee, shares);
+        self.total_shares += shares;
+        Ok(())
+    }
+
+    /// Alıcının henüz serbest bırakılmamış payını hesaplar
+    fn releasable(&self, payee: &str) -> u64 {
+        let shares = match self.shares.get(payee) {
+            Some(&shares) => shares,
+            None => return 0,
+        };
+        let entitled = (self.total_received as u128 * shares as u128 / self.total_shares as u128) as u64;
+        entitled - self.released.get(payee).copied().unwrap_or(0)
+    }
+
+    fn release(&mut self, payee: &str) -> u64 {
+        let amount = self.releasable(payee);
+        if amount > 0 {
+            *self.released.entry(payee.to_string()).or_default() += amount;
+        }
+        amount
+    }
+}
+
+/// Royalty ve satış gelirlerini paylara göre dağıtan simülasyon modülü
+#[wasm_bindgen]
+pub struct PaymentSplitter {
+    owner: String,
+    collection_split: Split,
+    token_splits: HashMap<u64, Split>, // Token ID -> Token'a Özel Paylaşım
+    balances: HashMap<String, u64>, // Adres -> Simüle Edilmiş Bakiye
+}
+
+#[wasm_bindgen]
+impl PaymentSplitter {
+    /// Yeni bir ödeme paylaştırıcı oluşturur
+    #[wasm_bindgen(constructor)]
+    pub fn new(owner: String) -> PaymentSplitter {
+        log_event("PaymentSplitter Created", &format!("Owner: {}", owner));
+        PaymentSplitter {
+            owner,
+            collection_split: Split::default(),
+            token_splits: HashMap::new(),
+            balances: HashMap::new(),
+        }
+    }
+
+    /// Koleksiyon geneli paylaşıma alıcı ekler (sadece sahip yapabilir)
+    pub fn add_payee(&mut self, caller: String, payee: String, shares: u64) -> Result<(), String> {
+        self.ensure_owner(&caller)?;
+
+        self.collection_split.add_payee(payee.clone(), shares)?;
+        log_event("Payee Added", &format!("Payee: {}, Shares: {}", payee, shares));
+        Ok(())
+    }
+
+    /// Belirli bir token'ın paylaşımına alıcı ekler (sadece sahip yapabilir)
+    pub fn add_token_payee(&mut self, caller: String, token_id: u64, payee: String, shares: u64) -> Result<(), String> {
+        self.ensure_owner(&caller)?;
+
+        self.token_splits.entry(token_id).or_default().add_payee(payee.clone(), shares)?;
+        log_event(
+            "Payee Added",
+            &format!("Token ID: {}, Payee: {}, Shares: {}", token_id, payee, shares),
+        );
+        Ok(())
+    }
+
+    /// Koleksiyon geneli ödeme alır
+    pub fn receive_payment(&mut self, amount: u64) -> Result<(), String> {
+        Self::credit(&mut self.collection_split, amount)?;
+        log_event("Payment Received", &format!("Amount: {}", amount));
+        Ok(())
+    }
+
+    /// Belirli bir token için ödeme alır (token paylaşımı yoksa koleksiyona gider)
+    pub fn receive_token_payment(&mut self, token_id: u64, amount: u64) -> Result<(), String> {
+        let split = match self.token_splits.get_mut(&token_id) {
+            Some(split) => split,
+            None => &mut self.collection_split,
+        };
+        Self::credit(split, amount)?;
+        log_event("Payment Received", &format!("Token ID: {}, Amount: {}", token_id, amount));
+        Ok(())
+    }
+
+    /// Alıcının tüm paylaşımlardaki bekleyen alacağını döner
+    pub fn releasable(&self, payee: String) -> u64 {
+        self.collection_split.releasable(&payee)
+            + self.token_splits.values().map(|split| split.releasable(&payee)).sum::<u64>()
+    }
+
+    /// Bekleyen alacağı alıcının bakiyesine aktarır ve aktarılan miktarı döner
+    pub fn release(&mut self, payee: String) -> Result<u64, String> {
+        let mut amount = self.collection_split.release(&payee);
+        for split in self.token_splits.values_mut() {
+            amount += split.release(&payee);
+        }
+
+        if amount == 0 {
+            log_event("Release Failed", &format!("Payee: {}", payee));
+            return Err("Account is not due payment".to_string());
+        }
+
+        *self.balances.entry(payee.clone()).or_default() += amount;
+        log_event("Payment Released", &format!("Payee: {}, Amount: {}", payee, amount));
+        Ok(amount)
+    }
+
+    /// Alıcının tüm paylaşımlarda serbest bırakılmış toplam miktarını döner
+    pub fn released(&self, payee: String) -> u64 {
+        std::iter::once(&self.collection_split)
+            .chain(self.token_splits.values())
+            .map(|split| split.released.get(&payee).copied().unwrap_or(0))
+            .sum()
+    }
+
+    /// Adresin simüle edilmiş bakiyesini döner
+    pub fn balance_of(&self, account: String) -> u64 {
+        self.balances.get(&account).copied().unwrap_or(0)
+    }
+
+    /// Koleksiyon geneli paylaşımda alıcının payını döner
+    pub fn shares(&self, payee: String) -> u64 {
+        self.collection_split.shares.get(&payee).copied().unwrap_or(0)
+    }
+
+    /// Token paylaşımında alıcının payını döner
+    pub fn token_shares(&self, token_id: u64, payee: String) -> u64 {
+        self.token_splits
+            .get(&token_id)
+            .and_then(|split| split.shares.get(&payee).copied())
+            .unwrap_or(0)
+    }
+
+    /// Koleksiyon geneli paylaşımdaki alıcıları kayıt sırasıyla listeler
+    pub fn payees(&self) -> Vec<String> {
+        self.collection_split.payees.clone()
+    }
+
+    fn ensure_owner(&self, caller: &str) -> Result<(), String> {
+        if caller != self.owner {
+            log_event("Payee Registration Failed", "Unauthorized attempt");
+            return Err("Only the owner can register payees".to_string());
+        }
+        Ok(())
+    }
+
+    fn credit(split: &mut Split, amount: u64) -> Result<(), String> {
+        if split.total_shares == 0 {
+            return Err("No payees registered".to_string());
+        }
+        split.total_received = split.total_received.checked_add(amount).ok_or("Payment overflows")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_split_release() {
+        let mut splitter = PaymentSplitter::new("owner".to_string());
+
+        // Only the owner can register payees
+        assert!(splitter.add_payee("user1".to_string(), "artist".to_string(), 3).is_err());
+        splitter.add_payee("owner".to_string(), "artist".to_string(), 3).unwrap();
+        splitter.add_payee("owner".to_string(), "dev".to_string(), 1).unwrap();
+
+        splitter.receive_payment(1_000).unwrap();
+        assert_eq!(splitter.releasable("artist".to_string()), 750);
+        assert_eq!(splitter.release("artist".to_string()).unwrap(), 750);
+        assert_eq!(splitter.balance_of("artist".to_string()), 750);
+
+        // Nothing left until more funds arrive
+        assert!(splitter.release("artist".to_string()).is_err());
+        splitter.receive_payment(400).unwrap();
+        assert_eq!(splitter.release("artist".to_string()).unwrap(), 300);
+        assert_eq!(splitter.release("dev".to_string()).unwrap(), 350);
+        assert_eq!(splitter.released("artist".to_string()), 1_050);
+    }
+
+    #[test]
+    fn test_token_split_overrides_collection() {
+        let mut splitter = PaymentSplitter::new("owner".to_string());
+        splitter.add_payee("owner".to_string(), "artist".to_string(), 1).unwrap();
+        splitter.add_token_payee("owner".to_string(), 7, "artist".to_string(), 1).unwrap();
+        splitter.add_token_payee("owner".to_string(), 7, "collab".to_string(), 1).unwrap();
+
+        splitter.receive_token_payment(7, 100).unwrap();
+        splitter.receive_token_payment(8, 100).unwrap();
+
+        assert_eq!(splitter.release("artist".to_string()).unwrap(), 150);
+        assert_eq!(splitter.release("collab".to_string()).unwrap(), 50);
+    }
+}
@@ -0,0 +1,205 @@
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use tsify::Tsify;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+extern "C" {
+    // Pencere ve worker ortamlarında ortak olan yüksek çözünürlüklü saat
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
+}
+
+/// Tarayıcının performans zaman çizelgesine span kaydeden `tracing` abonesini kurar
+///
+/// Her genel işlem `operation` adlı, `name` alanı işlem adı olan bir span açar. Abone zaten
+/// kurulduysa hata döner.
+#[cfg(all(target_arch = "wasm32", feature = "tracing"))]
+#[wasm_bindgen]
+pub fn init_tracing() -> Result<(), String> {
+    tracing_wasm::try_set_as_global_default().map_err(|err| err.to_string())
+}
+
+/// Süre ölçümü için monoton saat (milisaniye, kesirli)
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn precise_now_ms() -> f64 {
+    performance_now()
+}
+
+/// Süre ölçümü için monoton saat (milisaniye, kesirli)
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn precise_now_ms() -> f64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1_000.0
+}
+
+/// Tek bir işlemin sayaç ve süre özeti
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Tsify)]
+pub struct OpTiming {
+    pub count: u64,
+    pub failures: u64, // `Err` ile sonuçlanan çağrılar
+    pub total_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Ölçülen çağrıların süre özetleri
+#[derive(Clone, Debug, Default)]
+struct Counters {
+    all: BTreeMap<&'static str, OpTiming>, // İşlem adı -> Tüm çağrılar (iç içe olanlar dahil)
+    outermost: BTreeMap<&'static str, OpTiming>, // İşlem adı -> Başka bir ölçüm içinde başlamayan çağrılar
+    open: u32, // Açık zamanlayıcı sayısı
+}
+
+impl Counters {
+    fn record(map: &mut BTreeMap<&'static str, OpTiming>, op: &'static str, elapsed: f64, failed: bool) {
+        let timing = map.entry(op).or_default();
+        timing.count += 1;
+        if failed {
+            timing.failures += 1;
+        }
+        timing.total_ms += elapsed;
+        timing.max_ms = timing.max_ms.max(elapsed);
+    }
+}
+
+/// İşlem adı -> süre özeti; `&self` sorguları da ölçülebilsin diye iç değişkenlikle tutulur
+///
+/// Kopyalar sayaçları paylaşmaz. Durum serileştirilmez; yalnızca çalışma anı gözlemidir.
+#[derive(Debug, Default)]
+pub(crate) struct PerfCounters(Rc<RefCell<Counters>>);
+
+impl Clone for PerfCounters {
+    fn clone(&self) -> PerfCounters {
+        let counters = Counters {
+            open: 0,
+            ..self.0.borrow().clone()
+        };
+        PerfCounters(Rc::new(RefCell::new(counters)))
+    }
+}
+
+impl PerfCounters {
+    /// Ölçümü başlatır; dönen zamanlayıcı düşürüldüğünde süre kaydedilir
+    ///
+    /// `tracing` özelliği açıksa zamanlayıcı yaşadığı sürece işlemin span'i de açık kalır.
+    pub(crate) fn start(&self, op: &'static str) -> Timer {
+        let outermost = {
+            let mut counters = self.0.borrow_mut();
+            counters.open += 1;
+            counters.open == 1
+        };
+        Timer {
+            counters: self.0.clone(),
+            op,
+            started: precise_now_ms(),
+            outermost,
+            failed: false,
+            #[cfg(feature = "tracing")]
+            _span: tracing::info_span!("operation", name = op).entered(),
+        }
+    }
+
+    /// `Result` dönen işlemin ölçümünü başlatır
+    ///
+    /// Sonuç `Timer::finish` ile bildirilmeden düşürülen zamanlayıcının (ör. `?` ile erken dönüş)
+    /// çağrısı başarısız sayılır.
+    pub(crate) fn start_fallible(&self, op: &'static str) -> Timer {
+        let mut timer = self.start(op);
+        timer.failed = true;
+        timer
+    }
+
+    pub(crate) fn report(&self) -> BTreeMap<&'static str, OpTiming> {
+        self.0.borrow().all.clone()
+    }
+
+    /// Yalnızca en dıştaki çağrıların özeti; bileşik işlemlerin iç çağrıları ayrıca sayılmaz
+    pub(crate) fn outermost_report(&self) -> BTreeMap<&'static str, OpTiming> {
+        self.0.borrow().outermost.clone()
+    }
+
+    pub(crate) fn reset(&self) {
+        let mut counters = self.0.borrow_mut();
+        counters.all.clear();
+        counters.outermost.clear();
+    }
+}
+
+/// Kapsam sonunda süreyi sayaçlara ekleyen zamanlayıcı
+pub(crate) struct Timer {
+    counters: Rc<RefCell<Counters>>,
+    op: &'static str,
+    started: f64,
+    outermost: bool,
+    failed: bool,
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan, // Süre kaydedildikten sonra kapanır
+}
+
+impl Timer {
+    /// İşlemin sonucunu kaydeder ve aynen döner; `Err` sonuç çağrıyı başarısız sayar
+    pub(crate) fn finish<T, E>(mut self, result: Result<T, E>) -> Result<T, E> {
+        self.failed = result.is_err();
+        result
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let elapsed = (precise_now_ms() - self.started).max(0.0);
+        let mut counters = self.counters.borrow_mut();
+        counters.open = counters.open.saturating_sub(1);
+        Counters::record(&mut counters.all, self.op, elapsed, self.failed);
+        if self.outermost {
+            Counters::record(&mut counters.outermost, self.op, elapsed, self.failed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timers_accumulate() {
+        let counters = PerfCounters::default();
+        for _ in 0..3 {
+            let _timer = counters.start("mint");
+        }
+        {
+            // A fallible call dropped without reporting its result (an early `?` return) failed
+            let _timer = counters.start_fallible("transfer");
+        }
+        {
+            // A failure event logged by a successful call is not counted
+            let timer = counters.start_fallible("transfer");
+            crate::utils::log_event("Transfer Failed", "Retried");
+            assert!(timer.finish(Ok::<(), String>(())).is_ok());
+        }
+        {
+            // Nested calls are reported but only the outermost call counts as an operation
+            let buy = counters.start_fallible("buy");
+            let transfer = counters.start_fallible("transfer");
+            assert!(transfer.finish(Err::<(), _>("Frozen")).is_err());
+            assert!(buy.finish(Err::<(), _>("Frozen")).is_err());
+        }
+        assert_eq!(counters.outermost_report()["transfer"].count, 2);
+        assert_eq!(counters.outermost_report()["buy"].failures, 1);
+
+        let copy = counters.clone();
+        counters.reset();
+        assert!(counters.report().is_empty());
+        let report = copy.report();
+        assert_eq!((report["mint"].count, report["mint"].failures), (3, 0));
+        assert!(report["mint"].max_ms <= report["mint"].total_ms);
+        assert_eq!((report["transfer"].count, report["transfer"].failures), (3, 2));
+    }
+}
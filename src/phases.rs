@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// Zamanlanmış bir mint aşaması (presale, public ...)
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MintPhase {
+    pub name: String,
+    pub start_ts: u64, // Başlangıç (dahil, Unix saniye)
+    pub end_ts: u64, // Bitiş (hariç, Unix saniye)
+    pub price: u64, // Token başına fiyat (simüle edilmiş birim)
+    pub per_wallet_limit: Option<u64>, // Bu aşamada cüzdan başına mint sınırı
+}
+
+impl MintPhase {
+    /// Aşamanın verilen anda aktif olup olmadığını döner
+    pub fn is_active(&self, now: u64) -> bool {
+        self.start_ts <= now && now < self.end_ts
+    }
+
+    fn overlaps(&self, other: &MintPhase) -> bool {
+        self.start_ts < other.end_ts && other.start_ts < self.end_ts
+    }
+}
+
+/// Başlangıç zamanına göre sıralı, çakışmayan aşama takvimi
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct PhaseSchedule {
+    phases: Vec<MintPhase>,
+}
+
+impl PhaseSchedule {
+    /// Aşamayı ekler veya aynı isimli aşamayı günceller
+    pub(crate) fn configure(&mut self, phase: MintPhase) -> Result<(), String> {
+        if phase.start_ts >= phase.end_ts {
+            return Err("Phase must start before it ends".to_string());
+        }
+        if self.phases.iter().any(|p| p.name != phase.name && p.overlaps(&phase)) {
+            return Err("Phase overlaps an existing phase".to_string());
+        }
+
+        self.phases.retain(|p| p.name != phase.name);
+        self.phases.push(phase);
+        self.phases.sort_by_key(|p| p.start_ts);
+        Ok(())
+    }
+
+    /// Verilen anda aktif olan aşamayı döner
+    pub(crate) fn active(&self, now: u64) -> Option<&MintPhase> {
+        self.phases.iter().find(|p| p.is_active(now))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.phases.is_empty()
+    }
+
+    pub(crate) fn all(&self) -> &[MintPhase] {
+        &self.phases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(name: &str, start_ts: u64, end_ts: u64) -> MintPhase {
+        MintPhase {
+            name: name.to_string(),
+            start_ts,
+            end_ts,
+            price: 0,
+            per_wallet_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_schedule() {
+        let mut schedule = PhaseSchedule::default();
+        schedule.configure(phase("public", 200, 300)).unwrap();
+        schedule.configure(phase("presale", 100, 200)).unwrap();
+
+        assert!(schedule.configure(phase("late", 250, 400)).is_err());
+        assert!(schedule.configure(phase("empty", 400, 400)).is_err());
+
+        assert_eq!(schedule.active(199).unwrap().name, "presale");
+        assert_eq!(schedule.active(200).unwrap().name, "public");
+        assert!(schedule.active(300).is_none());
+
+        // Reconfiguring a phase replaces it
+        schedule.configure(phase("public", 200, 500)).unwrap();
+        assert_eq!(schedule.all().len(), 2);
+        assert_eq!(schedule.active(450).unwrap().name, "public");
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+
+/// Tokenların biriktirdiği XP ve seviye eğrisi
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Progression {
+    xp: BTreeMap<u64, u64>, // Token ID -> Toplam XP
+    curve: Vec<u64>, // `curve[i]`: (i + 2). seviyeye ulaşmak için gereken toplam XP (artan sırada)
+}
+
+impl Default for Progression {
+    fn default() -> Progression {
+        Progression {
+            xp: BTreeMap::new(),
+            curve: vec![100, 300, 600, 1_000, 1_500],
+        }
+    }
+}
+
+impl Progression {
+    /// Seviye eğrisi aynı, XP kaydı olmayan kopya (`clone_with` için)
+    pub(crate) fn settings_only(&self) -> Progression {
+        Progression {
+            xp: BTreeMap::new(),
+            curve: self.curve.clone(),
+        }
+    }
+
+    /// Seviye eğrisini ayarlar; eşikler sıfırdan büyük ve kesin artan olmalıdır
+    pub(crate) fn set_curve(&mut self, curve: Vec<u64>) -> Result<(), String> {
+        if curve.first() == Some(&0) || curve.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err("XP thresholds must be positive and strictly increasing".to_string());
+        }
+        self.curve = curve;
+        Ok(())
+    }
+
+    pub(crate) fn curve(&self) -> &[u64] {
+        &self.curve
+    }
+
+    pub(crate) fn xp(&self, token_id: u64) -> u64 {
+        self.xp.get(&token_id).copied().unwrap_or(0)
+    }
+
+    /// XP'nin karşılık geldiği seviye (1'den başlar)
+    pub(crate) fn level_for(&self, xp: u64) -> u32 {
+        1 + self.curve.partition_point(|&threshold| threshold <= xp) as u32
+    }
+
+    /// XP ekler ve `(önceki seviye, yeni seviye)` döner
+    pub(crate) fn add(&mut self, token_id: u64, amount: u64) -> (u32, u32) {
+        let xp = self.xp.entry(token_id).or_default();
+        let before = *xp;
+        *xp = xp.saturating_add(amount);
+        let after = *xp;
+        (self.level_for(before), self.level_for(after))
+    }
+
+    pub(crate) fn clear_token(&mut self, token_id: u64) {
+        self.xp.remove(&token_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xp_curve() {
+        let mut progression = Progression::default();
+        assert!(progression.set_curve(vec![10, 10]).is_err());
+        assert!(progression.set_curve(vec![0, 10]).is_err());
+        progression.set_curve(vec![10, 30]).unwrap();
+
+        assert_eq!(progression.add(1, 9), (1, 1));
+        assert_eq!(progression.add(1, 1), (1, 2));
+        assert_eq!(progression.add(1, 100), (2, 3));
+        assert_eq!(progression.xp(1), 110);
+        progression.clear_token(1);
+        assert_eq!(progression.level_for(progression.xp(1)), 1);
+    }
+}
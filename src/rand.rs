@@ -0,0 +1,131 @@
+use wasm_bindgen::prelude::*;
+
+use crate::hash::keccak256;
+use crate::bytes::parse_hash;
+
+/// Tohumdan üretilen, her çalıştırmada aynı diziyi veren rastgele sayı üreteci
+///
+/// Her çıktı `keccak256(tohum || sayaç)` özetinden alınır; aynı tohum her ortamda aynı
+/// karıştırmayı ve çekilişi verir.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct SeededRng {
+    seed: [u8; 32],
+    counter: u64,
+}
+
+#[wasm_bindgen]
+impl SeededRng {
+    /// Kullanıcının verdiği metin tohumdan üreteç oluşturur
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: String) -> SeededRng {
+        SeededRng::from_seed(keccak256(seed.as_bytes()))
+    }
+
+    /// 32 baytlık (simüle) VRF çıktısından üreteç oluşturur
+    pub fn from_vrf_output(output: String) -> Result<SeededRng, String> {
+        Ok(SeededRng::from_seed(parse_hash(&output)?))
+    }
+
+    /// Sıradaki 64 bitlik sayıyı döner
+    pub fn next_u64(&mut self) -> u64 {
+        let mut data = [0u8; 40];
+        data[..32].copy_from_slice(&self.seed);
+        data[32..].copy_from_slice(&self.counter.to_be_bytes());
+        self.counter += 1;
+
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&keccak256(&data)[..8]);
+        u64::from_be_bytes(word)
+    }
+
+    /// `[0, bound)` aralığında eşit dağılımlı bir sayı döner
+    pub fn next_below(&mut self, bound: u64) -> Result<u64, String> {
+        if bound == 0 {
+            return Err("Bound must be greater than zero".to_string());
+        }
+        // Mod yanlılığını önlemek için aralığın tam katlarının dışındaki değerler atlanır
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return Ok(value % bound);
+            }
+        }
+    }
+
+    /// Listeyi Fisher-Yates ile karıştırır
+    pub fn shuffle(&mut self, mut items: Vec<u64>) -> Vec<u64> {
+        self.shuffle_slice(&mut items);
+        items
+    }
+
+    /// Katılımcılar arasından tekrar etmeden en fazla `count` kazanan çeker
+    pub fn draw(&mut self, entrants: Vec<String>, count: u32) -> Vec<String> {
+        self.sample(entrants, count as usize)
+    }
+}
+
+impl SeededRng {
+    pub(crate) fn from_seed(seed: [u8; 32]) -> SeededRng {
+        SeededRng { seed, counter: 0 }
+    }
+
+    pub(crate) fn shuffle_slice<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next_below(i as u64 + 1).expect("bound is positive") as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Listeden tekrar etmeden `count` öğe seçer (seçim sırasıyla)
+    pub(crate) fn sample<T>(&mut self, mut items: Vec<T>, count: usize) -> Vec<T> {
+        let count = count.min(items.len());
+        for i in 0..count {
+            let j = i + self.next_below((items.len() - i) as u64).expect("bound is positive") as usize;
+            items.swap(i, j);
+        }
+        items.truncate(count);
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rng_is_reproducible() {
+        let mut a = SeededRng::new("drop-42".to_string());
+        let mut b = SeededRng::new("drop-42".to_string());
+        let sequence: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        assert_eq!(sequence, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(SeededRng::new("drop-43".to_string()).next_u64(), sequence[0]);
+
+        let shuffled = a.shuffle((0..20).collect());
+        assert_eq!(shuffled, b.shuffle((0..20).collect()));
+        let mut sorted = shuffled.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+        assert_ne!(shuffled, sorted);
+
+        let vrf = format!("0x{}", "ab".repeat(32));
+        assert_eq!(
+            SeededRng::from_vrf_output(vrf.clone()).unwrap().next_u64(),
+            SeededRng::from_vrf_output(vrf).unwrap().next_u64()
+        );
+        assert!(SeededRng::from_vrf_output("0x12".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_draw() {
+        let entrants: Vec<String> = (0..10).map(|i| format!("user{}", i)).collect();
+        let mut rng = SeededRng::new("raffle".to_string());
+        let winners = rng.draw(entrants.clone(), 3);
+        assert_eq!(winners.len(), 3);
+        assert!(winners.iter().all(|winner| entrants.contains(winner)));
+        assert!(winners[0] != winners[1] && winners[1] != winners[2] && winners[0] != winners[2]);
+        assert_eq!(rng.draw(entrants, 20).len(), 10);
+        assert!(rng.next_below(0).is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+use crate::attributes::{Attribute, AttributeStore};
+
+/// Nadirlik puanlama yöntemi
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RarityMethod {
+    /// rarity.tools tarzı: her özellik için `1 / sıklık` toplamı
+    RarityScore,
+    /// Bilgi içeriği: her özellik için `-log2(sıklık)` toplamı (istatistiksel nadirlikle aynı sıralama)
+    InformationContent,
+}
+
+/// Son hesaplanan nadirlik puanları ve sıralaması
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RarityTable {
+    scores: HashMap<u64, f64>, // Token ID -> Puan
+    ranking: Vec<u64>, // En nadirden en yaygına Token ID'ler
+    ranks: HashMap<u64, u32>, // Token ID -> Sıra (1'den başlar)
+}
+
+impl RarityTable {
+    /// Özelliği olan tüm tokenların puanlarını hesaplar
+    ///
+    /// Sıklık, özelliğin o değerine sahip token sayısının özelliği olan token sayısına oranıdır.
+    /// Eşit puanlarda küçük Token ID önce gelir.
+    pub(crate) fn compute(store: &AttributeStore, method: RarityMethod) -> RarityTable {
+        let scores = store
+            .iter()
+            .map(|(token_id, attributes)| (token_id, score(store, attributes, method)))
+            .collect();
+        RarityTable::from_scores(scores)
+    }
+
+    /// Hazır puanlardan sıralamayı kurar (parça parça hesaplanan puanlar için)
+    pub(crate) fn from_scores(scores: HashMap<u64, f64>) -> RarityTable {
+        let mut ranking: Vec<u64> = scores.keys().copied().collect();
+        ranking.sort_by(|a, b| scores[b].total_cmp(&scores[a]).then(a.cmp(b)));
+        let ranks = ranking
+            .iter()
+            .enumerate()
+            .map(|(index, &token_id)| (token_id, index as u32 + 1))
+            .collect();
+
+        RarityTable { scores, ranking, ranks }
+    }
+
+    pub(crate) fn score(&self, token_id: u64) -> Option<f64> {
+        self.scores.get(&token_id).copied()
+    }
+
+    pub(crate) fn rank(&self, token_id: u64) -> Option<u32> {
+        self.ranks.get(&token_id).copied()
+    }
+
+    pub(crate) fn top(&self, n: usize) -> Vec<u64> {
+        self.ranking.iter().take(n).copied().collect()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.ranking.len()
+    }
+}
+
+/// Tek bir tokenın özelliklerinden nadirlik puanını hesaplar
+pub(crate) fn score(store: &AttributeStore, attributes: &[Attribute], method: RarityMethod) -> f64 {
+    let total = store.token_count() as f64;
+    attributes
+        .iter()
+        .map(|attribute| {
+            let count = store.count_with(&attribute.trait_type, &attribute.value.index_key()) as f64;
+            match method {
+                RarityMethod::RarityScore => total / count,
+                RarityMethod::InformationContent => -(count / total).log2(),
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attributes::AttributeValue;
+
+    #[test]
+    fn test_rarity_ranking() {
+        let mut store = AttributeStore::default();
+        for token_id in 1..=4 {
+            let hat = if token_id == 3 { "Crown" } else { "Cap" };
+            store.set(token_id, "Hat".to_string(), AttributeValue::String(hat.to_string()));
+            store.set(token_id, "Eyes".to_string(), AttributeValue::String("Blue".to_string()));
+        }
+
+        let table = RarityTable::compute(&store, RarityMethod::RarityScore);
+        assert_eq!(table.score(3), Some(4.0 + 1.0));
+        assert_eq!(table.rank(3), Some(1));
+        assert_eq!(table.top(2), vec![3, 1]);
+
+        let table = RarityTable::compute(&store, RarityMethod::InformationContent);
+        assert_eq!(table.score(3), Some(2.0));
+        assert_eq!(table.rank(4), Some(4));
+        assert_eq!(table.len(), 4);
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::convert::TryFromJsValue;
+use wasm_bindgen::prelude::*;
+
+use crate::erc1155::ERC1155;
+use crate::erc721::ERC721;
+use crate::hash::keccak256;
+use crate::role_manager::RoleManager;
+use crate::bytes::to_hex;
+use crate::utils::log_event;
+
+/// Kayıt defterinde tutulabilen kontrat türleri
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContractKind {
+    ERC721,
+    ERC1155,
+    RoleManager,
+}
+
+impl ContractKind {
+    /// Adres türetmede CREATE2 `init_code` özetinin yerini tutan etiket
+    fn tag(self) -> &'static [u8] {
+        match self {
+            ContractKind::ERC721 => b"ERC721",
+            ContractKind::ERC1155 => b"ERC1155",
+            ContractKind::RoleManager => b"RoleManager",
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Contract {
+    ERC721(Box<ERC721>),
+    ERC1155(ERC1155),
+    RoleManager(RoleManager),
+}
+
+impl Contract {
+    fn kind(&self) -> ContractKind {
+        match self {
+            Contract::ERC721(_) => ContractKind::ERC721,
+            Contract::ERC1155(_) => ContractKind::ERC1155,
+            Contract::RoleManager(_) => ContractKind::RoleManager,
+        }
+    }
+
+    fn into_js(self) -> JsValue {
+        match self {
+            Contract::ERC721(contract) => (*contract).into(),
+            Contract::ERC1155(contract) => contract.into(),
+            Contract::RoleManager(contract) => contract.into(),
+        }
+    }
+
+    /// Geri çağırmaya ödünç verilen JS nesnesinden kontratı geri alır; nesne tüketildiyse boş döner
+    fn from_js(kind: ContractKind, value: JsValue) -> Option<Contract> {
+        match kind {
+            ContractKind::ERC721 => ERC721::try_from_js_value(value).ok().map(Box::new).map(Contract::ERC721),
+            ContractKind::ERC1155 => ERC1155::try_from_js_value(value).ok().map(Contract::ERC1155),
+            ContractKind::RoleManager => RoleManager::try_from_js_value(value).ok().map(Contract::RoleManager),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Deployment {
+    deployer: String, // Kontratı yok edebilecek tek adres
+    contract: Contract,
+}
+
+/// Birden çok kontratı deterministik adreslerle kuran, bulan ve yok eden fabrika
+///
+/// Adres CREATE2'deki gibi kurucu, tuz ve kontrat türünden türetilir; aynı üçlü her zaman aynı
+/// adresi verir ve aynı adrese ikinci kez kurulum yapılamaz.
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct ContractRegistry {
+    deployments: BTreeMap<String, Deployment>, // Adres -> Kurulum
+}
+
+#[wasm_bindgen]
+impl ContractRegistry {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ContractRegistry {
+        ContractRegistry::default()
+    }
+
+    /// Kurulum adresini hesaplar: keccak256(0xff ++ keccak256(deployer) ++ keccak256(salt) ++ keccak256(kind))[12..]
+    pub fn compute_address(kind: ContractKind, deployer: String, salt: String) -> String {
+        let mut data = vec![0xff];
+        data.extend_from_slice(&keccak256(deployer.as_bytes()));
+        data.extend_from_slice(&keccak256(salt.as_bytes()));
+        data.extend_from_slice(&keccak256(kind.tag()));
+        to_hex(&keccak256(&data)[12..])
+    }
+
+    /// Sahibi `deployer` olan yeni bir ERC721 kurar ve adresini döner
+    pub fn deploy_erc721(&mut self, deployer: String, salt: String) -> Result<String, String> {
+        let contract = Contract::ERC721(Box::new(ERC721::new(deployer.clone())));
+        self.deploy(deployer, salt, contract)
+    }
+
+    /// Sahibi `deployer` olan yeni bir ERC1155 kurar ve adresini döner
+    pub fn deploy_erc1155(&mut self, deployer: String, salt: String, uri: String) -> Result<String, String> {
+        let contract = Contract::ERC1155(ERC1155::new(deployer.clone(), uri));
+        self.deploy(deployer, salt, contract)
+    }
+
+    /// Sahibi `deployer` olan yeni bir rol yöneticisi kurar ve adresini döner
+    pub fn deploy_role_manager(&mut self, deployer: String, salt: String) -> Result<String, String> {
+        let contract = Contract::RoleManager(RoleManager::new(deployer.clone()));
+        self.deploy(deployer, salt, contract)
+    }
+
+    /// Adreste kurulu kontratın türünü döner
+    pub fn kind_of(&self, address: String) -> Option<ContractKind> {
+        self.deployments.get(&address).map(|deployment| deployment.contract.kind())
+    }
+
+    /// Kurulu tüm kontratların adreslerini artan sırada döner
+    pub fn addresses(&self) -> Vec<String> {
+        self.deployments.keys().cloned().collect()
+    }
+
+    /// Verilen türdeki kontratların adreslerini artan sırada döner
+    pub fn addresses_of_kind(&self, kind: ContractKind) -> Vec<String> {
+        self.deployments
+            .iter()
+            .filter(|(_, deployment)| deployment.contract.kind() == kind)
+            .map(|(address, _)| address.clone())
+            .collect()
+    }
+
+    pub fn len(&self) -> u32 {
+        self.deployments.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.deployments.is_empty()
+    }
+
+    /// Kontratı kayıt defterinden siler (sadece kurucu); adres yeniden kullanılabilir
+    pub fn destroy(&mut self, caller: String, address: String) -> Result<(), String> {
+        let deployment = self.deployments.get(&address).ok_or("No contract at address")?;
+        if deployment.deployer != caller {
+            log_event("Contract Destroy Failed", "Unauthorized attempt");
+            return Err("Only the deployer can destroy the contract".to_string());
+        }
+        self.deployments.remove(&address);
+        log_event("Contract Destroyed", &format!("Address: {}", address));
+        Ok(())
+    }
+
+    /// Adresteki ERC721'i geri çağırmaya ödünç verir ve çağrının sonucunu döner
+    ///
+    /// `registry.with_erc721(address, (erc721) => erc721.mint(owner, 1n))`. Nesne geri çağırma
+    /// dışında saklanmamalıdır; çağrı bitince kayıt defterine geri alınır.
+    pub fn with_erc721(
+        &mut self,
+        address: String,
+        #[wasm_bindgen(unchecked_param_type = "(contract: ERC721) => any")] callback: js_sys::Function,
+    ) -> Result<JsValue, String> {
+        self.lend(&address, ContractKind::ERC721, &callback)
+    }
+
+    /// Adresteki ERC1155'i geri çağırmaya ödünç verir (`with_erc721` ile aynı kurallar)
+    pub fn with_erc1155(
+        &mut self,
+        address: String,
+        #[wasm_bindgen(unchecked_param_type = "(contract: ERC1155) => any")] callback: js_sys::Function,
+    ) -> Result<JsValue, String> {
+        self.lend(&address, ContractKind::ERC1155, &callback)
+    }
+
+    /// Adresteki rol yöneticisini geri çağırmaya ödünç verir (`with_erc721` ile aynı kurallar)
+    pub fn with_role_manager(
+        &mut self,
+        address: String,
+        #[wasm_bindgen(unchecked_param_type = "(contract: RoleManager) => any")] callback: js_sys::Function,
+    ) -> Result<JsValue, String> {
+        self.lend(&address, ContractKind::RoleManager, &callback)
+    }
+}
+
+impl ContractRegistry {
+    /// Adresteki ERC721'e erişir (Rust tarafı için `with_erc721`)
+    pub fn erc721(&self, address: &str) -> Option<&ERC721> {
+        match &self.deployments.get(address)?.contract {
+            Contract::ERC721(contract) => Some(contract.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn erc721_mut(&mut self, address: &str) -> Option<&mut ERC721> {
+        match &mut self.deployments.get_mut(address)?.contract {
+            Contract::ERC721(contract) => Some(contract.as_mut()),
+            _ => None,
+        }
+    }
+
+    /// Adresteki ERC1155'e erişir (Rust tarafı için `with_erc1155`)
+    pub fn erc1155(&self, address: &str) -> Option<&ERC1155> {
+        match &self.deployments.get(address)?.contract {
+            Contract::ERC1155(contract) => Some(contract),
+            _ => None,
+        }
+    }
+
+    pub fn erc1155_mut(&mut self, address: &str) -> Option<&mut ERC1155> {
+        match &mut self.deployments.get_mut(address)?.contract {
+            Contract::ERC1155(contract) => Some(contract),
+            _ => None,
+        }
+    }
+
+    /// Adresteki rol yöneticisine erişir (Rust tarafı için `with_role_manager`)
+    pub fn role_manager(&self, address: &str) -> Option<&RoleManager> {
+        match &self.deployments.get(address)?.contract {
+            Contract::RoleManager(contract) => Some(contract),
+            _ => None,
+        }
+    }
+
+    pub fn role_manager_mut(&mut self, address: &str) -> Option<&mut RoleManager> {
+        match &mut self.deployments.get_mut(address)?.contract {
+            Contract::RoleManager(contract) => Some(contract),
+            _ => None,
+        }
+    }
+
+    fn deploy(&mut self, deployer: String, salt: String, contract: Contract) -> Result<String, String> {
+        let kind = contract.kind();
+        let address = ContractRegistry::compute_address(kind, deployer.clone(), salt);
+        if self.deployments.contains_key(&address) {
+            log_event("Contract Deploy Failed", &format!("Address {} is already in use", address));
+            return Err("A contract is already deployed at this address".to_string());
+        }
+        log_event("Contract Deployed", &format!("Kind: {:?}, Address: {}, Deployer: {}", kind, address, deployer));
+        self.deployments.insert(address.clone(), Deployment { deployer, contract });
+        Ok(address)
+    }
+
+    fn lend(&mut self, address: &str, kind: ContractKind, callback: &js_sys::Function) -> Result<JsValue, String> {
+        match self.deployments.get(address) {
+            None => return Err("No contract at address".to_string()),
+            Some(deployment) if deployment.contract.kind() != kind => {
+                return Err(format!("Contract at address is a {:?}", deployment.contract.kind()))
+            }
+            Some(_) => {}
+        }
+        let Deployment { deployer, contract } = self.deployments.remove(address).expect("checked above");
+        let handle = contract.into_js();
+        let result = callback.call1(&JsValue::NULL, &handle);
+        match Contract::from_js(kind, handle) {
+            Some(contract) => {
+                self.deployments.insert(address.to_string(), Deployment { deployer, contract });
+            }
+            None => {
+                log_event("Contract Lost", &format!("Address: {}", address));
+                return Err("Callback consumed the contract; it was removed from the registry".to_string());
+            }
+        }
+        result.map_err(|err| err.as_string().unwrap_or_else(|| "Callback threw an error".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_lifecycle() {
+        let mut registry = ContractRegistry::new();
+        let art = registry.deploy_erc721("alice".to_string(), "art".to_string()).unwrap();
+        let items = registry
+            .deploy_erc1155("alice".to_string(), "art".to_string(), "ipfs://items/".to_string())
+            .unwrap();
+        let roles = registry.deploy_role_manager("bob".to_string(), "art".to_string()).unwrap();
+
+        // Addresses depend on deployer, salt and kind only
+        let expected = ContractRegistry::compute_address(ContractKind::ERC721, "alice".to_string(), "art".to_string());
+        assert_eq!(art, expected);
+        assert_ne!(art, items);
+        assert!(registry.deploy_erc721("alice".to_string(), "art".to_string()).is_err());
+
+        registry.erc721_mut(&art).unwrap().mint("alice".to_string(), 1).unwrap();
+        assert_eq!(registry.erc721(&art).unwrap().owner_of(1), Some("alice".to_string()));
+        assert!(registry.erc1155(&art).is_none());
+        assert_eq!(registry.role_manager(&roles).unwrap().owner(), "bob");
+
+        assert_eq!(registry.kind_of(items.clone()), Some(ContractKind::ERC1155));
+        assert_eq!(registry.addresses_of_kind(ContractKind::ERC721), vec![art.clone()]);
+        assert_eq!(registry.len(), 3);
+
+        assert!(registry.destroy("bob".to_string(), art.clone()).is_err());
+        registry.destroy("alice".to_string(), art.clone()).unwrap();
+        assert_eq!(registry.kind_of(art), None);
+        assert_eq!(registry.addresses().len(), 2);
+    }
+}
@@ -0,0 +1,994 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use wasm_bindgen::prelude::*;
+
+use crate::caller::{Caller, Session};
+use crate::clock::Clock;
+use crate::events::{AuditLog, Listeners, LogEntry, RustListener};
+use crate::migration::{self, deserialize_since, serialize_since, with_layout, STATE_VERSION};
+use crate::pagination::AddressPage;
+use crate::utils::log_event;
+
+/// Yöneticisi ayrıca belirlenmemiş rollerin varsayılan yönetici rolü
+pub const DEFAULT_ADMIN_ROLE: &str = "DEFAULT_ADMIN";
+
+/// Kontratı duraklatma yetkisine sahip rol
+pub const PAUSER_ROLE: &str = "PAUSER";
+
+/// Kontrat ayarlarını (royalty vb.) değiştirme yetkisine sahip rol
+pub const ADMIN_ROLE: &str = "ADMIN";
+
+/// Tembel mint kuponlarını imzalama yetkisine sahip rol
+pub const SIGNER_ROLE: &str = "SIGNER";
+
+/// Yasaklı adres listesini (denylist) yönetme yetkisine sahip rol
+pub const COMPLIANCE_ROLE: &str = "COMPLIANCE";
+
+/// Oyun içi ilerlemeyi (XP vb.) güncelleme yetkisine sahip rol
+pub const GAME_ROLE: &str = "GAME";
+
+/// Kontratı gecikmesiz duraklatabilen ve sıradaki yetkili işlemleri veto edebilen acil durum rolü
+pub const GUARDIAN_ROLE: &str = "GUARDIAN";
+
+/// ERC-20 ve ERC-721 token basma yetkisine sahip rol
+pub const MINTER_ROLE: &str = "MINTER";
+
+/// ERC-20 token yakma ve herhangi bir ERC-721 token'ı yakma yetkisine sahip rol
+pub const BURNER_ROLE: &str = "BURNER";
+
+/// Sahip, rol üyelikleri ve rol ayarları; atama zamanları ve denetim günlüğü içermez
+#[derive(Serialize)]
+pub(crate) struct AccessConfig<'a> {
+    owner: &'a str,
+    pending_owner: &'a Option<String>,
+    roles: &'a BTreeMap<String, Vec<String>>,
+    role_admins: &'a BTreeMap<String, String>,
+    expirations: &'a BTreeMap<String, BTreeMap<String, u64>>,
+    implications: &'a BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Sahiplik ve rol tabanlı erişim yönetimi
+///
+/// Her rolün bir yönetici rolü vardır (varsayılan `DEFAULT_ADMIN_ROLE`); rolü atayıp geri
+/// alabilenler sahip ile yönetici rolünün üyeleridir (OpenZeppelin `AccessControl` ile uyumlu).
+/// Süreli atamalar, bitiş zamanına ulaşıldığında saate göre geçersiz sayılır. Bir rol başka
+/// rolleri kapsayabilir; kapsanan rollerin erişim kontrolleri üst rolün üyelerince de geçilir.
+///
+/// Her değişiklik rol denetim günlüğüne yazılır ve kayıtlı dinleyicilere iletilir.
+//
+// Sonradan eklenen alanlar, eklendikleri düzen sürümünden eski anlık görüntülerde yer kaplamaz
+// (sürüm 2: `role_admins`, 3: `expirations` ve `clock`, 4: `implications`, 6: `audit_log`,
+// 7: `pending_owner`).
+#[wasm_bindgen]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RoleManager {
+    owner: String,
+    roles: BTreeMap<String, Vec<String>>, // Rol -> Kullanıcı Adresleri
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<2, _, _>",
+        deserialize_with = "deserialize_since::<2, _, _>"
+    )]
+    role_admins: BTreeMap<String, String>, // Rol -> Yönetici rol (yalnızca varsayılandan farklı olanlar)
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<3, _, _>",
+        deserialize_with = "deserialize_since::<3, _, _>"
+    )]
+    expirations: BTreeMap<String, BTreeMap<String, u64>>, // Rol -> Kullanıcı Adresi -> Bitiş Zamanı (Unix saniye)
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<3, _, _>",
+        deserialize_with = "deserialize_since::<3, _, _>"
+    )]
+    clock: Clock,
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<4, _, _>",
+        deserialize_with = "deserialize_since::<4, _, _>"
+    )]
+    implications: BTreeMap<String, BTreeSet<String>>, // Üst Rol -> Doğrudan kapsadığı roller
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<6, _, _>",
+        deserialize_with = "deserialize_since::<6, _, _>"
+    )]
+    audit_log: AuditLog, // Rol ve sahiplik değişiklikleri
+    #[serde(
+        default,
+        serialize_with = "serialize_since::<7, _, _>",
+        deserialize_with = "deserialize_since::<7, _, _>"
+    )]
+    pending_owner: Option<String>, // İki adımlı devirde kabul bekleyen yeni sahip
+    #[serde(skip)]
+    listeners: Listeners,
+    #[serde(skip)]
+    session: Session, // `connect` ile bağlanan çağıran (`msg.sender`)
+}
+
+#[wasm_bindgen]
+impl RoleManager {
+    /// Yeni bir rol yöneticisi oluşturur
+    #[wasm_bindgen(constructor)]
+    pub fn new(owner: String) -> RoleManager {
+        log_event("RoleManager Created", &format!("Owner: {}", owner));
+        RoleManager {
+            owner,
+            roles: BTreeMap::new(),
+            role_admins: BTreeMap::new(),
+            expirations: BTreeMap::new(),
+            clock: Clock::default(),
+            implications: BTreeMap::new(),
+            audit_log: AuditLog::default(),
+            pending_owner: None,
+            listeners: Listeners::default(),
+            session: Session::default(),
+        }
+    }
+
+    /// Mevcut sahibi döner (sahiplikten vazgeçildiyse boş)
+    pub fn owner(&self) -> String {
+        self.owner.clone()
+    }
+
+    /// Devri kabul etmesi beklenen adresi döner
+    pub fn pending_owner(&self) -> Option<String> {
+        self.pending_owner.clone()
+    }
+
+    /// Kullanıcıya rol atar (sahip veya rolün yönetici rolüne sahip olanlar yapabilir)
+    pub fn assign_role(&mut self, caller: String, role: String, user: String) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &role) {
+            log_event("Role Assignment Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&role)));
+        }
+
+        self.grant(&role, &user, None);
+        log_event("Role Assigned", &format!("Role: {}, User: {}", role, user));
+        self.record(LogEntry::new("RoleGranted").from(&caller).to(&user).details(role));
+        Ok(())
+    }
+
+    /// Kullanıcıya `expires_at` (Unix saniye) anına kadar geçerli bir rol atar
+    ///
+    /// Kullanıcı rolü zaten kalıcı olarak taşıyorsa atama süreli hale gelir.
+    pub fn assign_role_until(
+        &mut self,
+        caller: String,
+        role: String,
+        user: String,
+        expires_at: u64,
+    ) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &role) {
+            log_event("Role Assignment Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&role)));
+        }
+        if expires_at <= self.clock.now() {
+            log_event("Role Assignment Failed", "Expiration is in the past");
+            return Err("Expiration must be in the future".to_string());
+        }
+
+        self.grant(&role, &user, Some(expires_at));
+        log_event("Role Assigned", &format!("Role: {}, User: {}, Expires At: {}", role, user, expires_at));
+        let details = format!("{} until {}", role, expires_at);
+        self.record(LogEntry::new("RoleGranted").from(&caller).to(&user).details(details));
+        Ok(())
+    }
+
+    /// Süreli bir rol atamasının bitiş zamanını ileri alır
+    pub fn extend_role(&mut self, caller: String, role: String, user: String, expires_at: u64) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &role) {
+            log_event("Role Extension Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&role)));
+        }
+        if !self.is_member(&role, &user) {
+            log_event("Role Extension Failed", &format!("Role: {}, User: {}", role, user));
+            return Err(format!("Account does not have the {} role", role));
+        }
+        let current = self.expiration_of(&role, &user).ok_or("Role grant does not expire")?;
+        if expires_at <= current {
+            log_event("Role Extension Failed", "Expiration is not later than the current one");
+            return Err("New expiration must be later than the current one".to_string());
+        }
+
+        self.grant(&role, &user, Some(expires_at));
+        log_event("Role Extended", &format!("Role: {}, User: {}, Expires At: {}", role, user, expires_at));
+        let details = format!("{} until {}", role, expires_at);
+        self.record(LogEntry::new("RoleExtended").from(&caller).to(&user).details(details));
+        Ok(())
+    }
+
+    /// Süresi dolmuş tüm rol atamalarını kaldırır ve kaldırılan atama sayısını döner
+    pub fn revoke_expired(&mut self) -> u32 {
+        let now = self.clock.now();
+        let expired: Vec<(String, String)> = self
+            .expirations
+            .iter()
+            .flat_map(|(role, users)| {
+                users
+                    .iter()
+                    .filter(move |(_, &expires_at)| expires_at <= now)
+                    .map(move |(user, _)| (role.clone(), user.clone()))
+            })
+            .collect();
+
+        for (role, user) in &expired {
+            self.revoke(role, user);
+            log_event("Role Expired", &format!("Role: {}, User: {}", role, user));
+            self.record(LogEntry::new("RoleRevoked").to(user).details(format!("{} expired", role)));
+        }
+        expired.len() as u32
+    }
+
+    /// Süreli atamanın bitiş zamanını döner (kalıcı atamalarda veya rol yoksa `None`)
+    pub fn get_role_expiration(&self, role: String, user: String) -> Option<u64> {
+        self.expiration_of(&role, &user)
+    }
+
+    /// Süreli atamaların değerlendirildiği simüle zamanı ayarlar (Unix saniye)
+    pub fn set_block_timestamp(&mut self, timestamp: u64) {
+        self.clock.set(timestamp);
+    }
+
+    /// Simüle edilen zamanı ileri sarar
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.clock.advance(seconds);
+    }
+
+    /// Simülasyonu bırakıp sistem saatine döner
+    pub fn use_system_time(&mut self) {
+        self.clock.use_system_time();
+    }
+
+    /// Kullanıcıdan rolü geri alır (sahip veya rolün yönetici rolüne sahip olanlar yapabilir)
+    pub fn remove_role(&mut self, caller: String, role: String, user: String) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &role) {
+            log_event("Role Removal Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&role)));
+        }
+
+        self.revoke(&role, &user);
+        log_event("Role Removed", &format!("Role: {}, User: {}", role, user));
+        self.record(LogEntry::new("RoleRevoked").from(&caller).to(&user).details(role));
+        Ok(())
+    }
+
+    /// Rolü kullanıcı listesine tek seferde atar; herhangi bir adres geçersizse hiçbiri atanmaz
+    pub fn assign_role_batch(&mut self, caller: String, role: String, users: Vec<String>) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &role) {
+            log_event("Role Assignment Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&role)));
+        }
+        validate_batch(&users).inspect_err(|err| log_event("Role Assignment Failed", err))?;
+
+        for user in &users {
+            self.grant(&role, user, None);
+        }
+        log_event("Roles Assigned", &format!("Role: {}, Users: {:?}", role, users));
+        self.record(LogEntry::new("RolesGranted").from(&caller).details(format!("{}: {}", role, users.join(","))));
+        Ok(())
+    }
+
+    /// Rolü kullanıcı listesinden tek seferde geri alır; rolü taşımayan bir adres varsa hiçbiri alınmaz
+    pub fn remove_role_batch(&mut self, caller: String, role: String, users: Vec<String>) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &role) {
+            log_event("Role Removal Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&role)));
+        }
+        validate_batch(&users).inspect_err(|err| log_event("Role Removal Failed", err))?;
+        if let Some(user) = users.iter().find(|user| !self.is_member(&role, user)) {
+            log_event("Role Removal Failed", &format!("Role: {}, User: {}", role, user));
+            return Err(format!("Account {} does not have the {} role", user, role));
+        }
+
+        for user in &users {
+            self.revoke(&role, user);
+        }
+        log_event("Roles Removed", &format!("Role: {}, Users: {:?}", role, users));
+        self.record(LogEntry::new("RolesRevoked").from(&caller).details(format!("{}: {}", role, users.join(","))));
+        Ok(())
+    }
+
+    /// Rolün yönetici rolünü döner (belirlenmemişse `DEFAULT_ADMIN_ROLE`)
+    pub fn get_role_admin(&self, role: String) -> String {
+        self.admin_of(&role).to_string()
+    }
+
+    /// Rolün yönetici rolünü değiştirir (sahip veya rolün mevcut yöneticisine sahip olanlar yapabilir)
+    pub fn set_role_admin(&mut self, caller: String, role: String, admin_role: String) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &role) {
+            log_event("Role Admin Update Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&role)));
+        }
+
+        let previous = self.admin_of(&role).to_string();
+        if admin_role == DEFAULT_ADMIN_ROLE {
+            self.role_admins.remove(&role);
+        } else {
+            self.role_admins.insert(role.clone(), admin_role.clone());
+        }
+        let details = format!("Role: {}, Previous Admin: {}, New Admin: {}", role, previous, admin_role);
+        log_event("Role Admin Changed", &details);
+        self.record(LogEntry::new("RoleAdminChanged").from(&caller).details(details));
+        Ok(())
+    }
+
+    /// `parent_role` üyelerinin `child_role` erişim kontrollerini de geçmesini sağlar (geçişlidir)
+    ///
+    /// Sahip veya `child_role`'ün yöneticileri yapabilir; döngü oluşturan kapsamalar reddedilir.
+    pub fn set_role_implication(
+        &mut self,
+        caller: String,
+        parent_role: String,
+        child_role: String,
+    ) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &child_role) {
+            log_event("Role Implication Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&child_role)));
+        }
+        if self.implied_roles(&child_role).contains(parent_role.as_str()) {
+            log_event("Role Implication Failed", &format!("Parent: {}, Child: {}", parent_role, child_role));
+            return Err(format!("{} already implies {}", child_role, parent_role));
+        }
+
+        self.implications.entry(parent_role.clone()).or_default().insert(child_role.clone());
+        log_event("Role Implication Set", &format!("Parent: {}, Child: {}", parent_role, child_role));
+        let details = format!("{} implies {}", parent_role, child_role);
+        self.record(LogEntry::new("RoleImplicationSet").from(&caller).details(details));
+        Ok(())
+    }
+
+    /// `parent_role`'ün `child_role`'ü doğrudan kapsamasını kaldırır
+    pub fn remove_role_implication(
+        &mut self,
+        caller: String,
+        parent_role: String,
+        child_role: String,
+    ) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if !self.is_role_admin(&caller, &child_role) {
+            log_event("Role Implication Removal Failed", "Unauthorized attempt");
+            return Err(format!("Caller is missing the {} role", self.admin_of(&child_role)));
+        }
+
+        if let Some(children) = self.implications.get_mut(&parent_role) {
+            children.remove(&child_role);
+            if children.is_empty() {
+                self.implications.remove(&parent_role);
+            }
+        }
+        log_event("Role Implication Removed", &format!("Parent: {}, Child: {}", parent_role, child_role));
+        let details = format!("{} implies {}", parent_role, child_role);
+        self.record(LogEntry::new("RoleImplicationRemoved").from(&caller).details(details));
+        Ok(())
+    }
+
+    /// Rolün doğrudan kapsadığı rolleri alfabetik sırayla listeler
+    pub fn get_role_implications(&self, role: String) -> Vec<String> {
+        self.implications.get(&role).map(|children| children.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Kullanıcının role doğrudan veya kapsayan bir rol üzerinden sahip olup olmadığını kontrol eder
+    pub fn role_based_access(&self, user: String, role: String) -> bool {
+        self.has_role(&role, &user)
+    }
+
+    /// Sahipliği yeni bir adrese devreder (sadece mevcut sahip yapabilir)
+    pub fn transfer_ownership(&mut self, current_owner: String, new_owner: String) -> Result<(), String> {
+        self.ensure_sender(&current_owner)?;
+        if !self.is_owner(&current_owner) {
+            log_event("Ownership Transfer Failed", "Unauthorized attempt");
+            return Err("Only the owner can transfer ownership".to_string());
+        }
+
+        self.owner = new_owner.clone();
+        self.pending_owner = None;
+        log_event("Ownership Transferred", &format!("From: {}, To: {}", current_owner, new_owner));
+        self.record(LogEntry::new("OwnershipTransferred").from(&current_owner).to(&new_owner));
+        Ok(())
+    }
+
+    /// İki adımlı sahiplik devrini başlatır; yeni sahip `accept_ownership` ile kabul edene kadar
+    /// mevcut sahip yetkili kalır (sadece mevcut sahip yapabilir)
+    pub fn begin_ownership_transfer(&mut self, current_owner: String, new_owner: String) -> Result<(), String> {
+        self.ensure_sender(&current_owner)?;
+        if !self.is_owner(&current_owner) {
+            log_event("Ownership Transfer Failed", "Unauthorized attempt");
+            return Err("Only the owner can transfer ownership".to_string());
+        }
+        if new_owner.is_empty() {
+            log_event("Ownership Transfer Failed", "New owner cannot be empty");
+            return Err("New owner cannot be empty".to_string());
+        }
+
+        self.pending_owner = Some(new_owner.clone());
+        log_event("Ownership Transfer Started", &format!("From: {}, To: {}", current_owner, new_owner));
+        self.record(LogEntry::new("OwnershipTransferStarted").from(&current_owner).to(&new_owner));
+        Ok(())
+    }
+
+    /// Bekleyen sahiplik devrini kabul eder (sadece bekleyen sahip yapabilir)
+    pub fn accept_ownership(&mut self, caller: String) -> Result<(), String> {
+        self.ensure_sender(&caller)?;
+        if self.pending_owner.as_deref() != Some(caller.as_str()) {
+            log_event("Ownership Acceptance Failed", "Unauthorized attempt");
+            return Err("Only the pending owner can accept ownership".to_string());
+        }
+
+        let previous = std::mem::replace(&mut self.owner, caller.clone());
+        self.pending_owner = None;
+        log_event("Ownership Transferred", &format!("From: {}, To: {}", previous, caller));
+        self.record(LogEntry::new("OwnershipTransferred").from(&previous).to(&caller));
+        Ok(())
+    }
+
+    /// Sahiplikten kalıcı olarak vazgeçer (sadece mevcut sahip yapabilir)
+    ///
+    /// Sonrasında sahiplik devri kimse tarafından yapılamaz; rol atama ve geri alma yalnızca
+    /// yönetici rollerinin üyelerine kalır, atanmış roller geçerli kalır.
+    pub fn renounce_ownership(&mut self, current_owner: String) -> Result<(), String> {
+        self.ensure_sender(&current_owner)?;
+        if !self.is_owner(&current_owner) {
+            log_event("Ownership Renounce Failed", "Unauthorized attempt");
+            return Err("Only the owner can renounce ownership".to_string());
+        }
+
+        self.owner = String::new();
+        self.pending_owner = None;
+        log_event("Ownership Renounced", &format!("Previous Owner: {}", current_owner));
+        self.record(LogEntry::new("OwnershipTransferred").from(&current_owner).details("Renounced".to_string()));
+        Ok(())
+    }
+
+    /// Kullanıcının kendi rolünden vazgeçmesi (çağıran `user`'ın kendisidir)
+    pub fn renounce_role(&mut self, user: String, role: String) -> Result<(), String> {
+        self.ensure_sender(&user)?;
+        if !self.is_member(&role, &user) {
+            log_event("Role Renounce Failed", &format!("Role: {}, User: {}", role, user));
+            return Err(format!("Account does not have the {} role", role));
+        }
+
+        self.revoke(&role, &user);
+        log_event("Role Renounced", &format!("Role: {}, User: {}", role, user));
+        self.record(LogEntry::new("RoleRevoked").from(&user).to(&user).details(role));
+        Ok(())
+    }
+
+    /// Belirtilen role sahip tüm kullanıcıları listeler (süresi dolmuş atamalar hariç)
+    pub fn list_role_users(&self, role: String) -> Vec<String> {
+        self.members(&role).cloned().collect()
+    }
+
+    /// Belirtilen role sahip kullanıcıları ara `Vec<String>` kopyası olmadan JS dizisi olarak döner
+    #[wasm_bindgen(unchecked_return_type = "string[]")]
+    pub fn list_role_users_array(&self, role: String) -> js_sys::Array {
+        let array = js_sys::Array::new();
+        for user in self.members(&role) {
+            array.push(&JsValue::from_str(user));
+        }
+        array
+    }
+
+    /// Rolün kullanıcılarını atanma sırasına göre sayfalı döner
+    pub fn list_role_users_page(&self, role: String, offset: u32, limit: u32) -> AddressPage {
+        AddressPage::new(self.list_role_users(role).into_iter(), offset, limit)
+    }
+
+    /// Role sahip kullanıcı sayısını döner
+    pub fn get_role_member_count(&self, role: String) -> u32 {
+        self.members(&role).count() as u32
+    }
+
+    /// Rolün `index` sıradaki üyesini atanma sırasına göre döner (aralık dışındaysa `None`)
+    pub fn get_role_member(&self, role: String, index: u32) -> Option<String> {
+        self.members(&role).nth(index as usize).cloned()
+    }
+
+    /// En az bir üyesi olan rolleri alfabetik sırayla listeler
+    pub fn list_roles(&self) -> Vec<String> {
+        self.roles.keys().filter(|role| self.members(role).next().is_some()).cloned().collect()
+    }
+
+    /// Kullanıcıya doğrudan atanmış rolleri alfabetik sırayla listeler
+    pub fn roles_of(&self, user: String) -> Vec<String> {
+        self.roles.keys().filter(|role| self.is_member(role, &user)).cloned().collect()
+    }
+
+    /// Çağıranı bağlar; bağlıyken işlemlerde bildirilen çağıran adresi onunla eşleşmelidir
+    pub fn connect(&mut self, caller: &Caller) {
+        self.session.connect(caller);
+    }
+
+    /// Bağlı çağıranı kaldırır
+    pub fn disconnect(&mut self) {
+        self.session.disconnect();
+    }
+
+    /// Bağlı çağıranın adresini döner
+    pub fn sender(&self) -> Option<String> {
+        self.session.sender()
+    }
+
+    /// Rol ve sahiplik değişikliklerinde çağrılacak JS dinleyicisi ekler; hataları yok sayılır
+    ///
+    /// Dinleyici, denetim günlüğüne eklenen `LogEntry` kaydını (RoleGranted, RoleRevoked,
+    /// OwnershipTransferred ...) alır.
+    pub fn on_role_event(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "RoleEventCallback")] callback: js_sys::Function,
+    ) {
+        self.listeners.add_js(callback);
+    }
+
+    /// Kayıtlı tüm rol olayı dinleyicilerini kaldırır
+    pub fn clear_role_listeners(&mut self) {
+        self.listeners.clear();
+    }
+
+    /// Kayıtlı rol olayı dinleyicisi sayısını döner
+    pub fn role_listener_count(&self) -> usize {
+        self.listeners.len()
+    }
+
+    /// Rol ve sahiplik değişikliklerini sırayla döner
+    pub fn role_audit_log(&self) -> Vec<LogEntry> {
+        self.audit_log.iter().cloned().collect()
+    }
+
+    /// Rol değişikliği denetim günlüğünü uyum incelemeleri için JSON dizisi olarak dışa aktarır
+    pub fn export_role_audit_log(&self) -> String {
+        serde_json::to_string(&self.role_audit_log()).expect("log entries are always serializable")
+    }
+
+    /// Rol yöneticisinin tüm durumunu deterministik JSON olarak dışa aktarır
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("role manager state is always serializable")
+    }
+
+    /// JSON'dan rol yöneticisi durumunu yükler
+    pub fn from_json(json: String) -> Result<RoleManager, String> {
+        serde_json::from_str(&json).map_err(|e| format!("Invalid RoleManager JSON: {}", e))
+    }
+
+    /// Rol yöneticisinin durumunu sürüm başlıklı ikili anlık görüntü olarak döner
+    pub fn snapshot(&self) -> Vec<u8> {
+        migration::encode(bincode::serialize(self).expect("role manager state is always serializable"))
+    }
+
+    /// İkili anlık görüntüden rol yöneticisi durumunu yükler
+    ///
+    /// Eski sürümlerde bulunmayan alanlar varsayılan değerleriyle başlar; dinleyiciler taşınmaz.
+    pub fn restore(bytes: &[u8]) -> Result<RoleManager, String> {
+        let (version, payload) = migration::decode(bytes);
+        if version > STATE_VERSION {
+            return Err(format!(
+                "Snapshot version {} is newer than supported version {}",
+                version, STATE_VERSION
+            ));
+        }
+        with_layout(version, || bincode::deserialize(payload))
+            .map_err(|e| format!("Invalid RoleManager snapshot: {}", e))
+    }
+}
+
+impl RoleManager {
+    /// Bağlı bir çağıran varsa işlemi yapan adresin o olduğunu doğrular (`msg.sender`)
+    fn ensure_sender(&self, caller: &str) -> Result<(), String> {
+        self.session.ensure(caller).inspect_err(|err| log_event("Caller Rejected", err))
+    }
+
+    /// Çağıranın sahip olup olmadığını döner; sahiplikten vazgeçildiyse kimse sahip değildir
+    fn is_owner(&self, caller: &str) -> bool {
+        !self.owner.is_empty() && caller == self.owner
+    }
+
+    /// Rolün yönetici rolü (belirlenmemişse `DEFAULT_ADMIN_ROLE`)
+    fn admin_of(&self, role: &str) -> &str {
+        self.role_admins.get(role).map(String::as_str).unwrap_or(DEFAULT_ADMIN_ROLE)
+    }
+
+    /// Çağıranın rolü yönetip yönetemeyeceğini döner (sahip veya yönetici rolün üyesi)
+    fn is_role_admin(&self, caller: &str, role: &str) -> bool {
+        self.is_owner(caller) || self.has_role(self.admin_of(role), caller)
+    }
+
+    /// Rust tarafı için ödünç alınan değerlerle rol kontrolü
+    ///
+    /// Rolü kapsayan rollerin üyeleri de geçer; süresi dolmuş atamalar geçersizdir.
+    pub(crate) fn has_role(&self, role: &str, user: &str) -> bool {
+        self.implications
+            .iter()
+            .filter(|(parent, _)| self.implied_roles(parent).contains(role))
+            .any(|(parent, _)| self.is_member(parent, user))
+            || self.is_member(role, user)
+    }
+
+    /// Kullanıcının role doğrudan atanmış olup olmadığı (süresi dolmuş atamalar hariç)
+    fn is_member(&self, role: &str, user: &str) -> bool {
+        self.members(role).any(|u| u == user)
+    }
+
+    /// Rolün geçişli olarak kapsadığı roller (kendisi dahil)
+    fn implied_roles<'a>(&'a self, role: &'a str) -> BTreeSet<&'a str> {
+        let mut seen = BTreeSet::new();
+        let mut pending = vec![role];
+        while let Some(current) = pending.pop() {
+            if seen.insert(current) {
+                pending.extend(self.implications.get(current).into_iter().flatten().map(String::as_str));
+            }
+        }
+        seen
+    }
+
+    /// Rolün süresi dolmamış üyeleri, atanma sırasıyla
+    fn members<'a>(&'a self, role: &str) -> impl Iterator<Item = &'a String> + 'a {
+        let now = self.clock.now();
+        let expirations = self.expirations.get(role);
+        self.roles.get(role).into_iter().flatten().filter(move |user| {
+            expirations.and_then(|users| users.get(*user)).is_none_or(|&expires_at| expires_at > now)
+        })
+    }
+
+    /// Atamanın bitiş zamanı (kalıcıysa `None`)
+    fn expiration_of(&self, role: &str, user: &str) -> Option<u64> {
+        self.expirations.get(role)?.get(user).copied()
+    }
+
+    /// Rolü atar; `expires_at` yoksa atama kalıcıdır
+    fn grant(&mut self, role: &str, user: &str, expires_at: Option<u64>) {
+        let users = self.roles.entry(role.to_string()).or_default();
+        if !users.iter().any(|u| u == user) {
+            users.push(user.to_string());
+        }
+        match expires_at {
+            Some(expires_at) => {
+                self.expirations.entry(role.to_string()).or_default().insert(user.to_string(), expires_at);
+            }
+            None => self.clear_expiration(role, user),
+        }
+    }
+
+    /// Rolü ve varsa bitiş zamanını kaldırır
+    fn revoke(&mut self, role: &str, user: &str) {
+        if let Some(users) = self.roles.get_mut(role) {
+            users.retain(|u| u != user);
+        }
+        self.clear_expiration(role, user);
+    }
+
+    fn clear_expiration(&mut self, role: &str, user: &str) {
+        if let Some(users) = self.expirations.get_mut(role) {
+            users.remove(user);
+            if users.is_empty() {
+                self.expirations.remove(role);
+            }
+        }
+    }
+
+    /// Rust dinleyicisi ekler (JS tarafı için `on_role_event`)
+    pub fn add_role_listener<F>(&mut self, listener: F)
+    where
+        F: Fn(&LogEntry) + 'static,
+    {
+        let listener: RustListener = std::rc::Rc::new(listener);
+        self.listeners.add_rust(listener);
+    }
+
+    /// Değişikliği denetim günlüğüne ekler ve dinleyicilere bildirir
+    fn record(&mut self, entry: LogEntry) {
+        let entry = self.audit_log.record(entry, self.clock.now_ms());
+        self.listeners.notify(entry);
+    }
+
+    /// Dinleyicileri alır (kontrat durumu geri yüklenirken korunmaları için)
+    pub(crate) fn take_listeners(&mut self) -> Listeners {
+        std::mem::take(&mut self.listeners)
+    }
+
+    pub(crate) fn set_listeners(&mut self, listeners: Listeners) {
+        self.listeners = listeners;
+    }
+
+    /// Saati kontratın saatiyle eşitler (kontrat zamanı değiştiğinde çağrılır)
+    pub(crate) fn sync_clock(&mut self, clock: &Clock) {
+        self.clock = clock.clone();
+    }
+
+    /// Kontrat durum özetine giren erişim ayarları
+    pub(crate) fn access_config(&self) -> AccessConfig<'_> {
+        AccessConfig {
+            owner: &self.owner,
+            pending_owner: &self.pending_owner,
+            roles: &self.roles,
+            role_admins: &self.role_admins,
+            expirations: &self.expirations,
+            implications: &self.implications,
+        }
+    }
+}
+
+/// Toplu rol işlemindeki adresleri doğrular: boş olamaz, tekrar edemez
+fn validate_batch(users: &[String]) -> Result<(), String> {
+    if users.is_empty() {
+        return Err("Batch cannot be empty".to_string());
+    }
+    let mut seen = BTreeSet::new();
+    for user in users {
+        if user.is_empty() {
+            return Err("User address cannot be empty".to_string());
+        }
+        if !seen.insert(user.as_str()) {
+            return Err(format!("Duplicate user in batch: {}", user));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_and_remove_role() {
+        let mut roles = RoleManager::new("owner".to_string());
+
+        // Only the owner can assign roles
+        assert!(roles.assign_role("user1".to_string(), "admin".to_string(), "user1".to_string()).is_err());
+        assert!(roles.assign_role("owner".to_string(), "admin".to_string(), "user1".to_string()).is_ok());
+        assert!(roles.role_based_access("user1".to_string(), "admin".to_string()));
+        assert_eq!(roles.list_role_users("admin".to_string()), vec!["user1".to_string()]);
+
+        // Remove role
+        assert!(roles.remove_role("owner".to_string(), "admin".to_string(), "user1".to_string()).is_ok());
+        assert!(!roles.role_based_access("user1".to_string(), "admin".to_string()));
+    }
+
+    #[test]
+    fn test_transfer_ownership() {
+        let mut roles = RoleManager::new("owner".to_string());
+
+        assert!(roles.transfer_ownership("user1".to_string(), "user1".to_string()).is_err());
+        assert!(roles.transfer_ownership("owner".to_string(), "new_owner".to_string()).is_ok());
+        assert_eq!(roles.owner(), "new_owner".to_string());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.assign_role("owner".to_string(), "b".to_string(), "user1".to_string()).unwrap();
+        roles.assign_role("owner".to_string(), "a".to_string(), "user2".to_string()).unwrap();
+
+        let json = roles.to_json();
+        assert!(json.starts_with(r#"{"owner":"owner","roles":{"a":["user2"],"b":["user1"]},"role_admins":{}"#));
+
+        let restored = RoleManager::from_json(json.clone()).unwrap();
+        assert_eq!(restored.to_json(), json);
+        assert!(RoleManager::from_json("{}".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_renounce() {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.assign_role("owner".to_string(), "admin".to_string(), "user1".to_string()).unwrap();
+
+        // Accounts can only renounce roles they hold
+        assert!(roles.renounce_role("user2".to_string(), "admin".to_string()).is_err());
+        assert!(roles.renounce_role("user1".to_string(), "admin".to_string()).is_ok());
+        assert!(!roles.role_based_access("user1".to_string(), "admin".to_string()));
+
+        assert!(roles.renounce_ownership("user1".to_string()).is_err());
+        assert!(roles.renounce_ownership("owner".to_string()).is_ok());
+        assert_eq!(roles.owner(), String::new());
+
+        // Nobody, including an empty caller, holds ownership afterwards
+        assert!(roles.assign_role("owner".to_string(), "admin".to_string(), "user1".to_string()).is_err());
+        assert!(roles.assign_role(String::new(), "admin".to_string(), "user1".to_string()).is_err());
+        assert!(roles.transfer_ownership(String::new(), "user1".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_role_enumeration() {
+        let mut roles = RoleManager::new("owner".to_string());
+        for (role, user) in [("b", "user1"), ("a", "user2"), ("b", "user2"), ("c", "user3")] {
+            roles.assign_role("owner".to_string(), role.to_string(), user.to_string()).unwrap();
+        }
+        roles.remove_role("owner".to_string(), "c".to_string(), "user3".to_string()).unwrap();
+
+        assert_eq!(roles.get_role_member_count("b".to_string()), 2);
+        assert_eq!(roles.get_role_member_count("missing".to_string()), 0);
+        assert_eq!(roles.get_role_member("b".to_string(), 1), Some("user2".to_string()));
+        assert_eq!(roles.get_role_member("b".to_string(), 2), None);
+
+        // Roles whose last member was removed drop out of the listing
+        assert_eq!(roles.list_roles(), vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(roles.roles_of("user2".to_string()), vec!["a".to_string(), "b".to_string()]);
+        assert!(roles.roles_of("user3".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_expiring_roles() {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.set_block_timestamp(1_000);
+
+        assert!(roles.assign_role_until("owner".to_string(), "c".to_string(), "user1".to_string(), 1_000).is_err());
+        roles.assign_role_until("owner".to_string(), "c".to_string(), "user1".to_string(), 1_100).unwrap();
+        roles.assign_role_until("owner".to_string(), "c".to_string(), "user2".to_string(), 1_050).unwrap();
+        assert!(roles.role_based_access("user1".to_string(), "c".to_string()));
+        assert_eq!(roles.get_role_expiration("c".to_string(), "user1".to_string()), Some(1_100));
+
+        assert!(roles.extend_role("owner".to_string(), "c".to_string(), "user1".to_string(), 1_100).is_err());
+        roles.extend_role("owner".to_string(), "c".to_string(), "user1".to_string(), 1_200).unwrap();
+
+        // Expired grants stop counting before they are cleaned up
+        roles.advance_time(100);
+        assert!(!roles.role_based_access("user2".to_string(), "c".to_string()));
+        assert_eq!(roles.list_role_users("c".to_string()), vec!["user1".to_string()]);
+        assert!(roles.extend_role("owner".to_string(), "c".to_string(), "user2".to_string(), 2_000).is_err());
+        assert_eq!(roles.revoke_expired(), 1);
+        assert_eq!(roles.get_role_expiration("c".to_string(), "user2".to_string()), None);
+
+        // A permanent grant clears the expiration and cannot be extended
+        roles.assign_role("owner".to_string(), "c".to_string(), "user1".to_string()).unwrap();
+        roles.advance_time(1_000);
+        assert!(roles.role_based_access("user1".to_string(), "c".to_string()));
+        assert!(roles.extend_role("owner".to_string(), "c".to_string(), "user1".to_string(), 5_000).is_err());
+        assert_eq!(roles.revoke_expired(), 0);
+    }
+
+    #[test]
+    fn test_role_implications() {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.assign_role("owner".to_string(), "ADMIN".to_string(), "admin".to_string()).unwrap();
+        roles.set_role_implication("owner".to_string(), "ADMIN".to_string(), "MODERATOR".to_string()).unwrap();
+        roles.set_role_implication("owner".to_string(), "MODERATOR".to_string(), "VIEWER".to_string()).unwrap();
+
+        // Implication is transitive but does not create memberships
+        assert!(roles.role_based_access("admin".to_string(), "VIEWER".to_string()));
+        assert_eq!(roles.roles_of("admin".to_string()), vec!["ADMIN".to_string()]);
+        assert!(roles.renounce_role("admin".to_string(), "VIEWER".to_string()).is_err());
+
+        // Cycles are rejected, including self-implication
+        assert!(roles.set_role_implication("owner".to_string(), "VIEWER".to_string(), "ADMIN".to_string()).is_err());
+        assert!(roles.set_role_implication("owner".to_string(), "ADMIN".to_string(), "ADMIN".to_string()).is_err());
+        assert!(roles.set_role_implication("admin".to_string(), "ADMIN".to_string(), "OTHER".to_string()).is_err());
+
+        roles.remove_role_implication("owner".to_string(), "MODERATOR".to_string(), "VIEWER".to_string()).unwrap();
+        assert!(roles.role_based_access("admin".to_string(), "MODERATOR".to_string()));
+        assert!(!roles.role_based_access("admin".to_string(), "VIEWER".to_string()));
+        assert_eq!(roles.get_role_implications("ADMIN".to_string()), vec!["MODERATOR".to_string()]);
+    }
+
+    #[test]
+    fn test_role_batches() {
+        let mut roles = RoleManager::new("owner".to_string());
+        let users: Vec<String> = (0..200).map(|i| format!("moderator{}", i)).collect();
+
+        assert!(roles.assign_role_batch("user1".to_string(), "MOD".to_string(), users.clone()).is_err());
+        let duplicate = vec!["a".to_string(), "b".to_string(), "a".to_string()];
+        assert!(roles.assign_role_batch("owner".to_string(), "MOD".to_string(), duplicate).is_err());
+        assert_eq!(roles.get_role_member_count("MOD".to_string()), 0);
+
+        roles.assign_role_batch("owner".to_string(), "MOD".to_string(), users.clone()).unwrap();
+        assert_eq!(roles.get_role_member_count("MOD".to_string()), 200);
+
+        // Revocation is all-or-nothing
+        let partial = vec!["moderator1".to_string(), "stranger".to_string()];
+        assert!(roles.remove_role_batch("owner".to_string(), "MOD".to_string(), partial).is_err());
+        assert!(roles.role_based_access("moderator1".to_string(), "MOD".to_string()));
+        roles.remove_role_batch("owner".to_string(), "MOD".to_string(), users[..150].to_vec()).unwrap();
+        assert_eq!(roles.get_role_member("MOD".to_string(), 0), Some("moderator150".to_string()));
+    }
+
+    #[test]
+    fn test_role_events() {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.set_block_timestamp(1_000);
+        let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let sink = seen.clone();
+        roles.add_role_listener(move |entry| sink.borrow_mut().push(entry.kind.clone()));
+
+        roles.assign_role("owner".to_string(), "a".to_string(), "user1".to_string()).unwrap();
+        assert!(roles.assign_role("user1".to_string(), "a".to_string(), "user2".to_string()).is_err());
+        roles.renounce_role("user1".to_string(), "a".to_string()).unwrap();
+        roles.transfer_ownership("owner".to_string(), "new_owner".to_string()).unwrap();
+        assert_eq!(*seen.borrow(), vec!["RoleGranted", "RoleRevoked", "OwnershipTransferred"]);
+
+        // The audit log survives serialization and exports with sequence numbers and timestamps
+        let restored = RoleManager::from_json(roles.to_json()).unwrap();
+        let log = restored.role_audit_log();
+        assert_eq!((log[2].seq, log[2].timestamp), (2, 1_000_000));
+        assert_eq!(log[2].to, Some("new_owner".to_string()));
+        assert_eq!(restored.role_listener_count(), 0);
+        assert!(restored.export_role_audit_log().starts_with(r#"[{"seq":0,"timestamp":1000000,"kind":"RoleGranted""#));
+    }
+
+    #[test]
+    fn test_two_step_ownership() {
+        let mut roles = RoleManager::new("owner".to_string());
+        assert!(roles.begin_ownership_transfer("user1".to_string(), "user1".to_string()).is_err());
+        roles.begin_ownership_transfer("owner".to_string(), "new_owner".to_string()).unwrap();
+
+        // The current owner keeps control until the transfer is accepted
+        assert!(roles.accept_ownership("user1".to_string()).is_err());
+        assert!(roles.assign_role("owner".to_string(), "a".to_string(), "user1".to_string()).is_ok());
+        roles.accept_ownership("new_owner".to_string()).unwrap();
+        assert_eq!((roles.owner(), roles.pending_owner()), ("new_owner".to_string(), None));
+    }
+
+    #[test]
+    fn test_state_persistence() {
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.set_block_timestamp(1_000);
+        roles.assign_role("owner".to_string(), "a".to_string(), "user1".to_string()).unwrap();
+        roles.assign_role_until("owner".to_string(), "b".to_string(), "user2".to_string(), 2_000).unwrap();
+        roles.set_role_admin("owner".to_string(), "b".to_string(), "a".to_string()).unwrap();
+        roles.begin_ownership_transfer("owner".to_string(), "next".to_string()).unwrap();
+
+        let from_json = RoleManager::from_json(roles.to_json()).unwrap();
+        let from_snapshot = RoleManager::restore(&roles.snapshot()).unwrap();
+        for restored in [from_json, from_snapshot] {
+            assert_eq!(restored.pending_owner(), Some("next".to_string()));
+            assert_eq!(restored.get_role_admin("b".to_string()), "a".to_string());
+            assert_eq!(restored.get_role_expiration("b".to_string(), "user2".to_string()), Some(2_000));
+            assert_eq!(restored.to_json(), roles.to_json());
+        }
+
+        // Older snapshots load with the fields they did not have yet left empty
+        let mut legacy = migration::encode(with_layout(2, || bincode::serialize(&roles)).unwrap());
+        legacy[4..8].copy_from_slice(&2u32.to_le_bytes());
+        let restored = RoleManager::restore(&legacy).unwrap();
+        assert_eq!(restored.get_role_admin("b".to_string()), "a".to_string());
+        assert_eq!(restored.get_role_expiration("b".to_string(), "user2".to_string()), None);
+        assert_eq!(restored.pending_owner(), None);
+
+        legacy[4..8].copy_from_slice(&(STATE_VERSION + 1).to_le_bytes());
+        assert!(RoleManager::restore(&legacy).is_err());
+    }
+
+    #[test]
+    fn test_connected_caller() {
+        crate::caller::set_test_mode(true);
+        let mut roles = RoleManager::new("owner".to_string());
+        roles.connect(&Caller::impersonate("user1".to_string()).unwrap());
+        assert!(roles.assign_role("owner".to_string(), "a".to_string(), "user1".to_string()).is_err());
+
+        roles.connect(&Caller::impersonate("owner".to_string()).unwrap());
+        roles.assign_role("owner".to_string(), "a".to_string(), "user1".to_string()).unwrap();
+        assert!(roles.renounce_role("user1".to_string(), "a".to_string()).is_err());
+        roles.disconnect();
+        assert!(roles.renounce_role("user1".to_string(), "a".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_role_admins() {
+        let mut roles = RoleManager::new("owner".to_string());
+        assert_eq!(roles.get_role_admin("MINTER".to_string()), DEFAULT_ADMIN_ROLE.to_string());
+
+        // Members of the default admin role manage roles without an explicit admin
+        roles.assign_role("owner".to_string(), DEFAULT_ADMIN_ROLE.to_string(), "admin".to_string()).unwrap();
+        assert!(roles.assign_role("admin".to_string(), "MINTER".to_string(), "minter".to_string()).is_ok());
+        assert!(roles.assign_role("minter".to_string(), "MINTER".to_string(), "user1".to_string()).is_err());
+
+        // Delegating MINTER to MINTER_ADMIN takes it away from the default admins
+        assert!(roles.set_role_admin("minter".to_string(), "MINTER".to_string(), "MINTER_ADMIN".to_string()).is_err());
+        roles.set_role_admin("admin".to_string(), "MINTER".to_string(), "MINTER_ADMIN".to_string()).unwrap();
+        assert_eq!(roles.get_role_admin("MINTER".to_string()), "MINTER_ADMIN".to_string());
+        assert!(roles.remove_role("admin".to_string(), "MINTER".to_string(), "minter".to_string()).is_err());
+
+        roles.assign_role("owner".to_string(), "MINTER_ADMIN".to_string(), "lead".to_string()).unwrap();
+        assert!(roles.remove_role("lead".to_string(), "MINTER".to_string(), "minter".to_string()).is_ok());
+        assert!(!roles.role_based_access("minter".to_string(), "MINTER".to_string()));
+
+        // Role admins keep working after ownership is renounced
+        roles.renounce_ownership("owner".to_string()).unwrap();
+        assert!(roles.assign_role("lead".to_string(), "MINTER".to_string(), "user1".to_string()).is_ok());
+    }
+}
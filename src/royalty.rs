@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// Baz puan paydası (%100 = 10_000 bps)
+pub const FEE_DENOMINATOR: u16 = 10_000;
+
+/// ERC-2981 royalty kaydı: alıcı adres ve baz puan oranı
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoyaltyInfo {
+    pub receiver: String,
+    pub bps: u16,
+}
+
+impl RoyaltyInfo {
+    /// Oranı doğrulayarak yeni bir royalty kaydı oluşturur
+    pub fn new(receiver: String, bps: u16) -> Result<RoyaltyInfo, String> {
+        if bps > FEE_DENOMINATOR {
+            return Err("Royalty fee exceeds sale price".to_string());
+        }
+        Ok(RoyaltyInfo { receiver, bps })
+    }
+
+    /// Satış fiyatı üzerinden ödenecek royalty miktarını hesaplar (aşağı yuvarlanır)
+    pub fn amount(&self, sale_price: u64) -> u64 {
+        (sale_price as u128 * self.bps as u128 / FEE_DENOMINATOR as u128) as u64
+    }
+}
+
+/// `royalty_info` sonucu: ödeme alıcısı ve miktarı
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoyaltyPayment {
+    pub receiver: String,
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_royalty_amount() {
+        let royalty = RoyaltyInfo::new("artist".to_string(), 250).unwrap();
+        assert_eq!(royalty.amount(10_000), 250);
+        assert_eq!(royalty.amount(399), 9);
+        assert_eq!(royalty.amount(u64::MAX), u64::MAX / 40);
+
+        assert!(RoyaltyInfo::new("artist".to_string(), 10_001).is_err());
+    }
+}
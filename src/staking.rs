@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+
+/// Stake edilmiş tek bir token'ın kaydı
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct StakePosition {
+    pub(crate) owner: String,
+    pub(crate) staked_at: u64, // Stake başlangıcı (Unix saniye)
+    accrued_from: u64, // Ödülün en son hesaplandığı an (Unix saniye)
+}
+
+impl StakePosition {
+    /// Son hesaplamadan bu yana biriken ödül
+    fn accrued(&self, rate: u64, now: u64) -> u64 {
+        rate.saturating_mul(now.saturating_sub(self.accrued_from))
+    }
+}
+
+/// Stake edilen tokenları, sahiplerini ve biriken ödülleri izler; stake edilen token transfer edilemez
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Staking {
+    positions: BTreeMap<u64, StakePosition>, // Token ID -> Stake Kaydı
+    reward_rate: u64, // Stake edilen token başına saniyelik ödül
+    unclaimed: BTreeMap<String, u64>, // Adres -> Hesaplanmış Ama Talep Edilmemiş Ödül
+    balances: BTreeMap<String, u64>, // Adres -> Talep Edilmiş Ödül Bakiyesi
+}
+
+impl Staking {
+    /// Ödül oranı aynı, stake ve bakiye kaydı olmayan kopya (`clone_with` için)
+    pub(crate) fn settings_only(&self) -> Staking {
+        Staking {
+            reward_rate: self.reward_rate,
+            ..Staking::default()
+        }
+    }
+
+    /// Token'ı verilen andan itibaren stake eder
+    pub(crate) fn stake(&mut self, token_id: u64, owner: &str, now: u64) -> Result<(), String> {
+        if self.positions.contains_key(&token_id) {
+            return Err("Token is already staked".to_string());
+        }
+        self.positions.insert(
+            token_id,
+            StakePosition {
+                owner: owner.to_string(),
+                staked_at: now,
+                accrued_from: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Token'ın stake kaydını kaldırıp döner (sadece stake eden yapabilir); biriken ödül korunur
+    pub(crate) fn unstake(&mut self, token_id: u64, owner: &str, now: u64) -> Result<StakePosition, String> {
+        match self.positions.get(&token_id) {
+            None => Err("Token is not staked".to_string()),
+            Some(position) if position.owner != owner => Err("Only the staker can unstake".to_string()),
+            Some(position) => {
+                let reward = position.accrued(self.reward_rate, now);
+                *self.unclaimed.entry(owner.to_string()).or_default() += reward;
+                Ok(self.positions.remove(&token_id).expect("position exists"))
+            }
+        }
+    }
+
+    /// Ödül oranını değiştirir; o ana kadar eski oranla biriken ödüller hesaplanıp saklanır
+    pub(crate) fn set_reward_rate(&mut self, rate: u64, now: u64) {
+        for position in self.positions.values_mut() {
+            let reward = position.accrued(self.reward_rate, now);
+            *self.unclaimed.entry(position.owner.clone()).or_default() += reward;
+            position.accrued_from = now;
+        }
+        self.reward_rate = rate;
+    }
+
+    pub(crate) fn reward_rate(&self) -> u64 {
+        self.reward_rate
+    }
+
+    /// Adresin talep edebileceği toplam ödülü döner
+    pub(crate) fn pending_rewards(&self, owner: &str, now: u64) -> u64 {
+        let accruing: u64 = self
+            .positions
+            .values()
+            .filter(|position| position.owner == owner)
+            .map(|position| position.accrued(self.reward_rate, now))
+            .sum();
+        self.unclaimed.get(owner).copied().unwrap_or(0) + accruing
+    }
+
+    /// Bekleyen ödülü adresin bakiyesine aktarır ve aktarılan miktarı döner
+    pub(crate) fn claim(&mut self, owner: &str, now: u64) -> u64 {
+        let amount = self.pending_rewards(owner, now);
+        for position in self.positions.values_mut().filter(|position| position.owner == owner) {
+            position.accrued_from = now;
+        }
+        self.unclaimed.remove(owner);
+        *self.balances.entry(owner.to_string()).or_default() += amount;
+        amount
+    }
+
+    /// Adresin talep edilmiş ödül bakiyesini döner
+    pub(crate) fn balance_of(&self, owner: &str) -> u64 {
+        self.balances.get(owner).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn is_staked(&self, token_id: u64) -> bool {
+        self.positions.contains_key(&token_id)
+    }
+
+    /// Adresin stake ettiği tokenları artan sırada döner
+    pub(crate) fn tokens_of(&self, owner: &str) -> Vec<u64> {
+        self.positions
+            .iter()
+            .filter(|(_, position)| position.owner == owner)
+            .map(|(&token_id, _)| token_id)
+            .collect()
+    }
+
+    /// Token'ın ne kadar süredir stake edildiğini saniye cinsinden döner
+    pub(crate) fn duration(&self, token_id: u64, now: u64) -> Option<u64> {
+        self.positions
+            .get(&token_id)
+            .map(|position| now.saturating_sub(position.staked_at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stake_positions() {
+        let mut staking = Staking::default();
+        staking.stake(2, "user1", 100).unwrap();
+        staking.stake(1, "user1", 150).unwrap();
+        assert!(staking.stake(1, "user1", 150).is_err());
+
+        assert_eq!(staking.tokens_of("user1"), vec![1, 2]);
+        assert_eq!(staking.duration(2, 400), Some(300));
+
+        assert!(staking.unstake(2, "user2", 400).is_err());
+        assert_eq!(staking.unstake(2, "user1", 400).unwrap().staked_at, 100);
+        assert!(!staking.is_staked(2));
+        assert!(staking.unstake(2, "user1", 400).is_err());
+    }
+
+    #[test]
+    fn test_reward_accrual() {
+        let mut staking = Staking::default();
+        staking.set_reward_rate(2, 0);
+        staking.stake(1, "user1", 100).unwrap();
+        staking.stake(2, "user1", 150).unwrap();
+        assert_eq!(staking.pending_rewards("user1", 200), 200 + 100);
+
+        // Rate changes only apply from the moment they are made
+        staking.set_reward_rate(10, 200);
+        assert_eq!(staking.pending_rewards("user1", 210), 300 + 200);
+
+        // Unstaked tokens keep what they earned until it is claimed
+        staking.unstake(2, "user1", 210).unwrap();
+        assert_eq!(staking.claim("user1", 220), 500 + 100);
+        assert_eq!(staking.pending_rewards("user1", 220), 0);
+        assert_eq!(staking.balance_of("user1"), 600);
+    }
+}
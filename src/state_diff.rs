@@ -0,0 +1,126 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use tsify::Tsify;
+
+/// Tek bir token alanının iki durum arasındaki değişimi (yoksa `None`)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Tsify)]
+pub struct TokenChange {
+    pub token_id: u64,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Operatör onayının eklenmesi (`approved: true`) veya kaldırılması
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Tsify)]
+pub struct OperatorChange {
+    pub owner: String,
+    pub operator: String,
+    pub approved: bool,
+}
+
+/// İki kontrat durumu arasındaki token farkları (indexer çıktısıyla karşılaştırma için)
+///
+/// Listeler Token ID (operatörlerde sahip ve operatör) sırasındadır. `minted` ve `burned`
+/// sahipleri, `transferred` eski ve yeni sahibi taşır. URI farkları yalnızca iki durumda da
+/// var olan tokenlar için listelenir.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Tsify)]
+pub struct StateDiff {
+    pub minted: Vec<TokenChange>,
+    pub burned: Vec<TokenChange>,
+    pub transferred: Vec<TokenChange>,
+    pub uri_changes: Vec<TokenChange>,
+    pub approval_changes: Vec<TokenChange>,
+    pub operator_changes: Vec<OperatorChange>,
+}
+
+/// Karşılaştırılan alanların sıralı görünümü
+pub(crate) struct StateView<'a> {
+    pub(crate) owners: BTreeMap<u64, &'a str>, // Token ID -> Sahip
+    pub(crate) uris: BTreeMap<u64, String>, // Token ID -> Token URI
+    pub(crate) approvals: BTreeMap<u64, &'a str>, // Token ID -> Onaylı Adres
+    pub(crate) operators: BTreeSet<(&'a str, &'a str)>, // (Sahip, Operatör)
+}
+
+impl StateDiff {
+    pub(crate) fn new(before: &StateView, after: &StateView) -> StateDiff {
+        let mut diff = StateDiff::default();
+        for (&token_id, &owner) in &before.owners {
+            match after.owners.get(&token_id) {
+                None => diff.burned.push(TokenChange::new(token_id, Some(owner), None)),
+                Some(&new_owner) if new_owner != owner => {
+                    diff.transferred.push(TokenChange::new(token_id, Some(owner), Some(new_owner)))
+                }
+                Some(_) => {}
+            }
+        }
+        for (&token_id, &owner) in &after.owners {
+            if !before.owners.contains_key(&token_id) {
+                diff.minted.push(TokenChange::new(token_id, None, Some(owner)));
+            }
+        }
+
+        for token_id in before.owners.keys().filter(|id| after.owners.contains_key(id)) {
+            let (old, new) = (before.uris.get(token_id), after.uris.get(token_id));
+            if old != new {
+                diff.uri_changes.push(TokenChange::new(*token_id, old.map(String::as_str), new.map(String::as_str)));
+            }
+        }
+
+        let approved_tokens: BTreeSet<u64> = before.approvals.keys().chain(after.approvals.keys()).copied().collect();
+        for token_id in approved_tokens {
+            let (old, new) = (before.approvals.get(&token_id), after.approvals.get(&token_id));
+            if old != new {
+                diff.approval_changes.push(TokenChange::new(token_id, old.copied(), new.copied()));
+            }
+        }
+
+        for &(owner, operator) in before.operators.symmetric_difference(&after.operators) {
+            diff.operator_changes.push(OperatorChange {
+                owner: owner.to_string(),
+                operator: operator.to_string(),
+                approved: after.operators.contains(&(owner, operator)),
+            });
+        }
+        diff
+    }
+}
+
+impl TokenChange {
+    fn new(token_id: u64, before: Option<&str>, after: Option<&str>) -> TokenChange {
+        TokenChange { token_id, before: before.map(str::to_string), after: after.map(str::to_string) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::erc721::ERC721;
+
+    #[test]
+    fn test_state_diff() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 10, String::new()).unwrap();
+        erc721.approve("owner".to_string(), "bob".to_string(), 2).unwrap();
+        erc721.set_approval_for_all("owner".to_string(), "market".to_string(), true).unwrap();
+        let before = erc721.snapshot();
+
+        erc721.transfer("owner".to_string(), "alice".to_string(), 1).unwrap();
+        erc721.burn("owner".to_string(), 5).unwrap();
+        erc721.mint("owner".to_string(), 20).unwrap();
+        erc721.approve("owner".to_string(), "carol".to_string(), 2).unwrap();
+        erc721.set_approval_for_all("owner".to_string(), "market".to_string(), false).unwrap();
+        erc721.set_token_uri("owner".to_string(), 3, "https://example.com/3.json".to_string()).unwrap();
+
+        let diff = ERC721::restore(&before).unwrap().state_diff(&erc721);
+        let ids = |changes: &[TokenChange]| changes.iter().map(|change| change.token_id).collect::<Vec<_>>();
+        assert_eq!((ids(&diff.minted), ids(&diff.burned), ids(&diff.transferred)), (vec![20], vec![5], vec![1]));
+        assert_eq!(diff.transferred[0].after, Some("alice".to_string()));
+        assert_eq!((ids(&diff.uri_changes), diff.uri_changes[0].before.clone()), (vec![3], None));
+        assert_eq!(diff.approval_changes[0].after, Some("carol".to_string()));
+        assert_eq!((diff.operator_changes.len(), diff.operator_changes[0].approved), (1, false));
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["burned"][0]["before"], "owner");
+        assert_eq!(erc721.state_diff(&erc721), StateDiff::default());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::utils::now_ms;
+
+/// Varsayılan kayıt aralığı: art arda gelen değişiklikler bu süre içinde tek yazmada birleşir
+pub(crate) const DEFAULT_DEBOUNCE_MS: f64 = 250.0;
+
+/// Kontrat durumunun hangi anahtara ve ne sıklıkla yazılacağını tutar
+#[derive(Clone)]
+pub(crate) struct Persistence {
+    pub(crate) key: String,
+    pub(crate) debounce_ms: f64,
+    last_saved_at: f64,
+    dirty: bool,
+}
+
+impl Persistence {
+    pub(crate) fn new(key: String) -> Persistence {
+        Persistence {
+            key,
+            debounce_ms: DEFAULT_DEBOUNCE_MS,
+            last_saved_at: f64::NEG_INFINITY,
+            dirty: false,
+        }
+    }
+
+    /// Değişikliği işaretler ve yazma zamanının gelip gelmediğini döner
+    pub(crate) fn should_save(&mut self, force: bool) -> bool {
+        self.dirty = true;
+        force || now_ms() - self.last_saved_at >= self.debounce_ms
+    }
+
+    /// Başarılı yazmayı kaydeder
+    pub(crate) fn mark_saved(&mut self) {
+        self.dirty = false;
+        self.last_saved_at = now_ms();
+    }
+
+    /// Henüz yazılmamış değişiklik olup olmadığını döner
+    pub(crate) fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .ok_or("No window available")?
+        .local_storage()
+        .map_err(|_| "localStorage is not accessible".to_string())?
+        .ok_or_else(|| "localStorage is not available".to_string())
+}
+
+/// Anahtar altında kayıtlı değeri okur
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn load(key: &str) -> Result<Option<String>, String> {
+    local_storage()?
+        .get_item(key)
+        .map_err(|_| format!("Failed to read storage key {}", key))
+}
+
+/// Değeri anahtar altına yazar
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn save(key: &str, value: &str) -> Result<(), String> {
+    local_storage()?
+        .set_item(key, value)
+        .map_err(|_| format!("Failed to write storage key {}", key))
+}
+
+// Tarayıcı dışında (ör. `cargo test`) localStorage yerine bellek içi depo kullanılır
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static MEMORY_STORAGE: std::cell::RefCell<std::collections::HashMap<String, String>> =
+        std::cell::RefCell::new(std::collections::HashMap::new());
+}
+
+/// Anahtar altında kayıtlı değeri okur
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn load(key: &str) -> Result<Option<String>, String> {
+    Ok(MEMORY_STORAGE.with(|storage| storage.borrow().get(key).cloned()))
+}
+
+/// Değeri anahtar altına yazar
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn save(key: &str, value: &str) -> Result<(), String> {
+    MEMORY_STORAGE.with(|storage| storage.borrow_mut().insert(key.to_string(), value.to_string()));
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::attributes::Attribute;
+use crate::utils::base64_encode;
+
+/// Her değer için parça tanımlanmamışsa kullanılan joker anahtar
+pub(crate) const ANY_VALUE: &str = "*";
+
+/// Bir özelliğe bağlı SVG katmanı: değer -> SVG parçası şablonu
+///
+/// Şablonlarda `{value}` özelliğin değeriyle, `{token_id}` Token ID ile değiştirilir.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct Layer {
+    trait_type: String,
+    fragments: BTreeMap<String, String>, // Değer (veya "*") -> SVG Parçası
+}
+
+/// Token görselini özelliklerden katman katman oluşturan SVG şablonu
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct SvgTemplate {
+    width: u32,
+    height: u32,
+    layers: Vec<Layer>, // Çizim sırasına göre (ilk katman en altta)
+}
+
+impl Default for SvgTemplate {
+    fn default() -> SvgTemplate {
+        SvgTemplate {
+            width: 350,
+            height: 350,
+            layers: Vec::new(),
+        }
+    }
+}
+
+impl SvgTemplate {
+    pub(crate) fn set_canvas(&mut self, width: u32, height: u32) -> Result<(), String> {
+        if width == 0 || height == 0 {
+            return Err("Canvas size must be greater than zero".to_string());
+        }
+        self.width = width;
+        self.height = height;
+        Ok(())
+    }
+
+    /// Özellik değeri için SVG parçası tanımlar; özelliğin katmanı yoksa en üste eklenir
+    pub(crate) fn set_fragment(&mut self, trait_type: String, value: String, fragment: String) {
+        let index = match self.layers.iter().position(|layer| layer.trait_type == trait_type) {
+            Some(index) => index,
+            None => {
+                self.layers.push(Layer {
+                    trait_type,
+                    fragments: BTreeMap::new(),
+                });
+                self.layers.len() - 1
+            }
+        };
+        self.layers[index].fragments.insert(value, fragment);
+    }
+
+    pub(crate) fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Token'ın özelliklerinden SVG belgesini oluşturur; eşleşmeyen katmanlar atlanır
+    pub(crate) fn render(&self, token_id: u64, attributes: &[Attribute]) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">",
+            w = self.width,
+            h = self.height
+        );
+        for layer in &self.layers {
+            let value = match attributes.iter().find(|attribute| attribute.trait_type == layer.trait_type) {
+                Some(attribute) => attribute.value.index_key(),
+                None => continue,
+            };
+            if let Some(fragment) = layer.fragments.get(&value).or_else(|| layer.fragments.get(ANY_VALUE)) {
+                svg.push_str(
+                    &fragment
+                        .replace("{value}", &escape_xml(&value))
+                        .replace("{token_id}", &token_id.to_string()),
+                );
+            }
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+/// SVG belgesini `data:image/svg+xml;base64,...` adresine çevirir
+pub(crate) fn to_data_uri(svg: &str) -> String {
+    format!("data:image/svg+xml;base64,{}", base64_encode(svg.as_bytes()))
+}
+
+/// Metni XML içinde güvenle kullanılabilir hale getirir
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attributes::AttributeValue;
+
+    #[test]
+    fn test_render_layers() {
+        let mut template = SvgTemplate::default();
+        template.set_canvas(100, 100).unwrap();
+        template.set_fragment("Background".to_string(), "Red".to_string(), "<rect fill=\"red\"/>".to_string());
+        let label = "<text>{value} #{token_id}</text>".to_string();
+        template.set_fragment("Name".to_string(), ANY_VALUE.to_string(), label);
+        template.set_fragment("Hat".to_string(), "Crown".to_string(), "<path d=\"M0 0\"/>".to_string());
+
+        let attributes = vec![
+            Attribute {
+                trait_type: "Name".to_string(),
+                value: AttributeValue::String("<Bob>".to_string()),
+            },
+            Attribute {
+                trait_type: "Background".to_string(),
+                value: AttributeValue::String("Red".to_string()),
+            },
+        ];
+        assert_eq!(
+            template.render(7, &attributes),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"100\" viewBox=\"0 0 100 100\">\
+             <rect fill=\"red\"/><text>&lt;Bob&gt; #7</text></svg>"
+        );
+        assert!(to_data_uri("<svg/>").starts_with("data:image/svg+xml;base64,PHN2Zy8+"));
+        assert!(template.set_canvas(0, 10).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+/// Takasın bir tarafının emanete koyduğu tokenlar ve simüle ETH
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SwapSide {
+    pub tokens: Vec<u64>,
+    pub funds: u64,
+}
+
+#[wasm_bindgen]
+impl SwapSide {
+    #[wasm_bindgen(constructor)]
+    pub fn new(tokens: Vec<u64>, funds: u64) -> SwapSide {
+        SwapSide { tokens, funds }
+    }
+}
+
+/// İki taraf arasında emanetli NFT takası
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Swap {
+    pub maker: String,
+    pub taker: String,
+    pub maker_side: SwapSide,
+    pub taker_side: SwapSide,
+    pub expiry: u64, // Bu andan sonra takas gerçekleşemez, emanetler iade edilebilir (Unix saniye)
+    pub taker_deposited: bool, // Teklif eden taraf oluştururken emanete koyar
+    pub maker_confirmed: bool,
+    pub taker_confirmed: bool,
+}
+
+impl Swap {
+    pub(crate) fn is_party(&self, address: &str) -> bool {
+        self.maker == address || self.taker == address
+    }
+
+    pub(crate) fn ready(&self) -> bool {
+        self.taker_deposited && self.maker_confirmed && self.taker_confirmed
+    }
+}
+
+/// Bekleyen takaslar
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Swaps {
+    swaps: BTreeMap<u64, Swap>, // Takas ID -> Takas
+    next_swap_id: u64,
+}
+
+impl Swaps {
+    pub(crate) fn create(&mut self, swap: Swap) -> Result<u64, String> {
+        if swap.maker == swap.taker {
+            return Err("Cannot swap with yourself".to_string());
+        }
+        let mut tokens: Vec<u64> = swap.maker_side.tokens.iter().chain(&swap.taker_side.tokens).copied().collect();
+        let count = tokens.len();
+        tokens.sort_unstable();
+        tokens.dedup();
+        if tokens.len() != count {
+            return Err("A token can only appear once in a swap".to_string());
+        }
+        if count == 0 {
+            return Err("Swap must contain at least one token".to_string());
+        }
+
+        let id = self.next_swap_id;
+        self.next_swap_id += 1;
+        self.swaps.insert(id, swap);
+        Ok(id)
+    }
+
+    pub(crate) fn get(&self, swap_id: u64) -> Option<&Swap> {
+        self.swaps.get(&swap_id)
+    }
+
+    pub(crate) fn get_mut(&mut self, swap_id: u64) -> Option<&mut Swap> {
+        self.swaps.get_mut(&swap_id)
+    }
+
+    pub(crate) fn remove(&mut self, swap_id: u64) -> Option<Swap> {
+        self.swaps.remove(&swap_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(maker_tokens: Vec<u64>, taker_tokens: Vec<u64>) -> Swap {
+        Swap {
+            maker: "alice".to_string(),
+            taker: "bob".to_string(),
+            maker_side: SwapSide::new(maker_tokens, 0),
+            taker_side: SwapSide::new(taker_tokens, 0),
+            expiry: 100,
+            taker_deposited: false,
+            maker_confirmed: false,
+            taker_confirmed: false,
+        }
+    }
+
+    #[test]
+    fn test_swap_validation() {
+        let mut swaps = Swaps::default();
+        assert!(swaps.create(swap(vec![1], vec![1])).is_err());
+        assert!(swaps.create(swap(vec![], vec![])).is_err());
+        let id = swaps.create(swap(vec![1], vec![2])).unwrap();
+
+        let swap = swaps.get_mut(id).unwrap();
+        assert!(swap.is_party("bob") && !swap.is_party("carol"));
+        swap.maker_confirmed = true;
+        swap.taker_confirmed = true;
+        assert!(!swap.ready());
+        swap.taker_deposited = true;
+        assert!(swap.ready());
+    }
+}
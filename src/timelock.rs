@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tsify::Tsify;
+
+use crate::role_manager::{ADMIN_ROLE, PAUSER_ROLE};
+
+/// Gecikme etkinken yalnızca zaman kilidi üzerinden uygulanabilen yetkili işlem
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AdminAction {
+    SetBaseUri { uri: String },
+    SetDefaultRoyalty { receiver: String, bps: u16 },
+    Pause,
+    Unpause,
+    UpdateDelay { delay: u64 },
+    ConfigureMultisig { signers: Vec<String>, threshold: u32, expiry: u64 },
+}
+
+impl AdminAction {
+    /// İşlemi sıraya alabilmek için gereken rol
+    pub(crate) fn required_role(&self) -> &'static str {
+        match self {
+            AdminAction::Pause | AdminAction::Unpause => PAUSER_ROLE,
+            _ => ADMIN_ROLE,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct QueuedAction {
+    pub(crate) action: AdminAction,
+    pub(crate) proposer: String, // İşlem bu adresin yetkisiyle uygulanır
+    pub(crate) eta: u64, // Uygulanabileceği en erken an (Unix saniye)
+}
+
+/// Yetkili işlemleri gecikmeyle uygulatan zaman kilidi (OpenZeppelin `TimelockController` benzeri)
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Timelock {
+    delay: u64, // 0 ise zaman kilidi devre dışıdır
+    queue: BTreeMap<u64, QueuedAction>, // İşlem ID -> Sıradaki İşlem
+    next_id: u64,
+    #[serde(skip)]
+    executing: bool, // Sıradaki bir işlem uygulanıyor mu
+}
+
+impl Timelock {
+    /// Gecikmesi aynı, sırada işlem olmayan kopya (`clone_with` için)
+    pub(crate) fn settings_only(&self) -> Timelock {
+        Timelock {
+            delay: self.delay,
+            ..Timelock::default()
+        }
+    }
+
+    pub(crate) fn delay(&self) -> u64 {
+        self.delay
+    }
+
+    pub(crate) fn set_delay(&mut self, delay: u64) {
+        self.delay = delay;
+    }
+
+    /// Gecikme etkinse ve işlem zaman kilidinden gelmiyorsa hata döner
+    pub(crate) fn ensure_scheduled(&self) -> Result<(), String> {
+        if self.delay > 0 && !self.executing {
+            return Err("Action must be queued in the timelock".to_string());
+        }
+        Ok(())
+    }
+
+    pub(crate) fn queue(&mut self, action: AdminAction, proposer: &str, now: u64) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.queue.insert(
+            id,
+            QueuedAction {
+                action,
+                proposer: proposer.to_string(),
+                eta: now.saturating_add(self.delay),
+            },
+        );
+        id
+    }
+
+    /// Süresi dolmuş işlemi sıradan çıkarır ve döner; uygulama başarısız olursa `requeue` ile geri konmalıdır
+    pub(crate) fn take_ready(&mut self, id: u64, now: u64) -> Result<QueuedAction, String> {
+        let queued = self.queue.get(&id).ok_or("Action is not queued")?;
+        if now < queued.eta {
+            return Err(format!("Action is not ready until {}", queued.eta));
+        }
+        Ok(self.queue.remove(&id).expect("action is queued"))
+    }
+
+    pub(crate) fn requeue(&mut self, id: u64, queued: QueuedAction) {
+        self.queue.insert(id, queued);
+    }
+
+    pub(crate) fn cancel(&mut self, id: u64) -> Result<QueuedAction, String> {
+        self.queue.remove(&id).ok_or_else(|| "Action is not queued".to_string())
+    }
+
+    pub(crate) fn get(&self, id: u64) -> Option<&QueuedAction> {
+        self.queue.get(&id)
+    }
+
+    pub(crate) fn set_executing(&mut self, executing: bool) {
+        self.executing = executing;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_and_take() {
+        let mut timelock = Timelock::default();
+        assert!(timelock.ensure_scheduled().is_ok());
+
+        timelock.set_delay(100);
+        assert!(timelock.ensure_scheduled().is_err());
+        let id = timelock.queue(AdminAction::Pause, "owner", 1_000);
+        assert_eq!(timelock.get(id).unwrap().eta, 1_100);
+        assert!(timelock.take_ready(id, 1_099).is_err());
+        assert_eq!(timelock.take_ready(id, 1_100).unwrap().action, AdminAction::Pause);
+        assert!(timelock.cancel(id).is_err());
+
+        timelock.set_executing(true);
+        assert!(timelock.ensure_scheduled().is_ok());
+    }
+}
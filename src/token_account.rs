@@ -0,0 +1,133 @@
+use wasm_bindgen::prelude::*;
+
+use crate::eip712::{encode_address, encode_u64};
+use crate::erc721::ERC721;
+use crate::hash::keccak256;
+use crate::bytes::to_hex;
+use crate::utils::log_event;
+
+/// Token'a bağlı hesap adreslerini (ERC-6551) deterministik olarak türeten kayıt defteri
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountRegistry {
+    pub implementation: String, // 0x önekli 20 baytlık hesap uygulaması adresi
+}
+
+#[wasm_bindgen]
+impl AccountRegistry {
+    #[wasm_bindgen(constructor)]
+    pub fn new(implementation: String) -> Result<AccountRegistry, String> {
+        encode_address(&implementation)?;
+        Ok(AccountRegistry { implementation })
+    }
+
+    /// Hesap adresini hesaplar: keccak256(abi.encode(implementation, salt, chainId, tokenContract, tokenId))
+    ///
+    /// Aynı girdiler her zaman aynı adresi verir; hesabın oluşturulmuş olması gerekmez.
+    pub fn account(&self, chain_id: u64, token_contract: String, token_id: u64, salt: u64) -> Result<String, String> {
+        let mut data = encode_address(&self.implementation)?.to_vec();
+        data.extend_from_slice(&encode_u64(salt));
+        data.extend_from_slice(&encode_u64(chain_id));
+        data.extend_from_slice(&encode_address(&token_contract)?);
+        data.extend_from_slice(&encode_u64(token_id));
+        Ok(to_hex(&keccak256(&data)[12..]))
+    }
+
+    /// Koleksiyondaki mevcut bir token için hesap nesnesini oluşturur
+    pub fn create_account(&self, parent: &ERC721, token_id: u64, salt: u64) -> Result<TokenAccount, String> {
+        parent.owner_of(token_id).ok_or("Token does not exist")?;
+        let domain = parent.eip712_domain();
+        let address = self.account(domain.chain_id, domain.verifying_contract.clone(), token_id, salt)?;
+        log_event("Token Account Created", &format!("Token ID: {}, Account: {}", token_id, address));
+        Ok(TokenAccount {
+            address,
+            token_contract: domain.verifying_contract,
+            token_id,
+        })
+    }
+}
+
+/// Token'a bağlı hesap: simüle ETH ve başka tokenlar tutabilir, üst token'ın güncel sahibi yönetir
+///
+/// Bakiyeler üst koleksiyonun ETH defterinde tutulur; her çağrıda aynı üst koleksiyon verilmelidir.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenAccount {
+    pub address: String,
+    pub token_contract: String,
+    pub token_id: u64,
+}
+
+#[wasm_bindgen]
+impl TokenAccount {
+    /// Hesabı yöneten adresi, yani üst token'ın güncel sahibini döner
+    pub fn owner(&self, parent: &ERC721) -> Option<String> {
+        parent.owner_of(self.token_id)
+    }
+
+    /// Hesabın simüle ETH bakiyesini döner
+    pub fn balance(&self, parent: &ERC721) -> u64 {
+        parent.eth_balance(self.address.clone())
+    }
+
+    /// Hesaptaki simüle ETH'yi başka bir adrese gönderir (sadece üst token'ın sahibi)
+    pub fn send_funds(&self, caller: String, parent: &mut ERC721, to: String, amount: u64) -> Result<(), String> {
+        self.ensure_controller(&caller, parent)?;
+        parent.bank_mut().transfer(&self.address, &to, amount)?;
+        log_event("Account Funds Sent", &format!("Account: {}, To: {}, Amount: {}", self.address, to, amount));
+        Ok(())
+    }
+
+    /// Hesabın sahip olduğu bir token'ı başka bir adrese gönderir (sadece üst token'ın sahibi)
+    pub fn send_token(
+        &self,
+        caller: String,
+        parent: &ERC721,
+        collection: &mut ERC721,
+        token_id: u64,
+        to: String,
+    ) -> Result<(), String> {
+        self.ensure_controller(&caller, parent)?;
+        collection.transfer(self.address.clone(), to, token_id)
+    }
+}
+
+impl TokenAccount {
+    fn ensure_controller(&self, caller: &str, parent: &ERC721) -> Result<(), String> {
+        if parent.owner_of(self.token_id).as_deref() != Some(caller) {
+            log_event("Token Account Call Failed", "Unauthorized attempt");
+            return Err("Only the owner of the parent token can use the account".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bound_account() {
+        let registry = AccountRegistry::new(format!("0x{}", "42".repeat(20))).unwrap();
+        let mut parent = ERC721::new("owner".to_string());
+        parent.mint("owner".to_string(), 1).unwrap();
+        let mut items = ERC721::new("owner".to_string());
+        items.mint("owner".to_string(), 9).unwrap();
+
+        let account = registry.create_account(&parent, 1, 0).unwrap();
+        assert_eq!(registry.create_account(&parent, 1, 0).unwrap(), account);
+        assert_ne!(registry.create_account(&parent, 1, 1).unwrap().address, account.address);
+        assert!(registry.create_account(&parent, 2, 0).is_err());
+
+        parent.deposit(account.address.clone(), 100).unwrap();
+        items.transfer("owner".to_string(), account.address.clone(), 9).unwrap();
+
+        // Control follows the parent token
+        parent.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        assert!(account.send_funds("owner".to_string(), &mut parent, "owner".to_string(), 10).is_err());
+        account.send_funds("user1".to_string(), &mut parent, "user1".to_string(), 40).unwrap();
+        account.send_token("user1".to_string(), &parent, &mut items, 9, "user1".to_string()).unwrap();
+        assert_eq!(account.balance(&parent), 60);
+        assert_eq!(items.owner_of(9), Some("user1".to_string()));
+    }
+}
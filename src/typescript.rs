@@ -0,0 +1,28 @@
+use wasm_bindgen::prelude::*;
+
+// Serde türleri `Tsify` ile kendi arayüzlerini üretir; burada yalnızca elle kurulan JSON
+// nesneleri ve JS geri çağırma imzaları tanımlanır.
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &'static str = r#"
+/** OpenSea biçimindeki token özelliği (`get_attributes`, birleştirme kuralları) */
+export interface TokenAttribute {
+    display_type?: "number" | "boost_number" | "date";
+    trait_type: string;
+    value: string | number;
+}
+
+/** İşlem adı -> çağrı sayısı ve süre özeti (`perf_report`) */
+export type PerfReport = Record<string, OpTiming>;
+
+/** İşlem kancası; "before" kancasında `false` dönmek veya hata fırlatmak işlemi iptal eder */
+export type HookCallback = (context: HookContext) => boolean | void;
+
+/** İki girdinin özelliklerinden çıktının özelliklerini üretir; hata fırlatmak birleştirmeyi iptal eder */
+export type MergeRuleCallback = (a: TokenAttribute[], b: TokenAttribute[]) => TokenAttribute[];
+
+/** Rol ve sahiplik değişikliklerini alan dinleyici (`on_role_event`) */
+export type RoleEventCallback = (entry: LogEntry) => void;
+
+/** `set_logger` ile kaydedilen günlük fonksiyonu */
+export type LoggerCallback = (record: LogRecord) => void;
+"#;
@@ -0,0 +1,181 @@
+use wasm_bindgen::prelude::*;
+
+use crate::bytes::decode_hex;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Token ve koleksiyon URI'lerini doğrular
+///
+/// `ipfs://` adreslerinde CID (v0 veya multibase v1), `ar://` adreslerinde Arweave işlem kimliği
+/// çözülerek kontrol edilir. `strict` açıksa yalnızca merkeziyetsiz (ve `data:`) adreslere izin verilir.
+#[wasm_bindgen]
+pub fn validate_uri(uri: &str, strict: bool) -> Result<(), String> {
+    if let Some(rest) = uri.strip_prefix("ipfs://") {
+        let rest = rest.strip_prefix("ipfs/").unwrap_or(rest);
+        validate_cid(root_segment(rest))
+    } else if let Some(rest) = uri.strip_prefix("ar://") {
+        validate_arweave_id(root_segment(rest))
+    } else if uri.starts_with("http://") || uri.starts_with("https://") {
+        if strict {
+            return Err(format!("HTTP URIs are not allowed for decentralized collections: {}", uri));
+        }
+        Ok(())
+    } else if uri.starts_with("data:") {
+        Ok(())
+    } else {
+        Err(format!("Unsupported URI scheme: {}", uri))
+    }
+}
+
+/// Adresin ilk yol parçasını (CID veya işlem kimliği) döner
+fn root_segment(rest: &str) -> &str {
+    rest.split(['/', '?', '#']).next().unwrap_or("")
+}
+
+/// CIDv0 (`Qm...`) veya multibase önekli CIDv1 kimliğini çözüp yapısını doğrular
+pub(crate) fn validate_cid(cid: &str) -> Result<(), String> {
+    let invalid = || format!("Invalid CID: {}", cid);
+
+    // CIDv0: base58btc ile kodlanmış sha2-256 multihash
+    if cid.len() == 46 && cid.starts_with("Qm") {
+        let bytes = decode_base58(cid).ok_or_else(invalid)?;
+        return match bytes.as_slice() {
+            [0x12, 0x20, digest @ ..] if digest.len() == 32 => Ok(()),
+            _ => Err(invalid()),
+        };
+    }
+
+    let mut chars = cid.chars();
+    let bytes = match chars.next() {
+        Some('b') => decode_base32(chars.as_str()),
+        Some('B') => decode_base32(&chars.as_str().to_ascii_lowercase()),
+        Some('z') => decode_base58(chars.as_str()),
+        Some('f') | Some('F') => decode_hex(chars.as_str()).ok(),
+        Some(prefix) => return Err(format!("Unsupported multibase prefix '{}' in CID: {}", prefix, cid)),
+        None => return Err("CID is empty".to_string()),
+    }
+    .ok_or_else(invalid)?;
+
+    // <sürüm><codec><multihash kodu><özet uzunluğu><özet>
+    let mut input = bytes.as_slice();
+    if read_varint(&mut input) != Some(1) {
+        return Err(invalid());
+    }
+    read_varint(&mut input).ok_or_else(invalid)?;
+    read_varint(&mut input).ok_or_else(invalid)?;
+    let length = read_varint(&mut input).ok_or_else(invalid)?;
+    if length == 0 || input.len() as u64 != length {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// Arweave işlem kimliğini doğrular: 32 baytın base64url (dolgusuz) karşılığı, 43 karakter
+pub(crate) fn validate_arweave_id(id: &str) -> Result<(), String> {
+    let valid = id.len() == 43 && id.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_');
+    if !valid {
+        return Err(format!("Invalid Arweave transaction ID: {}", id));
+    }
+    Ok(())
+}
+
+/// Unsigned varint okur ve girdiyi ilerletir
+fn read_varint(input: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (index, &byte) in input.iter().enumerate().take(9) {
+        value |= ((byte & 0x7f) as u64) << (7 * index);
+        if byte & 0x80 == 0 {
+            *input = &input[index + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn decode_base58(text: &str) -> Option<Vec<u8>> {
+    let mut bytes: Vec<u8> = Vec::new(); // Büyük sayı, küçük basamak önce
+    for c in text.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&symbol| symbol == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // Baştaki her '1' bir sıfır baytı temsil eder
+    let zeros = text.bytes().take_while(|&c| c == b'1').count();
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Some(bytes)
+}
+
+fn decode_base32(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in text.bytes() {
+        buffer = (buffer << 5) | BASE32_ALPHABET.iter().position(|&symbol| symbol == c)? as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::hash::keccak256;
+    use crate::bytes::to_hex;
+    use crate::utils::base64_encode;
+
+    /// Testler için etiketten türetilmiş geçerli bir CIDv1 (base16) üretir
+    pub(crate) fn cid(label: &str) -> String {
+        format!("f01701220{}", &to_hex(&keccak256(label.as_bytes()))[2..])
+    }
+
+    /// Testler için etiketten türetilmiş geçerli bir Arweave işlem kimliği üretir
+    pub(crate) fn arweave_id(label: &str) -> String {
+        base64_encode(&keccak256(label.as_bytes()))
+            .trim_end_matches('=')
+            .replace('+', "-")
+            .replace('/', "_")
+    }
+
+    #[test]
+    fn test_validate_cid() {
+        // Well-known CIDs in v0, v1 (base32) and upper-case v1 form
+        assert!(validate_cid("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG").is_ok());
+        assert!(validate_cid("bafybeie5gq4jxvzmsym6hjlwxej4rwdoxt7wadqvmmwbqi7r27fclha2va").is_ok());
+        assert!(validate_cid("BAFYBEIE5GQ4JXVZMSYM6HJLWXEJ4RWDOXT7WADQVMMWBQI7R27FCLHA2VA").is_ok());
+        assert!(validate_cid(&cid("token")).is_ok());
+
+        // Single-character typos break the encoding or the multihash length
+        assert!(validate_cid("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0").is_err());
+        assert!(validate_cid("bafybeie5gq4jxvzmsym6hjlwxej4rwdoxt7wadqvmmwbqi7r27fclha2v").is_err());
+        assert!(validate_cid("mAXASIA").is_err());
+        assert!(validate_cid("").is_err());
+    }
+
+    #[test]
+    fn test_validate_uri() {
+        let cid = cid("collection");
+        assert!(validate_uri(&format!("ipfs://{}/1.json", cid), true).is_ok());
+        assert!(validate_uri(&format!("ipfs://ipfs/{}", cid), true).is_ok());
+        assert!(validate_uri(&format!("ar://{}", arweave_id("collection")), true).is_ok());
+        assert!(validate_uri("data:application/json;base64,e30=", true).is_ok());
+        assert!(validate_uri("https://example.com/1.json", false).is_ok());
+
+        assert!(validate_uri("https://example.com/1.json", true).is_err());
+        assert!(validate_uri("ipfs://base/", false).is_err());
+        assert!(validate_uri("ar://short", false).is_err());
+        assert!(validate_uri("ftp://example.com", false).is_err());
+    }
+}
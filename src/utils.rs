@@ -1,6 +1,153 @@
+use std::cell::Cell;
+use wasm_bindgen::prelude::*;
+#[cfg(all(target_arch = "wasm32", feature = "logging"))]
 use web_sys::console;
 
+#[cfg(feature = "logging")]
+use crate::logger::{self, LogRecord};
+
+/// Olay günlüğünün ayrıntı düzeyi
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Off = 0,
+    Error = 1, // Yalnızca başarısız işlemler ("... Failed" olayları)
+    Info = 2, // Tüm olaylar
+}
+
+thread_local! {
+    static LOG_LEVEL: Cell<LogLevel> = const { Cell::new(LogLevel::Info) };
+}
+
+/// Olay günlüğünün ayrıntı düzeyini ayarlar; `logging` özelliği kapalıysa etkisizdir
+#[wasm_bindgen]
+pub fn set_log_level(level: LogLevel) {
+    LOG_LEVEL.with(|current| current.set(level));
+}
+
+/// Olay günlüğünün ayrıntı düzeyini döner
+#[wasm_bindgen]
+pub fn log_level() -> LogLevel {
+    LOG_LEVEL.with(Cell::get)
+}
+
+/// Olayın düzeyi: adı "Failed" ile bitenler hata, diğerleri bilgi olayıdır
+fn event_level(event: &str) -> LogLevel {
+    if event.ends_with("Failed") {
+        LogLevel::Error
+    } else {
+        LogLevel::Info
+    }
+}
+
+/// Olayın ayarlı düzeyde günlüğe yazılıp yazılmayacağını döner
+#[cfg_attr(not(feature = "logging"), allow(dead_code))]
+pub(crate) fn log_enabled(event: &str) -> bool {
+    let level = event_level(event);
+    level != LogLevel::Off && level <= log_level()
+}
+
 /// Yardımcı fonksiyon: Olay günlüğü
+///
+/// Olay `set_logger` ile kaydedilen fonksiyona iletilir; fonksiyon yoksa tarayıcı konsoluna yazılır
+/// (tarayıcı dışında konsol bulunmaz).
+#[cfg(feature = "logging")]
 pub fn log_event(event: &str, details: &str) {
-    console::log_2(&event.into(), &details.into());
+    if !log_enabled(event) {
+        return;
+    }
+    let record = LogRecord {
+        level: event_level(event),
+        event: event.to_string(),
+        details: details.to_string(),
+        timestamp: now_ms() as u64,
+    };
+    if !logger::dispatch(record) {
+        #[cfg(target_arch = "wasm32")]
+        console::log_2(&event.into(), &details.into());
+    }
+}
+
+/// Yardımcı fonksiyon: Olay günlüğü (`logging` özelliği kapalıyken boştur)
+#[cfg(not(feature = "logging"))]
+pub fn log_event(_event: &str, _details: &str) {}
+
+/// Şu anki zamanı milisaniye cinsinden döner
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Şu anki zamanı milisaniye cinsinden döner
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn now_ms() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs_f64() * 1_000.0)
+        .unwrap_or(0.0)
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+extern "C" {
+    // Pencere ve worker ortamlarında ortak olan zamanlayıcı
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(callback: &js_sys::Function, delay_ms: i32);
+}
+
+/// Kontrolü bir olay döngüsü turu için tarayıcıya bırakır (`setTimeout(0)`)
+///
+/// Mikro görev yerine makro görev kullanılır; böylece bekleyen çizim ve girdi olayları işlenir.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn yield_now() {
+    let timeout = js_sys::Promise::new(&mut |resolve, _reject| set_timeout(&resolve, 0));
+    let _ = wasm_bindgen_futures::JsFuture::from(timeout).await;
+}
+
+/// Kontrolü olay döngüsüne bırakır; tarayıcı dışında beklemeden döner
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn yield_now() {}
+
+/// Baytları standart (RFC 4648, dolgulu) base64 metne çevirir
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let word = (chunk[0] as u32) << 16
+            | (chunk.get(1).copied().unwrap_or(0) as u32) << 8
+            | chunk.get(2).copied().unwrap_or(0) as u32;
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(word >> (18 - 6 * index) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_log_levels() {
+        assert!(log_enabled("Token Minted") && log_enabled("Minting Failed"));
+        set_log_level(LogLevel::Error);
+        assert!(!log_enabled("Token Minted") && log_enabled("Minting Failed"));
+        set_log_level(LogLevel::Off);
+        assert!(!log_enabled("Minting Failed"));
+        set_log_level(LogLevel::Info);
+        assert_eq!(log_level(), LogLevel::Info);
+    }
 }
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+
+/// Oy gücünün belirli bir andan itibaren geçerli olan değeri
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+struct Checkpoint {
+    timepoint: u64, // Unix zamanı (saniye)
+    votes: u64,
+}
+
+/// Zaman sıralı checkpoint dizisi; aynı andaki güncellemeler son kaydın üzerine yazılır
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct History(Vec<Checkpoint>);
+
+impl History {
+    fn latest(&self) -> u64 {
+        self.0.last().map(|checkpoint| checkpoint.votes).unwrap_or(0)
+    }
+
+    /// `timepoint` anında (dahil) geçerli olan değeri döner
+    fn at(&self, timepoint: u64) -> u64 {
+        let index = self.0.partition_point(|checkpoint| checkpoint.timepoint <= timepoint);
+        if index == 0 {
+            0
+        } else {
+            self.0[index - 1].votes
+        }
+    }
+
+    fn push(&mut self, timepoint: u64, votes: u64) {
+        match self.0.last_mut() {
+            Some(last) if last.timepoint == timepoint => last.votes = votes,
+            _ => self.0.push(Checkpoint { timepoint, votes }),
+        }
+    }
+}
+
+/// ERC721Votes: her token bir oy birimidir; oy gücü yalnızca yetki devrinden (delegate) sonra sayılır
+///
+/// Sahipler kendi oylarını kullanmak için kendilerine devretmelidir (OpenZeppelin ile aynı).
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Votes {
+    units: BTreeMap<String, u64>, // Adres -> Sahip Olunan Token Sayısı
+    delegates: BTreeMap<String, String>, // Sahip -> Oy Devredilen Adres
+    checkpoints: BTreeMap<String, History>, // Delege -> Oy Gücü Geçmişi
+    total: History, // Toplam oy birimi (arz) geçmişi
+}
+
+impl Votes {
+    pub(crate) fn delegates(&self, account: &str) -> Option<&String> {
+        self.delegates.get(account)
+    }
+
+    /// Sahibin tüm oy birimlerini yeni delegeye taşır
+    pub(crate) fn delegate(&mut self, owner: &str, delegatee: &str, now: u64) {
+        let previous = self.delegates.insert(owner.to_string(), delegatee.to_string());
+        let units = self.units.get(owner).copied().unwrap_or(0);
+        self.move_votes(previous.as_deref(), Some(delegatee), units, now);
+    }
+
+    /// Token hareketini işler: mint'te `from`, burn'de `to` boştur
+    pub(crate) fn transfer_units(&mut self, from: Option<&str>, to: Option<&str>, amount: u64, now: u64) {
+        if from == to || amount == 0 {
+            return;
+        }
+        match from {
+            Some(from) => *self.units.entry(from.to_string()).or_default() -= amount,
+            None => self.total.push(now, self.total.latest() + amount),
+        }
+        match to {
+            Some(to) => *self.units.entry(to.to_string()).or_default() += amount,
+            None => self.total.push(now, self.total.latest() - amount),
+        }
+        let from = from.and_then(|from| self.delegates.get(from)).cloned();
+        let to = to.and_then(|to| self.delegates.get(to)).cloned();
+        self.move_votes(from.as_deref(), to.as_deref(), amount, now);
+    }
+
+    /// Adresin sahip olduğu token sayısı (devirden bağımsız)
+    pub(crate) fn units(&self, account: &str) -> u64 {
+        self.units.get(account).copied().unwrap_or(0)
+    }
+
+    /// En az bir token'ı olan adresleri token sayılarıyla döner
+    pub(crate) fn holders(&self) -> impl Iterator<Item = (&String, u64)> {
+        self.units.iter().filter(|(_, &units)| units > 0).map(|(account, &units)| (account, units))
+    }
+
+    pub(crate) fn votes(&self, account: &str) -> u64 {
+        self.checkpoints.get(account).map(History::latest).unwrap_or(0)
+    }
+
+    pub(crate) fn past_votes(&self, account: &str, timepoint: u64) -> u64 {
+        self.checkpoints.get(account).map(|history| history.at(timepoint)).unwrap_or(0)
+    }
+
+    pub(crate) fn past_total_supply(&self, timepoint: u64) -> u64 {
+        self.total.at(timepoint)
+    }
+
+    pub(crate) fn checkpoint_count(&self, account: &str) -> usize {
+        self.checkpoints.get(account).map(|history| history.0.len()).unwrap_or(0)
+    }
+
+    fn move_votes(&mut self, from: Option<&str>, to: Option<&str>, amount: u64, now: u64) {
+        if from == to || amount == 0 {
+            return;
+        }
+        if let Some(from) = from {
+            let history = self.checkpoints.entry(from.to_string()).or_default();
+            history.push(now, history.latest() - amount);
+        }
+        if let Some(to) = to {
+            let history = self.checkpoints.entry(to.to_string()).or_default();
+            history.push(now, history.latest() + amount);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delegation_checkpoints() {
+        let mut votes = Votes::default();
+        votes.transfer_units(None, Some("alice"), 3, 100);
+        // Units only count once they are delegated
+        assert_eq!(votes.votes("alice"), 0);
+
+        votes.delegate("alice", "alice", 110);
+        votes.transfer_units(Some("alice"), Some("bob"), 1, 120);
+        votes.delegate("bob", "alice", 130);
+        votes.transfer_units(Some("alice"), None, 1, 130);
+
+        assert_eq!(votes.votes("alice"), 2);
+        assert_eq!(votes.past_votes("alice", 109), 0);
+        assert_eq!(votes.past_votes("alice", 110), 3);
+        assert_eq!(votes.past_votes("alice", 125), 2);
+        assert_eq!(votes.past_total_supply(125), 3);
+        assert_eq!(votes.past_total_supply(130), 2);
+        // Two updates in the same second share a checkpoint
+        assert_eq!(votes.checkpoint_count("alice"), 3);
+    }
+}
@@ -0,0 +1,73 @@
+use wasm_bindgen::prelude::*;
+
+use crate::eip712::{encode_address, encode_string, encode_u64, hash_struct, Eip712Domain};
+
+const VOUCHER_TYPE: &str = "NFTVoucher(uint256 tokenId,string uri,uint256 price,address recipient)";
+
+/// Yetkili imzacının zincir dışında imzaladığı tembel mint kuponu
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NFTVoucher {
+    pub token_id: u64,
+    pub uri: String,
+    pub price: u64,
+    pub recipient: String, // 0x önekli 20 baytlık adres
+}
+
+#[wasm_bindgen]
+impl NFTVoucher {
+    /// Yeni bir kupon oluşturur
+    #[wasm_bindgen(constructor)]
+    pub fn new(token_id: u64, uri: String, price: u64, recipient: String) -> NFTVoucher {
+        NFTVoucher {
+            token_id,
+            uri,
+            price,
+            recipient,
+        }
+    }
+}
+
+impl NFTVoucher {
+    /// Kuponun alan ayırıcısıyla birlikte imzalanacak EIP-712 özetini hesaplar
+    pub(crate) fn digest(&self, domain: &Eip712Domain) -> Result<[u8; 32], String> {
+        let struct_hash = hash_struct(
+            VOUCHER_TYPE,
+            &[
+                encode_u64(self.token_id),
+                encode_string(&self.uri),
+                encode_u64(self.price),
+                encode_address(&self.recipient)?,
+            ],
+        );
+        domain.digest(&struct_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip712::recover_address;
+    use crate::eip712::tests::sign;
+    use k256::ecdsa::SigningKey;
+
+    #[test]
+    fn test_recover_voucher_signer() {
+        let key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let domain = Eip712Domain::default();
+        let voucher = NFTVoucher::new(1, "ipfs://token".to_string(), 100, format!("0x{}", "11".repeat(20)));
+
+        let digest = voucher.digest(&domain).unwrap();
+        let (signature, address) = sign(&key, &digest);
+        assert_eq!(recover_address(&digest, &signature).unwrap(), address);
+
+        // Tampering with any field changes the recovered signer
+        let mut tampered = voucher.clone();
+        tampered.price = 1;
+        let digest = tampered.digest(&domain).unwrap();
+        assert_ne!(recover_address(&digest, &signature).unwrap(), address);
+
+        let invalid = NFTVoucher::new(1, String::new(), 0, "user1".to_string());
+        assert!(invalid.digest(&domain).is_err());
+    }
+}
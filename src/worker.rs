@@ -0,0 +1,79 @@
+use js_sys::{ArrayBuffer, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+use crate::erc721::ERC721;
+use crate::utils::log_event;
+
+/// Web Worker'a (veya başka bir iş parçacığına) taşınmak üzere ayrılmış kontrat durumu
+///
+/// `ERC721` JS kancaları ve `Rc` tabanlı önbellekler taşıdığından `Send` değildir; taşıma her
+/// zaman bu ikili durum üzerinden yapılır. Kancalar, birleştirme kuralı, checkpoint'ler,
+/// performans sayaçları ve kalıcılık ayarı taşınmaz; hedefte yeniden kurulmalıdır.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DetachedState(Vec<u8>);
+
+impl DetachedState {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for DetachedState {
+    fn from(bytes: Vec<u8>) -> DetachedState {
+        DetachedState(bytes)
+    }
+}
+
+#[wasm_bindgen]
+impl ERC721 {
+    /// Kontratı tüketip durumunu `postMessage` ile aktarılabilir (transferable) bir `ArrayBuffer`'a yazar
+    ///
+    /// Çağrıdan sonra bu JS nesnesi kullanılamaz: `worker.postMessage(buffer, [buffer])`.
+    pub fn detach_state(self) -> ArrayBuffer {
+        let state = self.detach();
+        Uint8Array::from(state.as_bytes()).buffer()
+    }
+
+    /// `detach_state` ile ayrılan durumdan kontratı yeniden kurar (ör. worker içinde)
+    pub fn attach_state(buffer: ArrayBuffer) -> Result<ERC721, String> {
+        ERC721::attach(DetachedState(Uint8Array::new(&buffer).to_vec()))
+    }
+}
+
+impl ERC721 {
+    /// Kontratı iş parçacıkları arasında taşınabilir duruma çevirir (Rust tarafı için `detach_state`)
+    pub fn detach(self) -> DetachedState {
+        let bytes = self.snapshot();
+        log_event("State Detached", &format!("Bytes: {}", bytes.len()));
+        DetachedState(bytes)
+    }
+
+    /// Ayrılmış durumdan kontratı kurar (Rust tarafı için `attach_state`)
+    pub fn attach(state: DetachedState) -> Result<ERC721, String> {
+        let erc721 = ERC721::restore(&state.0)?;
+        log_event("State Attached", &format!("Bytes: {}", state.0.len()));
+        Ok(erc721)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_moves_across_threads() {
+        let mut erc721 = ERC721::new("owner".to_string());
+        erc721.mint_batch("owner".to_string(), 1, 10, String::new()).unwrap();
+        erc721.transfer("owner".to_string(), "alice".to_string(), 3).unwrap();
+
+        let state = erc721.detach();
+        let owner = std::thread::spawn(move || ERC721::attach(state).unwrap().owner_of(3)).join().unwrap();
+        assert_eq!(owner, Some("alice".to_string()));
+
+        assert!(ERC721::attach(DetachedState::from(vec![1, 2, 3])).is_err());
+    }
+}
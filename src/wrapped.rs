@@ -0,0 +1,128 @@
+use wasm_bindgen::prelude::*;
+
+use crate::erc721::ERC721;
+use crate::utils::log_event;
+
+/// Sarılan orijinal tokenların kaynak koleksiyonda tutulduğu adres
+pub const WRAP_VAULT: &str = "wrap-vault";
+
+/// Kaynak koleksiyondaki tokenı kilitleyip 1:1 sarmalayıcı token mint'leyen koleksiyon
+///
+/// Sarmalayıcı tokenlar aynı Token ID'yi taşır; yönetişim gibi yeni davranışlar sarmalayıcı
+/// koleksiyon üzerinden eski koleksiyonlara eklenebilir. Her çağrıda aynı kaynak örneği verilmelidir.
+#[wasm_bindgen]
+pub struct WrappedERC721 {
+    collection: ERC721,
+}
+
+#[wasm_bindgen]
+impl WrappedERC721 {
+    /// Sarmalayıcı koleksiyonu oluşturur; `owner` sarmalayıcı koleksiyonun sahibidir
+    #[wasm_bindgen(constructor)]
+    pub fn new(owner: String) -> WrappedERC721 {
+        WrappedERC721 {
+            collection: ERC721::new(owner),
+        }
+    }
+
+    /// Kaynak token'ı kasaya kilitler ve sahibine aynı ID'li sarmalayıcı token mint'ler
+    pub fn wrap(&mut self, owner: String, source: &mut ERC721, token_id: u64) -> Result<(), String> {
+        // Sarmalayıcı mint önceden doğrulanır; kaynak transfer başarılı olursa mint de başarılı olur
+        let mint = self
+            .collection
+            .check_mint(&owner, &owner, token_id)
+            .inspect_err(|err| log_event("Wrap Failed", err))?;
+        source
+            .transfer(owner.clone(), WRAP_VAULT.to_string(), token_id)
+            .inspect_err(|err| log_event("Wrap Failed", err))?;
+        self.collection.apply_mint(mint);
+        log_event("Token Wrapped", &format!("Token ID: {}, Owner: {}", token_id, owner));
+        Ok(())
+    }
+
+    /// Sarmalayıcı token'ı yakar ve orijinal token'ı sarmalayıcının sahibine geri verir
+    ///
+    /// Sarmalayıcı token için onaylı operatörler de çağırabilir.
+    pub fn unwrap(&mut self, caller: String, source: &mut ERC721, token_id: u64) -> Result<(), String> {
+        let owner = self.collection.owner_of(token_id).ok_or("Token is not wrapped")?;
+        let release = source
+            .check_transfer(WRAP_VAULT, &owner, token_id)
+            .inspect_err(|err| log_event("Unwrap Failed", err))?;
+        self.collection
+            .burn(caller, token_id)
+            .inspect_err(|err| log_event("Unwrap Failed", err))?;
+        source.apply_transfer(release);
+        log_event("Token Unwrapped", &format!("Token ID: {}, Owner: {}", token_id, owner));
+        Ok(())
+    }
+
+    /// Sarmalayıcı token'ın sahibini döner
+    pub fn owner_of(&self, token_id: u64) -> Option<String> {
+        self.collection.owner_of(token_id)
+    }
+
+    /// Token'ın sarılı olup olmadığını döner
+    pub fn is_wrapped(&self, token_id: u64) -> bool {
+        self.collection.owner_of(token_id).is_some()
+    }
+}
+
+impl WrappedERC721 {
+    /// Sarmalayıcı koleksiyonu döner
+    pub fn collection(&self) -> &ERC721 {
+        &self.collection
+    }
+
+    /// Sarmalayıcı koleksiyona yeni davranışlar (ör. yönetişim) eklemek için değiştirilebilir erişim
+    pub fn collection_mut(&mut self) -> &mut ERC721 {
+        &mut self.collection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::HookKind;
+
+    #[test]
+    fn test_wrap_and_unwrap() {
+        let mut source = ERC721::new("owner".to_string());
+        source.mint("owner".to_string(), 1).unwrap();
+        source.transfer("owner".to_string(), "user1".to_string(), 1).unwrap();
+        let mut wrapped = WrappedERC721::new("dao".to_string());
+
+        assert!(wrapped.wrap("user2".to_string(), &mut source, 1).is_err());
+        wrapped.wrap("user1".to_string(), &mut source, 1).unwrap();
+        assert_eq!(source.owner_of(1), Some(WRAP_VAULT.to_string()));
+        assert_eq!(wrapped.owner_of(1), Some("user1".to_string()));
+
+        // Wrapper tokens gain the wrapper collection's behaviors, e.g. voting power
+        let collection = wrapped.collection_mut();
+        collection.delegate("user1".to_string(), "user1".to_string()).unwrap();
+        assert_eq!(collection.get_votes("user1".to_string()), 1);
+
+        assert!(wrapped.unwrap("user2".to_string(), &mut source, 1).is_err());
+        wrapped.unwrap("user1".to_string(), &mut source, 1).unwrap();
+        assert_eq!(source.owner_of(1), Some("user1".to_string()));
+        assert!(!wrapped.is_wrapped(1));
+    }
+
+    #[test]
+    fn test_failed_wrap_keeps_both_sides() {
+        let mut source = ERC721::new("owner".to_string());
+        source.mint("owner".to_string(), 1).unwrap();
+        let mut wrapped = WrappedERC721::new("dao".to_string());
+        wrapped.collection_mut().add_hook(HookKind::BeforeMint, |_| Err("Closed".to_string()));
+
+        assert!(wrapped.wrap("owner".to_string(), &mut source, 1).is_err());
+        assert_eq!(source.owner_of(1), Some("owner".to_string()));
+
+        // A source release rejected by a hook leaves the wrapper token unburned
+        wrapped.collection_mut().clear_hooks();
+        wrapped.wrap("owner".to_string(), &mut source, 1).unwrap();
+        source.add_hook(HookKind::BeforeTransfer, |_| Err("Frozen".to_string()));
+        assert!(wrapped.unwrap("owner".to_string(), &mut source, 1).is_err());
+        assert!(wrapped.is_wrapped(1));
+        assert_eq!(source.owner_of(1), Some(WRAP_VAULT.to_string()));
+    }
+}